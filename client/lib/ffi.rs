@@ -507,6 +507,7 @@ pub extern "C" fn casper_get_item(
     node_address: *const c_char,
     verbosity_level: u64,
     state_root_hash: *const c_char,
+    maybe_block_id: *const c_char,
     key: *const c_char,
     path: *const c_char,
     response_buf: *mut c_uchar,
@@ -517,6 +518,7 @@ pub extern "C" fn casper_get_item(
     let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
     let node_address = try_unsafe_arg!(node_address);
     let state_root_hash = try_unsafe_arg!(state_root_hash);
+    let maybe_block_id = try_unsafe_arg!(maybe_block_id);
     let key = try_unsafe_arg!(key);
     let path = try_unsafe_arg!(path);
     runtime.block_on(async move {
@@ -525,6 +527,7 @@ pub extern "C" fn casper_get_item(
             node_address,
             verbosity_level,
             state_root_hash,
+            maybe_block_id,
             key,
             path,
         );
@@ -543,6 +546,7 @@ pub extern "C" fn casper_get_balance(
     node_address: *const c_char,
     verbosity_level: u64,
     state_root_hash: *const c_char,
+    maybe_block_id: *const c_char,
     purse: *const c_char,
     response_buf: *mut c_uchar,
     response_buf_len: usize,
@@ -552,6 +556,7 @@ pub extern "C" fn casper_get_balance(
     let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
     let node_address = try_unsafe_arg!(node_address);
     let state_root_hash = try_unsafe_arg!(state_root_hash);
+    let maybe_block_id = try_unsafe_arg!(maybe_block_id);
     let purse = try_unsafe_arg!(purse);
     runtime.block_on(async move {
         let result = super::get_balance(
@@ -559,6 +564,7 @@ pub extern "C" fn casper_get_balance(
             node_address,
             verbosity_level,
             state_root_hash,
+            maybe_block_id,
             purse,
         );
         let response = try_unwrap_rpc!(result);
@@ -597,7 +603,7 @@ pub extern "C" fn casper_get_era_info_by_switch_block(
     })
 }
 
-/// Retrieves the bids and validators as of the most recently added `Block`.
+/// Retrieves the bids and validators as of a given `Block`.
 ///
 /// See [super::get_auction_info](super::get_auction_info) for more details.
 #[no_mangle]
@@ -605,6 +611,7 @@ pub extern "C" fn casper_get_auction_info(
     maybe_rpc_id: *const c_char,
     node_address: *const c_char,
     verbosity_level: u64,
+    maybe_block_id: *const c_char,
     response_buf: *mut c_uchar,
     response_buf_len: usize,
 ) -> casper_error_t {
@@ -612,8 +619,10 @@ pub extern "C" fn casper_get_auction_info(
     let runtime = try_unwrap_option!(&mut *runtime, or_else => Error::FFISetupNotCalled);
     let maybe_rpc_id = try_unsafe_arg!(maybe_rpc_id);
     let node_address = try_unsafe_arg!(node_address);
+    let maybe_block_id = try_unsafe_arg!(maybe_block_id);
     runtime.block_on(async move {
-        let result = super::get_auction_info(maybe_rpc_id, node_address, verbosity_level);
+        let result =
+            super::get_auction_info(maybe_rpc_id, node_address, verbosity_level, maybe_block_id);
         let response = try_unwrap_rpc!(result);
         copy_str_to_buf(&response, response_buf, response_buf_len);
         casper_error_t::CASPER_SUCCESS
@@ -74,6 +74,45 @@ pub fn put_deploy(
     RpcCall::new(maybe_rpc_id, node_address, verbosity_level).put_deploy(deploy)
 }
 
+/// Creates a `Deploy` and has the node execute it without committing its effects, returning the
+/// execution result which includes the cost of the `Deploy`.
+///
+/// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
+///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
+///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is `1`, the JSON-RPC request will be printed to `stdout` with long
+///   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
+///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
+///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
+///   will not be printed to `stdout`.
+/// * `maybe_block_id` must be a hex-encoded, 32-byte hash digest or a `u64` representing the
+///   `Block` height or empty. The `Deploy` is executed against the global state as of this `Block`.
+///   If empty, the latest `Block` will be used.
+/// * `deploy` contains deploy-related options for this `Deploy`. See
+///   [`DeployStrParams`](struct.DeployStrParams.html) for more details.
+/// * `session` contains session-related options for this `Deploy`. See
+///   [`SessionStrParams`](struct.SessionStrParams.html) for more details.
+/// * `payment` contains payment-related options for this `Deploy`. See
+///   [`PaymentStrParams`](struct.PaymentStrParams.html) for more details.
+pub fn estimate_gas(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    maybe_block_id: &str,
+    deploy: DeployStrParams<'_>,
+    session: SessionStrParams<'_>,
+    payment: PaymentStrParams<'_>,
+) -> Result<JsonRpc> {
+    let deploy = Deploy::with_payment_and_session(
+        deploy.try_into()?,
+        payment.try_into()?,
+        session.try_into()?,
+    )?;
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).estimate_gas(deploy, maybe_block_id)
+}
+
 /// Creates a `Deploy` and outputs it to a file or stdout.
 ///
 /// As a file, the `Deploy` can subsequently be signed by other parties using
@@ -242,6 +281,38 @@ pub fn get_deploy(
     RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_deploy(deploy_hash)
 }
 
+/// Retrieves a page of the hashes of `Deploy`s sent by an account, along with the heights of the
+/// `Block`s in which they were executed.
+///
+/// The node must have its account deploy index enabled for this to succeed.
+///
+/// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
+///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
+///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is `1`, the JSON-RPC request will be printed to `stdout` with long
+///   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
+///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
+///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
+///   will not be printed to `stdout`.
+/// * `public_key` must be a hex-encoded [`PublicKey`](https://docs.rs/casper-types/latest/casper_types/enum.PublicKey.html)
+///   of the account.
+/// * `offset` is the number of `Deploy`s to skip, or empty to start from the first one.
+/// * `limit` is the maximum number of `Deploy`s to retrieve, or empty to retrieve as many as the
+///   node allows in a single request.
+pub fn list_account_deploys(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    public_key: &str,
+    offset: &str,
+    limit: &str,
+) -> Result<JsonRpc> {
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level)
+        .list_account_deploys(public_key, offset, limit)
+}
+
 /// Retrieves a `Block` from the network.
 ///
 /// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
@@ -323,7 +394,11 @@ pub fn get_state_root_hash(
 ///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
 ///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
 ///   will not be printed to `stdout`.
-/// * `state_root_hash` must be a hex-encoded, 32-byte hash digest.
+/// * `state_root_hash` must be a hex-encoded, 32-byte hash digest.  It must be empty if
+///   `maybe_block_id` is provided.
+/// * `maybe_block_id` is either the hash or the height of the block whose global state is to be
+///   queried, instead of `state_root_hash`.  Leave empty to query by `state_root_hash`.  The block
+///   is fetched separately to check the state root hash the node ran the query against.
 /// * `key` must be a formatted [`PublicKey`](https://docs.rs/casper-node/latest/casper-node/crypto/asymmetric_key/enum.PublicKey.html)
 ///   or [`Key`](https://docs.rs/casper-types/latest/casper-types/enum.PublicKey.html). This will
 ///   take one of the following forms:
@@ -341,10 +416,50 @@ pub fn get_item(
     node_address: &str,
     verbosity_level: u64,
     state_root_hash: &str,
+    maybe_block_id: &str,
     key: &str,
     path: &str,
 ) -> Result<JsonRpc> {
-    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_item(state_root_hash, key, path)
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_item(
+        state_root_hash,
+        maybe_block_id,
+        key,
+        path,
+    )
+}
+
+/// Retrieves an item from a dictionary stored on the network.
+///
+/// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
+///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
+///   random `i64` will be assigned. Otherwise the provided string will be used verbatim.
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is `1`, the JSON-RPC request will be printed to `stdout` with long
+///   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
+///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
+///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
+///   will not be printed to `stdout`.
+/// * `state_root_hash` must be a hex-encoded, 32-byte hash digest.  It must be empty if
+///   `maybe_block_id` is provided.
+/// * `maybe_block_id` is either the hash or the height of the block whose global state is to be
+///   queried, instead of `state_root_hash`.  Leave empty to query by `state_root_hash`.  The block
+///   is fetched separately to check the state root hash the node ran the query against.
+/// * `dictionary_str_params` identifies the dictionary item to retrieve. See
+///   [`DictionaryItemStrParams`] for details.
+pub fn get_dictionary_item(
+    maybe_rpc_id: &str,
+    node_address: &str,
+    verbosity_level: u64,
+    state_root_hash: &str,
+    maybe_block_id: &str,
+    dictionary_str_params: DictionaryItemStrParams<'_>,
+) -> Result<JsonRpc> {
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_dictionary_item(
+        state_root_hash,
+        maybe_block_id,
+        dictionary_str_params,
+    )
 }
 
 /// Retrieves a purse's balance from the network.
@@ -359,7 +474,11 @@ pub fn get_item(
 ///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
 ///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
 ///   will not be printed to `stdout`.
-/// * `state_root_hash` must be a hex-encoded, 32-byte hash digest.
+/// * `state_root_hash` must be a hex-encoded, 32-byte hash digest.  It must be empty if
+///   `maybe_block_id` is provided.
+/// * `maybe_block_id` is either the hash or the height of the block whose global state is to be
+///   queried, instead of `state_root_hash`.  Leave empty to query by `state_root_hash`.  The block
+///   is fetched separately to check the state root hash the node ran the query against.
 /// * `purse` is a URef, formatted as e.g.
 /// ```text
 /// uref-0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20-007
@@ -369,9 +488,41 @@ pub fn get_balance(
     node_address: &str,
     verbosity_level: u64,
     state_root_hash: &str,
+    maybe_block_id: &str,
     purse: &str,
 ) -> Result<JsonRpc> {
-    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_balance(state_root_hash, purse)
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_balance(
+        state_root_hash,
+        maybe_block_id,
+        purse,
+    )
+}
+
+/// Retrieves the balances of several purses from the network in a single JSON-RPC batch request.
+///
+/// * `node_address` is the hostname or IP and port of the node on which the HTTP service is
+///   running, e.g. `"http://127.0.0.1:7777"`.
+/// * When `verbosity_level` is `1`, the JSON-RPC request will be printed to `stdout` with long
+///   string fields (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char
+///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
+///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
+///   will not be printed to `stdout`.
+/// * `state_root_hash` must be a hex-encoded, 32-byte hash digest.
+/// * `purses` are URefs, each formatted as e.g.
+/// ```text
+/// uref-0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20-007
+/// ```
+///
+/// The JSON-RPC identifier of each request is its index in `purses`, and the responses are
+/// returned in the same order as `purses`.  The node rejects batches larger than its configured
+/// `max_batch_size`, so large numbers of purses should be split across several calls.
+pub fn get_balances(
+    node_address: &str,
+    verbosity_level: u64,
+    state_root_hash: &str,
+    purses: &[&str],
+) -> Result<Vec<JsonRpc>> {
+    RpcCall::new("", node_address, verbosity_level).get_balances(state_root_hash, purses)
 }
 
 /// Retrieves era information from the network.
@@ -399,7 +550,7 @@ pub fn get_era_info_by_switch_block(
         .get_era_info_by_switch_block(maybe_block_id)
 }
 
-/// Retrieves the bids and validators as of the most recently added `Block`.
+/// Retrieves the bids and validators as of a given `Block`.
 ///
 /// * `maybe_rpc_id` is the JSON-RPC identifier, applied to the request and returned in the
 ///   response. If it can be parsed as an `i64` it will be used as a JSON integer. If empty, a
@@ -411,12 +562,16 @@ pub fn get_era_info_by_switch_block(
 ///   count of the field.  When `verbosity_level` is greater than `1`, the request will be printed
 ///   to `stdout` with no abbreviation of long fields.  When `verbosity_level` is `0`, the request
 ///   will not be printed to `stdout`.
+/// * `maybe_block_id` must be a hex-encoded, 32-byte hash digest or a `u64` representing the
+///   `Block` height or empty. If empty, the bids and validators as of the most recently added
+///   `Block` will be returned.
 pub fn get_auction_info(
     maybe_rpc_id: &str,
     node_address: &str,
     verbosity_level: u64,
+    maybe_block_id: &str,
 ) -> Result<JsonRpc> {
-    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_auction_info()
+    RpcCall::new(maybe_rpc_id, node_address, verbosity_level).get_auction_info(maybe_block_id)
 }
 
 /// Retrieves information and examples for all currently supported RPCs.
@@ -908,6 +1063,43 @@ impl<'a> SessionStrParams<'a> {
     }
 }
 
+/// Identifier of an item in a dictionary, used by [`get_dictionary_item`].
+#[derive(Debug)]
+pub enum DictionaryItemStrParams<'a> {
+    /// The dictionary's seed `URef` is stored under `dictionary_name` in an account's named keys.
+    AccountNamedKey {
+        /// The account's formatted `Key::Account`, e.g.
+        /// `account-hash-0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20`.
+        key: &'a str,
+        /// The named key under which the dictionary's seed `URef` is stored.
+        dictionary_name: &'a str,
+        /// The key of the item in the dictionary.
+        dictionary_item_key: &'a str,
+    },
+    /// The dictionary's seed `URef` is stored under `dictionary_name` in a stored contract's named
+    /// keys.
+    ContractNamedKey {
+        /// The contract's formatted `Key::Hash`, e.g.
+        /// `hash-0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20`.
+        key: &'a str,
+        /// The named key under which the dictionary's seed `URef` is stored.
+        dictionary_name: &'a str,
+        /// The key of the item in the dictionary.
+        dictionary_item_key: &'a str,
+    },
+    /// The dictionary is identified by its seed `URef`.
+    URef {
+        /// The dictionary's formatted seed `URef`, e.g.
+        /// `uref-0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20-007`.
+        seed_uref: &'a str,
+        /// The key of the item in the dictionary.
+        dictionary_item_key: &'a str,
+    },
+    /// The item is identified by its formatted `Key::Dictionary`, e.g.
+    /// `dictionary-0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20`.
+    Dictionary(&'a str),
+}
+
 /// When `verbosity_level` is `1`, the value will be printed to `stdout` with long string fields
 /// (e.g. hex-formatted raw Wasm bytes) shortened to a string indicating the char count of the
 /// field.  When `verbosity_level` is greater than `1`, the value will be printed to `stdout` with
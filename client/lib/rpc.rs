@@ -4,7 +4,7 @@ use futures::executor;
 use jsonrpc_lite::{Id, JsonRpc, Params};
 use rand::Rng;
 use reqwest::Client;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Map, Value};
 
 use casper_execution_engine::core::engine_state::ExecutableDeployItem;
@@ -17,8 +17,15 @@ use casper_node::{
             GetEraInfoBySwitchBlock, GetEraInfoParams, GetStateRootHash, GetStateRootHashParams,
         },
         docs::ListRpcs,
-        info::{GetDeploy, GetDeployParams},
-        state::{GetAuctionInfo, GetBalance, GetBalanceParams, GetItem, GetItemParams},
+        info::{
+            GetAccountDeploys, GetAccountDeploysParams, GetDeploy, GetDeployParams,
+            MAX_ACCOUNT_DEPLOYS_LIMIT,
+        },
+        state::{
+            DictionaryIdentifier, GetAuctionInfo, GetAuctionInfoParams, GetBalance,
+            GetBalanceParams, GetDictionaryItem, GetDictionaryItemParams, GetItem, GetItemParams,
+            SpeculativeExec, SpeculativeExecParams,
+        },
         RpcWithOptionalParams, RpcWithParams, RpcWithoutParams, RPC_API_PATH,
     },
     types::{BlockHash, Deploy, DeployHash},
@@ -28,7 +35,7 @@ use casper_types::{AsymmetricType, Key, PublicKey, RuntimeArgs, URef, U512};
 use crate::{
     deploy::{DeployExt, DeployParams, SendDeploy, Transfer},
     error::{Error, Result},
-    validation,
+    validation, DictionaryItemStrParams,
 };
 
 /// Target for a given transfer.
@@ -38,7 +45,7 @@ pub(crate) enum TransferTarget {
 }
 
 /// Struct representing a single JSON-RPC call to the casper node.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct RpcCall {
     rpc_id: Id,
     node_address: String,
@@ -87,12 +94,47 @@ impl RpcCall {
         GetDeploy::request_with_map_params(self, params)
     }
 
-    pub(crate) fn get_item(self, state_root_hash: &str, key: &str, path: &str) -> Result<JsonRpc> {
-        let state_root_hash =
-            Digest::from_hex(state_root_hash).map_err(|error| Error::CryptoError {
-                context: "state_root_hash",
-                error,
-            })?;
+    pub(crate) fn list_account_deploys(
+        self,
+        public_key: &str,
+        offset: &str,
+        limit: &str,
+    ) -> Result<JsonRpc> {
+        let public_key = PublicKey::from_hex(public_key).map_err(|error| Error::CryptoError {
+            context: "public_key",
+            error: error.into(),
+        })?;
+        let offset = if offset.is_empty() {
+            0
+        } else {
+            offset
+                .parse()
+                .map_err(|error| Error::FailedToParseInt("offset", error))?
+        };
+        let limit = if limit.is_empty() {
+            MAX_ACCOUNT_DEPLOYS_LIMIT
+        } else {
+            limit
+                .parse()
+                .map_err(|error| Error::FailedToParseInt("limit", error))?
+        };
+        let params = GetAccountDeploysParams {
+            public_key,
+            offset,
+            limit,
+        };
+        GetAccountDeploys::request_with_map_params(self, params)
+    }
+
+    pub(crate) fn get_item(
+        self,
+        state_root_hash: &str,
+        maybe_block_identifier: &str,
+        key: &str,
+        path: &str,
+    ) -> Result<JsonRpc> {
+        let (maybe_state_root_hash, maybe_block_identifier) =
+            Self::state_identifier(state_root_hash, maybe_block_identifier)?;
 
         let key = {
             if let Ok(key) = Key::from_formatted_str(key) {
@@ -111,15 +153,97 @@ impl RpcCall {
         };
 
         let params = GetItemParams {
-            state_root_hash,
+            state_root_hash: maybe_state_root_hash,
+            block_identifier: maybe_block_identifier,
             key: key.to_formatted_string(),
             path: path.clone(),
         };
-        let response = GetItem::request_with_map_params(self, params)?;
+        let response = GetItem::request_with_map_params(self.clone(), params)?;
+        let state_root_hash = self.resolved_state_root_hash(
+            &response,
+            maybe_state_root_hash,
+            maybe_block_identifier,
+        )?;
         validation::validate_query_response(&response, &state_root_hash, &key, &path)?;
         Ok(response)
     }
 
+    pub(crate) fn get_dictionary_item(
+        self,
+        state_root_hash: &str,
+        maybe_block_identifier: &str,
+        dictionary_str_params: DictionaryItemStrParams<'_>,
+    ) -> Result<JsonRpc> {
+        let (maybe_state_root_hash, maybe_block_identifier) =
+            Self::state_identifier(state_root_hash, maybe_block_identifier)?;
+
+        let (dictionary_identifier, maybe_expected_key) = match dictionary_str_params {
+            DictionaryItemStrParams::AccountNamedKey {
+                key,
+                dictionary_name,
+                dictionary_item_key,
+            } => {
+                let identifier = DictionaryIdentifier::AccountNamedKey {
+                    key: key.to_string(),
+                    dictionary_name: dictionary_name.to_string(),
+                    dictionary_item_key: dictionary_item_key.to_string(),
+                };
+                (identifier, None)
+            }
+            DictionaryItemStrParams::ContractNamedKey {
+                key,
+                dictionary_name,
+                dictionary_item_key,
+            } => {
+                let identifier = DictionaryIdentifier::ContractNamedKey {
+                    key: key.to_string(),
+                    dictionary_name: dictionary_name.to_string(),
+                    dictionary_item_key: dictionary_item_key.to_string(),
+                };
+                (identifier, None)
+            }
+            DictionaryItemStrParams::URef {
+                seed_uref,
+                dictionary_item_key,
+            } => {
+                let uref = URef::from_formatted_str(seed_uref)
+                    .map_err(|error| Error::FailedToParseURef("seed_uref", error))?;
+                let key = Key::dictionary(uref, dictionary_item_key.as_bytes());
+                let identifier = DictionaryIdentifier::URef {
+                    seed_uref: seed_uref.to_string(),
+                    dictionary_item_key: dictionary_item_key.to_string(),
+                };
+                (identifier, Some(key))
+            }
+            DictionaryItemStrParams::Dictionary(dictionary_key) => {
+                let key = match Key::from_formatted_str(dictionary_key) {
+                    Ok(key @ Key::Dictionary(_)) => key,
+                    _ => return Err(Error::FailedToParseKey),
+                };
+                let identifier = DictionaryIdentifier::Dictionary(dictionary_key.to_string());
+                (identifier, Some(key))
+            }
+        };
+
+        let params = GetDictionaryItemParams {
+            state_root_hash: maybe_state_root_hash,
+            block_identifier: maybe_block_identifier,
+            dictionary_identifier,
+        };
+        let response = GetDictionaryItem::request_with_map_params(self.clone(), params)?;
+        let state_root_hash = self.resolved_state_root_hash(
+            &response,
+            maybe_state_root_hash,
+            maybe_block_identifier,
+        )?;
+        validation::validate_get_dictionary_item_response(
+            &response,
+            &state_root_hash,
+            maybe_expected_key.as_ref(),
+        )?;
+        Ok(response)
+    }
+
     pub(crate) fn get_state_root_hash(self, maybe_block_identifier: &str) -> Result<JsonRpc> {
         match Self::block_identifier(maybe_block_identifier)? {
             Some(block_identifier) => {
@@ -130,25 +254,72 @@ impl RpcCall {
         }
     }
 
-    pub(crate) fn get_balance(self, state_root_hash: &str, purse_uref: &str) -> Result<JsonRpc> {
-        let state_root_hash =
-            Digest::from_hex(state_root_hash).map_err(|error| Error::CryptoError {
-                context: "state_root_hash",
-                error,
-            })?;
+    pub(crate) fn get_balance(
+        self,
+        state_root_hash: &str,
+        maybe_block_identifier: &str,
+        purse_uref: &str,
+    ) -> Result<JsonRpc> {
+        let (maybe_state_root_hash, maybe_block_identifier) =
+            Self::state_identifier(state_root_hash, maybe_block_identifier)?;
         let uref = URef::from_formatted_str(purse_uref)
             .map_err(|error| Error::FailedToParseURef("purse_uref", error))?;
         let key = Key::from(uref);
 
         let params = GetBalanceParams {
-            state_root_hash,
+            state_root_hash: maybe_state_root_hash,
+            block_identifier: maybe_block_identifier,
             purse_uref: purse_uref.to_string(),
         };
-        let response = GetBalance::request_with_map_params(self, params)?;
+        let response = GetBalance::request_with_map_params(self.clone(), params)?;
+        let state_root_hash = self.resolved_state_root_hash(
+            &response,
+            maybe_state_root_hash,
+            maybe_block_identifier,
+        )?;
         validation::validate_get_balance_response(&response, &state_root_hash, &key)?;
         Ok(response)
     }
 
+    pub(crate) fn get_balances(
+        self,
+        state_root_hash: &str,
+        purse_urefs: &[&str],
+    ) -> Result<Vec<JsonRpc>> {
+        let state_root_hash =
+            Digest::from_hex(state_root_hash).map_err(|error| Error::CryptoError {
+                context: "state_root_hash",
+                error,
+            })?;
+        let mut keys = Vec::with_capacity(purse_urefs.len());
+        let mut requests = Vec::with_capacity(purse_urefs.len());
+        for purse_uref in purse_urefs {
+            let uref = URef::from_formatted_str(purse_uref)
+                .map_err(|error| Error::FailedToParseURef("purse_uref", error))?;
+            keys.push(Key::from(uref));
+            let params = GetBalanceParams {
+                state_root_hash: Some(state_root_hash),
+                block_identifier: None,
+                purse_uref: purse_uref.to_string(),
+            };
+            requests.push((GetBalance::METHOD, Params::from(params.into_json_map())));
+        }
+
+        let responses = executor::block_on(self.batch_request(requests))?;
+        for response in &responses {
+            if response.get_result().is_none() {
+                continue;
+            }
+            let key = match response.get_id() {
+                Some(Id::Num(index)) => keys.get(index as usize),
+                _ => None,
+            }
+            .ok_or_else(|| Error::InvalidRpcResponse(response.clone()))?;
+            validation::validate_get_balance_response(response, &state_root_hash, key)?;
+        }
+        Ok(responses)
+    }
+
     pub(crate) fn get_era_info_by_switch_block(
         self,
         maybe_block_identifier: &str,
@@ -164,8 +335,16 @@ impl RpcCall {
         Ok(response)
     }
 
-    pub(crate) fn get_auction_info(self) -> Result<JsonRpc> {
-        GetAuctionInfo::request(self)
+    pub(crate) fn get_auction_info(self, maybe_block_identifier: &str) -> Result<JsonRpc> {
+        let response = match Self::block_identifier(maybe_block_identifier)? {
+            None => GetAuctionInfo::request(self),
+            Some(block_identifier) => {
+                let params = GetAuctionInfoParams { block_identifier };
+                GetAuctionInfo::request_with_map_params(self, params)
+            }
+        }?;
+        validation::validate_get_auction_info_response(&response)?;
+        Ok(response)
     }
 
     pub(crate) fn list_rpcs(self) -> Result<JsonRpc> {
@@ -221,6 +400,19 @@ impl RpcCall {
         PutDeploy::request_with_map_params(self, params)
     }
 
+    pub(crate) fn estimate_gas(
+        self,
+        deploy: Deploy,
+        maybe_block_identifier: &str,
+    ) -> Result<JsonRpc> {
+        let params = SpeculativeExecParams {
+            state_root_hash: None,
+            block_identifier: Self::block_identifier(maybe_block_identifier)?,
+            deploy,
+        };
+        SpeculativeExec::request_with_map_params(self, params)
+    }
+
     pub(crate) fn get_block(self, maybe_block_identifier: &str) -> Result<JsonRpc> {
         let maybe_block_identifier = Self::block_identifier(maybe_block_identifier)?;
         let response = match maybe_block_identifier {
@@ -246,6 +438,57 @@ impl RpcCall {
         Ok(response)
     }
 
+    /// Returns the state root hash against which the query in `response` was run.  If the query was
+    /// made by block identifier, the identified block is fetched and the state root hash in its
+    /// header must match.
+    fn resolved_state_root_hash(
+        self,
+        response: &JsonRpc,
+        maybe_state_root_hash: Option<Digest>,
+        maybe_block_identifier: Option<BlockIdentifier>,
+    ) -> Result<Digest> {
+        let state_root_hash =
+            validation::resolved_state_root_hash(response, maybe_state_root_hash)?;
+        if let Some(block_identifier) = maybe_block_identifier {
+            let params = GetBlockParams { block_identifier };
+            let block_response = GetBlock::request_with_map_params(self, params)?;
+            validation::validate_block_state_root_hash(
+                &block_response,
+                block_identifier,
+                &state_root_hash,
+            )?;
+        }
+        Ok(state_root_hash)
+    }
+
+    /// Parses the state root hash if no block identifier is given, or the block identifier
+    /// otherwise.  It is an error to provide both.
+    fn state_identifier(
+        state_root_hash: &str,
+        maybe_block_identifier: &str,
+    ) -> Result<(Option<Digest>, Option<BlockIdentifier>)> {
+        if maybe_block_identifier.is_empty() {
+            let state_root_hash =
+                Digest::from_hex(state_root_hash).map_err(|error| Error::CryptoError {
+                    context: "state_root_hash",
+                    error,
+                })?;
+            return Ok((Some(state_root_hash), None));
+        }
+
+        if !state_root_hash.is_empty() {
+            return Err(Error::ConflictingArguments {
+                context: "state_identifier",
+                args: vec![
+                    format!("state_root_hash={}", state_root_hash),
+                    format!("block_identifier={}", maybe_block_identifier),
+                ],
+            });
+        }
+
+        Ok((None, Self::block_identifier(maybe_block_identifier)?))
+    }
+
     fn block_identifier(maybe_block_identifier: &str) -> Result<Option<BlockIdentifier>> {
         if maybe_block_identifier.is_empty() {
             return Ok(None);
@@ -317,6 +560,82 @@ impl RpcCall {
         }
         Err(Error::InvalidRpcResponse(rpc_response))
     }
+
+    /// Sends the given requests to the node in a single JSON-RPC batch.
+    ///
+    /// The requests are assigned IDs matching their indices in `requests`, and the responses are
+    /// returned in the same order.  Individual responses may be errors; an error is only returned
+    /// if the batch as a whole fails.
+    async fn batch_request(self, requests: Vec<(&str, Params)>) -> Result<Vec<JsonRpc>> {
+        if requests.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let url = format!("{}/{}", self.node_address, RPC_API_PATH);
+        let rpc_reqs: Vec<JsonRpc> = requests
+            .into_iter()
+            .enumerate()
+            .map(|(index, (method, params))| {
+                JsonRpc::request_with_params(Id::from(index as i64), method, params)
+            })
+            .collect();
+
+        crate::pretty_print_at_level(&rpc_reqs, self.verbosity_level);
+
+        let client = Client::new();
+        let response = client
+            .post(&url)
+            .json(&rpc_reqs)
+            .send()
+            .await
+            .map_err(Error::FailedToGetResponse)?;
+
+        if let Err(error) = response.error_for_status_ref() {
+            if self.verbosity_level > 0 {
+                println!("Failed Sending {}", error);
+            }
+            return Err(Error::FailedToGetResponse(error));
+        }
+
+        let batch_response = response
+            .json::<BatchResponse>()
+            .await
+            .map_err(Error::FailedToParseResponse)?;
+
+        match batch_response {
+            BatchResponse::Batch(mut rpc_responses) => {
+                if self.verbosity_level > 0 {
+                    println!("Received {} responses:", rpc_responses.len());
+                }
+                rpc_responses.sort_by_key(|rpc_response| match rpc_response.get_id() {
+                    Some(Id::Num(index)) => index,
+                    _ => i64::MAX,
+                });
+                Ok(rpc_responses)
+            }
+            BatchResponse::Single(rpc_response) => {
+                if let Some(error) = rpc_response.get_error() {
+                    if self.verbosity_level > 0 {
+                        println!("Batch returned an error");
+                    }
+                    return Err(Error::ResponseIsError(error.clone()));
+                }
+                if self.verbosity_level > 0 {
+                    println!("Invalid response returned");
+                }
+                Err(Error::InvalidRpcResponse(rpc_response))
+            }
+        }
+    }
+}
+
+/// The response to a batch request.  The node replies with a single response rather than an array
+/// if the batch as a whole is rejected, e.g. for being too large.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BatchResponse {
+    Batch(Vec<JsonRpc>),
+    Single(JsonRpc),
 }
 
 /// General purpose client trait for making requests to casper node's HTTP endpoints.
@@ -358,6 +677,10 @@ impl RpcClient for GetItem {
     const RPC_METHOD: &'static str = <Self as RpcWithParams>::METHOD;
 }
 
+impl RpcClient for GetDictionaryItem {
+    const RPC_METHOD: &'static str = Self::METHOD;
+}
+
 impl RpcClient for GetEraInfoBySwitchBlock {
     const RPC_METHOD: &'static str = Self::METHOD;
 }
@@ -366,6 +689,14 @@ impl RpcClient for GetAuctionInfo {
     const RPC_METHOD: &'static str = Self::METHOD;
 }
 
+impl RpcClient for GetAccountDeploys {
+    const RPC_METHOD: &'static str = Self::METHOD;
+}
+
+impl RpcClient for SpeculativeExec {
+    const RPC_METHOD: &'static str = Self::METHOD;
+}
+
 impl RpcClient for ListRpcs {
     const RPC_METHOD: &'static str = Self::METHOD;
 }
@@ -387,7 +718,11 @@ impl IntoJsonMap for GetBlockParams {}
 impl IntoJsonMap for GetBlockTransfersParams {}
 impl IntoJsonMap for GetStateRootHashParams {}
 impl IntoJsonMap for GetDeployParams {}
+impl IntoJsonMap for GetAccountDeploysParams {}
 impl IntoJsonMap for GetBalanceParams {}
 impl IntoJsonMap for GetItemParams {}
+impl IntoJsonMap for GetDictionaryItemParams {}
 impl IntoJsonMap for GetEraInfoParams {}
+impl IntoJsonMap for GetAuctionInfoParams {}
+impl IntoJsonMap for SpeculativeExecParams {}
 impl IntoJsonMap for ListRpcs {}
@@ -1,22 +1,32 @@
-use std::convert::TryFrom;
+use std::{collections::BTreeMap, convert::TryFrom};
 
 use jsonrpc_lite::JsonRpc;
 use thiserror::Error;
 
-use casper_execution_engine::{
-    core, core::ValidationError, shared::stored_value::StoredValue,
-    storage::trie::merkle_proof::TrieMerkleProof,
-};
+use casper_execution_engine::shared::stored_value::StoredValue;
 use casper_node::{
     crypto::hash::Digest,
-    rpcs::chain::{BlockIdentifier, EraSummary, GetEraInfoResult},
-    types::{json_compatibility, Block, BlockValidationError, JsonBlock},
+    rpcs::{
+        chain::{BlockIdentifier, EraSummary, GetEraInfoResult},
+        state::GetAuctionInfoResult,
+    },
+    types::{
+        json_compatibility::{self, AuctionState},
+        Block, BlockValidationError, JsonBlock,
+    },
+};
+use casper_types::{
+    bytesrepr,
+    merkle_proof::{self, TrieMerkleProof, TrieMerkleProofBatch, ValidationError},
+    system::auction::{EraValidators, ValidatorWeights, AUCTION_DELAY_KEY, ERA_ID_KEY},
+    CLValue, Contract, EraId, Key, U512,
 };
-use casper_types::{bytesrepr, Key, U512};
 
 const GET_ITEM_RESULT_BALANCE_VALUE: &str = "balance_value";
 const GET_ITEM_RESULT_STORED_VALUE: &str = "stored_value";
 const GET_ITEM_RESULT_MERKLE_PROOF: &str = "merkle_proof";
+const GET_ITEM_RESULT_STATE_ROOT_HASH: &str = "state_root_hash";
+const GET_DICTIONARY_ITEM_RESULT_DICTIONARY_KEY: &str = "dictionary_key";
 
 /// Error that can be returned when validating a block returned from a JSON-RPC method.
 #[derive(Error, Debug)]
@@ -56,6 +66,18 @@ pub enum ValidateResponseError {
     /// Block height was not as requested.
     #[error("block height was not as requested")]
     UnexpectedBlockHeight,
+
+    /// Auction state does not correspond to the proven global state entries.
+    #[error("auction state does not correspond to the proven global state entries")]
+    UnexpectedAuctionState,
+
+    /// State root hash in response does not correspond to the one requested.
+    #[error("state root hash in response does not correspond to the one requested")]
+    UnexpectedStateRootHash,
+
+    /// Dictionary key does not correspond to the requested dictionary item.
+    #[error("dictionary key does not correspond to the requested dictionary item")]
+    UnexpectedDictionaryKey,
 }
 
 impl From<bytesrepr::Error> for ValidateResponseError {
@@ -70,6 +92,25 @@ impl From<BlockValidationError> for ValidateResponseError {
     }
 }
 
+/// Returns the state root hash against which the query in `response` was run, checking that it is
+/// `maybe_requested_state_root_hash` if that was provided.
+pub(crate) fn resolved_state_root_hash(
+    response: &JsonRpc,
+    maybe_requested_state_root_hash: Option<Digest>,
+) -> Result<Digest, ValidateResponseError> {
+    let value = response
+        .get_result()
+        .and_then(|value| value.get(GET_ITEM_RESULT_STATE_ROOT_HASH))
+        .ok_or(ValidateResponseError::ValidateResponseFailedToParse)?;
+    let state_root_hash: Digest = serde_json::from_value(value.to_owned())?;
+    match maybe_requested_state_root_hash {
+        Some(requested_state_root_hash) if requested_state_root_hash != state_root_hash => {
+            Err(ValidateResponseError::UnexpectedStateRootHash)
+        }
+        _ => Ok(state_root_hash),
+    }
+}
+
 pub(crate) fn validate_get_era_info_response(
     response: &JsonRpc,
 ) -> Result<(), ValidateResponseError> {
@@ -101,7 +142,7 @@ pub(crate) fn validate_get_era_info_response(
                 _ => return Err(ValidateResponseError::ValidateResponseFailedToParse),
             };
 
-            core::validate_query_proof(
+            merkle_proof::validate_query_proof(
                 &state_root_hash.to_owned().into(),
                 &proofs,
                 &key,
@@ -114,6 +155,94 @@ pub(crate) fn validate_get_era_info_response(
     }
 }
 
+pub(crate) fn validate_get_auction_info_response(
+    response: &JsonRpc,
+) -> Result<(), ValidateResponseError> {
+    let value = response
+        .get_result()
+        .ok_or(ValidateResponseError::ValidateResponseFailedToParse)?;
+
+    let result: GetAuctionInfoResult = serde_json::from_value(value.to_owned())?;
+    let auction_state = result.auction_state;
+
+    let proof_bytes = hex::decode(result.merkle_proof)
+        .map_err(|_| ValidateResponseError::ValidateResponseFailedToParse)?;
+    let proofs: TrieMerkleProofBatch<Key, StoredValue> = bytesrepr::deserialize(proof_bytes)?;
+
+    merkle_proof::validate_batch_proof(&auction_state.state_root_hash.into(), &proofs)?;
+
+    let mut contracts: Vec<Contract> = Vec::new();
+    let mut cl_values: BTreeMap<Key, CLValue> = BTreeMap::new();
+    let mut proven_era_validators = EraValidators::new();
+    let mut bids = BTreeMap::new();
+    for proof in proofs.proofs() {
+        let (key, value, _) = proof.into_parts();
+        match (key, value) {
+            (Key::EraValidators(era_id), StoredValue::EraValidators(recipients)) => {
+                let validator_weights = recipients
+                    .into_iter()
+                    .map(|(public_key, recipient)| (public_key, recipient.total_stake()))
+                    .collect::<ValidatorWeights>();
+                proven_era_validators.insert(era_id, validator_weights);
+            }
+            (Key::Bid(_), StoredValue::Bid(bid)) => {
+                bids.insert(bid.validator_public_key().clone(), *bid);
+            }
+            (Key::Hash(_), StoredValue::Contract(contract)) => contracts.push(contract),
+            (key @ Key::URef(_), StoredValue::CLValue(cl_value)) => {
+                cl_values.insert(key.normalize(), cl_value);
+            }
+            _ => return Err(ValidateResponseError::UnexpectedAuctionState),
+        }
+    }
+
+    // Only the eras from the current one up to the auction delay are reported, but every bid is.
+    // The reported eras are derived from the proven auction contract's era id and auction delay
+    // rather than taken from the response.
+    let read_auction_value = |contract: &Contract, name: &str| {
+        contract
+            .named_keys()
+            .get(name)
+            .and_then(|key| cl_values.get(&key.normalize()))
+            .cloned()
+    };
+    let mut auction_values = contracts.iter().filter_map(|contract| {
+        let era_id = read_auction_value(contract, ERA_ID_KEY)?;
+        let auction_delay = read_auction_value(contract, AUCTION_DELAY_KEY)?;
+        Some((era_id, auction_delay))
+    });
+    let (era_id, auction_delay) = match (auction_values.next(), auction_values.next()) {
+        (Some(auction_values), None) => auction_values,
+        _ => return Err(ValidateResponseError::UnexpectedAuctionState),
+    };
+    let era_id: EraId = era_id
+        .into_t()
+        .map_err(|_| ValidateResponseError::UnexpectedAuctionState)?;
+    let auction_delay: u64 = auction_delay
+        .into_t()
+        .map_err(|_| ValidateResponseError::UnexpectedAuctionState)?;
+
+    let mut era_validators = EraValidators::new();
+    for era_id in era_id.iter_inclusive(auction_delay) {
+        let validator_weights = proven_era_validators
+            .remove(&era_id)
+            .ok_or(ValidateResponseError::UnexpectedAuctionState)?;
+        era_validators.insert(era_id, validator_weights);
+    }
+
+    let proven_auction_state = AuctionState::new(
+        auction_state.state_root_hash,
+        auction_state.block_height,
+        Some(era_validators),
+        Some(bids),
+    );
+    if proven_auction_state != auction_state {
+        return Err(ValidateResponseError::UnexpectedAuctionState);
+    }
+
+    Ok(())
+}
+
 pub(crate) fn validate_query_response(
     response: &JsonRpc,
     state_root_hash: &Digest,
@@ -164,7 +293,7 @@ pub(crate) fn validate_query_response(
         }
     }
 
-    core::validate_query_proof(
+    merkle_proof::validate_query_proof(
         &state_root_hash.to_owned().into(),
         &proofs,
         key,
@@ -174,6 +303,30 @@ pub(crate) fn validate_query_response(
     .map_err(Into::into)
 }
 
+pub(crate) fn validate_get_dictionary_item_response(
+    response: &JsonRpc,
+    state_root_hash: &Digest,
+    maybe_expected_key: Option<&Key>,
+) -> Result<(), ValidateResponseError> {
+    let key = {
+        let value = response
+            .get_result()
+            .and_then(|value| value.get(GET_DICTIONARY_ITEM_RESULT_DICTIONARY_KEY))
+            .and_then(|value| value.as_str())
+            .ok_or(ValidateResponseError::ValidateResponseFailedToParse)?;
+        Key::from_formatted_str(value)
+            .map_err(|_| ValidateResponseError::ValidateResponseFailedToParse)?
+    };
+
+    match (key, maybe_expected_key) {
+        (Key::Dictionary(_), None) => (),
+        (Key::Dictionary(_), Some(expected_key)) if key == *expected_key => (),
+        _ => return Err(ValidateResponseError::UnexpectedDictionaryKey),
+    }
+
+    validate_query_response(response, state_root_hash, &key, &[])
+}
+
 pub(crate) fn validate_get_balance_response(
     response: &JsonRpc,
     state_root_hash: &Digest,
@@ -210,7 +363,7 @@ pub(crate) fn validate_get_balance_response(
             .map_err(|_| ValidateResponseError::ValidateResponseFailedToParse)?
     };
 
-    core::validate_balance_proof(
+    merkle_proof::validate_balance_proof(
         &state_root_hash.to_owned().into(),
         &balance_proof,
         *key,
@@ -219,6 +372,26 @@ pub(crate) fn validate_get_balance_response(
     .map_err(Into::into)
 }
 
+/// Checks that the block in `response` is the one identified by `block_identifier`, and that its
+/// header holds `state_root_hash`.
+pub(crate) fn validate_block_state_root_hash(
+    response: &JsonRpc,
+    block_identifier: BlockIdentifier,
+    state_root_hash: &Digest,
+) -> Result<(), ValidateResponseError> {
+    validate_get_block_response(response, &Some(block_identifier))?;
+    let json_block_value = response
+        .get_result()
+        .and_then(|value| value.get("block"))
+        .ok_or(ValidateResponseError::NoBlockInResponse)?;
+    let json_block: Option<JsonBlock> = serde_json::from_value(json_block_value.to_owned())?;
+    let block = Block::from(json_block.ok_or(ValidateResponseError::NoBlockInResponse)?);
+    if block.state_root_hash() != state_root_hash {
+        return Err(ValidateResponseError::UnexpectedStateRootHash);
+    }
+    Ok(())
+}
+
 pub(crate) fn validate_get_block_response(
     response: &JsonRpc,
     maybe_block_identifier: &Option<BlockIdentifier>,
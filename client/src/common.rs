@@ -142,7 +142,7 @@ pub mod force {
 pub mod state_root_hash {
     use super::*;
 
-    const ARG_NAME: &str = "state-root-hash";
+    pub(crate) const ARG_NAME: &str = "state-root-hash";
     const ARG_SHORT: &str = "s";
     const ARG_VALUE_NAME: &str = super::ARG_HEX_STRING;
     const ARG_HELP: &str = "Hex-encoded hash of the state root";
@@ -158,9 +158,7 @@ pub mod state_root_hash {
     }
 
     pub(crate) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
-        matches
-            .value_of(ARG_NAME)
-            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME))
+        matches.value_of(ARG_NAME).unwrap_or_default()
     }
 }
 
@@ -168,7 +166,7 @@ pub mod state_root_hash {
 pub mod block_identifier {
     use super::*;
 
-    const ARG_NAME: &str = "block-identifier";
+    pub(crate) const ARG_NAME: &str = "block-identifier";
     const ARG_SHORT: &str = "b";
     const ARG_VALUE_NAME: &str = "HEX STRING OR INTEGER";
     const ARG_HELP: &str =
@@ -189,3 +187,58 @@ pub mod block_identifier {
         matches.value_of(ARG_NAME).unwrap_or_default()
     }
 }
+
+/// Handles providing the arg for and retrieval of the public key.
+pub mod public_key {
+    use std::fs;
+
+    use casper_client::Error;
+    use casper_node::crypto::AsymmetricKeyExt;
+    use casper_types::{AsymmetricType, PublicKey};
+
+    use super::*;
+
+    const ARG_NAME: &str = "public-key";
+    const ARG_SHORT: &str = "p";
+    const ARG_VALUE_NAME: &str = "FORMATTED STRING or PATH";
+    const ARG_HELP: &str =
+        "This must be a properly formatted public key. The public key may instead be read in from \
+        a file, in which case enter the path to the file as the --public-key argument. The file \
+        should be one of the two public key files generated via the `keygen` subcommand; \
+        \"public_key_hex\" or \"public_key.pem\"";
+
+    pub(crate) fn arg(order: usize) -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .short(ARG_SHORT)
+            .required(true)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(order)
+    }
+
+    pub(crate) fn get(matches: &ArgMatches) -> Result<String, Error> {
+        let value = matches
+            .value_of(ARG_NAME)
+            .unwrap_or_else(|| panic!("should have {} arg", ARG_NAME));
+
+        // Try to read as a PublicKey PEM file first.
+        if let Ok(public_key) = PublicKey::from_file(value) {
+            return Ok(public_key.to_hex());
+        }
+
+        // Try to read as a hex-encoded PublicKey file next.
+        if let Ok(hex_public_key) = fs::read_to_string(value) {
+            let _ = PublicKey::from_hex(&hex_public_key).map_err(|error| {
+                eprintln!(
+                    "Can't parse the contents of {} as a public key: {}",
+                    value, error
+                );
+                Error::FailedToParseKey
+            })?;
+            return Ok(hex_public_key);
+        }
+
+        Ok(value.to_string())
+    }
+}
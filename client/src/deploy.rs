@@ -1,4 +1,5 @@
 mod creation_common;
+mod estimate_gas;
 mod get;
 mod list;
 mod make;
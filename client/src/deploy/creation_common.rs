@@ -15,6 +15,7 @@ pub(super) enum DisplayOrder {
     Verbose,
     NodeAddress,
     RpcId,
+    BlockIdentifier,
     SecretKey,
     Input,
     Output,
@@ -1,18 +1,21 @@
 use std::str;
 
-use clap::{App, Arg, ArgMatches, SubCommand};
+use clap::{App, Arg, ArgGroup, ArgMatches, SubCommand};
 
 use casper_client::Error;
 use casper_node::rpcs::state::GetBalance;
 
 use crate::{command::ClientCommand, common, Success};
 
+const STATE_IDENTIFIER_GROUP: &str = "state-identifier";
+
 /// This struct defines the order in which the args are shown for this subcommand's help message.
 enum DisplayOrder {
     Verbose,
     NodeAddress,
     RpcId,
     StateRootHash,
+    BlockIdentifier,
     PurseURef,
 }
 
@@ -57,10 +60,17 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetBalance {
                 DisplayOrder::NodeAddress as usize,
             ))
             .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
-            .arg(common::state_root_hash::arg(
-                DisplayOrder::StateRootHash as usize,
+            .arg(common::state_root_hash::arg(DisplayOrder::StateRootHash as usize).required(false))
+            .arg(common::block_identifier::arg(
+                DisplayOrder::BlockIdentifier as usize,
             ))
             .arg(purse_uref::arg())
+            .group(
+                ArgGroup::with_name(STATE_IDENTIFIER_GROUP)
+                    .arg(common::state_root_hash::ARG_NAME)
+                    .arg(common::block_identifier::ARG_NAME)
+                    .required(true),
+            )
     }
 
     fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
@@ -68,6 +78,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetBalance {
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
         let state_root_hash = common::state_root_hash::get(&matches);
+        let maybe_block_id = common::block_identifier::get(&matches);
         let purse_uref = purse_uref::get(&matches);
 
         casper_client::get_balance(
@@ -75,6 +86,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetBalance {
             node_address,
             verbosity_level,
             state_root_hash,
+            maybe_block_id,
             purse_uref,
         )
         .map(Success::from)
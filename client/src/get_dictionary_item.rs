@@ -0,0 +1,256 @@
+use clap::{App, Arg, ArgGroup, ArgMatches, SubCommand};
+
+use casper_client::{DictionaryItemStrParams, Error};
+use casper_node::rpcs::state::GetDictionaryItem;
+
+use crate::{command::ClientCommand, common, Success};
+
+const STATE_IDENTIFIER_GROUP: &str = "state-identifier";
+const DICTIONARY_IDENTIFIER_GROUP: &str = "dictionary-identifier";
+
+/// This struct defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    Verbose,
+    NodeAddress,
+    RpcId,
+    StateRootHash,
+    BlockIdentifier,
+    AccountHash,
+    ContractHash,
+    SeedURef,
+    DictionaryAddress,
+    DictionaryName,
+    DictionaryItemKey,
+}
+
+/// Handles providing the arg for and retrieval of the account hash.
+mod account_hash {
+    use super::*;
+
+    pub(super) const ARG_NAME: &str = "account-hash";
+    const ARG_VALUE_NAME: &str = "FORMATTED STRING";
+    const ARG_HELP: &str =
+        "The account hash of the account whose named keys contain the dictionary's seed URef, \
+        formatted as \"account-hash-<HEX STRING>\"";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::AccountHash as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> Option<&'a str> {
+        matches.value_of(ARG_NAME)
+    }
+}
+
+/// Handles providing the arg for and retrieval of the contract hash.
+mod contract_hash {
+    use super::*;
+
+    pub(super) const ARG_NAME: &str = "contract-hash";
+    const ARG_VALUE_NAME: &str = "FORMATTED STRING";
+    const ARG_HELP: &str =
+        "The hash of the stored contract whose named keys contain the dictionary's seed URef, \
+        formatted as \"hash-<HEX STRING>\"";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::ContractHash as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> Option<&'a str> {
+        matches.value_of(ARG_NAME)
+    }
+}
+
+/// Handles providing the arg for and retrieval of the dictionary's seed URef.
+mod seed_uref {
+    use super::*;
+
+    pub(super) const ARG_NAME: &str = "seed-uref";
+    const ARG_VALUE_NAME: &str = "FORMATTED STRING";
+    const ARG_HELP: &str = "The dictionary's seed URef, formatted as e.g. \
+        uref-0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f20-007";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::SeedURef as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> Option<&'a str> {
+        matches.value_of(ARG_NAME)
+    }
+}
+
+/// Handles providing the arg for and retrieval of the dictionary item's address.
+mod dictionary_address {
+    use super::*;
+
+    pub(super) const ARG_NAME: &str = "dictionary-address";
+    const ARG_VALUE_NAME: &str = "FORMATTED STRING";
+    const ARG_HELP: &str =
+        "The dictionary item's address, formatted as \"dictionary-<HEX STRING>\"";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::DictionaryAddress as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> Option<&'a str> {
+        matches.value_of(ARG_NAME)
+    }
+}
+
+/// Handles providing the arg for and retrieval of the dictionary's name.
+mod dictionary_name {
+    use super::*;
+
+    const ARG_NAME: &str = "dictionary-name";
+    const ARG_VALUE_NAME: &str = "STRING";
+    const ARG_HELP: &str =
+        "The named key under which the dictionary's seed URef is stored. Required with \
+        --account-hash or --contract-hash";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required_unless_one(&[seed_uref::ARG_NAME, dictionary_address::ARG_NAME])
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::DictionaryName as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the dictionary item's key.
+mod dictionary_item_key {
+    use super::*;
+
+    const ARG_NAME: &str = "dictionary-item-key";
+    const ARG_VALUE_NAME: &str = "STRING";
+    const ARG_HELP: &str =
+        "The key of the item in the dictionary. Not required with --dictionary-address";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required_unless(dictionary_address::ARG_NAME)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::DictionaryItemKey as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+fn get_dictionary_str_params<'a>(matches: &'a ArgMatches) -> DictionaryItemStrParams<'a> {
+    let dictionary_name = dictionary_name::get(matches);
+    let dictionary_item_key = dictionary_item_key::get(matches);
+
+    if let Some(key) = account_hash::get(matches) {
+        DictionaryItemStrParams::AccountNamedKey {
+            key,
+            dictionary_name,
+            dictionary_item_key,
+        }
+    } else if let Some(key) = contract_hash::get(matches) {
+        DictionaryItemStrParams::ContractNamedKey {
+            key,
+            dictionary_name,
+            dictionary_item_key,
+        }
+    } else if let Some(seed_uref) = seed_uref::get(matches) {
+        DictionaryItemStrParams::URef {
+            seed_uref,
+            dictionary_item_key,
+        }
+    } else {
+        let dictionary_address = dictionary_address::get(matches).unwrap_or_else(|| {
+            panic!(
+                "should have one of the {} args",
+                DICTIONARY_IDENTIFIER_GROUP
+            )
+        });
+        DictionaryItemStrParams::Dictionary(dictionary_address)
+    }
+}
+
+impl<'a, 'b> ClientCommand<'a, 'b> for GetDictionaryItem {
+    const NAME: &'static str = "get-dictionary-item";
+    const ABOUT: &'static str = "Retrieves an item from a dictionary stored on the network";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+            .arg(common::node_address::arg(
+                DisplayOrder::NodeAddress as usize,
+            ))
+            .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
+            .arg(common::state_root_hash::arg(DisplayOrder::StateRootHash as usize).required(false))
+            .arg(common::block_identifier::arg(
+                DisplayOrder::BlockIdentifier as usize,
+            ))
+            .arg(account_hash::arg())
+            .arg(contract_hash::arg())
+            .arg(seed_uref::arg())
+            .arg(dictionary_address::arg())
+            .arg(dictionary_name::arg())
+            .arg(dictionary_item_key::arg())
+            .group(
+                ArgGroup::with_name(STATE_IDENTIFIER_GROUP)
+                    .arg(common::state_root_hash::ARG_NAME)
+                    .arg(common::block_identifier::ARG_NAME)
+                    .required(true),
+            )
+            .group(
+                ArgGroup::with_name(DICTIONARY_IDENTIFIER_GROUP)
+                    .arg(account_hash::ARG_NAME)
+                    .arg(contract_hash::ARG_NAME)
+                    .arg(seed_uref::ARG_NAME)
+                    .arg(dictionary_address::ARG_NAME)
+                    .required(true),
+            )
+    }
+
+    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
+        let maybe_rpc_id = common::rpc_id::get(matches);
+        let node_address = common::node_address::get(matches);
+        let verbosity_level = common::verbose::get(matches);
+        let state_root_hash = common::state_root_hash::get(matches);
+        let maybe_block_id = common::block_identifier::get(matches);
+        let dictionary_str_params = get_dictionary_str_params(matches);
+
+        casper_client::get_dictionary_item(
+            maybe_rpc_id,
+            node_address,
+            verbosity_level,
+            state_root_hash,
+            maybe_block_id,
+            dictionary_str_params,
+        )
+        .map(Success::from)
+    }
+}
@@ -0,0 +1,104 @@
+use std::str;
+
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use casper_client::Error;
+use casper_node::rpcs::info::GetAccountDeploys;
+
+use crate::{command::ClientCommand, common, Success};
+
+/// This struct defines the order in which the args are shown for this subcommand's help message.
+enum DisplayOrder {
+    Verbose,
+    NodeAddress,
+    RpcId,
+    PublicKey,
+    Offset,
+    Limit,
+}
+
+/// Handles providing the arg for and retrieval of the number of deploys to skip.
+mod offset {
+    use super::*;
+
+    const ARG_NAME: &str = "offset";
+    const ARG_VALUE_NAME: &str = common::ARG_INTEGER;
+    const ARG_HELP: &str = "Number of the account's deploys to skip. Defaults to 0";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Offset as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+/// Handles providing the arg for and retrieval of the maximum number of deploys to list.
+mod limit {
+    use super::*;
+
+    const ARG_NAME: &str = "limit";
+    const ARG_VALUE_NAME: &str = common::ARG_INTEGER;
+    const ARG_HELP: &str =
+        "Maximum number of the account's deploys to list. Defaults to, and is capped at, the \
+        maximum allowed by the node in a single request";
+
+    pub(super) fn arg() -> Arg<'static, 'static> {
+        Arg::with_name(ARG_NAME)
+            .long(ARG_NAME)
+            .required(false)
+            .value_name(ARG_VALUE_NAME)
+            .help(ARG_HELP)
+            .display_order(DisplayOrder::Limit as usize)
+    }
+
+    pub(super) fn get<'a>(matches: &'a ArgMatches) -> &'a str {
+        matches.value_of(ARG_NAME).unwrap_or_default()
+    }
+}
+
+impl<'a, 'b> ClientCommand<'a, 'b> for GetAccountDeploys {
+    const NAME: &'static str = "list-account-deploys";
+    const ABOUT: &'static str =
+        "Retrieves the hashes of deploys sent by an account, in the order they were executed. \
+        Requires the node to have its account deploy index enabled";
+
+    fn build(display_order: usize) -> App<'a, 'b> {
+        SubCommand::with_name(Self::NAME)
+            .about(Self::ABOUT)
+            .display_order(display_order)
+            .arg(common::verbose::arg(DisplayOrder::Verbose as usize))
+            .arg(common::node_address::arg(
+                DisplayOrder::NodeAddress as usize,
+            ))
+            .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
+            .arg(common::public_key::arg(DisplayOrder::PublicKey as usize))
+            .arg(offset::arg())
+            .arg(limit::arg())
+    }
+
+    fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
+        let maybe_rpc_id = common::rpc_id::get(matches);
+        let node_address = common::node_address::get(matches);
+        let verbosity_level = common::verbose::get(matches);
+        let public_key = common::public_key::get(matches)?;
+        let offset = offset::get(matches);
+        let limit = limit::get(matches);
+
+        casper_client::list_account_deploys(
+            maybe_rpc_id,
+            node_address,
+            verbosity_level,
+            public_key.trim(),
+            offset,
+            limit,
+        )
+        .map(Success::from)
+    }
+}
@@ -7,9 +7,11 @@ mod docs;
 mod generate_completion;
 mod get_auction_info;
 mod get_balance;
+mod get_dictionary_item;
 mod get_era_info_by_switch_block;
 mod get_state_hash;
 mod keygen;
+mod list_account_deploys;
 mod query_state;
 
 use std::process;
@@ -21,8 +23,11 @@ use casper_node::rpcs::{
     account::PutDeploy,
     chain::{GetBlock, GetBlockTransfers, GetEraInfoBySwitchBlock, GetStateRootHash},
     docs::ListRpcs,
-    info::GetDeploy,
-    state::{GetAuctionInfo, GetBalance, GetItem as QueryState},
+    info::{GetAccountDeploys, GetDeploy},
+    state::{
+        GetAuctionInfo, GetBalance, GetDictionaryItem, GetItem as QueryState,
+        SpeculativeExec as EstimateGas,
+    },
 };
 
 use deploy::{ListDeploys, MakeDeploy, SendDeploy, SignDeploy};
@@ -38,6 +43,7 @@ const APP_NAME: &str = "Casper client";
 /// This struct defines the order in which the subcommands are shown in the app's help message.
 enum DisplayOrder {
     PutDeploy,
+    EstimateGas,
     MakeDeploy,
     SignDeploy,
     SendDeploy,
@@ -46,8 +52,10 @@ enum DisplayOrder {
     GetBlock,
     GetBlockTransfers,
     ListDeploys,
+    ListAccountDeploys,
     GetStateRootHash,
     QueryState,
+    GetDictionaryItem,
     GetBalance,
     GetEraInfo,
     GetAuctionInfo,
@@ -62,6 +70,7 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
         .version(crate_version!())
         .about(crate_description!())
         .subcommand(PutDeploy::build(DisplayOrder::PutDeploy as usize))
+        .subcommand(EstimateGas::build(DisplayOrder::EstimateGas as usize))
         .subcommand(MakeDeploy::build(DisplayOrder::MakeDeploy as usize))
         .subcommand(SignDeploy::build(DisplayOrder::SignDeploy as usize))
         .subcommand(SendDeploy::build(DisplayOrder::SendDeploy as usize))
@@ -72,11 +81,17 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             DisplayOrder::GetBlockTransfers as usize,
         ))
         .subcommand(ListDeploys::build(DisplayOrder::ListDeploys as usize))
+        .subcommand(GetAccountDeploys::build(
+            DisplayOrder::ListAccountDeploys as usize,
+        ))
         .subcommand(GetBalance::build(DisplayOrder::GetBalance as usize))
         .subcommand(GetStateRootHash::build(
             DisplayOrder::GetStateRootHash as usize,
         ))
         .subcommand(QueryState::build(DisplayOrder::QueryState as usize))
+        .subcommand(GetDictionaryItem::build(
+            DisplayOrder::GetDictionaryItem as usize,
+        ))
         .subcommand(GetEraInfoBySwitchBlock::build(
             DisplayOrder::GetEraInfo as usize,
         ))
@@ -94,6 +109,7 @@ async fn main() {
     let arg_matches = cli().get_matches();
     let (result, matches) = match arg_matches.subcommand() {
         (PutDeploy::NAME, Some(matches)) => (PutDeploy::run(matches), matches),
+        (EstimateGas::NAME, Some(matches)) => (EstimateGas::run(matches), matches),
         (MakeDeploy::NAME, Some(matches)) => (MakeDeploy::run(matches), matches),
         (SignDeploy::NAME, Some(matches)) => (SignDeploy::run(matches), matches),
         (SendDeploy::NAME, Some(matches)) => (SendDeploy::run(matches), matches),
@@ -102,9 +118,11 @@ async fn main() {
         (GetBlock::NAME, Some(matches)) => (GetBlock::run(matches), matches),
         (GetBlockTransfers::NAME, Some(matches)) => (GetBlockTransfers::run(matches), matches),
         (ListDeploys::NAME, Some(matches)) => (ListDeploys::run(matches), matches),
+        (GetAccountDeploys::NAME, Some(matches)) => (GetAccountDeploys::run(matches), matches),
         (GetBalance::NAME, Some(matches)) => (GetBalance::run(matches), matches),
         (GetStateRootHash::NAME, Some(matches)) => (GetStateRootHash::run(matches), matches),
         (QueryState::NAME, Some(matches)) => (QueryState::run(matches), matches),
+        (GetDictionaryItem::NAME, Some(matches)) => (GetDictionaryItem::run(matches), matches),
         (GetEraInfoBySwitchBlock::NAME, Some(matches)) => {
             (GetEraInfoBySwitchBlock::run(matches), matches)
         }
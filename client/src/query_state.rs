@@ -1,6 +1,6 @@
 use std::{fs, str};
 
-use clap::{App, Arg, ArgMatches, SubCommand};
+use clap::{App, Arg, ArgGroup, ArgMatches, SubCommand};
 
 use casper_client::Error;
 use casper_node::rpcs::state::GetItem;
@@ -8,12 +8,15 @@ use casper_types::PublicKey;
 
 use crate::{command::ClientCommand, common, Success};
 
+const STATE_IDENTIFIER_GROUP: &str = "state-identifier";
+
 /// This struct defines the order in which the args are shown for this subcommand's help message.
 enum DisplayOrder {
     Verbose,
     NodeAddress,
     RpcId,
     StateRootHash,
+    BlockIdentifier,
     Key,
     Path,
 }
@@ -111,11 +114,18 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetItem {
                 DisplayOrder::NodeAddress as usize,
             ))
             .arg(common::rpc_id::arg(DisplayOrder::RpcId as usize))
-            .arg(common::state_root_hash::arg(
-                DisplayOrder::StateRootHash as usize,
+            .arg(common::state_root_hash::arg(DisplayOrder::StateRootHash as usize).required(false))
+            .arg(common::block_identifier::arg(
+                DisplayOrder::BlockIdentifier as usize,
             ))
             .arg(key::arg())
             .arg(path::arg())
+            .group(
+                ArgGroup::with_name(STATE_IDENTIFIER_GROUP)
+                    .arg(common::state_root_hash::ARG_NAME)
+                    .arg(common::block_identifier::ARG_NAME)
+                    .required(true),
+            )
     }
 
     fn run(matches: &ArgMatches<'_>) -> Result<Success, Error> {
@@ -123,6 +133,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetItem {
         let node_address = common::node_address::get(matches);
         let verbosity_level = common::verbose::get(matches);
         let state_root_hash = common::state_root_hash::get(matches);
+        let maybe_block_id = common::block_identifier::get(matches);
         let key = key::get(matches)?;
         let path = path::get(matches);
 
@@ -131,6 +142,7 @@ impl<'a, 'b> ClientCommand<'a, 'b> for GetItem {
             node_address,
             verbosity_level,
             state_root_hash,
+            maybe_block_id,
             &key,
             path,
         )
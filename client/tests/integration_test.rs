@@ -12,12 +12,14 @@ use warp_json_rpc::Builder;
 use casper_node::crypto::Error as CryptoError;
 use hex::FromHexError;
 
-use casper_client::{DeployStrParams, Error, PaymentStrParams, SessionStrParams};
+use casper_client::{
+    DeployStrParams, DictionaryItemStrParams, Error, PaymentStrParams, SessionStrParams,
+};
 use casper_node::rpcs::{
     account::{PutDeploy, PutDeployParams},
     chain::{GetStateRootHash, GetStateRootHashParams},
     info::{GetDeploy, GetDeployParams},
-    state::{GetBalance, GetBalanceParams},
+    state::{GetBalance, GetBalanceParams, SpeculativeExec, SpeculativeExecParams},
     RpcWithOptionalParams, RpcWithParams,
 };
 
@@ -119,8 +121,26 @@ impl MockServerHandle {
         }
     }
 
-    fn get_balance(&self, state_root_hash: &str, purse_uref: &str) -> Result<(), ErrWrapper> {
-        casper_client::get_balance("1", &self.url(), 0, state_root_hash, purse_uref)
+    fn get_balance(
+        &self,
+        state_root_hash: &str,
+        maybe_block_id: &str,
+        purse_uref: &str,
+    ) -> Result<(), ErrWrapper> {
+        casper_client::get_balance(
+            "1",
+            &self.url(),
+            0,
+            state_root_hash,
+            maybe_block_id,
+            purse_uref,
+        )
+        .map(|_| ())
+        .map_err(ErrWrapper)
+    }
+
+    fn get_balances(&self, state_root_hash: &str, purse_urefs: &[&str]) -> Result<(), ErrWrapper> {
+        casper_client::get_balances(&self.url(), 0, state_root_hash, purse_urefs)
             .map(|_| ())
             .map_err(ErrWrapper)
     }
@@ -143,10 +163,42 @@ impl MockServerHandle {
             .map_err(ErrWrapper)
     }
 
-    fn get_item(&self, state_root_hash: &str, key: &str, path: &str) -> Result<(), ErrWrapper> {
-        casper_client::get_item("1", &self.url(), 0, state_root_hash, key, path)
-            .map(|_| ())
-            .map_err(ErrWrapper)
+    fn get_item(
+        &self,
+        state_root_hash: &str,
+        maybe_block_id: &str,
+        key: &str,
+        path: &str,
+    ) -> Result<(), ErrWrapper> {
+        casper_client::get_item(
+            "1",
+            &self.url(),
+            0,
+            state_root_hash,
+            maybe_block_id,
+            key,
+            path,
+        )
+        .map(|_| ())
+        .map_err(ErrWrapper)
+    }
+
+    fn get_dictionary_item(
+        &self,
+        state_root_hash: &str,
+        maybe_block_id: &str,
+        dictionary_str_params: DictionaryItemStrParams,
+    ) -> Result<(), ErrWrapper> {
+        casper_client::get_dictionary_item(
+            "1",
+            &self.url(),
+            0,
+            state_root_hash,
+            maybe_block_id,
+            dictionary_str_params,
+        )
+        .map(|_| ())
+        .map_err(ErrWrapper)
     }
 
     fn transfer(
@@ -188,14 +240,45 @@ impl MockServerHandle {
         .map_err(ErrWrapper)
     }
 
+    fn estimate_gas(
+        &self,
+        maybe_block_id: &str,
+        deploy_params: DeployStrParams,
+        session_params: SessionStrParams,
+        payment_params: PaymentStrParams,
+    ) -> Result<(), ErrWrapper> {
+        casper_client::estimate_gas(
+            "1",
+            &self.url(),
+            0,
+            maybe_block_id,
+            deploy_params,
+            session_params,
+            payment_params,
+        )
+        .map(|_| ())
+        .map_err(ErrWrapper)
+    }
+
     fn send_deploy_file(&self, input_path: &str) -> Result<(), ErrWrapper> {
         casper_client::send_deploy_file("1", &self.url(), 0, input_path)
             .map(|_| ())
             .map_err(ErrWrapper)
     }
 
-    fn get_auction_info(&self) -> Result<(), ErrWrapper> {
-        casper_client::get_auction_info("1", &self.url(), 0)
+    fn list_account_deploys(
+        &self,
+        public_key: &str,
+        offset: &str,
+        limit: &str,
+    ) -> Result<(), ErrWrapper> {
+        casper_client::list_account_deploys("1", &self.url(), 0, public_key, offset, limit)
+            .map(|_| ())
+            .map_err(ErrWrapper)
+    }
+
+    fn get_auction_info(&self, maybe_block_id: &str) -> Result<(), ErrWrapper> {
+        casper_client::get_auction_info("1", &self.url(), 0, maybe_block_id)
             .map(|_| ())
             .map_err(ErrWrapper)
     }
@@ -284,7 +367,7 @@ mod get_balance {
     async fn should_succeed_with_valid_arguments() {
         let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
         assert_eq!(
-            server_handle.get_balance(VALID_STATE_ROOT_HASH, VALID_PURSE_UREF),
+            server_handle.get_balance(VALID_STATE_ROOT_HASH, "", VALID_PURSE_UREF),
             // NOTE: this "success" means that we then fail to validate the response, but that
             // is outside the scope of this test.
             // The MockServerHandle could support a pre-baked response, which should successfully
@@ -299,7 +382,7 @@ mod get_balance {
     async fn should_fail_with_empty_arguments() {
         let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
         assert_eq!(
-            server_handle.get_balance("", ""),
+            server_handle.get_balance("", "", ""),
             Err(Error::CryptoError {
                 context: "state_root_hash",
                 error: CryptoError::FromHex(FromHexError::InvalidStringLength)
@@ -312,7 +395,7 @@ mod get_balance {
     async fn should_fail_with_empty_state_root_hash() {
         let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
         assert_eq!(
-            server_handle.get_balance("", VALID_PURSE_UREF),
+            server_handle.get_balance("", "", VALID_PURSE_UREF),
             Err(Error::CryptoError {
                 context: "state_root_hash",
                 error: CryptoError::FromHex(FromHexError::InvalidStringLength)
@@ -325,16 +408,43 @@ mod get_balance {
     async fn should_fail_with_empty_purse_uref() {
         let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
         assert_eq!(
-            server_handle.get_balance(VALID_STATE_ROOT_HASH, ""),
+            server_handle.get_balance(VALID_STATE_ROOT_HASH, "", ""),
             Err(Error::FailedToParseURef("purse_uref", URefFromStrError::InvalidPrefix).into())
         );
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_succeed_with_valid_block_id() {
+        let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
+        assert_eq!(
+            server_handle.get_balance("", "1", VALID_PURSE_UREF),
+            Err(
+                Error::InvalidResponse(ValidateResponseError::ValidateResponseFailedToParse).into()
+            )
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_fail_with_state_root_hash_and_block_id() {
+        let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
+        assert_eq!(
+            server_handle.get_balance(VALID_STATE_ROOT_HASH, "1", VALID_PURSE_UREF),
+            Err(Error::ConflictingArguments {
+                context: "state_identifier",
+                args: vec![
+                    format!("state_root_hash={}", VALID_STATE_ROOT_HASH),
+                    "block_identifier=1".to_string(),
+                ],
+            }
+            .into())
+        );
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn should_fail_with_bad_state_root_hash() {
         let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
         assert_eq!(
-            server_handle.get_balance("deadbeef", VALID_PURSE_UREF),
+            server_handle.get_balance("deadbeef", "", VALID_PURSE_UREF),
             Err(Error::CryptoError {
                 context: "state_root_hash",
                 error: CryptoError::FromHex(FromHexError::InvalidStringLength)
@@ -344,6 +454,43 @@ mod get_balance {
     }
 }
 
+mod get_balances {
+    use super::*;
+
+    use casper_types::URefFromStrError;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_succeed_with_no_purses() {
+        let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
+        assert_eq!(
+            server_handle.get_balances(VALID_STATE_ROOT_HASH, &[]),
+            Ok(())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_fail_with_bad_state_root_hash() {
+        let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
+        assert_eq!(
+            server_handle.get_balances("deadbeef", &[VALID_PURSE_UREF]),
+            Err(Error::CryptoError {
+                context: "state_root_hash",
+                error: CryptoError::FromHex(FromHexError::InvalidStringLength)
+            }
+            .into())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_fail_if_any_purse_uref_is_invalid() {
+        let server_handle = MockServerHandle::spawn::<GetBalanceParams>(GetBalance::METHOD);
+        assert_eq!(
+            server_handle.get_balances(VALID_STATE_ROOT_HASH, &[VALID_PURSE_UREF, ""]),
+            Err(Error::FailedToParseURef("purse_uref", URefFromStrError::InvalidPrefix).into())
+        );
+    }
+}
+
 mod get_state_root_hash {
     use super::*;
 
@@ -449,7 +596,7 @@ mod get_item {
         // mock implementation fails to validate
 
         assert_eq!(
-            server_handle.get_item(VALID_STATE_ROOT_HASH, VALID_PURSE_UREF, ""),
+            server_handle.get_item(VALID_STATE_ROOT_HASH, "", VALID_PURSE_UREF, ""),
             Err(
                 Error::InvalidResponse(ValidateResponseError::ValidateResponseFailedToParse).into()
             )
@@ -460,7 +607,7 @@ mod get_item {
     async fn should_fail_with_invalid_state_root_hash() {
         let server_handle = MockServerHandle::spawn::<GetItemParams>(GetItem::METHOD);
         assert_eq!(
-            server_handle.get_item("<invalid state root hash>", VALID_PURSE_UREF, ""),
+            server_handle.get_item("<invalid state root hash>", "", VALID_PURSE_UREF, ""),
             Err(Error::CryptoError {
                 context: "state_root_hash",
                 error: CryptoError::FromHex(FromHexError::OddLength)
@@ -469,11 +616,38 @@ mod get_item {
         );
     }
 
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_succeed_with_valid_block_id() {
+        let server_handle = MockServerHandle::spawn::<GetItemParams>(GetItem::METHOD);
+        assert_eq!(
+            server_handle.get_item("", "1", VALID_PURSE_UREF, ""),
+            Err(
+                Error::InvalidResponse(ValidateResponseError::ValidateResponseFailedToParse).into()
+            )
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_fail_with_state_root_hash_and_block_id() {
+        let server_handle = MockServerHandle::spawn::<GetItemParams>(GetItem::METHOD);
+        assert_eq!(
+            server_handle.get_item(VALID_STATE_ROOT_HASH, "1", VALID_PURSE_UREF, ""),
+            Err(Error::ConflictingArguments {
+                context: "state_identifier",
+                args: vec![
+                    format!("state_root_hash={}", VALID_STATE_ROOT_HASH),
+                    "block_identifier=1".to_string(),
+                ],
+            }
+            .into())
+        );
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     async fn should_fail_with_invalid_key() {
         let server_handle = MockServerHandle::spawn::<GetItemParams>(GetItem::METHOD);
         assert_eq!(
-            server_handle.get_item(VALID_STATE_ROOT_HASH, "invalid key", ""),
+            server_handle.get_item(VALID_STATE_ROOT_HASH, "", "invalid key", ""),
             Err(Error::FailedToParseKey.into())
         );
     }
@@ -482,7 +656,93 @@ mod get_item {
     async fn should_fail_with_empty_key() {
         let server_handle = MockServerHandle::spawn::<GetItemParams>(GetItem::METHOD);
         assert_eq!(
-            server_handle.get_item("<invalid state root hash>", "", ""),
+            server_handle.get_item("<invalid state root hash>", "", "", ""),
+            Err(Error::CryptoError {
+                context: "state_root_hash",
+                error: CryptoError::FromHex(FromHexError::OddLength)
+            }
+            .into())
+        );
+    }
+}
+
+mod get_dictionary_item {
+    use casper_client::ValidateResponseError;
+    use casper_node::rpcs::state::{GetDictionaryItem, GetDictionaryItemParams};
+
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_succeed_with_valid_seed_uref() {
+        let server_handle =
+            MockServerHandle::spawn::<GetDictionaryItemParams>(GetDictionaryItem::METHOD);
+
+        // in this case, the error means that the request was sent successfully, but due to to the
+        // mock implementation fails to validate
+
+        let dictionary_str_params = DictionaryItemStrParams::URef {
+            seed_uref: VALID_PURSE_UREF,
+            dictionary_item_key: "item",
+        };
+        assert_eq!(
+            server_handle.get_dictionary_item(VALID_STATE_ROOT_HASH, "", dictionary_str_params),
+            Err(
+                Error::InvalidResponse(ValidateResponseError::ValidateResponseFailedToParse).into()
+            )
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_succeed_with_valid_block_id() {
+        let server_handle =
+            MockServerHandle::spawn::<GetDictionaryItemParams>(GetDictionaryItem::METHOD);
+        let dictionary_str_params = DictionaryItemStrParams::URef {
+            seed_uref: VALID_PURSE_UREF,
+            dictionary_item_key: "item",
+        };
+        assert_eq!(
+            server_handle.get_dictionary_item("", "1", dictionary_str_params),
+            Err(
+                Error::InvalidResponse(ValidateResponseError::ValidateResponseFailedToParse).into()
+            )
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_fail_with_state_root_hash_and_block_id() {
+        let server_handle =
+            MockServerHandle::spawn::<GetDictionaryItemParams>(GetDictionaryItem::METHOD);
+        let dictionary_str_params = DictionaryItemStrParams::URef {
+            seed_uref: VALID_PURSE_UREF,
+            dictionary_item_key: "item",
+        };
+        assert_eq!(
+            server_handle.get_dictionary_item(VALID_STATE_ROOT_HASH, "1", dictionary_str_params),
+            Err(Error::ConflictingArguments {
+                context: "state_identifier",
+                args: vec![
+                    format!("state_root_hash={}", VALID_STATE_ROOT_HASH),
+                    "block_identifier=1".to_string(),
+                ],
+            }
+            .into())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_fail_with_invalid_state_root_hash() {
+        let server_handle =
+            MockServerHandle::spawn::<GetDictionaryItemParams>(GetDictionaryItem::METHOD);
+        let dictionary_str_params = DictionaryItemStrParams::URef {
+            seed_uref: VALID_PURSE_UREF,
+            dictionary_item_key: "item",
+        };
+        assert_eq!(
+            server_handle.get_dictionary_item(
+                "<invalid state root hash>",
+                "",
+                dictionary_str_params
+            ),
             Err(Error::CryptoError {
                 context: "state_root_hash",
                 error: CryptoError::FromHex(FromHexError::OddLength)
@@ -490,6 +750,31 @@ mod get_item {
             .into())
         );
     }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_fail_with_invalid_seed_uref() {
+        let server_handle =
+            MockServerHandle::spawn::<GetDictionaryItemParams>(GetDictionaryItem::METHOD);
+        let dictionary_str_params = DictionaryItemStrParams::URef {
+            seed_uref: "invalid uref",
+            dictionary_item_key: "item",
+        };
+        match server_handle.get_dictionary_item(VALID_STATE_ROOT_HASH, "", dictionary_str_params) {
+            Err(ErrWrapper(Error::FailedToParseURef("seed_uref", _))) => {}
+            other => panic!("incorrect error returned from client {:?}", other),
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_fail_with_non_dictionary_address() {
+        let server_handle =
+            MockServerHandle::spawn::<GetDictionaryItemParams>(GetDictionaryItem::METHOD);
+        let dictionary_str_params = DictionaryItemStrParams::Dictionary(VALID_PURSE_UREF);
+        assert_eq!(
+            server_handle.get_dictionary_item(VALID_STATE_ROOT_HASH, "", dictionary_str_params),
+            Err(Error::FailedToParseKey.into())
+        );
+    }
 }
 
 mod get_deploy {
@@ -519,15 +804,98 @@ mod get_deploy {
     }
 }
 
+mod list_account_deploys {
+    use super::*;
+
+    use casper_node::rpcs::info::{GetAccountDeploys, GetAccountDeploysParams};
+
+    const VALID_PUBLIC_KEY: &str =
+        "01c9e33693951aaac23c49bee44ad6f863eedcd38c084a3a8f11237716a3df9c2c";
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_succeed_with_valid_arguments() {
+        let server_handle =
+            MockServerHandle::spawn::<GetAccountDeploysParams>(GetAccountDeploys::METHOD);
+        assert_eq!(
+            server_handle.list_account_deploys(VALID_PUBLIC_KEY, "10", "20"),
+            Ok(())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_succeed_with_empty_offset_and_limit() {
+        let server_handle =
+            MockServerHandle::spawn::<GetAccountDeploysParams>(GetAccountDeploys::METHOD);
+        assert_eq!(
+            server_handle.list_account_deploys(VALID_PUBLIC_KEY, "", ""),
+            Ok(())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_fail_with_invalid_public_key() {
+        let server_handle =
+            MockServerHandle::spawn::<GetAccountDeploysParams>(GetAccountDeploys::METHOD);
+        assert!(matches!(
+            server_handle.list_account_deploys("012345", "", ""),
+            Err(ErrWrapper(Error::CryptoError {
+                context: "public_key",
+                ..
+            }))
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_fail_with_invalid_limit() {
+        let server_handle =
+            MockServerHandle::spawn::<GetAccountDeploysParams>(GetAccountDeploys::METHOD);
+        match server_handle.list_account_deploys(VALID_PUBLIC_KEY, "", "-1") {
+            Err(ErrWrapper(Error::FailedToParseInt("limit", _))) => {}
+            other => panic!("incorrect error returned from client {:?}", other),
+        }
+    }
+}
+
 mod get_auction_info {
     use super::*;
 
-    use casper_node::rpcs::{state::GetAuctionInfo, RpcWithoutParams};
+    use casper_client::ValidateResponseError;
+    use casper_node::rpcs::{
+        state::{GetAuctionInfo, GetAuctionInfoParams},
+        RpcWithOptionalParams,
+    };
 
     #[tokio::test(flavor = "multi_thread")]
     async fn should_succeed() {
         let server_handle = MockServerHandle::spawn_without_params(GetAuctionInfo::METHOD);
-        assert_eq!(server_handle.get_auction_info(), Ok(()));
+        // NOTE: this "success" means that we then fail to validate the response, but that is
+        // outside the scope of this test.
+        assert!(matches!(
+            server_handle.get_auction_info(""),
+            Err(ErrWrapper(Error::InvalidResponse(
+                ValidateResponseError::Serde(_)
+            )))
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_succeed_with_valid_block_height() {
+        let server_handle = MockServerHandle::spawn::<GetAuctionInfoParams>(GetAuctionInfo::METHOD);
+        assert!(matches!(
+            server_handle.get_auction_info("1"),
+            Err(ErrWrapper(Error::InvalidResponse(
+                ValidateResponseError::Serde(_)
+            )))
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_fail_with_invalid_block_id() {
+        let server_handle = MockServerHandle::spawn::<GetAuctionInfoParams>(GetAuctionInfo::METHOD);
+        match server_handle.get_auction_info("<not a valid hash>") {
+            Err(ErrWrapper(Error::FailedToParseInt("block_identifier", _))) => {}
+            other => panic!("incorrect error returned from client {:?}", other),
+        }
     }
 }
 
@@ -796,6 +1164,54 @@ mod put_deploy {
     }
 }
 
+mod estimate_gas {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_succeed_with_valid_arguments() {
+        let server_handle =
+            MockServerHandle::spawn::<SpeculativeExecParams>(SpeculativeExec::METHOD);
+        assert_eq!(
+            server_handle.estimate_gas(
+                "",
+                deploy_params::test_data_valid(),
+                session_params::test_data_with_package_hash(),
+                payment_params::test_data_with_name()
+            ),
+            Ok(())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_succeed_with_block_height() {
+        let server_handle =
+            MockServerHandle::spawn::<SpeculativeExecParams>(SpeculativeExec::METHOD);
+        assert_eq!(
+            server_handle.estimate_gas(
+                "1",
+                deploy_params::test_data_valid(),
+                session_params::test_data_with_package_hash(),
+                payment_params::test_data_with_name()
+            ),
+            Ok(())
+        );
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn should_fail_with_invalid_block_id() {
+        let server_handle =
+            MockServerHandle::spawn::<SpeculativeExecParams>(SpeculativeExec::METHOD);
+        assert!(server_handle
+            .estimate_gas(
+                "not a block id",
+                deploy_params::test_data_valid(),
+                session_params::test_data_with_package_hash(),
+                payment_params::test_data_with_name()
+            )
+            .is_err());
+    }
+}
+
 mod rate_limit {
     use super::*;
     use casper_node::types::Timestamp;
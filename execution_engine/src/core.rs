@@ -7,7 +7,9 @@ pub mod runtime;
 pub mod runtime_context;
 pub(crate) mod tracking_copy;
 
-pub use tracking_copy::{validate_balance_proof, validate_query_proof, ValidationError};
+pub use casper_types::merkle_proof::{
+    validate_balance_proof, validate_batch_proof, validate_query_proof, ValidationError,
+};
 
 pub const ADDRESS_LENGTH: usize = 32;
 
@@ -0,0 +1,49 @@
+use casper_types::{BlockTime, EraId};
+
+use crate::shared::newtypes::Blake2bHash;
+
+/// Properties of the block in which a deploy is executed.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct BlockInfo {
+    block_time: BlockTime,
+    block_height: u64,
+    era_id: EraId,
+    parent_block_hash: Blake2bHash,
+}
+
+impl BlockInfo {
+    /// Creates a new `BlockInfo`.
+    pub fn new(
+        block_time: BlockTime,
+        block_height: u64,
+        era_id: EraId,
+        parent_block_hash: Blake2bHash,
+    ) -> Self {
+        BlockInfo {
+            block_time,
+            block_height,
+            era_id,
+            parent_block_hash,
+        }
+    }
+
+    /// Returns the timestamp of the block.
+    pub fn block_time(&self) -> BlockTime {
+        self.block_time
+    }
+
+    /// Returns the height of the block.
+    pub fn block_height(&self) -> u64 {
+        self.block_height
+    }
+
+    /// Returns the era in which the block was proposed.
+    pub fn era_id(&self) -> EraId {
+        self.era_id
+    }
+
+    /// Returns the hash of the block's parent.
+    pub fn parent_block_hash(&self) -> Blake2bHash {
+        self.parent_block_hash
+    }
+}
@@ -0,0 +1,101 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{account::AccountHash, CLValue, ContractHash, ProtocolVersion, RuntimeArgs};
+
+use super::BlockInfo;
+use crate::shared::{gas::Gas, newtypes::Blake2bHash};
+
+/// Identifies the stored contract whose entry point is to be called.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub enum ContractIdentifier {
+    /// The contract stored under the given hash.
+    Hash(ContractHash),
+    /// The contract stored under the given name in the caller's named keys.
+    Name(String),
+}
+
+#[derive(Debug)]
+pub enum CallContractResult {
+    RootNotFound,
+    Success {
+        /// The value passed to `runtime::ret` by the entry point, or `()` if it didn't call it.
+        value: CLValue,
+        cost: Gas,
+    },
+}
+
+/// A request to call a stored contract's entry point without committing any of its effects.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallContractRequest {
+    state_hash: Blake2bHash,
+    block_info: BlockInfo,
+    protocol_version: ProtocolVersion,
+    caller: Option<AccountHash>,
+    contract: ContractIdentifier,
+    entry_point: String,
+    args: RuntimeArgs,
+    gas_limit: Gas,
+}
+
+impl CallContractRequest {
+    /// Creates a new request.
+    ///
+    /// If `caller` is `None`, the call is made by the virtual system account, in which case the
+    /// contract can only be identified by its hash.  `block_info` describes the block in which
+    /// the call is made.
+    pub fn new(
+        state_hash: Blake2bHash,
+        block_info: BlockInfo,
+        protocol_version: ProtocolVersion,
+        caller: Option<AccountHash>,
+        contract: ContractIdentifier,
+        entry_point: String,
+        args: RuntimeArgs,
+        gas_limit: Gas,
+    ) -> Self {
+        CallContractRequest {
+            state_hash,
+            block_info,
+            protocol_version,
+            caller,
+            contract,
+            entry_point,
+            args,
+            gas_limit,
+        }
+    }
+
+    pub fn state_hash(&self) -> Blake2bHash {
+        self.state_hash
+    }
+
+    pub fn block_info(&self) -> BlockInfo {
+        self.block_info
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+
+    pub fn caller(&self) -> Option<AccountHash> {
+        self.caller
+    }
+
+    pub fn contract(&self) -> &ContractIdentifier {
+        &self.contract
+    }
+
+    pub fn entry_point(&self) -> &str {
+        &self.entry_point
+    }
+
+    pub fn args(&self) -> &RuntimeArgs {
+        &self.args
+    }
+
+    pub fn gas_limit(&self) -> Gas {
+        self.gas_limit
+    }
+}
@@ -1,23 +1,30 @@
 use std::mem;
 
-use casper_types::{ProtocolVersion, PublicKey, SecretKey};
+use casper_types::{BlockTime, EraId, ProtocolVersion, PublicKey, SecretKey};
 
-use super::deploy_item::DeployItem;
+use super::{block_info::BlockInfo, deploy_item::DeployItem};
 use crate::shared::newtypes::Blake2bHash;
 
 #[derive(Debug)]
 pub struct ExecuteRequest {
     pub parent_state_hash: Blake2bHash,
     pub block_time: u64,
+    pub block_height: u64,
+    pub era_id: EraId,
+    pub parent_block_hash: Blake2bHash,
     pub deploys: Vec<DeployItem>,
     pub protocol_version: ProtocolVersion,
     pub proposer: PublicKey,
 }
 
 impl ExecuteRequest {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         parent_state_hash: Blake2bHash,
         block_time: u64,
+        block_height: u64,
+        era_id: EraId,
+        parent_block_hash: Blake2bHash,
         deploys: Vec<DeployItem>,
         protocol_version: ProtocolVersion,
         proposer: PublicKey,
@@ -25,6 +32,9 @@ impl ExecuteRequest {
         Self {
             parent_state_hash,
             block_time,
+            block_height,
+            era_id,
+            parent_block_hash,
             deploys,
             protocol_version,
             proposer,
@@ -38,6 +48,15 @@ impl ExecuteRequest {
     pub fn deploys(&self) -> &Vec<DeployItem> {
         &self.deploys
     }
+
+    pub fn block_info(&self) -> BlockInfo {
+        BlockInfo::new(
+            BlockTime::new(self.block_time),
+            self.block_height,
+            self.era_id,
+            self.parent_block_hash,
+        )
+    }
 }
 
 impl Default for ExecuteRequest {
@@ -48,6 +67,9 @@ impl Default for ExecuteRequest {
         Self {
             parent_state_hash: Blake2bHash::new(&[]),
             block_time: 0,
+            block_height: 0,
+            era_id: EraId::default(),
+            parent_block_hash: Blake2bHash::default(),
             deploys: vec![],
             protocol_version: Default::default(),
             proposer,
@@ -1,6 +1,8 @@
 use std::collections::VecDeque;
 
-use casper_types::{bytesrepr::FromBytes, CLTyped, CLValue, CLValueError, Key, TransferAddr};
+use casper_types::{
+    bytesrepr::FromBytes, CLTyped, CLValue, CLValueError, ContractEvent, Key, TransferAddr,
+};
 
 use super::{error, execution_effect::ExecutionEffect, op::Op};
 use crate::{
@@ -50,12 +52,14 @@ pub enum ExecutionResult {
         error: error::Error,
         effect: ExecutionEffect,
         transfers: Vec<TransferAddr>,
+        events: Vec<ContractEvent>,
         cost: Gas,
     },
     /// Execution was finished successfully
     Success {
         effect: ExecutionEffect,
         transfers: Vec<TransferAddr>,
+        events: Vec<ContractEvent>,
         cost: Gas,
     },
 }
@@ -65,6 +69,7 @@ impl Default for ExecutionResult {
         ExecutionResult::Success {
             effect: ExecutionEffect::default(),
             transfers: Vec::default(),
+            events: Vec::default(),
             cost: Gas::default(),
         }
     }
@@ -91,6 +96,7 @@ impl ExecutionResult {
             error,
             effect: Default::default(),
             transfers: Vec::default(),
+            events: Vec::default(),
             cost: Gas::default(),
         }
     }
@@ -139,24 +145,37 @@ impl ExecutionResult {
         }
     }
 
+    pub fn events(&self) -> &Vec<ContractEvent> {
+        match self {
+            ExecutionResult::Failure { events, .. } => events,
+            ExecutionResult::Success { events, .. } => events,
+        }
+    }
+
     pub fn with_cost(self, cost: Gas) -> Self {
         match self {
             ExecutionResult::Failure {
                 error,
                 effect,
                 transfers,
+                events,
                 ..
             } => ExecutionResult::Failure {
                 error,
                 effect,
                 transfers,
+                events,
                 cost,
             },
             ExecutionResult::Success {
-                effect, transfers, ..
+                effect,
+                transfers,
+                events,
+                ..
             } => ExecutionResult::Success {
                 effect,
                 transfers,
+                events,
                 cost,
             },
         }
@@ -168,18 +187,24 @@ impl ExecutionResult {
                 error,
                 cost,
                 transfers,
+                events,
                 ..
             } => ExecutionResult::Failure {
                 error,
                 effect,
                 transfers,
+                events,
                 cost,
             },
             ExecutionResult::Success {
-                cost, transfers, ..
+                cost,
+                transfers,
+                events,
+                ..
             } => ExecutionResult::Success {
                 effect,
                 transfers,
+                events,
                 cost,
             },
         }
@@ -190,17 +215,54 @@ impl ExecutionResult {
             ExecutionResult::Failure {
                 error,
                 effect,
+                events,
+                cost,
+                ..
+            } => ExecutionResult::Failure {
+                error,
+                effect,
+                transfers,
+                events,
+                cost,
+            },
+            ExecutionResult::Success {
+                cost,
+                effect,
+                events,
+                ..
+            } => ExecutionResult::Success {
+                effect,
+                transfers,
+                events,
+                cost,
+            },
+        }
+    }
+
+    pub fn with_events(self, events: Vec<ContractEvent>) -> Self {
+        match self {
+            ExecutionResult::Failure {
+                error,
+                effect,
+                transfers,
                 cost,
                 ..
             } => ExecutionResult::Failure {
                 error,
                 effect,
                 transfers,
+                events,
                 cost,
             },
-            ExecutionResult::Success { cost, effect, .. } => ExecutionResult::Success {
+            ExecutionResult::Success {
+                cost,
                 effect,
                 transfers,
+                ..
+            } => ExecutionResult::Success {
+                effect,
+                transfers,
+                events,
                 cost,
             },
         }
@@ -266,10 +328,12 @@ impl ExecutionResult {
             proposer_main_purse_balance_key,
         )?;
         let transfers = Vec::default();
+        let events = Vec::default();
         Ok(ExecutionResult::Failure {
             error,
             effect,
             transfers,
+            events,
             cost: gas_cost,
         })
     }
@@ -289,20 +353,24 @@ impl From<&ExecutionResult> for casper_types::ExecutionResult {
             ExecutionResult::Success {
                 effect,
                 transfers,
+                events,
                 cost,
             } => casper_types::ExecutionResult::Success {
                 effect: effect.into(),
                 transfers: transfers.clone(),
+                events: events.clone(),
                 cost: cost.value(),
             },
             ExecutionResult::Failure {
                 error,
                 effect,
                 transfers,
+                events,
                 cost,
             } => casper_types::ExecutionResult::Failure {
                 effect: effect.into(),
                 transfers: transfers.clone(),
+                events: events.clone(),
                 cost: cost.value(),
                 error_message: error.to_string(),
             },
@@ -381,12 +449,27 @@ impl ExecutionResultBuilder {
             .unwrap_or_default()
     }
 
+    /// Returns the events emitted by the payment code followed by those emitted by the session
+    /// code, leaving out the events of a failed execution as its effects are discarded.
+    pub fn events(&self) -> Vec<ContractEvent> {
+        [
+            &self.payment_execution_result,
+            &self.session_execution_result,
+        ]
+        .iter()
+        .filter_map(|maybe_result| maybe_result.as_ref())
+        .filter(|result| !result.is_failure())
+        .flat_map(|result| result.events().iter().cloned())
+        .collect()
+    }
+
     pub fn build<R: StateReader<Key, StoredValue>>(
         self,
         reader: &R,
         correlation_id: CorrelationId,
     ) -> Result<ExecutionResult, ExecutionResultBuilderError> {
         let transfers = self.transfers();
+        let events = self.events();
         let cost = self.total_cost();
         let mut ops = AdditiveMap::new();
         let mut transforms = AdditiveMap::new();
@@ -394,6 +477,7 @@ impl ExecutionResultBuilder {
         let mut ret: ExecutionResult = ExecutionResult::Success {
             effect: Default::default(),
             transfers,
+            events: events.clone(),
             cost,
         };
 
@@ -413,7 +497,8 @@ impl ExecutionResultBuilder {
         match self.session_execution_result {
             Some(result) => {
                 if result.is_failure() {
-                    ret = result.with_cost(cost);
+                    // The payment code's events stand, just like its effects.
+                    ret = result.with_cost(cost).with_events(events);
                 } else {
                     Self::add_effects(&mut ops, &mut transforms, result.effect());
                 }
@@ -1,4 +1,6 @@
 pub mod balance;
+pub mod block_info;
+pub mod call_contract;
 pub mod deploy_item;
 pub mod engine_config;
 pub mod era_validators;
@@ -35,18 +37,20 @@ use casper_types::{
     system::{
         auction::{
             EraValidators, ARG_ERA_END_TIMESTAMP_MILLIS, ARG_EVICTED_VALIDATORS,
-            ARG_REWARD_FACTORS, ARG_VALIDATOR_PUBLIC_KEYS, AUCTION_DELAY_KEY,
+            ARG_REWARD_FACTORS, ARG_VALIDATOR_PUBLIC_KEYS, AUCTION_DELAY_KEY, ERA_ID_KEY,
             LOCKED_FUNDS_PERIOD_KEY, UNBONDING_DELAY_KEY, VALIDATOR_SLOTS_KEY,
         },
         handle_payment,
         mint::{self, ROUND_SEIGNIORAGE_RATE_KEY},
     },
-    AccessRights, ApiError, BlockTime, CLValue, Contract, DeployHash, DeployInfo, Key, KeyTag,
-    Phase, ProtocolVersion, PublicKey, RuntimeArgs, URef, U512,
+    AccessRights, ApiError, CLValue, Contract, DeployHash, DeployInfo, Key, KeyTag, Phase,
+    ProtocolVersion, PublicKey, RuntimeArgs, URef, U512,
 };
 
 pub use self::{
     balance::{BalanceRequest, BalanceResult},
+    block_info::BlockInfo,
+    call_contract::{CallContractRequest, CallContractResult, ContractIdentifier},
     deploy_item::DeployItem,
     engine_config::EngineConfig,
     era_validators::{GetEraValidatorsError, GetEraValidatorsRequest},
@@ -56,7 +60,10 @@ pub use self::{
     execution::Error as ExecError,
     execution_result::{ExecutionResult, ExecutionResults, ForcedTransferResult},
     genesis::{ExecConfig, GenesisAccount, GenesisResult},
-    query::{GetBidsRequest, GetBidsResult, QueryRequest, QueryResult},
+    query::{
+        GetAuctionProofsRequest, GetAuctionProofsResult, GetBidsRequest, GetBidsResult,
+        QueryRequest, QueryResult,
+    },
     step::{RewardItem, SlashItem, StepRequest, StepResult},
     system_contract_cache::SystemContractCache,
     transfer::{TransferArgs, TransferRuntimeArgsBuilder, TransferTargetMode},
@@ -84,7 +91,7 @@ use crate::{
     storage::{
         global_state::{CommitResult, StateProvider},
         protocol_data::ProtocolData,
-        trie::Trie,
+        trie::{merkle_proof::TrieMerkleProofBatch, Trie},
     },
 };
 
@@ -435,7 +442,7 @@ where
                     &executor,
                     exec_request.protocol_version,
                     exec_request.parent_state_hash,
-                    BlockTime::new(exec_request.block_time),
+                    exec_request.block_info(),
                     deploy_item,
                     exec_request.proposer.clone(),
                 ),
@@ -444,7 +451,7 @@ where
                     &executor,
                     exec_request.protocol_version,
                     exec_request.parent_state_hash,
-                    BlockTime::new(exec_request.block_time),
+                    exec_request.block_info(),
                     deploy_item,
                     exec_request.proposer.clone(),
                 ),
@@ -516,7 +523,7 @@ where
         executor: &Executor,
         protocol_version: ProtocolVersion,
         prestate_hash: Blake2bHash,
-        blocktime: BlockTime,
+        block_info: BlockInfo,
         deploy_item: DeployItem,
         proposer: PublicKey,
     ) -> Result<ExecutionResult, Error> {
@@ -707,7 +714,7 @@ where
                             mint_base_key,
                             &account,
                             authorization_keys.clone(),
-                            blocktime,
+                            block_info,
                             deploy_item.deploy_hash,
                             gas_limit,
                             protocol_version,
@@ -797,7 +804,7 @@ where
                     handle_payment_base_key,
                     &account,
                     authorization_keys.clone(),
-                    blocktime,
+                    block_info,
                     deploy_item.deploy_hash,
                     gas_limit,
                     protocol_version,
@@ -842,7 +849,7 @@ where
                     mint_base_key,
                     &account,
                     authorization_keys.clone(),
-                    blocktime,
+                    block_info,
                     deploy_item.deploy_hash,
                     gas_limit,
                     protocol_version,
@@ -929,7 +936,7 @@ where
                 mint_base_key,
                 &account,
                 authorization_keys.clone(),
-                blocktime,
+                block_info,
                 deploy_item.deploy_hash,
                 gas_limit,
                 protocol_version,
@@ -992,7 +999,7 @@ where
                     Key::from(protocol_data.handle_payment()),
                     &system_account,
                     authorization_keys,
-                    blocktime,
+                    block_info,
                     deploy_item.deploy_hash,
                     gas_limit,
                     protocol_version,
@@ -1046,7 +1053,7 @@ where
         executor: &Executor,
         protocol_version: ProtocolVersion,
         prestate_hash: Blake2bHash,
-        blocktime: BlockTime,
+        block_info: BlockInfo,
         deploy_item: DeployItem,
         proposer: PublicKey,
     ) -> Result<ExecutionResult, Error> {
@@ -1282,7 +1289,7 @@ where
                     &account,
                     &mut payment_named_keys,
                     authorization_keys.clone(),
-                    blocktime,
+                    block_info,
                     deploy_hash,
                     payment_gas_limit,
                     protocol_version,
@@ -1301,7 +1308,7 @@ where
                     &account,
                     &mut payment_named_keys,
                     authorization_keys.clone(),
-                    blocktime,
+                    block_info,
                     deploy_hash,
                     payment_gas_limit,
                     protocol_version,
@@ -1513,7 +1520,7 @@ where
                 &account,
                 &mut session_named_keys,
                 authorization_keys.clone(),
-                blocktime,
+                block_info,
                 deploy_hash,
                 session_gas_limit,
                 protocol_version,
@@ -1609,7 +1616,7 @@ where
                     Key::from(protocol_data.handle_payment()),
                     &system_account,
                     authorization_keys,
-                    blocktime,
+                    block_info,
                     deploy_hash,
                     gas_limit,
                     protocol_version,
@@ -1736,7 +1743,7 @@ where
             Account::create(PublicKey::System.to_account_hash(), named_keys, purse)
         };
         let authorization_keys = BTreeSet::from_iter(vec![PublicKey::System.to_account_hash()]);
-        let blocktime = BlockTime::default();
+        let block_info = BlockInfo::default();
         let deploy_hash = {
             // seeds address generator w/ protocol version
             let bytes: Vec<u8> = get_era_validators_request
@@ -1758,7 +1765,7 @@ where
                 base_key,
                 &virtual_system_account,
                 authorization_keys,
-                blocktime,
+                block_info,
                 deploy_hash,
                 gas_limit,
                 protocol_version,
@@ -1779,6 +1786,92 @@ where
         }
     }
 
+    /// Calls a stored contract's entry point against the given state root, returning the value it
+    /// passes to `runtime::ret`.
+    ///
+    /// The effects of the call are discarded rather than committed.
+    pub fn call_contract(
+        &self,
+        correlation_id: CorrelationId,
+        call_contract_request: CallContractRequest,
+    ) -> Result<CallContractResult, Error> {
+        let protocol_version = call_contract_request.protocol_version();
+
+        let tracking_copy = match self.tracking_copy(call_contract_request.state_hash())? {
+            Some(tracking_copy) => Rc::new(RefCell::new(tracking_copy)),
+            None => return Ok(CallContractResult::RootNotFound),
+        };
+
+        let protocol_data = match self.get_protocol_data(protocol_version)? {
+            Some(protocol_data) => protocol_data,
+            None => return Err(Error::InvalidProtocolVersion(protocol_version)),
+        };
+
+        let preprocessor = Preprocessor::new(*protocol_data.wasm_config());
+
+        // Without a caller, the call is made by a virtual system account with no named keys.
+        let account = match call_contract_request.caller() {
+            Some(account_hash) => tracking_copy
+                .borrow_mut()
+                .get_account(correlation_id, account_hash)?,
+            None => {
+                let named_keys = NamedKeys::new();
+                let purse = URef::new(Default::default(), AccessRights::READ_ADD_WRITE);
+                Account::create(PublicKey::System.to_account_hash(), named_keys, purse)
+            }
+        };
+
+        let contract_hash = match call_contract_request.contract() {
+            ContractIdentifier::Hash(contract_hash) => *contract_hash,
+            ContractIdentifier::Name(name) => account
+                .named_keys()
+                .get(name)
+                .ok_or_else(|| execution::Error::NamedKeyNotFound(name.clone()))?
+                .into_hash()
+                .ok_or(Error::InvalidKeyVariant)?
+                .into(),
+        };
+
+        // The call never calls back into the session module, so the system module suffices.
+        let module = tracking_copy
+            .borrow_mut()
+            .get_system_module(&preprocessor)?;
+
+        let executor = Executor::new(self.config);
+
+        let authorization_keys = BTreeSet::from_iter(vec![account.account_hash()]);
+        let block_info = call_contract_request.block_info();
+        let deploy_hash = {
+            // seeds address generator w/ the call's parameters
+            let bytes = (
+                call_contract_request.entry_point().to_string(),
+                call_contract_request.args().clone(),
+            )
+                .into_bytes()?;
+            DeployHash::new(Blake2bHash::new(&bytes).value())
+        };
+
+        let (result, cost) = executor.call_stored_contract(
+            module,
+            contract_hash,
+            call_contract_request.entry_point(),
+            call_contract_request.args().clone(),
+            &account,
+            authorization_keys,
+            block_info,
+            deploy_hash,
+            call_contract_request.gas_limit(),
+            protocol_version,
+            correlation_id,
+            tracking_copy,
+            protocol_data,
+            SystemContractCache::clone(&self.system_contract_cache),
+        );
+
+        let value = result?;
+        Ok(CallContractResult::Success { value, cost })
+    }
+
     pub fn get_bids(
         &self,
         correlation_id: CorrelationId,
@@ -1808,6 +1901,60 @@ where
         Ok(GetBidsResult::Success { bids })
     }
 
+    /// Returns proofs of every `Key::Bid` and `Key::EraValidators` entry held in global state,
+    /// batched so that the trie nodes common to several proofs are only included once.
+    pub fn get_auction_proofs(
+        &self,
+        correlation_id: CorrelationId,
+        get_auction_proofs_request: GetAuctionProofsRequest,
+    ) -> Result<GetAuctionProofsResult, Error> {
+        let mut tracking_copy = match self.tracking_copy(get_auction_proofs_request.state_hash())? {
+            Some(tracking_copy) => tracking_copy,
+            None => return Ok(GetAuctionProofsResult::RootNotFound),
+        };
+
+        let protocol_version = get_auction_proofs_request.protocol_version();
+        let protocol_data = match self.get_protocol_data(protocol_version)? {
+            Some(protocol_data) => protocol_data,
+            None => return Err(Error::InvalidProtocolVersion(protocol_version)),
+        };
+
+        // The auction contract and its current era id and auction delay are proven too, so that
+        // the set of eras whose validators are reported can be derived from the proven state.
+        let auction_key = Key::from(protocol_data.auction());
+        let auction_contract = tracking_copy
+            .get_contract(correlation_id, protocol_data.auction())
+            .map_err(Error::from)?;
+        let mut keys_to_prove = vec![auction_key];
+        for name in &[ERA_ID_KEY, AUCTION_DELAY_KEY] {
+            let key = auction_contract
+                .named_keys()
+                .get(*name)
+                .ok_or_else(|| Error::Exec(ExecError::NamedKeyNotFound(name.to_string())))?;
+            keys_to_prove.push(*key);
+        }
+
+        for key_tag in &[KeyTag::Bid, KeyTag::EraValidators] {
+            let keys = tracking_copy
+                .get_keys(correlation_id, key_tag)
+                .map_err(|err| Error::Exec(err.into()))?;
+            keys_to_prove.extend(keys.iter().copied());
+        }
+
+        let mut proofs = Vec::new();
+        for key in keys_to_prove.iter() {
+            if let Some(proof) = tracking_copy
+                .read_with_proof(correlation_id, key)
+                .map_err(|err| Error::Exec(err.into()))?
+            {
+                proofs.push(proof);
+            }
+        }
+
+        let proofs = Box::new(TrieMerkleProofBatch::from_proofs(proofs)?);
+        Ok(GetAuctionProofsResult::Success { proofs })
+    }
+
     pub fn commit_step(
         &self,
         correlation_id: CorrelationId,
@@ -1910,7 +2057,7 @@ where
             base_key,
             &virtual_system_account,
             authorization_keys.clone(),
-            BlockTime::default(),
+            BlockInfo::default(),
             deploy_hash,
             gas_limit,
             step_request.protocol_version,
@@ -1953,7 +2100,7 @@ where
             base_key,
             &virtual_system_account,
             authorization_keys.clone(),
-            BlockTime::default(),
+            BlockInfo::default(),
             deploy_hash,
             gas_limit,
             step_request.protocol_version,
@@ -2002,7 +2149,7 @@ where
                     base_key,
                     &virtual_system_account,
                     authorization_keys,
-                    BlockTime::default(),
+                    BlockInfo::default(),
                     deploy_hash,
                     gas_limit,
                     step_request.protocol_version,
@@ -1,9 +1,9 @@
-use casper_types::{system::auction::Bids, Key};
+use casper_types::{system::auction::Bids, Key, ProtocolVersion};
 
 use crate::{
     core::tracking_copy::TrackingCopyQueryResult,
     shared::{newtypes::Blake2bHash, stored_value::StoredValue},
-    storage::trie::merkle_proof::TrieMerkleProof,
+    storage::trie::merkle_proof::{TrieMerkleProof, TrieMerkleProofBatch},
 };
 
 #[derive(Debug)]
@@ -94,3 +94,36 @@ impl GetBidsResult {
         }
     }
 }
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetAuctionProofsRequest {
+    state_hash: Blake2bHash,
+    protocol_version: ProtocolVersion,
+}
+
+impl GetAuctionProofsRequest {
+    pub fn new(state_hash: Blake2bHash, protocol_version: ProtocolVersion) -> Self {
+        GetAuctionProofsRequest {
+            state_hash,
+            protocol_version,
+        }
+    }
+
+    pub fn state_hash(&self) -> Blake2bHash {
+        self.state_hash
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        self.protocol_version
+    }
+}
+
+/// The result of requesting proofs of every `Key::Bid` and `Key::EraValidators` entry, along with
+/// the auction contract and its current era id and auction delay.
+#[derive(Debug)]
+pub enum GetAuctionProofsResult {
+    RootNotFound,
+    Success {
+        proofs: Box<TrieMerkleProofBatch<Key, StoredValue>>,
+    },
+}
@@ -7,9 +7,10 @@ use rand_chacha::ChaChaRng;
 
 use casper_types::{AccessRights, Phase, URef};
 
-use crate::core::{Address, ADDRESS_LENGTH};
+use crate::core::{engine_state::block_info::BlockInfo, Address, ADDRESS_LENGTH};
 
 const SEED_LENGTH: usize = 32;
+const RANDOM_SEED_TAG: &[u8] = b"casper_random_seed";
 
 /// An `AddressGenerator` generates `URef` addresses.
 pub struct AddressGenerator(ChaChaRng);
@@ -23,6 +24,26 @@ impl AddressGenerator {
             .build()
     }
 
+    /// Derives the `index`-th seed for contract-level randomness from a 32-byte deploy hash digest,
+    /// [`Phase`] and the [`BlockInfo`] of the block in which the deploy is executed.
+    ///
+    /// The result is deterministic for a given deploy executed in a given block, so it is
+    /// predictable by both the deploy sender and the block proposer and must not be relied upon
+    /// where unpredictability matters.
+    pub fn random_seed(hash: &[u8], phase: Phase, block_info: &BlockInfo, index: u64) -> Address {
+        let blocktime: u64 = block_info.block_time().into();
+        AddressGeneratorBuilder::new()
+            .seed_with(RANDOM_SEED_TAG)
+            .seed_with(&hash)
+            .seed_with(&[phase as u8])
+            .seed_with(&blocktime.to_le_bytes())
+            .seed_with(&block_info.block_height().to_le_bytes())
+            .seed_with(&block_info.parent_block_hash().value())
+            .seed_with(&index.to_le_bytes())
+            .build()
+            .create_address()
+    }
+
     pub fn create_address(&mut self) -> Address {
         let mut buff = [0u8; ADDRESS_LENGTH];
         self.0.fill_bytes(&mut buff);
@@ -74,9 +95,10 @@ impl AddressGeneratorBuilder {
 
 #[cfg(test)]
 mod tests {
-    use casper_types::Phase;
+    use casper_types::{BlockTime, EraId, Phase};
 
     use super::AddressGenerator;
+    use crate::core::engine_state::block_info::BlockInfo;
 
     const DEPLOY_HASH_1: [u8; 32] = [1u8; 32];
     const DEPLOY_HASH_2: [u8; 32] = [2u8; 32];
@@ -120,4 +142,40 @@ mod tests {
             "different phase should have different output"
         );
     }
+
+    #[test]
+    fn should_derive_random_seed_deterministically() {
+        let block_info = BlockInfo::new(BlockTime::new(42), 7, EraId::new(1), [3u8; 32].into());
+        let seed = AddressGenerator::random_seed;
+        let seed_a = seed(&DEPLOY_HASH_1, Phase::Session, &block_info, 0);
+        assert_eq!(seed_a, seed(&DEPLOY_HASH_1, Phase::Session, &block_info, 0));
+
+        let other_blocktime =
+            BlockInfo::new(BlockTime::new(43), 7, EraId::new(1), [3u8; 32].into());
+        let other_height = BlockInfo::new(BlockTime::new(42), 8, EraId::new(1), [3u8; 32].into());
+        let other_parent = BlockInfo::new(BlockTime::new(42), 7, EraId::new(1), [4u8; 32].into());
+        assert_ne!(seed_a, seed(&DEPLOY_HASH_2, Phase::Session, &block_info, 0));
+        assert_ne!(seed_a, seed(&DEPLOY_HASH_1, Phase::Payment, &block_info, 0));
+        assert_ne!(
+            seed_a,
+            seed(&DEPLOY_HASH_1, Phase::Session, &other_blocktime, 0)
+        );
+        assert_ne!(
+            seed_a,
+            seed(&DEPLOY_HASH_1, Phase::Session, &other_height, 0)
+        );
+        assert_ne!(
+            seed_a,
+            seed(&DEPLOY_HASH_1, Phase::Session, &other_parent, 0)
+        );
+        assert_ne!(seed_a, seed(&DEPLOY_HASH_1, Phase::Session, &block_info, 1));
+    }
+
+    #[test]
+    fn random_seed_should_differ_from_address_stream() {
+        let mut ag = AddressGenerator::new(&DEPLOY_HASH_1, Phase::Session);
+        let seed =
+            AddressGenerator::random_seed(&DEPLOY_HASH_1, Phase::Session, &BlockInfo::default(), 0);
+        assert_ne!(ag.create_address(), seed);
+    }
 }
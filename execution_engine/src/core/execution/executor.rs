@@ -9,15 +9,16 @@ use casper_types::{
     bytesrepr::FromBytes,
     contracts::NamedKeys,
     system::{auction, handle_payment, mint},
-    BlockTime, CLTyped, CLValue, ContractPackage, DeployHash, EntryPoint, EntryPointType, Key,
-    Phase, ProtocolVersion, RuntimeArgs,
+    CLTyped, CLValue, CallStackElement, ContractHash, ContractPackage, DeployHash, EntryPoint,
+    EntryPointType, Key, Phase, ProtocolVersion, RuntimeArgs,
 };
 
 use crate::{
     core::{
         engine_state::{
-            execution_effect::ExecutionEffect, execution_result::ExecutionResult,
-            system_contract_cache::SystemContractCache, EngineConfig,
+            block_info::BlockInfo, execution_effect::ExecutionEffect,
+            execution_result::ExecutionResult, system_contract_cache::SystemContractCache,
+            EngineConfig,
         },
         execution::{address_generator::AddressGenerator, Error},
         runtime::{extract_access_rights_from_keys, instance_and_memory, Runtime},
@@ -49,6 +50,7 @@ macro_rules! on_fail_charge {
                     error: exec_err.into(),
                     effect: Default::default(),
                     transfers: $transfers,
+                    events: Vec::default(),
                     cost: $cost,
                 };
             }
@@ -64,6 +66,7 @@ macro_rules! on_fail_charge {
                     error: exec_err.into(),
                     effect: $effect,
                     transfers: $transfers,
+                    events: Vec::default(),
                     cost: $cost,
                 };
             }
@@ -94,7 +97,7 @@ impl Executor {
         account: &Account,
         named_keys: &mut NamedKeys,
         authorization_keys: BTreeSet<AccountHash>,
-        blocktime: BlockTime,
+        block_info: BlockInfo,
         deploy_hash: DeployHash,
         gas_limit: Gas,
         protocol_version: ProtocolVersion,
@@ -138,6 +141,18 @@ impl Executor {
         };
         let gas_counter: Gas = Gas::default();
         let transfers = Vec::default();
+        let events = Vec::default();
+        let call_stack = {
+            let mut call_stack = vec![CallStackElement::session(account.account_hash())];
+            // Stored contracts called directly by a deploy run under their own key.
+            if let Key::Hash(contract_hash) = base_key {
+                call_stack.push(CallStackElement::stored_contract(
+                    ContractHash::new(contract_hash),
+                    entry_point_name.to_string(),
+                ));
+            }
+            call_stack
+        };
 
         // Snapshot of effects before execution, so in case of error
         // only nonce update can be returned.
@@ -152,18 +167,21 @@ impl Executor {
             authorization_keys,
             &account,
             base_key,
-            blocktime,
+            block_info,
             deploy_hash,
             gas_limit,
             gas_counter,
             hash_address_generator,
             uref_address_generator,
             target_address_generator,
+            Rc::default(),
             protocol_version,
             correlation_id,
             phase,
             protocol_data,
             transfers,
+            events,
+            call_stack,
         );
 
         let mut runtime = Runtime::new(self.config, system_contract_cache, memory, module, context);
@@ -191,6 +209,7 @@ impl Executor {
                     return ExecutionResult::Success {
                         effect: runtime.context().effect(),
                         transfers: runtime.context().transfers().to_owned(),
+                        events: runtime.context().events().to_owned(),
                         cost: runtime.context().gas_counter(),
                     };
                 }
@@ -199,6 +218,7 @@ impl Executor {
                         error: error.into(),
                         effect: effects_snapshot,
                         transfers: runtime.context().transfers().to_owned(),
+                        events: Vec::default(),
                         cost: runtime.context().gas_counter(),
                     };
                 }
@@ -215,6 +235,7 @@ impl Executor {
                     return ExecutionResult::Success {
                         effect: runtime.context().effect(),
                         transfers: runtime.context().transfers().to_owned(),
+                        events: runtime.context().events().to_owned(),
                         cost: runtime.context().gas_counter(),
                     };
                 }
@@ -223,6 +244,7 @@ impl Executor {
                         error: error.into(),
                         effect: effects_snapshot,
                         transfers: runtime.context().transfers().to_owned(),
+                        events: Vec::default(),
                         cost: runtime.context().gas_counter(),
                     };
                 }
@@ -239,6 +261,7 @@ impl Executor {
                     return ExecutionResult::Success {
                         effect: runtime.context().effect(),
                         transfers: runtime.context().transfers().to_owned(),
+                        events: runtime.context().events().to_owned(),
                         cost: runtime.context().gas_counter(),
                     }
                 }
@@ -247,6 +270,7 @@ impl Executor {
                         error: error.into(),
                         effect: effects_snapshot,
                         transfers: runtime.context().transfers().to_owned(),
+                        events: Vec::default(),
                         cost: runtime.context().gas_counter(),
                     }
                 }
@@ -262,6 +286,7 @@ impl Executor {
         ExecutionResult::Success {
             effect: runtime.context().effect(),
             transfers: runtime.context().transfers().to_owned(),
+            events: runtime.context().events().to_owned(),
             cost: runtime.context().gas_counter(),
         }
     }
@@ -274,7 +299,7 @@ impl Executor {
         account: &Account,
         payment_named_keys: &mut NamedKeys,
         authorization_keys: BTreeSet<AccountHash>,
-        blocktime: BlockTime,
+        block_info: BlockInfo,
         deploy_hash: DeployHash,
         payment_gas_limit: Gas,
         protocol_version: ProtocolVersion,
@@ -311,7 +336,7 @@ impl Executor {
             payment_base_key,
             &account,
             authorization_keys,
-            blocktime,
+            block_info,
             deploy_hash,
             payment_gas_limit,
             hash_address_generator,
@@ -330,6 +355,7 @@ impl Executor {
                     error: error.into(),
                     effect: Default::default(),
                     transfers: Vec::default(),
+                    events: Vec::default(),
                     cost: Gas::default(),
                 };
             }
@@ -341,12 +367,14 @@ impl Executor {
             Ok(()) => ExecutionResult::Success {
                 effect: runtime.context().effect(),
                 transfers: runtime.context().transfers().to_owned(),
+                events: runtime.context().events().to_owned(),
                 cost: runtime.context().gas_counter(),
             },
             Err(error) => ExecutionResult::Failure {
                 error: error.into(),
                 effect: effects_snapshot,
                 transfers: runtime.context().transfers().to_owned(),
+                events: Vec::default(),
                 cost: runtime.context().gas_counter(),
             },
         }
@@ -362,7 +390,7 @@ impl Executor {
         base_key: Key,
         account: &Account,
         authorization_keys: BTreeSet<AccountHash>,
-        blocktime: BlockTime,
+        block_info: BlockInfo,
         deploy_hash: DeployHash,
         gas_limit: Gas,
         protocol_version: ProtocolVersion,
@@ -444,7 +472,7 @@ impl Executor {
             base_key,
             account,
             authorization_keys,
-            blocktime,
+            block_info,
             deploy_hash,
             gas_limit,
             hash_address_generator,
@@ -462,6 +490,7 @@ impl Executor {
                 return ExecutionResult::Failure {
                     effect: effect_snapshot,
                     transfers,
+                    events: Vec::default(),
                     cost: gas_counter,
                     error: error.into(),
                 }
@@ -491,7 +520,7 @@ impl Executor {
         args: RuntimeArgs,
         account: &mut Account,
         authorization_keys: BTreeSet<AccountHash>,
-        blocktime: BlockTime,
+        block_info: BlockInfo,
         deploy_hash: DeployHash,
         gas_limit: Gas,
         hash_address_generator: Rc<RefCell<AddressGenerator>>,
@@ -521,7 +550,7 @@ impl Executor {
             base_key,
             account,
             authorization_keys,
-            blocktime,
+            block_info,
             deploy_hash,
             gas_limit,
             hash_address_generator,
@@ -568,6 +597,79 @@ impl Executor {
         Ok(ret)
     }
 
+    /// Calls the entry point of the stored contract under `contract_hash` on behalf of `account`,
+    /// returning the value passed to `runtime::ret` along with the gas consumed.
+    ///
+    /// The call is made from a session context backed by `module`, which is never itself invoked.
+    /// None of the effects are committed, so this is suitable for read-only calls.
+    pub fn call_stored_contract<R>(
+        &self,
+        module: Module,
+        contract_hash: ContractHash,
+        entry_point_name: &str,
+        args: RuntimeArgs,
+        account: &Account,
+        authorization_keys: BTreeSet<AccountHash>,
+        block_info: BlockInfo,
+        deploy_hash: DeployHash,
+        gas_limit: Gas,
+        protocol_version: ProtocolVersion,
+        correlation_id: CorrelationId,
+        tracking_copy: Rc<RefCell<TrackingCopy<R>>>,
+        protocol_data: ProtocolData,
+        system_contract_cache: SystemContractCache,
+    ) -> (Result<CLValue, Error>, Gas)
+    where
+        R: StateReader<Key, StoredValue>,
+        R::Error: Into<Error>,
+    {
+        let phase = Phase::Session;
+        let mut named_keys = account.named_keys().clone();
+        let base_key = account.account_hash().into();
+
+        let hash_address_generator = {
+            let generator = AddressGenerator::new(deploy_hash.as_bytes(), phase);
+            Rc::new(RefCell::new(generator))
+        };
+        let uref_address_generator = {
+            let generator = AddressGenerator::new(deploy_hash.as_bytes(), phase);
+            Rc::new(RefCell::new(generator))
+        };
+        let transfer_address_generator = {
+            let generator = AddressGenerator::new(deploy_hash.as_bytes(), phase);
+            Rc::new(RefCell::new(generator))
+        };
+
+        let mut runtime = match self.create_runtime(
+            module,
+            EntryPointType::Session,
+            RuntimeArgs::new(),
+            &mut named_keys,
+            Default::default(),
+            base_key,
+            account,
+            authorization_keys,
+            block_info,
+            deploy_hash,
+            gas_limit,
+            hash_address_generator,
+            uref_address_generator,
+            transfer_address_generator,
+            protocol_version,
+            correlation_id,
+            tracking_copy,
+            phase,
+            protocol_data,
+            system_contract_cache,
+        ) {
+            Ok((_, runtime)) => runtime,
+            Err(error) => return (Err(error), Gas::default()),
+        };
+
+        let result = runtime.call_contract(contract_hash, entry_point_name, args);
+        (result, runtime.context().gas_counter())
+    }
+
     pub fn create_runtime<'a, R>(
         &self,
         module: Module,
@@ -578,7 +680,7 @@ impl Executor {
         base_key: Key,
         account: &'a Account,
         authorization_keys: BTreeSet<AccountHash>,
-        blocktime: BlockTime,
+        block_info: BlockInfo,
         deploy_hash: DeployHash,
         gas_limit: Gas,
         hash_address_generator: Rc<RefCell<AddressGenerator>>,
@@ -603,6 +705,8 @@ impl Executor {
 
         let gas_counter = Gas::default();
         let transfers = Vec::default();
+        let events = Vec::default();
+        let call_stack = vec![CallStackElement::session(account.account_hash())];
 
         let runtime_context = RuntimeContext::new(
             tracking_copy,
@@ -613,18 +717,21 @@ impl Executor {
             authorization_keys,
             account,
             base_key,
-            blocktime,
+            block_info,
             deploy_hash,
             gas_limit,
             gas_counter,
             hash_address_generator,
             uref_address_generator,
             transfer_address_generator,
+            Rc::default(),
             protocol_version,
             correlation_id,
             phase,
             protocol_data,
             transfers,
+            events,
+            call_stack,
         );
 
         let (instance, memory) = instance_and_memory(
@@ -732,6 +839,7 @@ impl DirectSystemContractCall {
                 Ok(ret) => ExecutionResult::Success {
                     effect: runtime.context().effect(),
                     transfers: runtime.context().transfers().to_owned(),
+                    events: runtime.context().events().to_owned(),
                     cost: runtime.context().gas_counter(),
                 }
                 .take_with_ret(ret),
@@ -739,6 +847,7 @@ impl DirectSystemContractCall {
                     error: Error::CLValue(error).into(),
                     effect: execution_effect,
                     transfers: runtime.context().transfers().to_owned(),
+                    events: Vec::default(),
                     cost: runtime.context().gas_counter(),
                 }
                 .take_without_ret(),
@@ -747,6 +856,7 @@ impl DirectSystemContractCall {
                 error: error.into(),
                 effect: execution_effect,
                 transfers: runtime.context().transfers().to_owned(),
+                events: Vec::default(),
                 cost: runtime.context().gas_counter(),
             }
             .take_without_ret(),
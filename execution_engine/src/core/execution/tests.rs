@@ -20,6 +20,7 @@ fn on_fail_charge_test_helper<T>(
     ExecutionResult::Success {
         effect: Default::default(),
         transfers,
+        events: Vec::default(),
         cost: success_cost,
     }
 }
@@ -66,6 +67,7 @@ fn on_fail_charge_with_action() {
         ExecutionResult::Success {
             effect: Default::default(),
             transfers: Vec::default(),
+            events: Vec::default(),
             cost: Gas::default(),
         }
     };
@@ -50,6 +50,19 @@ pub enum FunctionIndex {
     Blake2b,
     RecordTransfer,
     RecordEraInfo,
+    EmitEvent,
+    VerifySignature,
+    Sha256,
+    Keccak256,
+    RandomSeed,
+    GetBlockHeight,
+    GetEraId,
+    GetParentBlockHash,
+    GetDeployHash,
+    LoadCallStack,
+    NewDictionary,
+    DictionaryGet,
+    DictionaryPut,
 }
 
 impl Into<usize> for FunctionIndex {
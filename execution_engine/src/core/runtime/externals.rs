@@ -1,5 +1,7 @@
 use std::{collections::BTreeSet, convert::TryFrom};
 
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
 use wasmi::{Externals, RuntimeArgs, RuntimeValue, Trap};
 
 use casper_types::{
@@ -9,7 +11,8 @@ use casper_types::{
     bytesrepr::{self, ToBytes},
     contracts::{ContractPackageStatus, EntryPoints, NamedKeys},
     system::auction::EraInfo,
-    ContractHash, ContractPackageHash, ContractVersion, EraId, Group, Key, URef, U512,
+    ContractHash, ContractPackageHash, ContractVersion, EraId, Group, Key, PublicKey, Signature,
+    URef, U512,
 };
 
 use super::{args::Args, scoped_instrumenter::ScopedInstrumenter, Error, Runtime};
@@ -916,14 +919,8 @@ where
                 scoped_instrumenter.add_property("out_size", out_size.to_string());
                 let input: Vec<u8> = self.bytes_from_mem(in_ptr, in_size as usize)?;
                 let digest = account::blake2b(&input);
-                if digest.len() != out_size as usize {
-                    let err_value = u32::from(api_error::ApiError::BufferTooSmall) as i32;
-                    return Ok(Some(RuntimeValue::I32(err_value)));
-                }
-                self.memory
-                    .set(out_ptr, &digest)
-                    .map_err(|error| Error::Interpreter(error.into()))?;
-                Ok(Some(RuntimeValue::I32(0)))
+                let result = self.write_digest(&digest, out_ptr, out_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(result))))
             }
 
             FunctionIndex::RecordTransfer => {
@@ -968,6 +965,209 @@ where
                 self.record_era_info(era_id, era_info)?;
                 Ok(Some(RuntimeValue::I32(0)))
             }
+
+            FunctionIndex::EmitEvent => {
+                // args(0) = pointer to the serialized topic in Wasm memory
+                // args(1) = size of the serialized topic
+                // args(2) = pointer to the serialized payload `CLValue` in Wasm memory
+                // args(3) = size of the serialized payload
+                let (topic_ptr, topic_size, payload_ptr, payload_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.emit_event,
+                    [topic_ptr, topic_size, payload_ptr, payload_size],
+                )?;
+                scoped_instrumenter.add_property("topic_size", topic_size.to_string());
+                scoped_instrumenter.add_property("payload_size", payload_size.to_string());
+                self.emit_event(topic_ptr, topic_size, payload_ptr, payload_size)?;
+                Ok(Some(RuntimeValue::I32(0)))
+            }
+
+            FunctionIndex::VerifySignature => {
+                // args(0) = pointer to the message bytes in Wasm memory
+                // args(1) = size of the message
+                // args(2) = pointer to the serialized `Signature` in Wasm memory
+                // args(3) = size of the serialized `Signature`
+                // args(4) = pointer to the serialized `PublicKey` in Wasm memory
+                // args(5) = size of the serialized `PublicKey`
+                let (
+                    message_ptr,
+                    message_size,
+                    signature_ptr,
+                    signature_size,
+                    public_key_ptr,
+                    public_key_size,
+                ) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.verify_signature,
+                    [
+                        message_ptr,
+                        message_size,
+                        signature_ptr,
+                        signature_size,
+                        public_key_ptr,
+                        public_key_size,
+                    ],
+                )?;
+                scoped_instrumenter.add_property("message_size", message_size.to_string());
+                let message: Vec<u8> = self.bytes_from_mem(message_ptr, message_size as usize)?;
+                let signature_bytes =
+                    self.bytes_from_mem(signature_ptr, signature_size as usize)?;
+                let public_key_bytes =
+                    self.bytes_from_mem(public_key_ptr, public_key_size as usize)?;
+                // Malformed signature or public key bytes fail the verification rather than trap.
+                let is_valid = match (
+                    bytesrepr::deserialize::<Signature>(signature_bytes),
+                    bytesrepr::deserialize::<PublicKey>(public_key_bytes),
+                ) {
+                    (Ok(signature), Ok(public_key)) => {
+                        casper_types::verify(&message, &signature, &public_key).is_ok()
+                    }
+                    _ => false,
+                };
+                Ok(Some(RuntimeValue::I32(i32::from(is_valid))))
+            }
+
+            FunctionIndex::Sha256 => {
+                // args(0) = pointer to the input bytes in Wasm memory
+                // args(1) = size of the input
+                // args(2) = pointer to the output buffer in Wasm memory
+                // args(3) = size of the output buffer
+                let (in_ptr, in_size, out_ptr, out_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.sha256,
+                    [in_ptr, in_size, out_ptr, out_size],
+                )?;
+                scoped_instrumenter.add_property("in_size", in_size.to_string());
+                scoped_instrumenter.add_property("out_size", out_size.to_string());
+                let input: Vec<u8> = self.bytes_from_mem(in_ptr, in_size as usize)?;
+                let digest = Sha256::digest(&input);
+                let result = self.write_digest(&digest, out_ptr, out_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(result))))
+            }
+
+            FunctionIndex::Keccak256 => {
+                // args(0) = pointer to the input bytes in Wasm memory
+                // args(1) = size of the input
+                // args(2) = pointer to the output buffer in Wasm memory
+                // args(3) = size of the output buffer
+                let (in_ptr, in_size, out_ptr, out_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.keccak256,
+                    [in_ptr, in_size, out_ptr, out_size],
+                )?;
+                scoped_instrumenter.add_property("in_size", in_size.to_string());
+                scoped_instrumenter.add_property("out_size", out_size.to_string());
+                let input: Vec<u8> = self.bytes_from_mem(in_ptr, in_size as usize)?;
+                let digest = Keccak256::digest(&input);
+                let result = self.write_digest(&digest, out_ptr, out_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(result))))
+            }
+
+            FunctionIndex::RandomSeed => {
+                // args(0) = pointer to the output buffer in Wasm memory
+                // args(1) = size of the output buffer
+                let (out_ptr, out_size) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.random_seed,
+                    [out_ptr, out_size],
+                )?;
+                let seed = self.random_seed();
+                let result = self.write_digest(&seed, out_ptr, out_size)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(result))))
+            }
+
+            FunctionIndex::GetBlockHeight => {
+                // args(0) = pointer to Wasm memory where to write.
+                let dest_ptr = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_block_height, [dest_ptr])?;
+                self.get_block_height(dest_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::GetEraId => {
+                // args(0) = pointer to Wasm memory where to write.
+                let dest_ptr = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_era_id, [dest_ptr])?;
+                self.get_era_id(dest_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::GetParentBlockHash => {
+                // args(0) = pointer to Wasm memory where to write.
+                let dest_ptr = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.get_parent_block_hash,
+                    [dest_ptr],
+                )?;
+                self.get_parent_block_hash(dest_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::GetDeployHash => {
+                // args(0) = pointer to Wasm memory where to write.
+                let dest_ptr = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.get_deploy_hash, [dest_ptr])?;
+                self.get_deploy_hash(dest_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::LoadCallStack => {
+                // args(0) = pointer to amount of call stack elements (output)
+                // args(1) = pointer to amount of serialized bytes (output)
+                let (call_stack_len_ptr, result_size_ptr) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.load_call_stack,
+                    [call_stack_len_ptr, result_size_ptr],
+                )?;
+                let ret = self.load_call_stack(call_stack_len_ptr, result_size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::NewDictionary => {
+                // args(0) = pointer to uref destination in Wasm memory
+                let uref_ptr = Args::parse(args)?;
+                self.charge_host_function_call(&host_function_costs.new_dictionary, [uref_ptr])?;
+                self.new_dictionary(uref_ptr)?;
+                Ok(None)
+            }
+
+            FunctionIndex::DictionaryGet => {
+                // args(0) = pointer to seed uref in Wasm memory
+                // args(1) = size of seed uref
+                // args(2) = pointer to dictionary item key in Wasm memory
+                // args(3) = size of dictionary item key
+                // args(4) = pointer to output size (output param)
+                let (uref_ptr, uref_size, key_ptr, key_size, output_size_ptr) = Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.dictionary_get,
+                    [uref_ptr, uref_size, key_ptr, key_size, output_size_ptr],
+                )?;
+                let ret =
+                    self.dictionary_get(uref_ptr, uref_size, key_ptr, key_size, output_size_ptr)?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
+
+            FunctionIndex::DictionaryPut => {
+                // args(0) = pointer to seed uref in Wasm memory
+                // args(1) = size of seed uref
+                // args(2) = pointer to dictionary item key in Wasm memory
+                // args(3) = size of dictionary item key
+                // args(4) = pointer to value
+                // args(5) = size of value
+                let (uref_ptr, uref_size, key_ptr, key_size, value_ptr, value_size) =
+                    Args::parse(args)?;
+                self.charge_host_function_call(
+                    &host_function_costs.dictionary_put,
+                    [
+                        uref_ptr, uref_size, key_ptr, key_size, value_ptr, value_size,
+                    ],
+                )?;
+                scoped_instrumenter.add_property("value_size", value_size);
+                let ret = self.dictionary_put(
+                    uref_ptr, uref_size, key_ptr, key_size, value_ptr, value_size,
+                )?;
+                Ok(Some(RuntimeValue::I32(api_error::i32_from(ret))))
+            }
         }
     }
 }
@@ -32,16 +32,17 @@ use casper_types::{
         standard_payment::{self, StandardPayment},
         SystemContractType,
     },
-    AccessRights, ApiError, CLType, CLTyped, CLValue, ContractHash, ContractPackageHash,
-    ContractVersionKey, ContractWasm, DeployHash, EntryPointType, EraId, Key, Phase,
-    ProtocolVersion, PublicKey, RuntimeArgs, Transfer, TransferResult, TransferredTo, URef, U128,
-    U256, U512,
+    AccessRights, ApiError, CLType, CLTyped, CLValue, CallStackElement, ContractEvent,
+    ContractHash, ContractPackageHash, ContractVersionKey, ContractWasm, DeployHash,
+    DictionaryValue, EntryPointType, EraId, Key, Phase, ProtocolVersion, PublicKey, RuntimeArgs,
+    Transfer, TransferResult, TransferredTo, URef, DICTIONARY_ITEM_KEY_MAX_LENGTH, U128, U256,
+    U512,
 };
 
 use crate::{
     core::{
         engine_state::{system_contract_cache::SystemContractCache, EngineConfig},
-        execution::{self, Error},
+        execution::{self, AddressGenerator, Error},
         resolvers::{create_module_resolver, memory_resolver::MemoryResolver},
         runtime::scoped_instrumenter::ScopedInstrumenter,
         runtime_context::{self, RuntimeContext},
@@ -100,6 +101,7 @@ pub fn key_to_tuple(key: Key) -> Option<([u8; 32], AccessRights)> {
         Key::Bid(_) => None,
         Key::Withdraw(_) => None,
         Key::EraValidators(_) => None,
+        Key::Dictionary(_) => None,
     }
 }
 
@@ -1208,6 +1210,38 @@ where
             .map_err(|e| Error::Interpreter(e.into()).into())
     }
 
+    /// Writes current block height to dest_ptr in Wasm memory.
+    fn get_block_height(&self, dest_ptr: u32) -> Result<(), Trap> {
+        let block_height = self.context.block_info().block_height();
+        self.write_value(dest_ptr, block_height)
+    }
+
+    /// Writes current era id to dest_ptr in Wasm memory.
+    fn get_era_id(&self, dest_ptr: u32) -> Result<(), Trap> {
+        let era_id = self.context.block_info().era_id();
+        self.write_value(dest_ptr, era_id)
+    }
+
+    /// Writes the hash of the current block's parent to dest_ptr in Wasm memory.
+    fn get_parent_block_hash(&self, dest_ptr: u32) -> Result<(), Trap> {
+        let parent_block_hash = self.context.block_info().parent_block_hash();
+        self.write_value(dest_ptr, parent_block_hash)
+    }
+
+    /// Writes current deploy hash to dest_ptr in Wasm memory.
+    fn get_deploy_hash(&self, dest_ptr: u32) -> Result<(), Trap> {
+        let deploy_hash = self.context.get_deploy_hash();
+        self.write_value(dest_ptr, deploy_hash)
+    }
+
+    /// Writes the serialized `value` to dest_ptr in Wasm memory.
+    fn write_value<T: ToBytes>(&self, dest_ptr: u32, value: T) -> Result<(), Trap> {
+        let bytes = value.into_bytes().map_err(Error::BytesRepr)?;
+        self.memory
+            .set(dest_ptr, &bytes)
+            .map_err(|e| Error::Interpreter(e.into()).into())
+    }
+
     /// Return some bytes from the memory and terminate the current `sub_call`. Note that the return
     /// type is `Trap`, indicating that this function will always kill the current Wasm instance.
     fn ret(
@@ -1315,17 +1349,19 @@ where
         let authorization_keys = self.context.authorization_keys().to_owned();
         let account = self.context.account();
         let base_key = self.protocol_data().mint().into();
-        let blocktime = self.context.get_blocktime();
+        let block_info = self.context.block_info();
         let deploy_hash = self.context.get_deploy_hash();
         let gas_limit = self.context.gas_limit();
         let gas_counter = self.context.gas_counter();
         let hash_address_generator = self.context.hash_address_generator();
         let uref_address_generator = self.context.uref_address_generator();
         let transfer_address_generator = self.context.transfer_address_generator();
+        let random_seed_counter = self.context.random_seed_counter();
         let correlation_id = self.context.correlation_id();
         let phase = self.context.phase();
         let protocol_data = self.context.protocol_data();
         let transfers = self.context.transfers().to_owned();
+        let call_stack = self.extended_call_stack(self.protocol_data().mint(), entry_point_name);
 
         let mint_context = RuntimeContext::new(
             self.context.state(),
@@ -1336,18 +1372,22 @@ where
             authorization_keys,
             account,
             base_key,
-            blocktime,
+            block_info,
             deploy_hash,
             gas_limit,
             gas_counter,
             hash_address_generator,
             uref_address_generator,
             transfer_address_generator,
+            random_seed_counter,
             protocol_version,
             correlation_id,
             phase,
             *protocol_data,
             transfers,
+            // System contracts don't emit events.
+            Vec::default(),
+            call_stack,
         );
 
         let mut mint_runtime = Runtime::new(
@@ -1460,17 +1500,20 @@ where
         let authorization_keys = self.context.authorization_keys().to_owned();
         let account = self.context.account();
         let base_key = self.protocol_data().handle_payment().into();
-        let blocktime = self.context.get_blocktime();
+        let block_info = self.context.block_info();
         let deploy_hash = self.context.get_deploy_hash();
         let gas_limit = self.context.gas_limit();
         let gas_counter = self.context.gas_counter();
         let fn_store_id = self.context.hash_address_generator();
         let address_generator = self.context.uref_address_generator();
         let transfer_address_generator = self.context.transfer_address_generator();
+        let random_seed_counter = self.context.random_seed_counter();
         let correlation_id = self.context.correlation_id();
         let phase = self.context.phase();
         let protocol_data = self.context.protocol_data();
         let transfers = self.context.transfers().to_owned();
+        let call_stack =
+            self.extended_call_stack(self.protocol_data().handle_payment(), entry_point_name);
 
         let runtime_context = RuntimeContext::new(
             self.context.state(),
@@ -1481,18 +1524,22 @@ where
             authorization_keys,
             account,
             base_key,
-            blocktime,
+            block_info,
             deploy_hash,
             gas_limit,
             gas_counter,
             fn_store_id,
             address_generator,
             transfer_address_generator,
+            random_seed_counter,
             protocol_version,
             correlation_id,
             phase,
             *protocol_data,
             transfers,
+            // System contracts don't emit events.
+            Vec::default(),
+            call_stack,
         );
 
         let mut runtime = Runtime::new(
@@ -1587,17 +1634,19 @@ where
         let authorization_keys = self.context.authorization_keys().to_owned();
         let account = self.context.account();
         let base_key = self.protocol_data().auction().into();
-        let blocktime = self.context.get_blocktime();
+        let block_info = self.context.block_info();
         let deploy_hash = self.context.get_deploy_hash();
         let gas_limit = self.context.gas_limit();
         let gas_counter = self.context.gas_counter();
         let fn_store_id = self.context.hash_address_generator();
         let address_generator = self.context.uref_address_generator();
         let transfer_address_generator = self.context.transfer_address_generator();
+        let random_seed_counter = self.context.random_seed_counter();
         let correlation_id = self.context.correlation_id();
         let phase = self.context.phase();
         let protocol_data = self.context.protocol_data();
         let transfers = self.context.transfers().to_owned();
+        let call_stack = self.extended_call_stack(self.protocol_data().auction(), entry_point_name);
 
         let runtime_context = RuntimeContext::new(
             self.context.state(),
@@ -1608,18 +1657,22 @@ where
             authorization_keys,
             account,
             base_key,
-            blocktime,
+            block_info,
             deploy_hash,
             gas_limit,
             gas_counter,
             fn_store_id,
             address_generator,
             transfer_address_generator,
+            random_seed_counter,
             protocol_version,
             correlation_id,
             phase,
             *protocol_data,
             transfers,
+            // System contracts don't emit events.
+            Vec::default(),
+            call_stack,
         );
 
         let mut runtime = Runtime::new(
@@ -2012,6 +2065,13 @@ where
 
         let entry_point_name = entry_point.name();
 
+        let call_stack = match key.into_hash() {
+            Some(contract_hash) => {
+                self.extended_call_stack(ContractHash::new(contract_hash), entry_point_name)
+            }
+            None => self.context.call_stack().to_owned(),
+        };
+
         let (instance, memory) = instance_and_memory(
             module.clone(),
             protocol_version,
@@ -2041,18 +2101,21 @@ where
             self.context.authorization_keys().clone(),
             &self.context.account(),
             base_key,
-            self.context.get_blocktime(),
+            self.context.block_info(),
             self.context.get_deploy_hash(),
             self.context.gas_limit(),
             self.context.gas_counter(),
             self.context.hash_address_generator(),
             self.context.uref_address_generator(),
             self.context.transfer_address_generator(),
+            self.context.random_seed_counter(),
             protocol_version,
             self.context.correlation_id(),
             self.context.phase(),
             *self.context.protocol_data(),
             self.context.transfers().to_owned(),
+            self.context.events().to_owned(),
+            call_stack,
         );
 
         let mut runtime = Runtime {
@@ -2090,6 +2153,8 @@ where
                     // running session code
                     *self.context.named_keys_mut() = runtime.context.named_keys().clone();
                 }
+                // The events emitted by the callee are only kept if its execution succeeded.
+                *self.context.events_mut() = runtime.context.events().to_owned();
                 return Ok(runtime.take_host_buffer().unwrap_or(CLValue::from_t(())?));
             }
         };
@@ -2113,6 +2178,7 @@ where
                         // running session code
                         *self.context.named_keys_mut() = runtime.context.named_keys().clone();
                     }
+                    *self.context.events_mut() = runtime.context.events().to_owned();
                     return runtime.take_host_buffer().ok_or(Error::ExpectedReturnValue);
                 }
                 Some(error) => return Err(error.clone()),
@@ -2244,6 +2310,43 @@ where
         Ok(Ok(()))
     }
 
+    fn load_call_stack(
+        &mut self,
+        call_stack_len_ptr: u32,
+        result_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let call_stack = self.context.call_stack().to_owned();
+        let call_stack_len = call_stack.len() as u32;
+        let call_stack_len_bytes = call_stack_len.to_le_bytes();
+        if let Err(error) = self.memory.set(call_stack_len_ptr, &call_stack_len_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        if call_stack_len == 0 {
+            // No need to do anything else, we leave host buffer empty.
+            return Ok(Ok(()));
+        }
+
+        let call_stack = CLValue::from_t(call_stack).map_err(Error::CLValue)?;
+
+        let length = call_stack.inner_bytes().len() as u32;
+        if let Err(error) = self.write_host_buffer(call_stack) {
+            return Ok(Err(error));
+        }
+
+        let length_bytes = length.to_le_bytes();
+        if let Err(error) = self.memory.set(result_size_ptr, &length_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
     fn create_contract_package(
         &mut self,
         is_locked: ContractPackageStatus,
@@ -2546,6 +2649,76 @@ where
         Ok(())
     }
 
+    /// Records an event emitted by the currently executing contract or session code.
+    fn emit_event(
+        &mut self,
+        topic_ptr: u32,
+        topic_size: u32,
+        payload_ptr: u32,
+        payload_size: u32,
+    ) -> Result<(), Trap> {
+        let topic = self.string_from_mem(topic_ptr, topic_size)?;
+        let payload: CLValue = self.t_from_mem(payload_ptr, payload_size)?;
+        let contract_hash = match self.context.base_key() {
+            Key::Hash(hash_addr) => Some(ContractHash::new(hash_addr)),
+            _ => None,
+        };
+        self.context.events_mut().push(ContractEvent {
+            contract_hash,
+            topic,
+            payload,
+        });
+        Ok(())
+    }
+
+    /// Writes `digest` into the output buffer, failing if the buffer size doesn't match the
+    /// digest length.
+    fn write_digest(
+        &mut self,
+        digest: &[u8],
+        out_ptr: u32,
+        out_size: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if digest.len() != out_size as usize {
+            return Ok(Err(ApiError::BufferTooSmall));
+        }
+        self.memory
+            .set(out_ptr, digest)
+            .map_err(|error| Error::Interpreter(error.into()))?;
+        Ok(Ok(()))
+    }
+
+    /// Returns the current call stack extended with a call to `entry_point_name` of the contract
+    /// under `contract_hash`.
+    fn extended_call_stack(
+        &self,
+        contract_hash: ContractHash,
+        entry_point_name: &str,
+    ) -> Vec<CallStackElement> {
+        let mut call_stack = self.context.call_stack().to_owned();
+        call_stack.push(CallStackElement::stored_contract(
+            contract_hash,
+            entry_point_name.to_string(),
+        ));
+        call_stack
+    }
+
+    /// Derives the next deterministic random seed for the current deploy, phase and block.
+    ///
+    /// Each call within the deploy's current phase, including calls made by stored contracts,
+    /// yields a different seed.
+    fn random_seed(&self) -> Address {
+        let random_seed_counter = self.context.random_seed_counter();
+        let index = random_seed_counter.get();
+        random_seed_counter.set(index + 1);
+        AddressGenerator::random_seed(
+            self.context.get_deploy_hash().as_bytes(),
+            self.context.phase(),
+            &self.context.block_info(),
+            index,
+        )
+    }
+
     /// Adds `value` to the cell that `key` points at.
     fn add(
         &mut self,
@@ -2596,6 +2769,101 @@ where
         Ok(Ok(()))
     }
 
+    /// Generates a new unforgeable reference which seeds a dictionary, writing it to `uref_ptr`
+    /// in Wasm memory.
+    fn new_dictionary(&mut self, uref_ptr: u32) -> Result<(), Trap> {
+        let uref = self.context.new_unit_uref()?;
+        self.memory
+            .set(uref_ptr, &uref.into_bytes().map_err(Error::BytesRepr)?)
+            .map_err(|e| Error::Interpreter(e.into()).into())
+    }
+
+    /// Reads the raw bytes of a dictionary item key from Wasm memory, checking its length.
+    fn dictionary_item_key_from_mem(
+        &self,
+        key_ptr: u32,
+        key_size: u32,
+    ) -> Result<Result<Vec<u8>, ApiError>, Trap> {
+        if key_size as usize > DICTIONARY_ITEM_KEY_MAX_LENGTH {
+            return Ok(Err(ApiError::DictionaryItemKeyExceedsLength));
+        }
+        Ok(Ok(self.bytes_from_mem(key_ptr, key_size as usize)?))
+    }
+
+    /// Reads the value stored under the dictionary item key specified by `key_ptr` and
+    /// `key_size` in the dictionary seeded by the `URef` specified by `uref_ptr` and `uref_size`,
+    /// and writes it to the host buffer.
+    fn dictionary_get(
+        &mut self,
+        uref_ptr: u32,
+        uref_size: u32,
+        key_ptr: u32,
+        key_size: u32,
+        output_size_ptr: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        if !self.can_write_to_host_buffer() {
+            // Exit early if the host buffer is already occupied
+            return Ok(Err(ApiError::HostBufferFull));
+        }
+
+        let seed_uref: URef = self.t_from_mem(uref_ptr, uref_size)?;
+        let dictionary_item_key = match self.dictionary_item_key_from_mem(key_ptr, key_size)? {
+            Ok(dictionary_item_key) => dictionary_item_key,
+            Err(error) => return Ok(Err(error)),
+        };
+
+        let cl_value = match self
+            .context
+            .dictionary_read(seed_uref, &dictionary_item_key)?
+        {
+            Some(stored_value) => DictionaryValue::try_from(stored_value)
+                .map_err(Error::TypeMismatch)?
+                .into_cl_value(),
+            None => return Ok(Err(ApiError::ValueNotFound)),
+        };
+
+        let value_size = cl_value.inner_bytes().len() as u32;
+        if let Err(error) = self.write_host_buffer(cl_value) {
+            return Ok(Err(error));
+        }
+
+        let value_bytes = value_size.to_le_bytes(); // Wasm is little-endian
+        if let Err(error) = self.memory.set(output_size_ptr, &value_bytes) {
+            return Err(Error::Interpreter(error.into()).into());
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Writes the value specified by `value_ptr` and `value_size` under the dictionary item key
+    /// specified by `key_ptr` and `key_size` in the dictionary seeded by the `URef` specified by
+    /// `uref_ptr` and `uref_size`.
+    fn dictionary_put(
+        &mut self,
+        uref_ptr: u32,
+        uref_size: u32,
+        key_ptr: u32,
+        key_size: u32,
+        value_ptr: u32,
+        value_size: u32,
+    ) -> Result<Result<(), ApiError>, Trap> {
+        let seed_uref: URef = self.t_from_mem(uref_ptr, uref_size)?;
+        let dictionary_item_key = match self.dictionary_item_key_from_mem(key_ptr, key_size)? {
+            Ok(dictionary_item_key) => dictionary_item_key,
+            Err(error) => return Ok(Err(error)),
+        };
+        let cl_value = self.cl_value_from_mem(value_ptr, value_size)?;
+        let dictionary_value =
+            DictionaryValue::new(cl_value, seed_uref, dictionary_item_key.clone().into());
+
+        self.context.dictionary_write(
+            seed_uref,
+            &dictionary_item_key,
+            StoredValue::Dictionary(dictionary_value),
+        )?;
+        Ok(Ok(()))
+    }
+
     /// Reverts contract execution with a status specified.
     fn revert(&mut self, status: u32) -> Trap {
         Error::Revert(status.into()).into()
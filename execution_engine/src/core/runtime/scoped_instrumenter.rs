@@ -143,6 +143,19 @@ impl Drop for ScopedInstrumenter {
             FunctionIndex::Blake2b => "host_blake2b",
             FunctionIndex::RecordTransfer => "host_record_transfer",
             FunctionIndex::RecordEraInfo => "host_record_era_info",
+            FunctionIndex::EmitEvent => "host_emit_event",
+            FunctionIndex::VerifySignature => "host_verify_signature",
+            FunctionIndex::Sha256 => "host_sha256",
+            FunctionIndex::Keccak256 => "host_keccak256",
+            FunctionIndex::RandomSeed => "host_random_seed",
+            FunctionIndex::GetBlockHeight => "host_get_block_height",
+            FunctionIndex::GetEraId => "host_get_era_id",
+            FunctionIndex::GetParentBlockHash => "host_get_parent_block_hash",
+            FunctionIndex::GetDeployHash => "host_get_deploy_hash",
+            FunctionIndex::LoadCallStack => "host_load_call_stack",
+            FunctionIndex::NewDictionary => "host_new_dictionary",
+            FunctionIndex::DictionaryGet => "host_dictionary_get",
+            FunctionIndex::DictionaryPut => "host_dictionary_put",
         };
 
         let mut properties = mem::take(&mut self.properties);
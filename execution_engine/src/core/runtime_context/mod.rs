@@ -1,5 +1,5 @@
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
     collections::{BTreeSet, HashMap, HashSet},
     convert::{TryFrom, TryInto},
     fmt::Debug,
@@ -15,14 +15,15 @@ use casper_types::{
     bytesrepr::ToBytes,
     contracts::NamedKeys,
     system::auction::EraInfo,
-    AccessRights, BlockTime, CLType, CLValue, Contract, ContractPackage, ContractPackageHash,
-    DeployHash, DeployInfo, EntryPointAccess, EntryPointType, Key, KeyTag, Phase, ProtocolVersion,
-    PublicKey, RuntimeArgs, Transfer, TransferAddr, URef, KEY_HASH_LENGTH,
+    AccessRights, BlockTime, CLType, CLValue, CallStackElement, Contract, ContractEvent,
+    ContractPackage, ContractPackageHash, DeployHash, DeployInfo, EntryPointAccess, EntryPointType,
+    Key, KeyTag, Phase, ProtocolVersion, PublicKey, RuntimeArgs, Transfer, TransferAddr, URef,
+    KEY_HASH_LENGTH,
 };
 
 use crate::{
     core::{
-        engine_state::execution_effect::ExecutionEffect,
+        engine_state::{block_info::BlockInfo, execution_effect::ExecutionEffect},
         execution::{AddressGenerator, Error},
         tracking_copy::{AddResult, TrackingCopy},
         Address,
@@ -92,19 +93,23 @@ pub struct RuntimeContext<'a, R> {
     // Key pointing to the entity we are currently running
     //(could point at an account or contract in the global state)
     base_key: Key,
-    blocktime: BlockTime,
+    block_info: BlockInfo,
     deploy_hash: DeployHash,
     gas_limit: Gas,
     gas_counter: Gas,
     hash_address_generator: Rc<RefCell<AddressGenerator>>,
     uref_address_generator: Rc<RefCell<AddressGenerator>>,
     transfer_address_generator: Rc<RefCell<AddressGenerator>>,
+    // Number of random seeds drawn so far by the deploy in the current phase
+    random_seed_counter: Rc<Cell<u64>>,
     protocol_version: ProtocolVersion,
     correlation_id: CorrelationId,
     phase: Phase,
     protocol_data: ProtocolData,
     entry_point_type: EntryPointType,
     transfers: Vec<TransferAddr>,
+    events: Vec<ContractEvent>,
+    call_stack: Vec<CallStackElement>,
 }
 
 impl<'a, R> RuntimeContext<'a, R>
@@ -122,18 +127,21 @@ where
         authorization_keys: BTreeSet<AccountHash>,
         account: &'a Account,
         base_key: Key,
-        blocktime: BlockTime,
+        block_info: BlockInfo,
         deploy_hash: DeployHash,
         gas_limit: Gas,
         gas_counter: Gas,
         hash_address_generator: Rc<RefCell<AddressGenerator>>,
         uref_address_generator: Rc<RefCell<AddressGenerator>>,
         transfer_address_generator: Rc<RefCell<AddressGenerator>>,
+        random_seed_counter: Rc<Cell<u64>>,
         protocol_version: ProtocolVersion,
         correlation_id: CorrelationId,
         phase: Phase,
         protocol_data: ProtocolData,
         transfers: Vec<TransferAddr>,
+        events: Vec<ContractEvent>,
+        call_stack: Vec<CallStackElement>,
     ) -> Self {
         RuntimeContext {
             tracking_copy,
@@ -143,7 +151,7 @@ where
             args: runtime_args,
             account,
             authorization_keys,
-            blocktime,
+            block_info,
             deploy_hash,
             base_key,
             gas_limit,
@@ -151,11 +159,14 @@ where
             hash_address_generator,
             uref_address_generator,
             transfer_address_generator,
+            random_seed_counter,
             protocol_version,
             correlation_id,
             phase,
             protocol_data,
             transfers,
+            events,
+            call_stack,
         }
     }
 
@@ -265,6 +276,11 @@ where
                 // Users cannot remove era validators info from global state
                 Ok(())
             }
+            Key::Dictionary(_) => {
+                self.named_keys.remove(name);
+                // Users cannot remove dictionary items from global state
+                Ok(())
+            }
         }
     }
 
@@ -273,7 +289,11 @@ where
     }
 
     pub fn get_blocktime(&self) -> BlockTime {
-        self.blocktime
+        self.block_info.block_time()
+    }
+
+    pub fn block_info(&self) -> BlockInfo {
+        self.block_info
     }
 
     pub fn get_deploy_hash(&self) -> DeployHash {
@@ -308,6 +328,10 @@ where
         Rc::clone(&self.transfer_address_generator)
     }
 
+    pub fn random_seed_counter(&self) -> Rc<Cell<u64>> {
+        Rc::clone(&self.random_seed_counter)
+    }
+
     pub(super) fn state(&self) -> Rc<RefCell<TrackingCopy<R>>> {
         Rc::clone(&self.tracking_copy)
     }
@@ -435,6 +459,36 @@ where
         })
     }
 
+    /// Reads the item stored under `dictionary_item_key` in the dictionary identified by
+    /// `seed_uref`, which must be a valid `URef` with read access in the current context.
+    pub fn dictionary_read(
+        &mut self,
+        seed_uref: URef,
+        dictionary_item_key: &[u8],
+    ) -> Result<Option<StoredValue>, Error> {
+        self.validate_readable(&Key::URef(seed_uref))?;
+        self.validate_uref(&seed_uref)?;
+
+        let dictionary_key = Key::dictionary(seed_uref, dictionary_item_key);
+        self.read_gs_direct(&dictionary_key)
+    }
+
+    /// Writes `value` under `dictionary_item_key` in the dictionary identified by `seed_uref`,
+    /// which must be a valid `URef` with write access in the current context.
+    pub fn dictionary_write(
+        &mut self,
+        seed_uref: URef,
+        dictionary_item_key: &[u8],
+        value: StoredValue,
+    ) -> Result<(), Error> {
+        self.validate_writeable(&Key::URef(seed_uref))?;
+        self.validate_uref(&seed_uref)?;
+        self.validate_value(&value)?;
+
+        let dictionary_key = Key::dictionary(seed_uref, dictionary_item_key);
+        self.metered_write_gs_unsafe(dictionary_key, value)
+    }
+
     pub fn get_keys(&mut self, key_tag: &KeyTag) -> Result<BTreeSet<Key>, Error> {
         self.tracking_copy
             .borrow_mut()
@@ -531,44 +585,61 @@ where
         &mut self.transfers
     }
 
+    pub fn events(&self) -> &Vec<ContractEvent> {
+        &self.events
+    }
+
+    pub fn events_mut(&mut self) -> &mut Vec<ContractEvent> {
+        &mut self.events
+    }
+
+    pub fn call_stack(&self) -> &Vec<CallStackElement> {
+        &self.call_stack
+    }
+
+    /// Validates whether keys used in the `cl_value` are not forged.
+    fn validate_cl_value(&self, cl_value: &CLValue) -> Result<(), Error> {
+        match cl_value.cl_type() {
+            CLType::Bool
+            | CLType::I32
+            | CLType::I64
+            | CLType::U8
+            | CLType::U32
+            | CLType::U64
+            | CLType::U128
+            | CLType::U256
+            | CLType::U512
+            | CLType::Unit
+            | CLType::String
+            | CLType::Option(_)
+            | CLType::List(_)
+            | CLType::ByteArray(..)
+            | CLType::Result { .. }
+            | CLType::Map { .. }
+            | CLType::Tuple1(_)
+            | CLType::Tuple3(_)
+            | CLType::Any
+            | CLType::PublicKey => Ok(()),
+            CLType::Key => {
+                let key: Key = cl_value.to_owned().into_t()?; // TODO: optimize?
+                self.validate_key(&key)
+            }
+            CLType::URef => {
+                let uref: URef = cl_value.to_owned().into_t()?; // TODO: optimize?
+                self.validate_uref(&uref)
+            }
+            tuple @ CLType::Tuple2(_) if *tuple == casper_types::named_key_type() => {
+                let (_name, key): (String, Key) = cl_value.to_owned().into_t()?; // TODO: optimize?
+                self.validate_key(&key)
+            }
+            CLType::Tuple2(_) => Ok(()),
+        }
+    }
+
     /// Validates whether keys used in the `value` are not forged.
     fn validate_value(&self, value: &StoredValue) -> Result<(), Error> {
         match value {
-            StoredValue::CLValue(cl_value) => match cl_value.cl_type() {
-                CLType::Bool
-                | CLType::I32
-                | CLType::I64
-                | CLType::U8
-                | CLType::U32
-                | CLType::U64
-                | CLType::U128
-                | CLType::U256
-                | CLType::U512
-                | CLType::Unit
-                | CLType::String
-                | CLType::Option(_)
-                | CLType::List(_)
-                | CLType::ByteArray(..)
-                | CLType::Result { .. }
-                | CLType::Map { .. }
-                | CLType::Tuple1(_)
-                | CLType::Tuple3(_)
-                | CLType::Any
-                | CLType::PublicKey => Ok(()),
-                CLType::Key => {
-                    let key: Key = cl_value.to_owned().into_t()?; // TODO: optimize?
-                    self.validate_key(&key)
-                }
-                CLType::URef => {
-                    let uref: URef = cl_value.to_owned().into_t()?; // TODO: optimize?
-                    self.validate_uref(&uref)
-                }
-                tuple @ CLType::Tuple2(_) if *tuple == casper_types::named_key_type() => {
-                    let (_name, key): (String, Key) = cl_value.to_owned().into_t()?; // TODO: optimize?
-                    self.validate_key(&key)
-                }
-                CLType::Tuple2(_) => Ok(()),
-            },
+            StoredValue::CLValue(cl_value) => self.validate_cl_value(cl_value),
             StoredValue::Account(account) => {
                 // This should never happen as accounts can't be created by contracts.
                 // I am putting this here for the sake of completeness.
@@ -590,6 +661,9 @@ where
             StoredValue::Bid(_) => Ok(()),
             StoredValue::Withdraw(_) => Ok(()),
             StoredValue::EraValidators(_) => Ok(()),
+            StoredValue::Dictionary(dictionary_value) => {
+                self.validate_cl_value(dictionary_value.cl_value())
+            }
         }
     }
 
@@ -680,6 +754,8 @@ where
             Key::Bid(_) => true,
             Key::Withdraw(_) => true,
             Key::EraValidators(_) => true,
+            // Dictionary items are only accessible via their seed `URef`.
+            Key::Dictionary(_) => false,
         }
     }
 
@@ -695,6 +771,7 @@ where
             Key::Bid(_) => false,
             Key::Withdraw(_) => false,
             Key::EraValidators(_) => false,
+            Key::Dictionary(_) => false,
         }
     }
 
@@ -710,6 +787,7 @@ where
             Key::Bid(_) => false,
             Key::Withdraw(_) => false,
             Key::EraValidators(_) => false,
+            Key::Dictionary(_) => false,
         }
     }
 
@@ -14,15 +14,15 @@ use casper_types::{
     },
     bytesrepr::ToBytes,
     contracts::NamedKeys,
-    AccessRights, BlockTime, CLValue, Contract, DeployHash, EntryPointType, EntryPoints, Key,
-    Phase, ProtocolVersion, RuntimeArgs, URef, KEY_HASH_LENGTH, U512,
+    AccessRights, CLValue, Contract, DeployHash, EntryPointType, EntryPoints, Key, Phase,
+    ProtocolVersion, RuntimeArgs, URef, KEY_HASH_LENGTH, U512,
 };
 
 use super::{Address, Error, RuntimeContext};
 use crate::{
     core::{
-        execution::AddressGenerator, runtime::extract_access_rights_from_keys,
-        tracking_copy::TrackingCopy,
+        engine_state::BlockInfo, execution::AddressGenerator,
+        runtime::extract_access_rights_from_keys, tracking_copy::TrackingCopy,
     },
     shared::{
         account::{Account, AssociatedKeys},
@@ -138,18 +138,21 @@ fn mock_runtime_context<'a>(
         BTreeSet::from_iter(vec![AccountHash::new([0; 32])]),
         &account,
         base_key,
-        BlockTime::new(0),
+        BlockInfo::default(),
         DeployHash::new([1u8; 32]),
         Gas::new(U512::from(GAS_LIMIT)),
         Gas::default(),
         Rc::new(RefCell::new(hash_address_generator)),
         Rc::new(RefCell::new(uref_address_generator)),
         Rc::new(RefCell::new(transfer_address_generator)),
+        Rc::default(),
         ProtocolVersion::V1_0_0,
         CorrelationId::new(),
         Phase::Session,
         *TEST_PROTOCOL_DATA,
         Vec::default(),
+        Vec::default(),
+        Vec::default(),
     )
 }
 
@@ -372,18 +375,21 @@ fn contract_key_addable_valid() {
         authorization_keys,
         &account,
         contract_key,
-        BlockTime::new(0),
+        BlockInfo::default(),
         DeployHash::new(DEPLOY_HASH),
         Gas::new(U512::from(GAS_LIMIT)),
         Gas::default(),
         Rc::new(RefCell::new(hash_address_generator)),
         Rc::new(RefCell::new(uref_address_generator)),
         Rc::new(RefCell::new(transfer_address_generator)),
+        Rc::default(),
         ProtocolVersion::V1_0_0,
         CorrelationId::new(),
         PHASE,
         Default::default(),
         Vec::default(),
+        Vec::default(),
+        Vec::default(),
     );
 
     runtime_context
@@ -445,18 +451,21 @@ fn contract_key_addable_invalid() {
         authorization_keys,
         &account,
         other_contract_key,
-        BlockTime::new(0),
+        BlockInfo::default(),
         DeployHash::new(DEPLOY_HASH),
         Gas::default(),
         Gas::default(),
         Rc::new(RefCell::new(hash_address_generator)),
         Rc::new(RefCell::new(uref_address_generator)),
         Rc::new(RefCell::new(transfer_address_generator)),
+        Rc::default(),
         ProtocolVersion::V1_0_0,
         CorrelationId::new(),
         PHASE,
         Default::default(),
         Vec::default(),
+        Vec::default(),
+        Vec::default(),
     );
 
     let result = runtime_context.metered_add_gs(contract_key, named_uref_tuple);
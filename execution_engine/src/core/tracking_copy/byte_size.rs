@@ -46,6 +46,7 @@ impl ByteSize for StoredValue {
                 StoredValue::Bid(bid) => bid.serialized_length(),
                 StoredValue::Withdraw(unbonding_purses) => unbonding_purses.serialized_length(),
                 StoredValue::EraValidators(recipients) => recipients.serialized_length(),
+                StoredValue::Dictionary(dictionary_value) => dictionary_value.serialized_length(),
             }
     }
 }
@@ -6,14 +6,13 @@ mod tests;
 
 use std::{
     collections::{BTreeSet, HashMap, HashSet, VecDeque},
-    convert::{From, TryInto},
+    convert::From,
     iter,
 };
 
 use linked_hash_map::LinkedHashMap;
-use thiserror::Error;
 
-use casper_types::{bytesrepr, CLType, CLValue, CLValueError, Key, KeyTag, Tagged, U512};
+use casper_types::{bytesrepr, CLType, CLValueError, Key, KeyTag, Tagged};
 
 pub use self::ext::TrackingCopyExt;
 use self::meter::{heap_meter::HeapSize, Meter};
@@ -21,7 +20,7 @@ use crate::{
     core::engine_state::{execution_effect::ExecutionEffect, op::Op},
     shared::{
         additive_map::AdditiveMap,
-        newtypes::{Blake2bHash, CorrelationId},
+        newtypes::CorrelationId,
         stored_value::StoredValue,
         transform::{self, Transform},
         TypeMismatch,
@@ -289,6 +288,17 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
         Ok(ret)
     }
 
+    /// Reads the value under `key` along with its proof directly from the underlying state,
+    /// ignoring any values written or mutated in this `TrackingCopy`.
+    pub fn read_with_proof(
+        &self,
+        correlation_id: CorrelationId,
+        key: &Key,
+    ) -> Result<Option<TrieMerkleProof<Key, StoredValue>>, R::Error> {
+        self.reader
+            .read_with_proof(correlation_id, &key.normalize())
+    }
+
     pub fn read(
         &mut self,
         correlation_id: CorrelationId,
@@ -494,6 +504,9 @@ impl<R: StateReader<Key, StoredValue>> TrackingCopy<R> {
                 StoredValue::EraValidators(_) => {
                     return Ok(query.into_not_found_result(&"EraValidators value found"));
                 }
+                StoredValue::Dictionary(_) => {
+                    return Ok(query.into_not_found_result(&"Dictionary value found."));
+                }
             }
         }
     }
@@ -538,135 +551,3 @@ impl<R: StateReader<Key, StoredValue>> StateReader<Key, StoredValue> for &Tracki
         self.reader.keys_with_prefix(correlation_id, prefix)
     }
 }
-
-#[derive(Error, Debug, PartialEq, Eq)]
-pub enum ValidationError {
-    #[error("The path should not have a different length than the proof less one.")]
-    PathLengthDifferentThanProofLessOne,
-
-    #[error("The provided key does not match the key in the proof.")]
-    UnexpectedKey,
-
-    #[error("The provided value does not match the value in the proof.")]
-    UnexpectedValue,
-
-    #[error("The proof hash is invalid.")]
-    InvalidProofHash,
-
-    #[error("The path went cold.")]
-    PathCold,
-
-    #[error("Serialization error: {0}")]
-    BytesRepr(bytesrepr::Error),
-
-    #[error("Key is not a URef")]
-    KeyIsNotAURef(Key),
-
-    #[error("Failed to convert stored value to key")]
-    ValueToCLValueConversion,
-
-    #[error("{0}")]
-    CLValueError(CLValueError),
-}
-
-impl From<CLValueError> for ValidationError {
-    fn from(err: CLValueError) -> Self {
-        ValidationError::CLValueError(err)
-    }
-}
-
-impl From<bytesrepr::Error> for ValidationError {
-    fn from(error: bytesrepr::Error) -> Self {
-        Self::BytesRepr(error)
-    }
-}
-
-pub fn validate_query_proof(
-    hash: &Blake2bHash,
-    proofs: &[TrieMerkleProof<Key, StoredValue>],
-    expected_first_key: &Key,
-    path: &[String],
-    expected_value: &StoredValue,
-) -> Result<(), ValidationError> {
-    if proofs.len() != path.len() + 1 {
-        return Err(ValidationError::PathLengthDifferentThanProofLessOne);
-    }
-
-    let mut proofs_iter = proofs.iter();
-
-    // length check above means we are safe to unwrap here
-    let first_proof = proofs_iter.next().unwrap();
-
-    if first_proof.key() != &expected_first_key.normalize() {
-        return Err(ValidationError::UnexpectedKey);
-    }
-
-    if hash != &first_proof.compute_state_hash()? {
-        return Err(ValidationError::InvalidProofHash);
-    }
-
-    let mut proof_value = first_proof.value();
-
-    for (proof, path_component) in proofs_iter.zip(path.iter()) {
-        let named_keys = match proof_value {
-            StoredValue::Account(account) => account.named_keys(),
-            StoredValue::Contract(contract) => contract.named_keys(),
-            _ => return Err(ValidationError::PathCold),
-        };
-
-        let key = match named_keys.get(path_component) {
-            Some(key) => key,
-            None => return Err(ValidationError::PathCold),
-        };
-
-        if proof.key() != &key.normalize() {
-            return Err(ValidationError::UnexpectedKey);
-        }
-
-        if hash != &proof.compute_state_hash()? {
-            return Err(ValidationError::InvalidProofHash);
-        }
-
-        proof_value = proof.value();
-    }
-
-    if proof_value != expected_value {
-        return Err(ValidationError::UnexpectedValue);
-    }
-
-    Ok(())
-}
-
-pub fn validate_balance_proof(
-    hash: &Blake2bHash,
-    balance_proof: &TrieMerkleProof<Key, StoredValue>,
-    expected_purse_key: Key,
-    expected_motes: &U512,
-) -> Result<(), ValidationError> {
-    let expected_balance_key = expected_purse_key
-        .into_uref()
-        .map(|uref| Key::Balance(uref.addr()))
-        .ok_or_else(|| ValidationError::KeyIsNotAURef(expected_purse_key.to_owned()))?;
-
-    if balance_proof.key() != &expected_balance_key.normalize() {
-        return Err(ValidationError::UnexpectedKey);
-    }
-
-    if hash != &balance_proof.compute_state_hash()? {
-        return Err(ValidationError::InvalidProofHash);
-    }
-
-    let balance_proof_stored_value = balance_proof.value().to_owned();
-
-    let balance_proof_clvalue: CLValue = balance_proof_stored_value
-        .try_into()
-        .map_err(|_| ValidationError::ValueToCLValueConversion)?;
-
-    let balance_motes: U512 = balance_proof_clvalue.into_t()?;
-
-    if expected_motes != &balance_motes {
-        return Err(ValidationError::UnexpectedValue);
-    }
-
-    Ok(())
-}
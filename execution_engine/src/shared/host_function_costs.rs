@@ -23,19 +23,36 @@ const DEFAULT_ADD_COST: u32 = 5_800;
 const DEFAULT_CALL_CONTRACT_COST: u32 = 4_500;
 const DEFAULT_CALL_CONTRACT_ARGS_SIZE_WEIGHT: u32 = 420;
 
+const DEFAULT_EMIT_EVENT_COST: u32 = 10_000;
+const DEFAULT_EMIT_EVENT_TOPIC_SIZE_WEIGHT: u32 = 980;
+const DEFAULT_EMIT_EVENT_PAYLOAD_SIZE_WEIGHT: u32 = 980;
+
 const DEFAULT_CREATE_PURSE_COST: u32 = 170_000;
+const DEFAULT_DICTIONARY_GET_COST: u32 = 5_500;
+const DEFAULT_DICTIONARY_GET_KEY_SIZE_WEIGHT: u32 = 590;
+const DEFAULT_DICTIONARY_PUT_COST: u32 = 9_500;
+const DEFAULT_DICTIONARY_PUT_KEY_BYTES_SIZE_WEIGHT: u32 = 1_800;
+const DEFAULT_DICTIONARY_PUT_VALUE_SIZE_WEIGHT: u32 = 520;
 const DEFAULT_GET_BALANCE_COST: u32 = 3_800;
+const DEFAULT_GET_BLOCK_HEIGHT_COST: u32 = 330;
 const DEFAULT_GET_BLOCKTIME_COST: u32 = 330;
 const DEFAULT_GET_CALLER_COST: u32 = 380;
+const DEFAULT_GET_DEPLOY_HASH_COST: u32 = 380;
+const DEFAULT_GET_ERA_ID_COST: u32 = 330;
 const DEFAULT_GET_KEY_COST: u32 = 2_000;
 const DEFAULT_GET_KEY_NAME_SIZE_WEIGHT: u32 = 440;
 const DEFAULT_GET_MAIN_PURSE_COST: u32 = 1_300;
+const DEFAULT_GET_PARENT_BLOCK_HASH_COST: u32 = 380;
 const DEFAULT_GET_PHASE_COST: u32 = 710;
 const DEFAULT_GET_SYSTEM_CONTRACT_COST: u32 = 1_100;
 const DEFAULT_HAS_KEY_COST: u32 = 1_500;
 const DEFAULT_HAS_KEY_NAME_SIZE_WEIGHT: u32 = 840;
 const DEFAULT_IS_VALID_UREF_COST: u32 = 760;
+const DEFAULT_KECCAK256_COST: u32 = 1_500;
+const DEFAULT_KECCAK256_INPUT_SIZE_WEIGHT: u32 = 40;
+const DEFAULT_LOAD_CALL_STACK_COST: u32 = 10_000;
 const DEFAULT_LOAD_NAMED_KEYS_COST: u32 = 42_000;
+const DEFAULT_NEW_DICTIONARY_COST: u32 = 17_000;
 const DEFAULT_NEW_UREF_COST: u32 = 17_000;
 const DEFAULT_NEW_UREF_VALUE_SIZE_WEIGHT: u32 = 590;
 
@@ -62,11 +79,18 @@ const DEFAULT_RET_VALUE_SIZE_WEIGHT: u32 = 420;
 
 const DEFAULT_REVERT_COST: u32 = 500;
 const DEFAULT_SET_ACTION_THRESHOLD_COST: u32 = 74_000;
+
+const DEFAULT_SHA256_COST: u32 = 1_500;
+const DEFAULT_SHA256_INPUT_SIZE_WEIGHT: u32 = 30;
+
 const DEFAULT_TRANSFER_FROM_PURSE_TO_ACCOUNT_COST: u32 = 160_000;
 const DEFAULT_TRANSFER_FROM_PURSE_TO_PURSE_COST: u32 = 82_000;
 const DEFAULT_TRANSFER_TO_ACCOUNT_COST: u32 = 24_000;
 const DEFAULT_UPDATE_ASSOCIATED_KEY_COST: u32 = 4_200;
 
+const DEFAULT_VERIFY_SIGNATURE_COST: u32 = 150_000;
+const DEFAULT_VERIFY_SIGNATURE_MESSAGE_SIZE_WEIGHT: u32 = 30;
+
 const DEFAULT_WRITE_COST: u32 = 14_000;
 const DEFAULT_WRITE_VALUE_SIZE_WEIGHT: u32 = 980;
 
@@ -227,6 +251,19 @@ pub struct HostFunctionCosts {
     pub remove_contract_user_group_urefs: HostFunction<[Cost; 6]>,
     pub print: HostFunction<[Cost; 2]>,
     pub blake2b: HostFunction<[Cost; 4]>,
+    pub emit_event: HostFunction<[Cost; 4]>,
+    pub verify_signature: HostFunction<[Cost; 6]>,
+    pub sha256: HostFunction<[Cost; 4]>,
+    pub keccak256: HostFunction<[Cost; 4]>,
+    pub random_seed: HostFunction<[Cost; 2]>,
+    pub get_block_height: HostFunction<[Cost; 1]>,
+    pub get_era_id: HostFunction<[Cost; 1]>,
+    pub get_parent_block_hash: HostFunction<[Cost; 1]>,
+    pub get_deploy_hash: HostFunction<[Cost; 1]>,
+    pub load_call_stack: HostFunction<[Cost; 2]>,
+    pub new_dictionary: HostFunction<[Cost; 1]>,
+    pub dictionary_get: HostFunction<[Cost; 5]>,
+    pub dictionary_put: HostFunction<[Cost; 6]>,
 }
 
 impl Default for HostFunctionCosts {
@@ -344,6 +381,72 @@ impl Default for HostFunctionCosts {
                 [NOT_USED, DEFAULT_PRINT_TEXT_SIZE_WEIGHT],
             ),
             blake2b: HostFunction::default(),
+            emit_event: HostFunction::new(
+                DEFAULT_EMIT_EVENT_COST,
+                [
+                    NOT_USED,
+                    DEFAULT_EMIT_EVENT_TOPIC_SIZE_WEIGHT,
+                    NOT_USED,
+                    DEFAULT_EMIT_EVENT_PAYLOAD_SIZE_WEIGHT,
+                ],
+            ),
+            verify_signature: HostFunction::new(
+                DEFAULT_VERIFY_SIGNATURE_COST,
+                [
+                    NOT_USED,
+                    DEFAULT_VERIFY_SIGNATURE_MESSAGE_SIZE_WEIGHT,
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                ],
+            ),
+            sha256: HostFunction::new(
+                DEFAULT_SHA256_COST,
+                [
+                    NOT_USED,
+                    DEFAULT_SHA256_INPUT_SIZE_WEIGHT,
+                    NOT_USED,
+                    NOT_USED,
+                ],
+            ),
+            keccak256: HostFunction::new(
+                DEFAULT_KECCAK256_COST,
+                [
+                    NOT_USED,
+                    DEFAULT_KECCAK256_INPUT_SIZE_WEIGHT,
+                    NOT_USED,
+                    NOT_USED,
+                ],
+            ),
+            random_seed: HostFunction::default(),
+            get_block_height: HostFunction::fixed(DEFAULT_GET_BLOCK_HEIGHT_COST),
+            get_era_id: HostFunction::fixed(DEFAULT_GET_ERA_ID_COST),
+            get_parent_block_hash: HostFunction::fixed(DEFAULT_GET_PARENT_BLOCK_HASH_COST),
+            get_deploy_hash: HostFunction::fixed(DEFAULT_GET_DEPLOY_HASH_COST),
+            load_call_stack: HostFunction::fixed(DEFAULT_LOAD_CALL_STACK_COST),
+            new_dictionary: HostFunction::fixed(DEFAULT_NEW_DICTIONARY_COST),
+            dictionary_get: HostFunction::new(
+                DEFAULT_DICTIONARY_GET_COST,
+                [
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    DEFAULT_DICTIONARY_GET_KEY_SIZE_WEIGHT,
+                    NOT_USED,
+                ],
+            ),
+            dictionary_put: HostFunction::new(
+                DEFAULT_DICTIONARY_PUT_COST,
+                [
+                    NOT_USED,
+                    NOT_USED,
+                    NOT_USED,
+                    DEFAULT_DICTIONARY_PUT_KEY_BYTES_SIZE_WEIGHT,
+                    NOT_USED,
+                    DEFAULT_DICTIONARY_PUT_VALUE_SIZE_WEIGHT,
+                ],
+            ),
         }
     }
 }
@@ -393,6 +496,19 @@ impl ToBytes for HostFunctionCosts {
         ret.append(&mut self.remove_contract_user_group_urefs.to_bytes()?);
         ret.append(&mut self.print.to_bytes()?);
         ret.append(&mut self.blake2b.to_bytes()?);
+        ret.append(&mut self.emit_event.to_bytes()?);
+        ret.append(&mut self.verify_signature.to_bytes()?);
+        ret.append(&mut self.sha256.to_bytes()?);
+        ret.append(&mut self.keccak256.to_bytes()?);
+        ret.append(&mut self.random_seed.to_bytes()?);
+        ret.append(&mut self.get_block_height.to_bytes()?);
+        ret.append(&mut self.get_era_id.to_bytes()?);
+        ret.append(&mut self.get_parent_block_hash.to_bytes()?);
+        ret.append(&mut self.get_deploy_hash.to_bytes()?);
+        ret.append(&mut self.load_call_stack.to_bytes()?);
+        ret.append(&mut self.new_dictionary.to_bytes()?);
+        ret.append(&mut self.dictionary_get.to_bytes()?);
+        ret.append(&mut self.dictionary_put.to_bytes()?);
         Ok(ret)
     }
 
@@ -439,6 +555,19 @@ impl ToBytes for HostFunctionCosts {
             + self.remove_contract_user_group_urefs.serialized_length()
             + self.print.serialized_length()
             + self.blake2b.serialized_length()
+            + self.emit_event.serialized_length()
+            + self.verify_signature.serialized_length()
+            + self.sha256.serialized_length()
+            + self.keccak256.serialized_length()
+            + self.random_seed.serialized_length()
+            + self.get_block_height.serialized_length()
+            + self.get_era_id.serialized_length()
+            + self.get_parent_block_hash.serialized_length()
+            + self.get_deploy_hash.serialized_length()
+            + self.load_call_stack.serialized_length()
+            + self.new_dictionary.serialized_length()
+            + self.dictionary_get.serialized_length()
+            + self.dictionary_put.serialized_length()
     }
 }
 
@@ -486,6 +615,19 @@ impl FromBytes for HostFunctionCosts {
         let (remove_contract_user_group_urefs, rem) = FromBytes::from_bytes(rem)?;
         let (print, rem) = FromBytes::from_bytes(rem)?;
         let (blake2b, rem) = FromBytes::from_bytes(rem)?;
+        let (emit_event, rem) = FromBytes::from_bytes(rem)?;
+        let (verify_signature, rem) = FromBytes::from_bytes(rem)?;
+        let (sha256, rem) = FromBytes::from_bytes(rem)?;
+        let (keccak256, rem) = FromBytes::from_bytes(rem)?;
+        let (random_seed, rem) = FromBytes::from_bytes(rem)?;
+        let (get_block_height, rem) = FromBytes::from_bytes(rem)?;
+        let (get_era_id, rem) = FromBytes::from_bytes(rem)?;
+        let (get_parent_block_hash, rem) = FromBytes::from_bytes(rem)?;
+        let (get_deploy_hash, rem) = FromBytes::from_bytes(rem)?;
+        let (load_call_stack, rem) = FromBytes::from_bytes(rem)?;
+        let (new_dictionary, rem) = FromBytes::from_bytes(rem)?;
+        let (dictionary_get, rem) = FromBytes::from_bytes(rem)?;
+        let (dictionary_put, rem) = FromBytes::from_bytes(rem)?;
         Ok((
             HostFunctionCosts {
                 read_value,
@@ -530,6 +672,19 @@ impl FromBytes for HostFunctionCosts {
                 remove_contract_user_group_urefs,
                 print,
                 blake2b,
+                emit_event,
+                verify_signature,
+                sha256,
+                keccak256,
+                random_seed,
+                get_block_height,
+                get_era_id,
+                get_parent_block_hash,
+                get_deploy_hash,
+                load_call_stack,
+                new_dictionary,
+                dictionary_get,
+                dictionary_put,
             },
             rem,
         ))
@@ -581,6 +736,19 @@ impl Distribution<HostFunctionCosts> for Standard {
             remove_contract_user_group_urefs: rng.gen(),
             print: rng.gen(),
             blake2b: rng.gen(),
+            emit_event: rng.gen(),
+            verify_signature: rng.gen(),
+            sha256: rng.gen(),
+            keccak256: rng.gen(),
+            random_seed: rng.gen(),
+            get_block_height: rng.gen(),
+            get_era_id: rng.gen(),
+            get_parent_block_hash: rng.gen(),
+            get_deploy_hash: rng.gen(),
+            load_call_stack: rng.gen(),
+            new_dictionary: rng.gen(),
+            dictionary_get: rng.gen(),
+            dictionary_put: rng.gen(),
         }
     }
 }
@@ -639,6 +807,19 @@ pub mod gens {
             remove_contract_user_group_urefs in host_function_cost_arb(),
             print in host_function_cost_arb(),
             blake2b in host_function_cost_arb(),
+            emit_event in host_function_cost_arb(),
+            verify_signature in host_function_cost_arb(),
+            sha256 in host_function_cost_arb(),
+            keccak256 in host_function_cost_arb(),
+            random_seed in host_function_cost_arb(),
+            get_block_height in host_function_cost_arb(),
+            get_era_id in host_function_cost_arb(),
+            get_parent_block_hash in host_function_cost_arb(),
+            get_deploy_hash in host_function_cost_arb(),
+            load_call_stack in host_function_cost_arb(),
+            new_dictionary in host_function_cost_arb(),
+            dictionary_get in host_function_cost_arb(),
+            dictionary_put in host_function_cost_arb(),
         ) -> HostFunctionCosts {
             HostFunctionCosts {
                 read_value,
@@ -683,6 +864,19 @@ pub mod gens {
                 remove_contract_user_group_urefs,
                 print,
                 blake2b,
+                emit_event,
+                verify_signature,
+                sha256,
+                keccak256,
+                random_seed,
+                get_block_height,
+                get_era_id,
+                get_parent_block_hash,
+                get_deploy_hash,
+                load_call_stack,
+                new_dictionary,
+                dictionary_get,
+                dictionary_put,
             }
         }
     }
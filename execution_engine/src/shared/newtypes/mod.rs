@@ -1,12 +1,12 @@
 //! Some newtypes.
-mod blake2b256;
 mod macros;
 use std::fmt::{self, Display, Formatter};
 
-pub use blake2b256::Blake2bHash;
 use serde::Serialize;
 use uuid::Uuid;
 
+pub use casper_types::merkle_proof::Blake2bHash;
+
 #[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Serialize)]
 pub struct CorrelationId(Uuid);
 
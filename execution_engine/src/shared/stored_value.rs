@@ -5,9 +5,10 @@ use serde_bytes::ByteBuf;
 
 use casper_types::{
     bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
-    contracts::ContractPackage,
+    contracts::{ContractPackage, NamedKeys},
+    merkle_proof::ProofValue,
     system::auction::{Bid, EraInfo, SeigniorageRecipients, UnbondingPurse},
-    CLValue, Contract, ContractWasm, DeployInfo, Transfer,
+    CLValue, Contract, ContractWasm, DeployInfo, DictionaryValue, Transfer,
 };
 
 use crate::shared::{account::Account, TypeMismatch};
@@ -25,6 +26,7 @@ enum Tag {
     Bid = 8,
     Withdraw = 9,
     EraValidators = 10,
+    Dictionary = 11,
 }
 
 #[derive(Eq, PartialEq, Clone, Debug)]
@@ -40,6 +42,7 @@ pub enum StoredValue {
     Bid(Box<Bid>),
     Withdraw(Vec<UnbondingPurse>),
     EraValidators(SeigniorageRecipients),
+    Dictionary(DictionaryValue),
 }
 
 impl StoredValue {
@@ -113,6 +116,13 @@ impl StoredValue {
         }
     }
 
+    pub fn as_dictionary(&self) -> Option<&DictionaryValue> {
+        match self {
+            StoredValue::Dictionary(dictionary_value) => Some(dictionary_value),
+            _ => None,
+        }
+    }
+
     pub fn type_name(&self) -> String {
         match self {
             StoredValue::CLValue(cl_value) => format!("{:?}", cl_value.cl_type()),
@@ -126,10 +136,25 @@ impl StoredValue {
             StoredValue::Bid(_) => "Bid".to_string(),
             StoredValue::Withdraw(_) => "Withdraw".to_string(),
             StoredValue::EraValidators(_) => "EraValidators".to_string(),
+            StoredValue::Dictionary(_) => "Dictionary".to_string(),
         }
     }
 }
 
+impl ProofValue for StoredValue {
+    fn named_keys(&self) -> Option<&NamedKeys> {
+        match self {
+            StoredValue::Account(account) => Some(account.named_keys()),
+            StoredValue::Contract(contract) => Some(contract.named_keys()),
+            _ => None,
+        }
+    }
+
+    fn as_cl_value(&self) -> Option<&CLValue> {
+        StoredValue::as_cl_value(self)
+    }
+}
+
 impl From<CLValue> for StoredValue {
     fn from(value: CLValue) -> StoredValue {
         StoredValue::CLValue(value)
@@ -167,6 +192,12 @@ impl From<SeigniorageRecipients> for StoredValue {
     }
 }
 
+impl From<DictionaryValue> for StoredValue {
+    fn from(dictionary_value: DictionaryValue) -> StoredValue {
+        StoredValue::Dictionary(dictionary_value)
+    }
+}
+
 impl TryFrom<StoredValue> for CLValue {
     type Error = TypeMismatch;
 
@@ -273,6 +304,20 @@ impl TryFrom<StoredValue> for EraInfo {
     }
 }
 
+impl TryFrom<StoredValue> for DictionaryValue {
+    type Error = TypeMismatch;
+
+    fn try_from(value: StoredValue) -> Result<Self, Self::Error> {
+        match value {
+            StoredValue::Dictionary(dictionary_value) => Ok(dictionary_value),
+            _ => Err(TypeMismatch::new(
+                "Dictionary".to_string(),
+                value.type_name(),
+            )),
+        }
+    }
+}
+
 impl ToBytes for StoredValue {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut result = bytesrepr::allocate_buffer(self)?;
@@ -294,6 +339,9 @@ impl ToBytes for StoredValue {
                 (Tag::Withdraw, unbonding_purses.to_bytes()?)
             }
             StoredValue::EraValidators(recipients) => (Tag::EraValidators, recipients.to_bytes()?),
+            StoredValue::Dictionary(dictionary_value) => {
+                (Tag::Dictionary, dictionary_value.to_bytes()?)
+            }
         };
         result.push(tag as u8);
         result.append(&mut serialized_data);
@@ -316,6 +364,7 @@ impl ToBytes for StoredValue {
                 StoredValue::Bid(bid) => bid.serialized_length(),
                 StoredValue::Withdraw(unbonding_purses) => unbonding_purses.serialized_length(),
                 StoredValue::EraValidators(recipients) => recipients.serialized_length(),
+                StoredValue::Dictionary(dictionary_value) => dictionary_value.serialized_length(),
             }
     }
 }
@@ -355,6 +404,11 @@ impl FromBytes for StoredValue {
             }
             tag if tag == Tag::EraValidators as u8 => SeigniorageRecipients::from_bytes(remainder)
                 .map(|(recipients, remainder)| (StoredValue::EraValidators(recipients), remainder)),
+            tag if tag == Tag::Dictionary as u8 => {
+                DictionaryValue::from_bytes(remainder).map(|(dictionary_value, remainder)| {
+                    (StoredValue::Dictionary(dictionary_value), remainder)
+                })
+            }
             _ => Err(bytesrepr::Error::Formatting),
         }
     }
@@ -386,7 +440,7 @@ pub mod gens {
     use casper_types::{
         gens::{
             cl_value_arb, contract_arb, contract_package_arb, contract_wasm_arb, deploy_info_arb,
-            transfer_arb,
+            dictionary_value_arb, transfer_arb,
         },
         system::auction::gens::era_info_arb,
     };
@@ -403,7 +457,8 @@ pub mod gens {
             contract_wasm_arb().prop_map(StoredValue::ContractWasm),
             era_info_arb(1..10).prop_map(StoredValue::EraInfo),
             deploy_info_arb().prop_map(StoredValue::DeployInfo),
-            transfer_arb().prop_map(StoredValue::Transfer)
+            transfer_arb().prop_map(StoredValue::Transfer),
+            dictionary_value_arb().prop_map(StoredValue::Dictionary)
         ]
     }
 }
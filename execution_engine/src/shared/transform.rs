@@ -209,6 +209,11 @@ impl Transform {
                     let found = "EraValidators".to_string();
                     Err(TypeMismatch::new(expected, found).into())
                 }
+                StoredValue::Dictionary(_) => {
+                    let expected = "Contract or Account".to_string();
+                    let found = "Dictionary".to_string();
+                    Err(TypeMismatch::new(expected, found).into())
+                }
             },
             Transform::Failure(error) => Err(error),
         }
@@ -353,6 +358,9 @@ impl From<&Transform> for casper_types::Transform {
             Transform::Write(StoredValue::EraValidators(recipients)) => {
                 casper_types::Transform::WriteEraValidators(recipients.clone())
             }
+            Transform::Write(StoredValue::Dictionary(dictionary_value)) => {
+                casper_types::Transform::WriteDictionary(dictionary_value.clone())
+            }
             Transform::AddInt32(value) => casper_types::Transform::AddInt32(*value),
             Transform::AddUInt64(value) => casper_types::Transform::AddUInt64(*value),
             Transform::AddUInt128(value) => casper_types::Transform::AddUInt128(*value),
@@ -12,8 +12,8 @@ use serde::{
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
-use crate::shared::newtypes::Blake2bHash;
 use casper_types::bytesrepr::{self, Bytes, FromBytes, ToBytes, U8_SERIALIZED_LENGTH};
+pub use casper_types::merkle_proof::{Pointer, RADIX};
 
 #[cfg(test)]
 pub mod gens;
@@ -23,79 +23,10 @@ pub mod merkle_proof;
 mod tests;
 
 pub const USIZE_EXCEEDS_U8: &str = "usize exceeds u8";
-pub const RADIX: usize = 256;
 
 /// A parent is represented as a pair of a child index and a node or extension.
 pub type Parents<K, V> = Vec<(u8, Trie<K, V>)>;
 
-/// Represents a pointer to the next object in a Merkle Trie
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
-pub enum Pointer {
-    LeafPointer(Blake2bHash),
-    NodePointer(Blake2bHash),
-}
-
-impl Pointer {
-    pub fn hash(&self) -> &Blake2bHash {
-        match self {
-            Pointer::LeafPointer(hash) => hash,
-            Pointer::NodePointer(hash) => hash,
-        }
-    }
-
-    pub fn into_hash(self) -> Blake2bHash {
-        match self {
-            Pointer::LeafPointer(hash) => hash,
-            Pointer::NodePointer(hash) => hash,
-        }
-    }
-
-    pub fn update(&self, hash: Blake2bHash) -> Self {
-        match self {
-            Pointer::LeafPointer(_) => Pointer::LeafPointer(hash),
-            Pointer::NodePointer(_) => Pointer::NodePointer(hash),
-        }
-    }
-
-    fn tag(&self) -> u8 {
-        match self {
-            Pointer::LeafPointer(_) => 0,
-            Pointer::NodePointer(_) => 1,
-        }
-    }
-}
-
-impl ToBytes for Pointer {
-    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
-        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
-        ret.push(self.tag());
-        ret.extend_from_slice(self.hash().as_ref());
-        Ok(ret)
-    }
-
-    #[inline(always)]
-    fn serialized_length(&self) -> usize {
-        U8_SERIALIZED_LENGTH + Blake2bHash::LENGTH
-    }
-}
-
-impl FromBytes for Pointer {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (tag, rem) = u8::from_bytes(bytes)?;
-        match tag {
-            0 => {
-                let (hash, rem) = Blake2bHash::from_bytes(rem)?;
-                Ok((Pointer::LeafPointer(hash), rem))
-            }
-            1 => {
-                let (hash, rem) = Blake2bHash::from_bytes(rem)?;
-                Ok((Pointer::NodePointer(hash), rem))
-            }
-            _ => Err(bytesrepr::Error::Formatting),
-        }
-    }
-}
-
 pub type PointerBlockValue = Option<Pointer>;
 pub type PointerBlockArray = [PointerBlockValue; RADIX];
 
@@ -8,7 +8,7 @@ fn radix_is_256() {
 }
 
 mod pointer_block {
-    use crate::storage::trie::*;
+    use crate::{shared::newtypes::Blake2bHash, storage::trie::*};
 
     /// A defense against changes to [`RADIX`](history::trie::RADIX).
     #[test]
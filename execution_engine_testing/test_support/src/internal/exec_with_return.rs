@@ -5,7 +5,7 @@ use casper_execution_engine::{
         engine_state,
         engine_state::{
             executable_deploy_item::ExecutableDeployItem, execution_effect::ExecutionEffect,
-            EngineConfig,
+            BlockInfo, EngineConfig,
         },
         execution::{self, AddressGenerator},
         runtime::{self, Runtime},
@@ -15,8 +15,8 @@ use casper_execution_engine::{
     storage::{global_state::StateProvider, protocol_data::ProtocolData},
 };
 use casper_types::{
-    account::AccountHash, bytesrepr::FromBytes, BlockTime, CLTyped, DeployHash, EntryPointType,
-    Key, Phase, ProtocolVersion, RuntimeArgs, URef, U512,
+    account::AccountHash, bytesrepr::FromBytes, BlockTime, CLTyped, CallStackElement, DeployHash,
+    EntryPointType, Key, Phase, ProtocolVersion, RuntimeArgs, URef, U512,
 };
 
 use crate::internal::{utils, WasmTestBuilder, DEFAULT_WASM_CONFIG};
@@ -101,6 +101,8 @@ where
     };
 
     let transfers = Vec::default();
+    let events = Vec::default();
+    let call_stack = vec![CallStackElement::session(address)];
 
     let context = RuntimeContext::new(
         Rc::clone(&tracking_copy),
@@ -111,7 +113,12 @@ where
         BTreeSet::new(),
         &account,
         base_key,
-        BlockTime::new(block_time),
+        BlockInfo::new(
+            BlockTime::new(block_time),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        ),
         deploy_hash,
         gas_limit,
         gas_counter,
@@ -123,6 +130,8 @@ where
         phase,
         protocol_data,
         transfers,
+        events,
+        call_stack,
     );
 
     let wasm_bytes = utils::read_wasm_file_bytes(wasm_file);
@@ -6,7 +6,8 @@ use casper_execution_engine::core::engine_state::{
     deploy_item::DeployItem, execute_request::ExecuteRequest,
 };
 use casper_types::{
-    account::AccountHash, runtime_args, ContractHash, ContractVersion, ProtocolVersion, RuntimeArgs,
+    account::AccountHash, runtime_args, ContractHash, ContractVersion, EraId, ProtocolVersion,
+    RuntimeArgs,
 };
 
 use crate::internal::{
@@ -44,6 +45,21 @@ impl ExecuteRequestBuilder {
         self
     }
 
+    pub fn with_block_height(mut self, block_height: u64) -> Self {
+        self.execute_request.block_height = block_height;
+        self
+    }
+
+    pub fn with_era_id(mut self, era_id: EraId) -> Self {
+        self.execute_request.era_id = era_id;
+        self
+    }
+
+    pub fn with_parent_block_hash(mut self, parent_block_hash: &[u8]) -> Self {
+        self.execute_request.parent_block_hash = parent_block_hash.try_into().unwrap();
+        self
+    }
+
     pub fn with_protocol_version(mut self, protocol_version: ProtocolVersion) -> Self {
         self.execute_request.protocol_version = protocol_version;
         self
@@ -0,0 +1,151 @@
+use assert_matches::assert_matches;
+
+use casper_engine_test_support::{
+    internal::{
+        ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PROTOCOL_VERSION,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::{
+    core::{
+        engine_state::{
+            BlockInfo, CallContractRequest, CallContractResult, ContractIdentifier, Error,
+        },
+        execution,
+    },
+    shared::{gas::Gas, newtypes::CorrelationId},
+};
+use casper_types::{runtime_args, ContractHash, RuntimeArgs, U512};
+
+const CONTRACT_PURSE_HOLDER_STORED: &str = "purse_holder_stored.wasm";
+const PURSE_HOLDER_STORED_CONTRACT_NAME: &str = "purse_holder_stored";
+const ENTRY_POINT_VERSION: &str = "version";
+const ENTRY_POINT_ADD: &str = "add_named_purse";
+const ARG_IS_LOCKED: &str = "is_locked";
+const ARG_PURSE: &str = "purse_name";
+const VERSION: &str = "1.0.0";
+const GAS_LIMIT: u64 = 1_000_000_000;
+
+fn setup() -> InMemoryWasmTestBuilder {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_PURSE_HOLDER_STORED,
+        runtime_args! { ARG_IS_LOCKED => false },
+    )
+    .build();
+    builder.exec(exec_request).expect_success().commit();
+    builder
+}
+
+fn purse_holder_contract_hash(builder: &InMemoryWasmTestBuilder) -> ContractHash {
+    builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(PURSE_HOLDER_STORED_CONTRACT_NAME)
+        .expect("should have contract")
+        .into_hash()
+        .expect("should be hash")
+        .into()
+}
+
+fn call_contract_request(
+    builder: &InMemoryWasmTestBuilder,
+    contract: ContractIdentifier,
+    entry_point: &str,
+    args: RuntimeArgs,
+    gas_limit: u64,
+) -> CallContractRequest {
+    CallContractRequest::new(
+        builder.get_post_state_hash(),
+        BlockInfo::default(),
+        *DEFAULT_PROTOCOL_VERSION,
+        Some(*DEFAULT_ACCOUNT_ADDR),
+        contract,
+        entry_point.to_string(),
+        args,
+        Gas::new(U512::from(gas_limit)),
+    )
+}
+
+#[ignore]
+#[test]
+fn should_return_value_from_stored_contract_by_name_and_hash() {
+    let builder = setup();
+
+    let contract_hash = purse_holder_contract_hash(&builder);
+
+    for contract in vec![
+        ContractIdentifier::Name(PURSE_HOLDER_STORED_CONTRACT_NAME.to_string()),
+        ContractIdentifier::Hash(contract_hash),
+    ] {
+        let request = call_contract_request(
+            &builder,
+            contract,
+            ENTRY_POINT_VERSION,
+            RuntimeArgs::new(),
+            GAS_LIMIT,
+        );
+        let result = builder
+            .get_engine_state()
+            .call_contract(CorrelationId::new(), request)
+            .expect("should call contract");
+
+        match result {
+            CallContractResult::Success { value, cost } => {
+                assert_eq!(value.into_t::<String>().unwrap(), VERSION);
+                assert!(cost > Gas::default());
+            }
+            CallContractResult::RootNotFound => panic!("should find root"),
+        }
+    }
+}
+
+#[ignore]
+#[test]
+fn should_not_commit_effects_of_call() {
+    let builder = setup();
+    let state_hash_before = builder.get_post_state_hash();
+
+    let request = call_contract_request(
+        &builder,
+        ContractIdentifier::Name(PURSE_HOLDER_STORED_CONTRACT_NAME.to_string()),
+        ENTRY_POINT_ADD,
+        runtime_args! { ARG_PURSE => "new_purse" },
+        GAS_LIMIT,
+    );
+    builder
+        .get_engine_state()
+        .call_contract(CorrelationId::new(), request)
+        .expect("should call contract");
+
+    assert_eq!(builder.get_post_state_hash(), state_hash_before);
+    let contract = builder
+        .get_contract(purse_holder_contract_hash(&builder))
+        .expect("should have contract");
+    assert!(!contract.named_keys().contains_key("new_purse"));
+}
+
+#[ignore]
+#[test]
+fn should_fail_call_exceeding_gas_limit() {
+    let builder = setup();
+
+    let request = call_contract_request(
+        &builder,
+        ContractIdentifier::Name(PURSE_HOLDER_STORED_CONTRACT_NAME.to_string()),
+        ENTRY_POINT_VERSION,
+        RuntimeArgs::new(),
+        1,
+    );
+    let error = builder
+        .get_engine_state()
+        .call_contract(CorrelationId::new(), request)
+        .expect_err("should fail");
+
+    assert_matches!(error, Error::Exec(execution::Error::GasLimit));
+}
@@ -0,0 +1,154 @@
+use assert_matches::assert_matches;
+
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_execution_engine::core::{engine_state::Error, execution};
+use casper_types::{
+    runtime_args, AccessRights, ApiError, Key, RuntimeArgs, URef, DICTIONARY_ITEM_KEY_MAX_LENGTH,
+};
+
+const DICTIONARY_WASM: &str = "dictionary.wasm";
+const DICTIONARY_ACCESS_RIGHTS_WASM: &str = "dictionary_access_rights.wasm";
+const DICTIONARY_NAME: &str = "dictionary";
+const DICTIONARY_VALUE: &str = "value";
+const DICTIONARY_ITEM_KEY: &str = "item";
+const ARG_DICTIONARY_ITEM_KEY: &str = "dictionary_item_key";
+const ARG_METHOD: &str = "method";
+const METHOD_WRITE_READ_ONLY: &str = "write_read_only";
+const METHOD_WRITE_FORGED: &str = "write_forged";
+
+#[ignore]
+#[test]
+fn should_put_and_get_dictionary_item() {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        DICTIONARY_WASM,
+        runtime_args! { ARG_DICTIONARY_ITEM_KEY => DICTIONARY_ITEM_KEY.to_string() },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .expect_success();
+
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let seed_uref = account
+        .named_keys()
+        .get(DICTIONARY_NAME)
+        .and_then(Key::as_uref)
+        .copied()
+        .expect("should have dictionary seed uref");
+
+    let stored_value = builder
+        .query(
+            None,
+            Key::dictionary(seed_uref, DICTIONARY_ITEM_KEY.as_bytes()),
+            &[],
+        )
+        .expect("should query dictionary item");
+    let dictionary_value = stored_value
+        .as_dictionary()
+        .expect("should be dictionary value");
+    assert_eq!(
+        dictionary_value.seed_uref(),
+        seed_uref.remove_access_rights()
+    );
+    assert_eq!(
+        dictionary_value.dictionary_item_key().as_slice(),
+        DICTIONARY_ITEM_KEY.as_bytes()
+    );
+
+    let value: String = dictionary_value
+        .cl_value()
+        .clone()
+        .into_t()
+        .expect("should convert");
+    assert_eq!(value, DICTIONARY_VALUE);
+}
+
+#[ignore]
+#[test]
+fn should_fail_with_too_long_dictionary_item_key() {
+    let dictionary_item_key = "a".repeat(DICTIONARY_ITEM_KEY_MAX_LENGTH + 1);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        DICTIONARY_WASM,
+        runtime_args! { ARG_DICTIONARY_ITEM_KEY => dictionary_item_key },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+
+    let error = builder
+        .get_exec_results()
+        .last()
+        .and_then(|response| response.last())
+        .and_then(|result| result.as_error())
+        .expect("should have error");
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::Revert(
+            ApiError::DictionaryItemKeyExceedsLength
+        ))
+    );
+}
+
+fn dictionary_access_rights_error(method: &str) -> Error {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        DICTIONARY_ACCESS_RIGHTS_WASM,
+        runtime_args! { ARG_METHOD => method },
+    )
+    .build();
+
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit();
+
+    builder
+        .get_exec_results()
+        .last()
+        .and_then(|response| response.last())
+        .and_then(|result| result.as_error())
+        .cloned()
+        .expect("should have error")
+}
+
+#[ignore]
+#[test]
+fn should_not_write_dictionary_item_through_read_only_uref() {
+    let error = dictionary_access_rights_error(METHOD_WRITE_READ_ONLY);
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::InvalidAccess {
+            required: AccessRights::WRITE
+        })
+    );
+}
+
+#[ignore]
+#[test]
+fn should_not_write_dictionary_item_through_forged_uref() {
+    let forged_uref = URef::new([1u8; 32], AccessRights::READ_ADD_WRITE);
+
+    let error = dictionary_access_rights_error(METHOD_WRITE_FORGED);
+    assert_matches!(
+        error,
+        Error::Exec(execution::Error::ForgedReference(uref)) if uref == forged_uref
+    );
+}
@@ -0,0 +1,184 @@
+use casper_engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, CLValue, ContractEvent, ContractHash, RuntimeArgs};
+
+const EMIT_EVENT_WASM: &str = "emit_event.wasm";
+const CONTRACT_NAME: &str = "emit_event_contract";
+const ENTRY_POINT_EMIT: &str = "emit";
+const ENTRY_POINT_EMIT_AND_REVERT: &str = "emit_and_revert";
+const ENTRY_POINT_EMIT_AND_PAY: &str = "emit_and_pay";
+const ARG_TOPIC: &str = "topic";
+const ARG_PAYLOAD: &str = "payload";
+const ARG_AMOUNT: &str = "amount";
+
+fn get_events(builder: &InMemoryWasmTestBuilder, index: usize) -> Vec<ContractEvent> {
+    builder
+        .get_exec_result(index)
+        .expect("should have exec result")
+        .first()
+        .expect("should have response")
+        .events()
+        .clone()
+}
+
+/// Installs the emitting contract, returning its hash.
+fn install_contract(builder: &mut InMemoryWasmTestBuilder) -> ContractHash {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        EMIT_EVENT_WASM,
+        runtime_args! {
+            ARG_TOPIC => String::from("install"),
+            ARG_PAYLOAD => 0u64,
+        },
+    )
+    .build();
+
+    builder.exec(exec_request).commit().expect_success();
+
+    builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(CONTRACT_NAME)
+        .expect("should have contract")
+        .into_hash()
+        .map(ContractHash::new)
+        .expect("should be a hash")
+}
+
+#[ignore]
+#[test]
+fn should_record_events_emitted_by_session_and_stored_contract() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let contract_hash = install_contract(&mut builder);
+
+    let expected_session_event = ContractEvent {
+        contract_hash: None,
+        topic: "install".to_string(),
+        payload: CLValue::from_t(0u64).unwrap(),
+    };
+    assert_eq!(get_events(&builder, 0), vec![expected_session_event]);
+
+    let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        contract_hash,
+        ENTRY_POINT_EMIT,
+        runtime_args! {
+            ARG_TOPIC => String::from("contract"),
+            ARG_PAYLOAD => 2u64,
+        },
+    )
+    .build();
+
+    builder.exec(exec_request).commit().expect_success();
+
+    let expected_contract_event = ContractEvent {
+        contract_hash: Some(contract_hash),
+        topic: "contract".to_string(),
+        payload: CLValue::from_t(2u64).unwrap(),
+    };
+    assert_eq!(get_events(&builder, 1), vec![expected_contract_event]);
+}
+
+#[ignore]
+#[test]
+fn should_discard_events_of_failed_execution() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let contract_hash = install_contract(&mut builder);
+
+    let exec_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        contract_hash,
+        ENTRY_POINT_EMIT_AND_REVERT,
+        runtime_args! {
+            ARG_TOPIC => String::from("reverted"),
+            ARG_PAYLOAD => 1u64,
+        },
+    )
+    .build();
+
+    builder.exec(exec_request).commit();
+
+    assert!(builder.is_error());
+    assert!(get_events(&builder, 1).is_empty());
+}
+
+#[ignore]
+#[test]
+fn should_record_events_emitted_by_payment_and_session() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let contract_hash = install_contract(&mut builder);
+
+    let expected_payment_event = ContractEvent {
+        contract_hash: None,
+        topic: "payment".to_string(),
+        payload: CLValue::from_t(1u64).unwrap(),
+    };
+    let expected_session_event = ContractEvent {
+        contract_hash: Some(contract_hash),
+        topic: "session".to_string(),
+        payload: CLValue::from_t(2u64).unwrap(),
+    };
+
+    // The events of the payment code precede those of the session code.  If the session code
+    // fails, only its own events are discarded.
+    for (deploy_index, &session_entry_point) in [ENTRY_POINT_EMIT, ENTRY_POINT_EMIT_AND_REVERT]
+        .iter()
+        .enumerate()
+    {
+        let deploy = DeployItemBuilder::new()
+            .with_address(*DEFAULT_ACCOUNT_ADDR)
+            .with_deploy_hash([deploy_index as u8 + 1; 32])
+            .with_stored_payment_hash(
+                contract_hash,
+                ENTRY_POINT_EMIT_AND_PAY,
+                runtime_args! {
+                    ARG_TOPIC => String::from("payment"),
+                    ARG_PAYLOAD => 1u64,
+                    ARG_AMOUNT => *DEFAULT_PAYMENT,
+                },
+            )
+            .with_stored_session_hash(
+                contract_hash,
+                session_entry_point,
+                runtime_args! {
+                    ARG_TOPIC => String::from("session"),
+                    ARG_PAYLOAD => 2u64,
+                },
+            )
+            .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+            .build();
+        let exec_request = ExecuteRequestBuilder::new().push_deploy(deploy).build();
+
+        builder.exec(exec_request).commit();
+
+        let events = get_events(&builder, deploy_index + 1);
+        if session_entry_point == ENTRY_POINT_EMIT {
+            builder.expect_success();
+            assert_eq!(
+                events,
+                vec![
+                    expected_payment_event.clone(),
+                    expected_session_event.clone()
+                ]
+            );
+        } else {
+            assert!(builder.is_error());
+            assert_eq!(events, vec![expected_payment_event.clone()]);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use casper_engine_test_support::{
+    internal::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_PAYMENT,
+        DEFAULT_RUN_GENESIS_REQUEST,
+    },
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, CallStackElement, ContractHash, EraId, RuntimeArgs};
+
+const CONTRACT_GET_BLOCK_INFO: &str = "get_block_info.wasm";
+const CONTRACT_GET_CALL_STACK: &str = "get_call_stack.wasm";
+const GET_CALL_STACK_CONTRACT_NAME: &str = "get_call_stack_contract";
+const ENTRY_POINT_OUTER: &str = "outer";
+const ENTRY_POINT_INNER: &str = "inner";
+const INNER_CALL_STACK: &str = "inner_call_stack";
+const ARG_AMOUNT: &str = "amount";
+const ARG_KNOWN_BLOCK_HEIGHT: &str = "known_block_height";
+const ARG_KNOWN_ERA_ID: &str = "known_era_id";
+const ARG_KNOWN_PARENT_BLOCK_HASH: &str = "known_parent_block_hash";
+const ARG_KNOWN_DEPLOY_HASH: &str = "known_deploy_hash";
+
+#[ignore]
+#[test]
+fn should_run_get_block_info_contract() {
+    let block_height: u64 = 42;
+    let era_id = EraId::new(7);
+    let parent_block_hash = [1u8; 32];
+    let deploy_hash = [2u8; 32];
+
+    let deploy = DeployItemBuilder::new()
+        .with_address(*DEFAULT_ACCOUNT_ADDR)
+        .with_session_code(
+            CONTRACT_GET_BLOCK_INFO,
+            runtime_args! {
+                ARG_KNOWN_BLOCK_HEIGHT => block_height,
+                ARG_KNOWN_ERA_ID => era_id,
+                ARG_KNOWN_PARENT_BLOCK_HASH => parent_block_hash,
+                ARG_KNOWN_DEPLOY_HASH => deploy_hash,
+            },
+        )
+        .with_empty_payment_bytes(runtime_args! { ARG_AMOUNT => *DEFAULT_PAYMENT })
+        .with_authorization_keys(&[*DEFAULT_ACCOUNT_ADDR])
+        .with_deploy_hash(deploy_hash)
+        .build();
+
+    let exec_request = ExecuteRequestBuilder::new()
+        .push_deploy(deploy)
+        .with_block_height(block_height)
+        .with_era_id(era_id)
+        .with_parent_block_hash(&parent_block_hash)
+        .build();
+
+    InMemoryWasmTestBuilder::default()
+        .run_genesis(&DEFAULT_RUN_GENESIS_REQUEST)
+        .exec(exec_request)
+        .commit()
+        .expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_extend_call_stack_for_nested_stored_contract_calls() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let install_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        CONTRACT_GET_CALL_STACK,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(install_request).expect_success().commit();
+
+    let contract_hash: ContractHash = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account")
+        .named_keys()
+        .get(GET_CALL_STACK_CONTRACT_NAME)
+        .expect("should have contract")
+        .into_hash()
+        .expect("should be hash")
+        .into();
+
+    let call_request = ExecuteRequestBuilder::contract_call_by_hash(
+        *DEFAULT_ACCOUNT_ADDR,
+        contract_hash,
+        ENTRY_POINT_OUTER,
+        RuntimeArgs::default(),
+    )
+    .build();
+    builder.exec(call_request).expect_success().commit();
+
+    let inner_call_stack: Vec<CallStackElement> = builder
+        .query(None, contract_hash.into(), &[INNER_CALL_STACK.to_string()])
+        .expect("should query")
+        .as_cl_value()
+        .cloned()
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should convert");
+
+    let expected_call_stack = vec![
+        CallStackElement::session(*DEFAULT_ACCOUNT_ADDR),
+        CallStackElement::stored_contract(contract_hash, ENTRY_POINT_OUTER.to_string()),
+        CallStackElement::stored_contract(contract_hash, ENTRY_POINT_INNER.to_string()),
+    ];
+    assert_eq!(inner_call_stack, expected_call_stack);
+}
@@ -0,0 +1,82 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{runtime_args, RuntimeArgs};
+
+const HASH_FUNCTIONS_WASM: &str = "hash_functions.wasm";
+const ARG_INPUT: &str = "input";
+const SHA256_DIGEST: &str = "sha256_digest";
+const KECCAK256_DIGEST: &str = "keccak256_digest";
+const RANDOM_SEED: &str = "random_seed";
+
+const INPUT: &[u8] = b"abc";
+const EXPECTED_SHA256_DIGEST: &str =
+    "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+const EXPECTED_KECCAK256_DIGEST: &str =
+    "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45";
+
+fn get_digest(builder: &InMemoryWasmTestBuilder, name: &str) -> [u8; 32] {
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let uref = account.named_keys().get(name).expect("should have value");
+
+    builder
+        .query(None, *uref, &[])
+        .expect("should query")
+        .as_cl_value()
+        .cloned()
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should convert")
+}
+
+fn run_hash_functions(builder: &mut InMemoryWasmTestBuilder) {
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        HASH_FUNCTIONS_WASM,
+        runtime_args! {
+            ARG_INPUT => INPUT.to_vec(),
+        },
+    )
+    .build();
+
+    builder.exec(exec_request).commit().expect_success();
+}
+
+#[ignore]
+#[test]
+fn should_compute_sha256_and_keccak256_digests() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    run_hash_functions(&mut builder);
+
+    let sha256_digest = get_digest(&builder, SHA256_DIGEST);
+    assert_eq!(base16::encode_lower(&sha256_digest), EXPECTED_SHA256_DIGEST);
+
+    let keccak256_digest = get_digest(&builder, KECCAK256_DIGEST);
+    assert_eq!(
+        base16::encode_lower(&keccak256_digest),
+        EXPECTED_KECCAK256_DIGEST
+    );
+}
+
+#[ignore]
+#[test]
+fn should_derive_different_random_seed_per_deploy() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    run_hash_functions(&mut builder);
+    let seed_1 = get_digest(&builder, RANDOM_SEED);
+
+    run_hash_functions(&mut builder);
+    let seed_2 = get_digest(&builder, RANDOM_SEED);
+
+    assert_ne!(seed_1, seed_2);
+}
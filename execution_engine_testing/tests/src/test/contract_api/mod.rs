@@ -1,10 +1,14 @@
 mod account;
 mod blake2b;
 mod create_purse;
+mod dictionary;
+mod emit_event;
 mod get_arg;
+mod get_block_info;
 mod get_blocktime;
 mod get_caller;
 mod get_phase;
+mod hash_functions;
 mod list_named_keys;
 mod main_purse;
 mod mint_purse;
@@ -15,3 +19,4 @@ mod transfer_purse_to_account;
 mod transfer_purse_to_purse;
 mod transfer_stored;
 mod transfer_u512_stored;
+mod verify_signature;
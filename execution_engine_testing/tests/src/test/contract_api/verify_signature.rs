@@ -0,0 +1,141 @@
+use casper_engine_test_support::{
+    internal::{ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_RUN_GENESIS_REQUEST},
+    DEFAULT_ACCOUNT_ADDR,
+};
+use casper_types::{
+    bytesrepr::ToBytes, runtime_args, PublicKey, RuntimeArgs, SecretKey, Signature, SECP256K1_TAG,
+};
+
+const VERIFY_SIGNATURE_WASM: &str = "verify_signature.wasm";
+const ARG_MESSAGE: &str = "message";
+const ARG_SIGNATURE: &str = "signature";
+const ARG_PUBLIC_KEY: &str = "public_key";
+const SIGNATURE_VALID: &str = "signature_valid";
+
+const MESSAGE: &[u8] = b"message signed by an off-chain oracle";
+
+fn verify_signature(message: &[u8], signature: &Signature, public_key: &PublicKey) -> bool {
+    verify_signature_bytes(
+        message,
+        signature.to_bytes().expect("should serialize signature"),
+        public_key.to_bytes().expect("should serialize public key"),
+    )
+}
+
+fn verify_signature_bytes(message: &[u8], signature: Vec<u8>, public_key: Vec<u8>) -> bool {
+    let mut builder = InMemoryWasmTestBuilder::default();
+
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let exec_request = ExecuteRequestBuilder::standard(
+        *DEFAULT_ACCOUNT_ADDR,
+        VERIFY_SIGNATURE_WASM,
+        runtime_args! {
+            ARG_MESSAGE => message.to_vec(),
+            ARG_SIGNATURE => signature,
+            ARG_PUBLIC_KEY => public_key,
+        },
+    )
+    .build();
+
+    builder.exec(exec_request).commit().expect_success();
+
+    let account = builder
+        .get_account(*DEFAULT_ACCOUNT_ADDR)
+        .expect("should have account");
+
+    let uref = account
+        .named_keys()
+        .get(SIGNATURE_VALID)
+        .expect("should have value");
+
+    builder
+        .query(None, *uref, &[])
+        .expect("should query")
+        .as_cl_value()
+        .cloned()
+        .expect("should be CLValue")
+        .into_t()
+        .expect("should convert")
+}
+
+fn key_pair(secret_key: SecretKey) -> (SecretKey, PublicKey) {
+    let public_key = PublicKey::from(&secret_key);
+    (secret_key, public_key)
+}
+
+#[ignore]
+#[test]
+fn should_verify_ed25519_signature() {
+    let (secret_key, public_key) =
+        key_pair(SecretKey::ed25519_from_bytes([1; SecretKey::ED25519_LENGTH]).unwrap());
+    let signature = casper_types::sign(MESSAGE, &secret_key, &public_key).expect("should sign");
+
+    assert!(verify_signature(MESSAGE, &signature, &public_key));
+}
+
+#[ignore]
+#[test]
+fn should_verify_secp256k1_signature() {
+    let (secret_key, public_key) =
+        key_pair(SecretKey::secp256k1_from_bytes([1; SecretKey::SECP256K1_LENGTH]).unwrap());
+    let signature = casper_types::sign(MESSAGE, &secret_key, &public_key).expect("should sign");
+
+    assert!(verify_signature(MESSAGE, &signature, &public_key));
+}
+
+#[ignore]
+#[test]
+fn should_reject_invalid_signature() {
+    let (secret_key, public_key) =
+        key_pair(SecretKey::ed25519_from_bytes([1; SecretKey::ED25519_LENGTH]).unwrap());
+    let (_, other_public_key) =
+        key_pair(SecretKey::secp256k1_from_bytes([1; SecretKey::SECP256K1_LENGTH]).unwrap());
+    let signature = casper_types::sign(MESSAGE, &secret_key, &public_key).expect("should sign");
+
+    assert!(!verify_signature(
+        b"tampered message",
+        &signature,
+        &public_key
+    ));
+    assert!(!verify_signature(MESSAGE, &signature, &other_public_key));
+}
+
+#[ignore]
+#[test]
+fn should_reject_malformed_signature_and_public_key() {
+    let (secret_key, public_key) =
+        key_pair(SecretKey::ed25519_from_bytes([1; SecretKey::ED25519_LENGTH]).unwrap());
+    let signature = casper_types::sign(MESSAGE, &secret_key, &public_key).expect("should sign");
+    let signature_bytes = signature.to_bytes().expect("should serialize signature");
+    let public_key_bytes = public_key.to_bytes().expect("should serialize public key");
+
+    // Truncated signature.
+    assert!(!verify_signature_bytes(
+        MESSAGE,
+        signature_bytes[..signature_bytes.len() - 1].to_vec(),
+        public_key_bytes.clone()
+    ));
+    // Unknown signature tag.
+    let mut bad_tag_signature = signature_bytes.clone();
+    bad_tag_signature[0] = u8::max_value();
+    assert!(!verify_signature_bytes(
+        MESSAGE,
+        bad_tag_signature,
+        public_key_bytes.clone()
+    ));
+    // Empty public key.
+    assert!(!verify_signature_bytes(
+        MESSAGE,
+        signature_bytes.clone(),
+        Vec::new()
+    ));
+    // Secp256k1 public key which is not a point on the curve.
+    let mut invalid_public_key = vec![SECP256K1_TAG, 0x02];
+    invalid_public_key.extend_from_slice(&[0xff; 32]);
+    assert!(!verify_signature_bytes(
+        MESSAGE,
+        signature_bytes,
+        invalid_public_key
+    ));
+}
@@ -1,3 +1,4 @@
+mod call_contract;
 mod check_transfer_success;
 mod contract_api;
 mod contract_context;
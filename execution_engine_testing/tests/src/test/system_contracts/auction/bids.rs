@@ -1,4 +1,7 @@
-use std::{collections::BTreeSet, iter::FromIterator};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    iter::FromIterator,
+};
 
 use assert_matches::assert_matches;
 use num_traits::{One, Zero};
@@ -8,8 +11,8 @@ use casper_engine_test_support::{
     internal::{
         utils, ExecuteRequestBuilder, InMemoryWasmTestBuilder, DEFAULT_ACCOUNTS,
         DEFAULT_AUCTION_DELAY, DEFAULT_GENESIS_TIMESTAMP_MILLIS,
-        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_RUN_GENESIS_REQUEST, DEFAULT_UNBONDING_DELAY,
-        SYSTEM_ADDR, TIMESTAMP_MILLIS_INCREMENT,
+        DEFAULT_LOCKED_FUNDS_PERIOD_MILLIS, DEFAULT_PROTOCOL_VERSION, DEFAULT_RUN_GENESIS_REQUEST,
+        DEFAULT_UNBONDING_DELAY, SYSTEM_ADDR, TIMESTAMP_MILLIS_INCREMENT,
     },
     DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE, MINIMUM_ACCOUNT_CREATION_BALANCE,
 };
@@ -18,10 +21,11 @@ use casper_execution_engine::{
         engine_state::{
             self,
             genesis::{GenesisAccount, GenesisValidator},
+            query::{GetAuctionProofsRequest, GetAuctionProofsResult},
         },
         execution,
     },
-    shared::motes::Motes,
+    shared::{motes::Motes, newtypes::CorrelationId, stored_value::StoredValue},
 };
 use casper_types::{
     self,
@@ -36,7 +40,7 @@ use casper_types::{
             ERA_ID_KEY, INITIAL_ERA_ID,
         },
     },
-    EraId, PublicKey, RuntimeArgs, SecretKey, U512,
+    EraId, Key, PublicKey, RuntimeArgs, SecretKey, U512,
 };
 
 const ARG_TARGET: &str = "target";
@@ -2959,3 +2963,61 @@ fn should_validate_genesis_delegators_bond_amount() {
 
     builder.run_genesis(&run_genesis_request);
 }
+
+#[ignore]
+#[test]
+fn should_prove_auction_era_id_and_auction_delay() {
+    let mut builder = InMemoryWasmTestBuilder::default();
+    builder.run_genesis(&DEFAULT_RUN_GENESIS_REQUEST);
+
+    let request =
+        GetAuctionProofsRequest::new(builder.get_post_state_hash(), *DEFAULT_PROTOCOL_VERSION);
+    let proofs = match builder
+        .get_engine_state()
+        .get_auction_proofs(CorrelationId::new(), request)
+        .expect("should get auction proofs")
+    {
+        GetAuctionProofsResult::Success { proofs } => proofs,
+        GetAuctionProofsResult::RootNotFound => panic!("should find root"),
+    };
+    let proven: BTreeMap<Key, StoredValue> = proofs
+        .proofs()
+        .into_iter()
+        .map(|proof| {
+            let (key, value, _) = proof.into_parts();
+            (key, value)
+        })
+        .collect();
+
+    let auction_hash = builder.get_auction_contract_hash();
+    let auction = builder
+        .get_contract(auction_hash)
+        .expect("should have auction contract");
+    assert_matches!(
+        proven.get(&Key::from(auction_hash)),
+        Some(StoredValue::Contract(_))
+    );
+
+    let read_proven_value = |name: &str| {
+        let key = auction.named_keys()[name].normalize();
+        proven
+            .get(&key)
+            .and_then(StoredValue::as_cl_value)
+            .cloned()
+            .expect("should prove auction named key value")
+    };
+    let era_id: EraId = read_proven_value(ERA_ID_KEY)
+        .into_t()
+        .expect("should be era id");
+    let auction_delay: u64 = read_proven_value(auction::AUCTION_DELAY_KEY)
+        .into_t()
+        .expect("should be auction delay");
+    assert_eq!(auction_delay, DEFAULT_AUCTION_DELAY);
+
+    for era_id in era_id.iter_inclusive(auction_delay) {
+        assert_matches!(
+            proven.get(&Key::EraValidators(era_id)),
+            Some(StoredValue::EraValidators(_))
+        );
+    }
+}
@@ -829,6 +829,19 @@ fn should_verify_wasm_add_bid_wasm_cost_is_not_recursive() {
         remove_contract_user_group_urefs: HostFunction::fixed(0),
         print: HostFunction::fixed(0),
         blake2b: HostFunction::fixed(0),
+        emit_event: HostFunction::fixed(0),
+        verify_signature: HostFunction::fixed(0),
+        sha256: HostFunction::fixed(0),
+        keccak256: HostFunction::fixed(0),
+        random_seed: HostFunction::fixed(0),
+        get_block_height: HostFunction::fixed(0),
+        get_era_id: HostFunction::fixed(0),
+        get_parent_block_hash: HostFunction::fixed(0),
+        get_deploy_hash: HostFunction::fixed(0),
+        load_call_stack: HostFunction::fixed(0),
+        new_dictionary: HostFunction::fixed(0),
+        dictionary_get: HostFunction::fixed(0),
+        dictionary_put: HostFunction::fixed(0),
     };
 
     let new_wasm_config = WasmConfig::new(
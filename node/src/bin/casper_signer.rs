@@ -0,0 +1,38 @@
+//! # Casper signing daemon
+//!
+//! Holds a validator's secret key and signs units, endorsements, pings and finality signatures on
+//! behalf of nodes configured with a `[consensus.remote_signer]`.  It refuses to sign two different
+//! units with the same sequence number in the same era.
+
+use std::{fs, path::PathBuf};
+
+use anyhow::Context;
+use structopt::StructOpt;
+
+use casper_node::{
+    crypto::signer::daemon::{Daemon, DaemonConfig},
+    logging,
+    utils::WithDir,
+};
+
+/// Command-line arguments.
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Signs consensus messages on behalf of a Casper validator node")]
+struct Args {
+    /// Path to the configuration file.
+    #[structopt(parse(from_os_str))]
+    config: PathBuf,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::from_args();
+    logging::init()?;
+
+    let contents = fs::read_to_string(&args.config)
+        .with_context(|| format!("failed to read {}", args.config.display()))?;
+    let config: DaemonConfig = toml::from_str(&contents)
+        .with_context(|| format!("failed to parse {}", args.config.display()))?;
+    let root = args.config.parent().map(PathBuf::from).unwrap_or_default();
+
+    Daemon::bind(WithDir::new(root, config))?.run()
+}
@@ -0,0 +1,54 @@
+//! # Highway DAG renderer
+//!
+//! Renders a Highway protocol state dump, as returned by the node's `/consensus/dump` REST
+//! endpoint, as a graphviz DOT graph of the protocol DAG, with finalized blocks highlighted.
+//!
+//! Example: `curl -s 'http://<ip>:8888/consensus/dump' | highway-dag | dot -Tsvg > dag.svg`
+
+use std::{
+    fs::File,
+    io::{self, BufReader, Read, Write},
+    path::PathBuf,
+};
+
+use anyhow::Context;
+use structopt::StructOpt;
+
+use casper_node::types::HighwayStateDump;
+
+/// Command-line arguments.
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Renders a Highway protocol state dump as a graphviz DOT graph")]
+struct Args {
+    /// Path to the JSON state dump.  Reads from stdin if omitted.
+    #[structopt(parse(from_os_str))]
+    input: Option<PathBuf>,
+
+    /// Path to write the DOT graph to.  Writes to stdout if omitted.
+    #[structopt(short, long, parse(from_os_str))]
+    output: Option<PathBuf>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::from_args();
+
+    let reader: Box<dyn Read> = match &args.input {
+        Some(path) => Box::new(BufReader::new(
+            File::open(path).with_context(|| format!("failed to open {}", path.display()))?,
+        )),
+        None => Box::new(io::stdin()),
+    };
+    let dump: HighwayStateDump =
+        serde_json::from_reader(reader).context("failed to parse state dump")?;
+
+    let dot = dump.to_dot();
+    match &args.output {
+        Some(path) => File::create(path)
+            .and_then(|mut file| file.write_all(dot.as_bytes()))
+            .with_context(|| format!("failed to write {}", path.display()))?,
+        None => io::stdout()
+            .write_all(dot.as_bytes())
+            .context("failed to write to stdout")?,
+    }
+    Ok(())
+}
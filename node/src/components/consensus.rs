@@ -28,7 +28,7 @@ use hex_fmt::HexFmt;
 use serde::{Deserialize, Serialize};
 use tracing::error;
 
-use casper_types::{EraId, PublicKey, U512};
+use casper_types::{EraId, PublicKey, Signature, U512};
 
 use crate::{
     components::Component,
@@ -52,7 +52,11 @@ use crate::effect::EffectExt;
 pub use config::Config;
 pub(crate) use consensus_protocol::{BlockContext, EraReport};
 pub(crate) use era_supervisor::EraSupervisor;
-pub(crate) use protocols::highway::HighwayProtocol;
+pub use highway_core::evidence::EvidenceError;
+pub(crate) use protocols::{
+    highway::era_fault::{serialized_equivocation, validate_serialized_evidence},
+    new_consensus_protocol,
+};
 use traits::NodeIdT;
 
 #[cfg(test)]
@@ -133,6 +137,12 @@ pub enum Event<I> {
     },
     /// Got the result of checking for an upgrade activation point.
     GotUpgradeActivationPoint(ActivationPoint),
+    /// The signature of a consensus message in the given era, or `None` if signing failed.
+    Signature {
+        era_id: EraId,
+        hash: Digest,
+        maybe_signature: Option<Signature>,
+    },
 }
 
 impl Debug for ConsensusMessage {
@@ -235,6 +245,14 @@ impl<I: Debug> Display for Event<I> {
             Event::GotUpgradeActivationPoint(activation_point) => {
                 write!(f, "new upgrade activation point: {:?}", activation_point)
             }
+            Event::Signature {
+                era_id,
+                hash,
+                maybe_signature,
+            } => match maybe_signature {
+                Some(_) => write!(f, "signed consensus message {} in {}", hash, era_id),
+                None => write!(f, "failed to sign consensus message {} in {}", hash, era_id),
+            },
         }
     }
 }
@@ -344,9 +362,21 @@ where
             Event::GotUpgradeActivationPoint(activation_point) => {
                 handling_es.got_upgrade_activation_point(activation_point)
             }
+            Event::Signature {
+                era_id,
+                hash,
+                maybe_signature,
+            } => handling_es.handle_signature(era_id, hash, maybe_signature),
             Event::ConsensusRequest(ConsensusRequest::Status(responder)) => {
                 handling_es.status(responder)
             }
+            Event::ConsensusRequest(ConsensusRequest::DumpState { era_id, responder }) => {
+                handling_es.dump_state(era_id, responder)
+            }
+            Event::ConsensusRequest(ConsensusRequest::ValidatorPerformance {
+                era_id,
+                responder,
+            }) => handling_es.validator_performance(era_id, responder),
         }
     }
 }
@@ -1,9 +1,9 @@
 use std::sync::Arc;
 
 use datasize::DataSize;
-use tracing::info;
+use tracing::{error, info};
 
-use casper_types::{PublicKey, SecretKey, Signature};
+use casper_types::{PublicKey, Signature};
 
 use crate::{
     components::consensus::{
@@ -13,28 +13,52 @@ use crate::{
     crypto::{
         self,
         hash::{self, Digest},
+        signer::{self, SignatureFormat, Signer, SigningPayload},
     },
 };
 
+/// The validator's signer, for use in a particular consensus instance.
 #[derive(DataSize)]
 pub(crate) struct Keypair {
-    secret_key: Arc<SecretKey>,
-    public_key: PublicKey,
+    #[data_size(skip)]
+    signer: Arc<dyn Signer>,
+    instance_id: Digest,
+    format: SignatureFormat,
 }
 
 impl Keypair {
-    pub(crate) fn new(secret_key: Arc<SecretKey>, public_key: PublicKey) -> Self {
+    pub(crate) fn new(
+        signer: Arc<dyn Signer>,
+        instance_id: Digest,
+        format: SignatureFormat,
+    ) -> Self {
         Self {
-            secret_key,
-            public_key,
+            signer,
+            instance_id,
+            format,
+        }
+    }
+
+    fn sign_payload(&self, payload: SigningPayload) -> Option<Signature> {
+        match self.signer.sign(&payload) {
+            Ok(signature) => Some(signature),
+            Err(error) => {
+                error!(%error, ?payload, "failed to sign");
+                None
+            }
         }
     }
 }
 
-impl From<Arc<SecretKey>> for Keypair {
-    fn from(secret_key: Arc<SecretKey>) -> Self {
-        let public_key: PublicKey = secret_key.as_ref().into();
-        Self::new(secret_key, public_key)
+#[cfg(test)]
+impl From<Arc<casper_types::SecretKey>> for Keypair {
+    fn from(secret_key: Arc<casper_types::SecretKey>) -> Self {
+        let signer = Arc::new(crypto::signer::LocalSigner::new(secret_key));
+        Self::new(
+            signer,
+            Digest::from([0; Digest::LENGTH]),
+            SignatureFormat::DomainSeparated,
+        )
     }
 }
 
@@ -42,8 +66,25 @@ impl ValidatorSecret for Keypair {
     type Hash = Digest;
     type Signature = Signature;
 
-    fn sign(&self, hash: &Digest) -> Signature {
-        crypto::sign(hash, self.secret_key.as_ref(), &self.public_key)
+    fn sign(&self, hash: &Digest) -> Option<Signature> {
+        self.sign_payload(SigningPayload::ConsensusMessage {
+            instance_id: self.instance_id,
+            hash: *hash,
+            format: self.format,
+        })
+    }
+
+    fn sign_unit(&self, hash: &Digest, seq_number: u64) -> Option<Signature> {
+        self.sign_payload(SigningPayload::Unit {
+            instance_id: self.instance_id,
+            seq_number,
+            hash: *hash,
+            format: self.format,
+        })
+    }
+
+    fn may_block(&self) -> bool {
+        self.signer.may_block()
     }
 }
 
@@ -63,11 +104,33 @@ impl Context for ClContext {
         hash::hash(data)
     }
 
-    fn verify_signature(hash: &Digest, public_key: &PublicKey, signature: &Signature) -> bool {
-        if let Err(error) = crypto::verify(hash, signature, public_key) {
+    fn verify_signature(
+        hash: &Digest,
+        public_key: &PublicKey,
+        signature: &Signature,
+        format: SignatureFormat,
+    ) -> bool {
+        let bytes = signer::consensus_message_bytes_to_sign(format, hash);
+        if let Err(error) = crypto::verify(bytes, signature, public_key) {
             info!(%error, %signature, %public_key, %hash, "failed to validate signature");
             return false;
         }
         true
     }
+
+    fn verify_unit_signature(
+        hash: &Digest,
+        instance_id: &Digest,
+        seq_number: u64,
+        public_key: &PublicKey,
+        signature: &Signature,
+        format: SignatureFormat,
+    ) -> bool {
+        let bytes = signer::unit_bytes_to_sign(format, instance_id, seq_number, hash);
+        if let Err(error) = crypto::verify(bytes, signature, public_key) {
+            info!(%error, %signature, %public_key, %hash, "failed to validate unit signature");
+            return false;
+        }
+        true
+    }
 }
@@ -5,8 +5,11 @@ use casper_types::{ProtocolVersion, SecretKey};
 
 use crate::{
     components::consensus::{protocols::highway::config::Config as HighwayConfig, EraId},
-    crypto::hash::Digest,
-    types::{chainspec::HighwayConfig as HighwayProtocolConfig, Chainspec, TimeDiff, Timestamp},
+    crypto::{hash::Digest, signer::RemoteSignerConfig},
+    types::{
+        chainspec::{ConsensusProtocolName, HighwayConfig as HighwayProtocolConfig},
+        Chainspec, TimeDiff, Timestamp,
+    },
     utils::External,
 };
 
@@ -17,6 +20,10 @@ use crate::{
 pub struct Config {
     /// Path to secret key file.
     pub secret_key_path: External<SecretKey>,
+    /// Connection to a remote signing daemon holding the secret key.  If set, `secret_key_path` is
+    /// not used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remote_signer: Option<RemoteSignerConfig>,
     /// Highway-specific node configuration.
     pub highway: HighwayConfig,
 }
@@ -25,6 +32,7 @@ impl Default for Config {
     fn default() -> Self {
         Config {
             secret_key_path: External::Missing,
+            remote_signer: None,
             highway: HighwayConfig::default(),
         }
     }
@@ -33,6 +41,8 @@ impl Default for Config {
 /// Consensus protocol configuration.
 #[derive(DataSize, Debug)]
 pub(crate) struct ProtocolConfig {
+    /// The consensus protocol to run in each era.
+    pub(crate) consensus_protocol: ConsensusProtocolName,
     pub(crate) highway_config: HighwayProtocolConfig,
     pub(crate) era_duration: TimeDiff,
     pub(crate) minimum_era_height: u64,
@@ -57,6 +67,7 @@ pub(crate) struct ProtocolConfig {
 impl From<&Chainspec> for ProtocolConfig {
     fn from(chainspec: &Chainspec) -> Self {
         ProtocolConfig {
+            consensus_protocol: chainspec.core_config.consensus_protocol,
             highway_config: chainspec.highway_config,
             era_duration: chainspec.core_config.era_duration,
             minimum_era_height: chainspec.core_config.minimum_era_height,
@@ -27,7 +27,7 @@ impl BlockContext {
         self.timestamp
     }
 
-    #[cfg(test)]
+    /// The block's relative height within the era.
     pub(crate) fn height(&self) -> u64 {
         self.height
     }
@@ -59,6 +59,15 @@ pub(crate) struct TerminalBlockData<C: Context> {
     pub(crate) inactive_validators: Vec<C::ValidatorId>,
 }
 
+/// Serialized evidence that a validator is faulty, to be stored and served to clients.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct SerializedEvidence<C: Context> {
+    /// The serialized evidence.
+    pub(crate) bytes: Vec<u8>,
+    /// If the evidence is of endorsing conflicting units, the creator of these units.
+    pub(crate) endorsed_creator: Option<C::ValidatorId>,
+}
+
 /// A finalized block. All nodes are guaranteed to see the same sequence of blocks, and to agree
 /// about all the information contained in this type, as long as the total weight of faulty
 /// validators remains below the threshold.
@@ -79,6 +88,24 @@ pub(crate) struct FinalizedBlock<C: Context> {
     pub(crate) proposer: C::ValidatorId,
 }
 
+/// A consensus message that needs to be signed by our validator.
+#[derive(Clone, DataSize, Debug, Eq, PartialEq)]
+pub(crate) enum SignatureRequest<C: Context> {
+    /// A new unit with the given hash and sequence number.
+    Unit { hash: C::Hash, seq_number: u64 },
+    /// Any other message with the given hash, i.e. an endorsement or a ping.
+    Message { hash: C::Hash },
+}
+
+impl<C: Context> SignatureRequest<C> {
+    /// Returns the hash to be signed.
+    pub(crate) fn hash(&self) -> &C::Hash {
+        match self {
+            SignatureRequest::Unit { hash, .. } | SignatureRequest::Message { hash } => hash,
+        }
+    }
+}
+
 pub(crate) type ProtocolOutcomes<I, C> = Vec<ProtocolOutcome<I, C>>;
 
 // TODO: get rid of anyhow::Error; use variant and derive Clone and PartialEq. This is for testing.
@@ -123,6 +150,10 @@ pub(crate) enum ProtocolOutcome<I, C: Context> {
     StandstillAlert,
     /// We want to disconnect from a sender of invalid data.
     Disconnect(I),
+    /// Sign the message and pass the result to `ConsensusProtocol::handle_signature`.
+    ///
+    /// This is only requested if the validator secret's `may_block` returns `true`.
+    RequestSignature(SignatureRequest<C>),
 }
 
 /// An API for a single instance of the consensus.
@@ -162,6 +193,15 @@ pub(crate) trait ConsensusProtocol<I, C: Context>: Send {
         now: Timestamp,
     ) -> ProtocolOutcomes<I, C>;
 
+    /// Handles the signature requested via `ProtocolOutcome::RequestSignature` for the message
+    /// with the given hash, or `None` if signing failed.
+    fn handle_signature(
+        &mut self,
+        hash: C::Hash,
+        maybe_signature: Option<C::Signature>,
+        now: Timestamp,
+    ) -> ProtocolOutcomes<I, C>;
+
     /// Turns this instance into an active validator, that participates in the consensus protocol.
     fn activate_validator(
         &mut self,
@@ -180,6 +220,9 @@ pub(crate) trait ConsensusProtocol<I, C: Context>: Send {
     /// Returns whether the validator `vid` is known to be faulty.
     fn has_evidence(&self, vid: &C::ValidatorId) -> bool;
 
+    /// Returns the serialized direct evidence against the validator `vid`, if present.
+    fn serialized_evidence(&self, vid: &C::ValidatorId) -> Option<SerializedEvidence<C>>;
+
     /// Marks the validator `vid` as faulty, based on evidence from a different instance.
     fn mark_faulty(&mut self, vid: &C::ValidatorId);
 
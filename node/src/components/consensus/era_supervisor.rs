@@ -28,7 +28,7 @@ use prometheus::Registry;
 use rand::Rng;
 use tracing::{debug, error, info, trace, warn};
 
-use casper_types::{AsymmetricType, EraId, PublicKey, SecretKey, U512};
+use casper_types::{AsymmetricType, EraId, PublicKey, Signature, U512};
 
 use crate::{
     components::consensus::{
@@ -37,21 +37,26 @@ use crate::{
         config::ProtocolConfig,
         consensus_protocol::{
             BlockContext, ConsensusProtocol, EraReport, FinalizedBlock as CpFinalizedBlock,
-            ProtocolOutcome, ProtocolOutcomes,
+            ProtocolOutcome, ProtocolOutcomes, SignatureRequest,
         },
         metrics::ConsensusMetrics,
+        protocols::highway::HighwayProtocol,
         traits::{ConsensusValueT, NodeIdT},
         ActionId, Config, ConsensusMessage, Event, ReactorEventT, TimerId,
     },
-    crypto::hash::Digest,
+    crypto::{
+        hash::Digest,
+        signer::{self, LocalSigner, RemoteSigner, SignatureFormat, Signer, SigningPayload},
+    },
     effect::{
         requests::{BlockValidationRequest, StorageRequest},
         EffectBuilder, EffectExt, EffectOptionExt, Effects, Responder,
     },
     fatal,
     types::{
-        ActivationPoint, Block, BlockHash, BlockHeader, DeployHash, DeployMetadata,
-        FinalitySignature, FinalizedBlock, ProtoBlock, TimeDiff, Timestamp,
+        ActivationPoint, Block, BlockHash, BlockHeader, DeployHash, DeployMetadata, EraFault,
+        EraValidatorPerformance, FinalitySignature, FinalizedBlock, HighwayStateDump, ProtoBlock,
+        TimeDiff, Timestamp,
     },
     utils::WithDir,
     NodeRng,
@@ -72,6 +77,7 @@ type ConsensusConstructor<I> = dyn Fn(
     Option<&dyn ConsensusProtocol<I, ClContext>>, // previous era's consensus instance
     Timestamp,                                    // start time for this era
     u64,                                          // random seed
+    SignatureFormat,                              // format of the bytes signed in this era
     Timestamp,                                    // now timestamp
 ) -> (
     Box<dyn ConsensusProtocol<I, ClContext>>,
@@ -86,7 +92,8 @@ pub struct EraSupervisor<I> {
     /// This map always contains exactly `2 * bonded_eras + 1` entries, with the last one being the
     /// current one.
     active_eras: HashMap<EraId, Era<I>>,
-    secret_signing_key: Arc<SecretKey>,
+    #[data_size(skip)]
+    signer: Arc<dyn Signer>,
     pub(super) public_signing_key: PublicKey,
     current_era: EraId,
     protocol_config: ProtocolConfig,
@@ -150,8 +157,17 @@ where
         }
         let unit_hashes_folder = config.with_dir(config.value().highway.unit_hashes_folder.clone());
         let (root, config) = config.into_parts();
-        let secret_signing_key = Arc::new(config.secret_key_path.clone().load(root)?);
-        let public_signing_key = PublicKey::from(secret_signing_key.as_ref());
+        let signer: Arc<dyn Signer> = match config.remote_signer.clone() {
+            Some(remote_signer_config) => Arc::new(RemoteSigner::connect(WithDir::new(
+                root,
+                remote_signer_config,
+            ))?),
+            None => {
+                let secret_key = config.secret_key_path.clone().load(root)?;
+                Arc::new(LocalSigner::new(Arc::new(secret_key)))
+            }
+        };
+        let public_signing_key = signer.public_key().clone();
         info!(our_id = %public_signing_key, "EraSupervisor pubkey",);
         let metrics = ConsensusMetrics::new(registry)
             .expect("failure to setup and register ConsensusMetrics");
@@ -162,7 +178,7 @@ where
 
         let era_supervisor = Self {
             active_eras: Default::default(),
-            secret_signing_key,
+            signer,
             public_signing_key,
             current_era,
             protocol_config,
@@ -298,6 +314,7 @@ where
         self.current_era = era_id;
         self.metrics.current_era.set(era_id.value() as i64);
         let instance_id = instance_id(&self.protocol_config, era_id);
+        let signature_format = self.signature_format(era_id);
 
         info!(
             ?validators,
@@ -333,11 +350,12 @@ where
             prev_era.map(|era| &*era.consensus),
             start_time,
             seed,
+            signature_format,
             now,
         );
 
         if should_activate {
-            let secret = Keypair::new(self.secret_signing_key.clone(), our_id.clone());
+            let secret = Keypair::new(Arc::clone(&self.signer), instance_id, signature_format);
             let unit_hash_file = self.unit_hashes_folder.join(format!(
                 "unit_hash_{:?}_{}.dat",
                 instance_id,
@@ -387,6 +405,12 @@ where
             && era_id <= self.current_era
     }
 
+    /// Returns the format of the bytes signed for units and other consensus messages in the given
+    /// era.
+    fn signature_format(&self, era_id: EraId) -> SignatureFormat {
+        self.protocol_config.highway_config.signature_format(era_id)
+    }
+
     /// Returns whether the validator with the given public key is bonded in that era.
     fn is_validator_in(&self, pub_key: &PublicKey, era_id: EraId) -> bool {
         let has_validator = |era: &Era<I>| era.validators().contains_key(&pub_key);
@@ -665,6 +689,17 @@ where
         })
     }
 
+    pub(super) fn handle_signature(
+        &mut self,
+        era_id: EraId,
+        hash: Digest,
+        maybe_signature: Option<Signature>,
+    ) -> Effects<Event<I>> {
+        self.delegate_to_era(era_id, move |consensus| {
+            consensus.handle_signature(hash, maybe_signature, Timestamp::now())
+        })
+    }
+
     pub(super) fn handle_message(&mut self, sender: I, msg: ConsensusMessage) -> Effects<Event<I>> {
         match msg {
             ConsensusMessage::Protocol { era_id, payload } => {
@@ -719,19 +754,31 @@ where
 
     pub(super) fn handle_block_added(&mut self, block: Block) -> Effects<Event<I>> {
         let our_pk = self.era_supervisor.public_signing_key.clone();
-        let our_sk = self.era_supervisor.secret_signing_key.clone();
         let era_id = block.header().era_id();
         self.era_supervisor.executed_block(block.header());
         let mut effects = if self.era_supervisor.is_validator_in(&our_pk, era_id) {
-            let block_hash = block.hash();
-            self.effect_builder
-                .announce_created_finality_signature(FinalitySignature::new(
-                    *block_hash,
-                    era_id,
-                    &our_sk,
-                    our_pk,
-                ))
-                .ignore()
+            let block_hash = *block.hash();
+            let payload = SigningPayload::FinalitySignature { block_hash, era_id };
+            let signer = Arc::clone(&self.era_supervisor.signer);
+            let effect_builder = self.effect_builder;
+            async move {
+                match signer::sign_on_blocking_pool(signer, payload).await {
+                    Ok(signature) => {
+                        effect_builder
+                            .announce_created_finality_signature(FinalitySignature {
+                                block_hash,
+                                era_id,
+                                signature,
+                                public_key: our_pk,
+                            })
+                            .await
+                    }
+                    Err(error) => {
+                        error!(%error, %block_hash, "failed to create finality signature")
+                    }
+                }
+            }
+            .ignore()
         } else {
             Effects::new()
         };
@@ -1129,10 +1176,28 @@ where
             }
             ProtocolOutcome::NewEvidence(pub_key) => {
                 info!(%pub_key, era = era_id.value(), "validator equivocated");
+                let timestamp = Timestamp::now();
                 let mut effects = self
                     .effect_builder
-                    .announce_fault_event(era_id, pub_key.clone(), Timestamp::now())
+                    .announce_fault_event(era_id, pub_key.clone(), timestamp)
                     .ignore();
+                if let Some(era) = self.era_supervisor.active_eras.get(&era_id) {
+                    if let Some(evidence) = era.consensus.serialized_evidence(&pub_key) {
+                        let era_fault = EraFault {
+                            era_id,
+                            public_key: pub_key.clone(),
+                            endorsed_creator: evidence.endorsed_creator,
+                            instance_id: *era.consensus.instance_id(),
+                            timestamp,
+                            evidence: evidence.bytes.into(),
+                        };
+                        effects.extend(
+                            self.effect_builder
+                                .put_era_fault_to_storage(era_fault)
+                                .ignore(),
+                        );
+                    }
+                }
                 for e_id in self
                     .era_supervisor
                     .iter_future(era_id, self.era_supervisor.bonded_eras())
@@ -1162,6 +1227,38 @@ where
                 .collect(),
             ProtocolOutcome::WeAreFaulty => Default::default(),
             ProtocolOutcome::DoppelgangerDetected => Default::default(),
+            ProtocolOutcome::RequestSignature(request) => {
+                // The signer may block, so we sign on the blocking thread pool and handle the
+                // signature in a separate event.
+                let instance_id = *self.era(era_id).consensus.instance_id();
+                let format = self.era_supervisor.signature_format(era_id);
+                let hash = *request.hash();
+                let payload = match request {
+                    SignatureRequest::Unit { hash, seq_number } => SigningPayload::Unit {
+                        instance_id,
+                        seq_number,
+                        hash,
+                        format,
+                    },
+                    SignatureRequest::Message { hash } => SigningPayload::ConsensusMessage {
+                        instance_id,
+                        hash,
+                        format,
+                    },
+                };
+                let signer = Arc::clone(&self.era_supervisor.signer);
+                async move {
+                    signer::sign_on_blocking_pool(signer, payload)
+                        .await
+                        .map_err(|error| error!(%error, %hash, "failed to sign consensus message"))
+                        .ok()
+                }
+                .event(move |maybe_signature| Event::Signature {
+                    era_id,
+                    hash,
+                    maybe_signature,
+                })
+            }
             ProtocolOutcome::FttExceeded => {
                 let eb = self.effect_builder;
                 eb.set_timeout(Duration::from_millis(FTT_EXCEEDED_SHUTDOWN_DELAY_MILLIS))
@@ -1202,6 +1299,44 @@ where
         responder.respond(Some((public_key, round_length))).ignore()
     }
 
+    pub(super) fn dump_state(
+        &self,
+        era_id: Option<EraId>,
+        responder: Responder<Option<Box<HighwayStateDump>>>,
+    ) -> Effects<Event<I>> {
+        let era_id = era_id.unwrap_or(self.era_supervisor.current_era);
+        let dump = self
+            .era_supervisor
+            .active_eras
+            .get(&era_id)
+            .and_then(|era| {
+                era.consensus
+                    .as_any()
+                    .downcast_ref::<HighwayProtocol<I, ClContext>>()
+            })
+            .map(|highway| Box::new(highway.dump_state(era_id)));
+        responder.respond(dump).ignore()
+    }
+
+    pub(super) fn validator_performance(
+        &self,
+        era_id: Option<EraId>,
+        responder: Responder<Option<Box<EraValidatorPerformance>>>,
+    ) -> Effects<Event<I>> {
+        let era_id = era_id.unwrap_or(self.era_supervisor.current_era);
+        let performance = self
+            .era_supervisor
+            .active_eras
+            .get(&era_id)
+            .and_then(|era| {
+                era.consensus
+                    .as_any()
+                    .downcast_ref::<HighwayProtocol<I, ClContext>>()
+            })
+            .map(|highway| Box::new(highway.validator_performance(era_id)));
+        responder.respond(performance).ignore()
+    }
+
     fn disconnect(&self, sender: I) -> Effects<Event<I>> {
         self.effect_builder
             .announce_disconnect_from_peer(sender)
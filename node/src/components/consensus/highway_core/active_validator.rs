@@ -1,4 +1,5 @@
 use std::{
+    collections::HashMap,
     fmt::{self, Debug},
     fs::{self, File},
     io::{self, Read, Write},
@@ -11,14 +12,14 @@ use tracing::{error, info, trace, warn};
 use super::{
     endorsement::{Endorsement, SignedEndorsement},
     evidence::Evidence,
-    highway::{Ping, ValidVertex, Vertex, WireUnit},
+    highway::{HashedWireUnit, Ping, ValidVertex, Vertex, WireUnit},
     state::{self, Panorama, State, Unit, Weight},
     validators::ValidatorIndex,
 };
 
 use crate::{
     components::consensus::{
-        consensus_protocol::BlockContext,
+        consensus_protocol::{BlockContext, SignatureRequest},
         highway_core::{highway::SignedWireUnit, state::Fault},
         traits::{Context, ValidatorSecret},
     },
@@ -42,6 +43,18 @@ pub(crate) enum Effect<C: Context> {
     ///
     /// When this is returned, the validator automatically deactivates.
     WeAreFaulty(Fault<C>),
+    /// The message needs to be signed, and `on_signature` called with the result.
+    RequestSignature(SignatureRequest<C>),
+}
+
+/// A new message other than a unit, waiting for its signature.
+#[derive(DataSize, Debug)]
+enum PendingMessage<C>
+where
+    C: Context,
+{
+    Endorsement(Endorsement<C>),
+    Ping(Timestamp),
 }
 
 /// A validator that actively participates in consensus by creating new vertices.
@@ -82,6 +95,13 @@ where
     target_ftt: Weight,
     /// If this flag is set we don't create new units and just send pings instead.
     paused: bool,
+    /// Whether to request signatures via `Effect::RequestSignature` instead of signing messages
+    /// ourselves, because signing may block.
+    request_signatures: bool,
+    /// Our new unit, while waiting for its signature.
+    pending_unit: Option<HashedWireUnit<C>>,
+    /// Our new messages other than units, by hash, while waiting for their signatures.
+    pending_messages: HashMap<C::Hash, PendingMessage<C>>,
 }
 
 impl<C: Context> Debug for ActiveValidator<C> {
@@ -118,6 +138,7 @@ impl<C: Context> ActiveValidator<C> {
             })
             .ok()
             .flatten();
+        let request_signatures = secret.may_block();
         let mut av = ActiveValidator {
             vidx,
             secret,
@@ -128,9 +149,12 @@ impl<C: Context> ActiveValidator<C> {
             own_last_unit,
             target_ftt,
             paused: false,
+            request_signatures,
+            pending_unit: None,
+            pending_messages: HashMap::new(),
         };
         let mut effects = av.schedule_timer(start_time, state);
-        effects.push(av.send_ping(current_time, instance_id));
+        effects.extend(av.send_ping(current_time, instance_id));
         (av, effects)
     }
 
@@ -212,7 +236,7 @@ impl<C: Context> ActiveValidator<C> {
             } else if timestamp == r_id + self.witness_offset(r_len) {
                 let panorama = self.panorama_at(state, timestamp);
                 if panorama.has_correct() {
-                    if let Some(witness_unit) =
+                    if let Some(witness_effect) =
                         self.new_unit(panorama, timestamp, None, state, instance_id)
                     {
                         if self
@@ -221,7 +245,7 @@ impl<C: Context> ActiveValidator<C> {
                         {
                             info!(round_id = %r_id, "sending witness in round with no proposal");
                         }
-                        effects.push(Effect::NewVertex(ValidVertex(Vertex::Unit(witness_unit))));
+                        effects.push(witness_effect);
                         return effects;
                     }
                 }
@@ -230,15 +254,25 @@ impl<C: Context> ActiveValidator<C> {
         // We are not creating a new unit. Send a ping if necessary, to show that we're online.
         if !state.has_ping(self.vidx, timestamp) {
             warn!(%timestamp, "too many validators offline, sending ping");
-            effects.push(self.send_ping(timestamp, instance_id));
+            effects.extend(self.send_ping(timestamp, instance_id));
         }
         effects
     }
 
-    /// Creates a Ping vertex.
-    pub(crate) fn send_ping(&self, timestamp: Timestamp, instance_id: C::InstanceId) -> Effect<C> {
-        let ping = Ping::new(self.vidx, timestamp, instance_id, &self.secret);
-        Effect::NewVertex(ValidVertex(Vertex::Ping(ping)))
+    /// Creates a Ping vertex, unless signing fails, or requests its signature.
+    pub(crate) fn send_ping(
+        &mut self,
+        timestamp: Timestamp,
+        instance_id: C::InstanceId,
+    ) -> Option<Effect<C>> {
+        if self.request_signatures {
+            let hash = Ping::<C>::hash(self.vidx, timestamp, instance_id);
+            self.pending_messages
+                .insert(hash, PendingMessage::Ping(timestamp));
+            return Some(Effect::RequestSignature(SignatureRequest::Message { hash }));
+        }
+        let ping = Ping::new(self.vidx, timestamp, instance_id, &self.secret)?;
+        Some(Effect::NewVertex(ValidVertex(Vertex::Ping(ping))))
     }
 
     /// Returns whether enough validators are online to finalize values with the target fault
@@ -272,21 +306,82 @@ impl<C: Context> ActiveValidator<C> {
         if self.should_send_confirmation(uhash, now, state) {
             let panorama = state.confirmation_panorama(self.vidx, uhash);
             if panorama.has_correct() {
-                if let Some(confirmation_unit) =
-                    self.new_unit(panorama, now, None, state, instance_id)
-                {
-                    let vv = ValidVertex(Vertex::Unit(confirmation_unit));
-                    effects.push(Effect::NewVertex(vv));
-                }
+                effects.extend(self.new_unit(panorama, now, None, state, instance_id));
             }
         };
         if self.should_endorse(uhash, state) {
-            let endorsement = self.endorse(uhash);
-            effects.extend(vec![Effect::NewVertex(ValidVertex(endorsement))]);
+            effects.extend(self.endorse(uhash));
         }
         effects
     }
 
+    /// Returns actions a validator needs to take upon receiving the signature requested via
+    /// `Effect::RequestSignature` for the message with the given hash, or `None` if signing
+    /// failed.
+    pub(crate) fn on_signature(
+        &mut self,
+        hash: &C::Hash,
+        maybe_signature: Option<C::Signature>,
+        state: &State<C>,
+        instance_id: C::InstanceId,
+    ) -> Vec<Effect<C>> {
+        match self.pending_unit.take() {
+            Some(hwunit) if hwunit.hash() == *hash => {
+                return self.on_unit_signature(hwunit, maybe_signature, state);
+            }
+            maybe_hwunit => self.pending_unit = maybe_hwunit,
+        }
+        let vertex = match (self.pending_messages.remove(hash), maybe_signature) {
+            (None, _) => {
+                warn!(%hash, "received signature of unknown message");
+                return vec![];
+            }
+            (Some(message), None) => {
+                warn!(?message, "failed to sign message");
+                return vec![];
+            }
+            (Some(PendingMessage::Endorsement(endorsement)), Some(signature)) => {
+                Vertex::Endorsements(SignedEndorsement::new(endorsement, signature).into())
+            }
+            (Some(PendingMessage::Ping(timestamp)), Some(signature)) => Vertex::Ping(
+                Ping::new_with_signature(self.vidx, timestamp, instance_id, signature),
+            ),
+        };
+        vec![Effect::NewVertex(ValidVertex(vertex))]
+    }
+
+    /// Returns the effect for our new unit, once it has been signed.
+    fn on_unit_signature(
+        &mut self,
+        hwunit: HashedWireUnit<C>,
+        maybe_signature: Option<C::Signature>,
+        state: &State<C>,
+    ) -> Vec<Effect<C>> {
+        let signature = match maybe_signature {
+            Some(signature) => signature,
+            None => {
+                warn!(hash = %hwunit.hash(), "failed to sign new unit");
+                return vec![];
+            }
+        };
+        // We don't create other units while waiting, so this can only happen if another node is
+        // running with our signing key.
+        if self.is_faulty(state)
+            || hwunit.wire_unit().panorama[self.vidx] != state.panorama()[self.vidx]
+        {
+            error!(
+                hash = %hwunit.hash(),
+                "our latest unit changed while waiting for signature; canceling unit creation"
+            );
+            return vec![];
+        }
+        let swunit = SignedWireUnit {
+            hashed_wire_unit: hwunit,
+            signature,
+        };
+        vec![self.own_unit_effect(swunit)]
+    }
+
     /// Returns actions validator needs to take upon receiving a new evidence.
     /// Endorses all latest units by honest validators that do not mark new perpetrator as faulty
     /// and cite some new message by that validator.
@@ -302,8 +397,7 @@ impl<C: Context> ActiveValidator<C> {
                 let unit = state.unit(v);
                 unit.new_hash_obs(state, vidx)
             })
-            .map(|v| self.endorse(v))
-            .map(|endorsement| Effect::NewVertex(ValidVertex(endorsement)))
+            .filter_map(|v| self.endorse(v))
             .collect()
     }
 
@@ -327,9 +421,7 @@ impl<C: Context> ActiveValidator<C> {
         let panorama = self.panorama_at(state, timestamp);
         let maybe_parent_hash = state.fork_choice(&panorama);
         if maybe_parent_hash.map_or(false, |hash| state.is_terminal_block(hash)) {
-            return self
-                .new_unit(panorama, timestamp, None, state, instance_id)
-                .map(|proposal_unit| Effect::NewVertex(ValidVertex(Vertex::Unit(proposal_unit))));
+            return self.new_unit(panorama, timestamp, None, state, instance_id);
         }
         let maybe_parent = maybe_parent_hash.map(|bh| state.block(bh));
         let height = maybe_parent.map_or(0, |block| block.height);
@@ -372,7 +464,6 @@ impl<C: Context> ActiveValidator<C> {
             return vec![];
         }
         self.new_unit(panorama, timestamp, Some(value), state, instance_id)
-            .map(|proposal_unit| Effect::NewVertex(ValidVertex(Vertex::Unit(proposal_unit))))
             .into_iter()
             .collect()
     }
@@ -421,9 +512,11 @@ impl<C: Context> ActiveValidator<C> {
         true
     }
 
-    /// Returns a new unit with the given data, and the correct sequence number.
+    /// Returns the effect for a new unit with the given data, and the correct sequence number, or
+    /// the request to sign it.
     ///
-    /// Returns `None` if it's not possible to create a valid unit with the given panorama.
+    /// Returns `None` if it's not possible to create a valid unit with the given panorama, or if
+    /// signing it fails.
     fn new_unit(
         &mut self,
         panorama: Panorama<C>,
@@ -431,11 +524,15 @@ impl<C: Context> ActiveValidator<C> {
         value: Option<C::ConsensusValue>,
         state: &State<C>,
         instance_id: C::InstanceId,
-    ) -> Option<SignedWireUnit<C>> {
+    ) -> Option<Effect<C>> {
         if !self.can_vote(state) {
             info!(?self.own_last_unit, "not voting - last own unit unknown");
             return None;
         }
+        if let Some(hwunit) = &self.pending_unit {
+            info!(hash = %hwunit.hash(), "not voting - waiting for signature of last own unit");
+            return None;
+        }
         if let Some((prop_time, _)) = self.next_proposal.take() {
             warn!(
                 ?timestamp,
@@ -471,14 +568,27 @@ impl<C: Context> ActiveValidator<C> {
             endorsed,
         }
         .into_hashed();
-        let swunit = SignedWireUnit::new(hwunit, &self.secret);
+        if self.request_signatures {
+            let hash = hwunit.hash();
+            self.pending_unit = Some(hwunit);
+            return Some(Effect::RequestSignature(SignatureRequest::Unit {
+                hash,
+                seq_number,
+            }));
+        }
+        let swunit = SignedWireUnit::try_new(hwunit, &self.secret)?;
+        Some(self.own_unit_effect(swunit))
+    }
+
+    /// Stores our new signed unit in the unit file and returns the effect to add and gossip it.
+    fn own_unit_effect(&self, swunit: SignedWireUnit<C>) -> Effect<C> {
         write_last_unit(&self.unit_file, swunit.clone()).unwrap_or_else(|err| {
             panic!(
                 "should successfully write unit's hash to {:?}, got {:?}",
                 self.unit_file, err
             )
         });
-        Some(swunit)
+        Effect::NewVertex(ValidVertex(Vertex::Unit(swunit)))
     }
 
     /// Returns a `ScheduleTimer` effect for the next time we need to be called.
@@ -566,11 +676,18 @@ impl<C: Context> ActiveValidator<C> {
                 .any(|(vidx, _)| state.is_faulty(vidx) && unit.new_hash_obs(state, vidx))
     }
 
-    /// Creates endorsement of the `vhash`.
-    fn endorse(&self, vhash: &C::Hash) -> Vertex<C> {
+    /// Creates endorsement of the `vhash`, unless signing fails, or requests its signature.
+    fn endorse(&mut self, vhash: &C::Hash) -> Option<Effect<C>> {
         let endorsement = Endorsement::new(*vhash, self.vidx);
-        let signature = self.secret.sign(&endorsement.hash());
-        Vertex::Endorsements(SignedEndorsement::new(endorsement, signature).into())
+        let hash = endorsement.hash();
+        if self.request_signatures {
+            self.pending_messages
+                .insert(hash, PendingMessage::Endorsement(endorsement));
+            return Some(Effect::RequestSignature(SignatureRequest::Message { hash }));
+        }
+        let signature = self.secret.sign(&hash)?;
+        let vertex = Vertex::Endorsements(SignedEndorsement::new(endorsement, signature).into());
+        Some(Effect::NewVertex(ValidVertex(vertex)))
     }
 
     /// Returns a panorama that is valid to use in our own unit at the given timestamp.
@@ -979,6 +1096,61 @@ mod tests {
         }
     }
 
+    #[test]
+    #[allow(clippy::unreadable_literal)] // 0xC0FFEE is more readable than 0x00C0_FFEE.
+    fn requests_signatures_if_signing_may_block() {
+        let state = State::new_test(&[Weight(3)], 0);
+        let instance_id = TEST_INSTANCE_ID;
+        let (mut alice, _) = ActiveValidator::new(
+            ALICE,
+            TestSecret(ALICE.0),
+            410.into(),
+            410.into(),
+            &state,
+            None,
+            Weight(2),
+            instance_id,
+        );
+        alice.request_signatures = true;
+
+        let propose_at = |alice: &mut ActiveValidator<TestContext>, timestamp: u64| {
+            let bctx = match &*alice.handle_timer(timestamp.into(), &state, instance_id) {
+                [Eff::ScheduleTimer(_), Eff::RequestNewBlock {
+                    block_context: bctx,
+                    ..
+                }] => *bctx,
+                effects => panic!("unexpected effects {:?}", effects),
+            };
+            alice.propose(0xC0FFEE, bctx, &state, instance_id)
+        };
+
+        // Instead of signing her proposal, Alice requests its signature.
+        let (hash, seq_number) = match &*propose_at(&mut alice, 416) {
+            [Eff::RequestSignature(SignatureRequest::Unit { hash, seq_number })] => {
+                (*hash, *seq_number)
+            }
+            effects => panic!("unexpected effects {:?}", effects),
+        };
+        assert_eq!(0, seq_number);
+
+        // While waiting for the signature, she doesn't create any other unit.
+        let effects = propose_at(&mut alice, 432);
+        assert!(effects.is_empty(), "unexpected effects {:?}", effects);
+
+        // Once it is signed, the proposal is created.
+        let signature = TestSecret(ALICE.0).sign(&hash);
+        let unit =
+            unwrap_single(&alice.on_signature(&hash, signature, &state, instance_id)).unwrap_unit();
+        assert_eq!(hash, unit.hash());
+        assert_eq!(Some(0xC0FFEE), unit.wire_unit().value);
+        assert!(alice.pending_unit.is_none());
+
+        // A signature that wasn't requested is ignored.
+        assert!(alice
+            .on_signature(&hash, signature, &state, instance_id)
+            .is_empty());
+    }
+
     #[test]
     fn waits_until_synchronized() -> Result<(), AddUnitError<TestContext>> {
         let instance_id = TEST_INSTANCE_ID;
@@ -6,17 +6,20 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use super::validators::ValidatorIndex;
-use crate::components::consensus::{
-    highway_core::{
-        endorsement::SignedEndorsement, highway::SignedWireUnit, state::Params,
-        validators::Validators,
+use crate::{
+    components::consensus::{
+        highway_core::{
+            endorsement::SignedEndorsement, highway::SignedWireUnit, state::Params,
+            validators::Validators,
+        },
+        traits::Context,
     },
-    traits::Context,
+    crypto::signer::SignatureFormat,
 };
 
 /// An error due to invalid evidence.
 #[derive(Debug, Error, PartialEq)]
-pub(crate) enum EvidenceError {
+pub enum EvidenceError {
     #[error("The sequence numbers in the equivocating units are different.")]
     EquivocationDifferentSeqNumbers,
     #[error("The creators in the equivocating units are different.")]
@@ -77,6 +80,15 @@ impl<C: Context> Evidence<C> {
         }
     }
 
+    /// Returns the ID of the validator who created the conflicting units, if this is evidence of
+    /// endorsing them.  This may be the perpetrator itself.
+    pub(crate) fn endorsed_creator(&self) -> Option<ValidatorIndex> {
+        match self {
+            Evidence::Equivocation(..) => None,
+            Evidence::Endorsements { unit1, .. } => Some(unit1.wire_unit().creator),
+        }
+    }
+
     /// Validates the evidence and returns `Ok(())` if it is valid.
     /// "Validation" can mean different things for different type of evidence.
     ///
@@ -88,10 +100,69 @@ impl<C: Context> Evidence<C> {
         instance_id: &C::InstanceId,
         params: &Params,
     ) -> Result<(), EvidenceError> {
-        match self {
-            Evidence::Equivocation(unit1, unit2) => {
-                Self::validate_equivocation(unit1, unit2, instance_id, validators)
+        if let Evidence::Endorsements { swimlane2, .. } = self {
+            if swimlane2.len() as u64 > params.endorsement_evidence_limit() {
+                return Err(EvidenceError::EndorsementTooManyUnits);
             }
+        }
+        self.validate_with(
+            |idx| validators.id(idx),
+            instance_id,
+            params.signature_format(),
+        )
+    }
+
+    /// Validates the evidence given only the IDs of the validators involved, rather than the era's
+    /// full validator set.
+    ///
+    /// Endorsement evidence also contains the conflicting units of the validator returned by
+    /// `endorsed_creator`, whose ID must be given as `maybe_endorsed_creator_id` unless it is the
+    /// perpetrator.  Otherwise the evidence is rejected with `UnknownPerpetrator`.
+    ///
+    /// The signatures are verified in the given format, i.e. the one of the evidence's era.
+    pub(crate) fn validate_for_perpetrator(
+        &self,
+        perpetrator_id: &C::ValidatorId,
+        maybe_endorsed_creator_id: Option<&C::ValidatorId>,
+        instance_id: &C::InstanceId,
+        signature_format: SignatureFormat,
+    ) -> Result<(), EvidenceError> {
+        let perpetrator = self.perpetrator();
+        let maybe_endorsed_creator = self.endorsed_creator();
+        self.validate_with(
+            |idx| {
+                if idx == perpetrator {
+                    Some(perpetrator_id)
+                } else if Some(idx) == maybe_endorsed_creator {
+                    maybe_endorsed_creator_id
+                } else {
+                    None
+                }
+            },
+            instance_id,
+            signature_format,
+        )
+    }
+
+    /// Validates the evidence, using `validator_id` to look up the IDs of the validators involved.
+    fn validate_with<'a, F>(
+        &self,
+        validator_id: F,
+        instance_id: &C::InstanceId,
+        signature_format: SignatureFormat,
+    ) -> Result<(), EvidenceError>
+    where
+        F: Fn(ValidatorIndex) -> Option<&'a C::ValidatorId>,
+        C::ValidatorId: 'a,
+    {
+        match self {
+            Evidence::Equivocation(unit1, unit2) => Self::validate_equivocation(
+                unit1,
+                unit2,
+                instance_id,
+                &validator_id,
+                signature_format,
+            ),
             Evidence::Endorsements {
                 endorsement1,
                 unit1,
@@ -99,11 +170,7 @@ impl<C: Context> Evidence<C> {
                 unit2,
                 swimlane2,
             } => {
-                if swimlane2.len() as u64 > params.endorsement_evidence_limit() {
-                    return Err(EvidenceError::EndorsementTooManyUnits);
-                }
-                let v_id = validators
-                    .id(endorsement1.validator_idx())
+                let v_id = validator_id(endorsement1.validator_idx())
                     .ok_or(EvidenceError::UnknownPerpetrator)?;
                 if *endorsement1.unit() != unit1.hash() || *endorsement2.unit() != unit2.hash() {
                     return Err(EvidenceError::EndorsementWrongHash);
@@ -120,11 +187,18 @@ impl<C: Context> Evidence<C> {
                     unit1,
                     swimlane2.last().unwrap_or(unit2),
                     instance_id,
-                    validators,
+                    &validator_id,
+                    signature_format,
                 )?;
-                if !C::verify_signature(&endorsement1.hash(), v_id, &endorsement1.signature())
-                    || !C::verify_signature(&endorsement2.hash(), v_id, &endorsement2.signature())
-                {
+                let verify = |endorsement: &SignedEndorsement<C>| {
+                    C::verify_signature(
+                        &endorsement.hash(),
+                        v_id,
+                        endorsement.signature(),
+                        signature_format,
+                    )
+                };
+                if !verify(endorsement1) || !verify(endorsement2) {
                     return Err(EvidenceError::Signature);
                 }
                 Ok(())
@@ -132,17 +206,20 @@ impl<C: Context> Evidence<C> {
         }
     }
 
-    fn validate_equivocation(
+    fn validate_equivocation<'a, F>(
         unit1: &SignedWireUnit<C>,
         unit2: &SignedWireUnit<C>,
         instance_id: &C::InstanceId,
-        validators: &Validators<C::ValidatorId>,
-    ) -> Result<(), EvidenceError> {
+        validator_id: &F,
+        signature_format: SignatureFormat,
+    ) -> Result<(), EvidenceError>
+    where
+        F: Fn(ValidatorIndex) -> Option<&'a C::ValidatorId>,
+        C::ValidatorId: 'a,
+    {
         let wunit1 = unit1.wire_unit();
         let wunit2 = unit2.wire_unit();
-        let v_id = validators
-            .id(wunit1.creator)
-            .ok_or(EvidenceError::UnknownPerpetrator)?;
+        let v_id = validator_id(wunit1.creator).ok_or(EvidenceError::UnknownPerpetrator)?;
         if wunit1.creator != wunit2.creator {
             return Err(EvidenceError::EquivocationDifferentCreators);
         }
@@ -155,8 +232,8 @@ impl<C: Context> Evidence<C> {
         if unit1 == unit2 {
             return Err(EvidenceError::EquivocationSameUnit);
         }
-        if !C::verify_signature(&unit1.hash(), v_id, &unit1.signature)
-            || !C::verify_signature(&unit2.hash(), v_id, &unit2.signature)
+        if !unit1.verify_signature(v_id, signature_format)
+            || !unit2.verify_signature(v_id, signature_format)
         {
             return Err(EvidenceError::Signature);
         }
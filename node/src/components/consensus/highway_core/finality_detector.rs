@@ -1,5 +1,5 @@
 mod horizon;
-mod rewards;
+pub(crate) mod rewards;
 
 use std::iter;
 
@@ -85,6 +85,20 @@ impl<C: Context> FinalityDetector<C> {
     pub(super) fn next_finalized<'a>(&mut self, state: &'a State<C>) -> Option<&'a C::Hash> {
         let start_time = Timestamp::now();
         let candidate = self.next_candidate(state)?;
+        let lvl = self.summit_level(candidate, state);
+        let elapsed = start_time.elapsed();
+        if lvl == 0 {
+            trace!(%elapsed, "found no finalized block");
+            return None;
+        }
+        self.last_finalized = Some(*candidate);
+        trace!(%elapsed, "found finalized block");
+        Some(candidate)
+    }
+
+    /// Returns the level of the highest summit for `candidate`, or `0` if there is none. The
+    /// `candidate` is finalized if the level is positive.
+    pub(crate) fn summit_level(&self, candidate: &C::Hash, state: &State<C>) -> usize {
         // For `lvl` → ∞, the quorum converges to a fixed value. After level 63, it is closer
         // to that limit than 1/2^-63. This won't make a difference in practice, so there is no
         // point looking for higher summits.
@@ -93,18 +107,13 @@ impl<C: Context> FinalityDetector<C> {
             trace!(%target_lvl, "looking for summit");
             let lvl = self.find_summit(target_lvl, candidate, state);
             if lvl == target_lvl {
-                self.last_finalized = Some(*candidate);
-                let elapsed = start_time.elapsed();
-                trace!(%elapsed, "found finalized block");
-                return Some(candidate);
+                return lvl;
             }
             // The required quorum increases with decreasing level, so choosing `target_lvl`
             // greater than `lvl` would always yield a summit of level `lvl` or lower.
             target_lvl = lvl;
         }
-        let elapsed = start_time.elapsed();
-        trace!(%elapsed, "found no finalized block");
-        None
+        0
     }
 
     /// Returns the number of levels of the highest summit with a quorum that a `target_lvl` summit
@@ -36,6 +36,43 @@ pub(crate) fn compute_rewards<C: Context>(state: &State<C>, bhash: &C::Hash) ->
     rewards
 }
 
+/// The number of rounds with a proposal in which a validator was assigned, by participation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct RoundStats {
+    /// The number of rounds in which the validator created at least one unit.
+    pub(crate) participated: u64,
+    /// The number of rounds in which the validator didn't create any units.
+    pub(crate) missed: u64,
+}
+
+/// Returns each validator's participation in the rounds in which the ancestors of `bhash` were
+/// proposed, as seen from `bhash`.
+///
+/// These are exactly the rounds that `compute_rewards` pays out for: a validator who missed them
+/// doesn't receive their share of the block reward.
+pub(crate) fn round_stats<C: Context>(
+    state: &State<C>,
+    bhash: &C::Hash,
+) -> ValidatorMap<RoundStats> {
+    let panorama = &state.unit(bhash).panorama;
+    let mut stats = ValidatorMap::from(vec![RoundStats::default(); panorama.len()]);
+    for proposal_hash in state.ancestor_hashes(bhash) {
+        let r_id = state.unit(proposal_hash).round_id();
+        for (vidx, obs) in panorama.enumerate() {
+            match round_participation(state, obs, r_id) {
+                RoundParticipation::Unassigned => (),
+                RoundParticipation::No => {
+                    stats[vidx].missed = stats[vidx].missed.saturating_add(1);
+                }
+                RoundParticipation::Yes(_) => {
+                    stats[vidx].participated = stats[vidx].participated.saturating_add(1);
+                }
+            }
+        }
+    }
+    stats
+}
+
 /// Returns the rewards for finalizing the block with hash `proposal_h`.
 fn compute_rewards_for<C: Context>(
     state: &State<C>,
@@ -143,10 +180,13 @@ fn round_participation<'a, C: Context>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::components::consensus::highway_core::{
-        highway_testing::{TEST_BLOCK_REWARD, TEST_ENDORSEMENT_EVIDENCE_LIMIT},
-        state::{tests::*, Params},
-        validators::ValidatorMap,
+    use crate::{
+        components::consensus::highway_core::{
+            highway_testing::{TEST_BLOCK_REWARD, TEST_ENDORSEMENT_EVIDENCE_LIMIT},
+            state::{tests::*, Params},
+            validators::ValidatorMap,
+        },
+        crypto::signer::SignatureFormat,
     };
 
     #[test]
@@ -179,6 +219,113 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn round_stats_test() -> Result<(), AddUnitError<TestContext>> {
+        let params = Params::new(
+            0,
+            TEST_BLOCK_REWARD,
+            TEST_BLOCK_REWARD / 5,
+            3,
+            19,
+            3,
+            u64::MAX,
+            Timestamp::zero(),
+            Timestamp::from(u64::MAX),
+            TEST_ENDORSEMENT_EVIDENCE_LIMIT,
+            SignatureFormat::DomainSeparated,
+        );
+        let weights = &[Weight(4), Weight(5), Weight(1)];
+        let mut state = State::new(weights, params, vec![]);
+
+        // Round 0: Everyone participates.
+        assert_eq!(BOB, state.leader(0.into()));
+        let bp0 = add_unit!(state, BOB, 0, 3u8, 0xB00; N, N, N)?;
+        let ac0 = add_unit!(state, ALICE, 1, 4u8, None; N, bp0, N)?;
+        let cc0 = add_unit!(state, CAROL, 1, 3u8, None; N, bp0, N)?;
+        let bw0 = add_unit!(state, BOB, 5, 3u8, None; ac0, bp0, cc0)?;
+        let cw0 = add_unit!(state, CAROL, 5, 3u8, None; N, bp0, cc0)?;
+        let aw0 = add_unit!(state, ALICE, 10, 4u8, None; ac0, bp0, N)?;
+
+        // Round 8: Alice is not assigned (length 16). Bob and Carol participate.
+        assert_eq!(BOB, state.leader(8.into()));
+        let bp8 = add_unit!(state, BOB, 8, 3u8, 0xB08; ac0, bw0, cw0)?;
+        let cw8 = add_unit!(state, CAROL, 13, 3u8, None; aw0, bp8, cw0)?;
+
+        // Round 16: Carol misses it.
+        assert_eq!(ALICE, state.leader(16.into()));
+        let ap16 = add_unit!(state, ALICE, 16, 4u8, 0xA16; aw0, bp8, cw8)?;
+        let bw16 = add_unit!(state, BOB, 17, 3u8, None; ap16, bp8, cw8)?;
+
+        // Alice's next block has all three rounds' proposals as ancestors.
+        let ap_last = add_unit!(state, ALICE, 0x0; ap16, bw16, cw8)?;
+
+        let rs = |participated, missed| RoundStats {
+            participated,
+            missed,
+        };
+        // The round of the block itself doesn't count yet.
+        assert_eq!(
+            ValidatorMap::from(vec![rs(1, 0), rs(2, 0), rs(2, 0)]),
+            round_stats(&state, &ap16)
+        );
+        assert_eq!(
+            ValidatorMap::from(vec![rs(2, 0), rs(3, 0), rs(2, 1)]),
+            round_stats(&state, &ap_last)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn round_stats_match_rewarded_rounds() -> Result<(), AddUnitError<TestContext>> {
+        let params = Params::new(
+            0,
+            TEST_BLOCK_REWARD,
+            TEST_BLOCK_REWARD / 5,
+            3,
+            19,
+            3,
+            u64::MAX,
+            Timestamp::zero(),
+            Timestamp::from(u64::MAX),
+            TEST_ENDORSEMENT_EVIDENCE_LIMIT,
+            SignatureFormat::DomainSeparated,
+        );
+        let weights = &[Weight(4), Weight(5), Weight(1)];
+        let mut state = State::new(weights, params, vec![]);
+
+        // Round 0: Alice and Bob make a summit, Carol is absent.
+        assert_eq!(BOB, state.leader(0.into()));
+        let bp0 = add_unit!(state, BOB, 0, 3u8, 0xB00; N, N, N)?;
+        let ac0 = add_unit!(state, ALICE, 1, 3u8, None; N, bp0, N)?;
+        let bw0 = add_unit!(state, BOB, 5, 3u8, None; ac0, bp0, N)?;
+        let aw0 = add_unit!(state, ALICE, 5, 3u8, None; ac0, bw0, N)?;
+
+        // Round 8: Bob and Carol make a summit, Alice is absent.
+        assert_eq!(BOB, state.leader(8.into()));
+        let bp8 = add_unit!(state, BOB, 8, 3u8, 0xB08; aw0, bw0, N)?;
+        let cc8 = add_unit!(state, CAROL, 9, 3u8, None; aw0, bp8, N)?;
+        let bw8 = add_unit!(state, BOB, 13, 3u8, None; aw0, bp8, cc8)?;
+        let cw8 = add_unit!(state, CAROL, 13, 3u8, None; aw0, bp8, cc8)?;
+
+        assert_eq!(ALICE, state.leader(16.into()));
+        let ap16 = add_unit!(state, ALICE, 16, 3u8, 0xA16; aw0, bw8, cw8)?;
+
+        // Bob's round-8 block only pays out for round 0, which Carol missed, even though Carol
+        // participated in round 8.
+        for bhash in &[bp0, bp8, ap16] {
+            let stats = round_stats(&state, bhash);
+            let rewards = compute_rewards(&state, bhash);
+            let rounds = state.ancestor_hashes(bhash).count() as u64;
+            for (vidx, stats) in stats.enumerate() {
+                // Everyone is assigned to every round that is paid out for.
+                assert_eq!(rounds, stats.participated + stats.missed);
+                // Here, every participant in a round is part of its summit.
+                assert_eq!(stats.participated > 0, rewards[vidx] > 0);
+            }
+        }
+        Ok(())
+    }
+
     // To keep the form of the reward formula, we spell out Carol's weight 1.
     #[allow(clippy::identity_op)]
     #[test]
@@ -201,6 +348,7 @@ mod tests {
             Timestamp::zero(),
             Timestamp::from(u64::MAX),
             TEST_ENDORSEMENT_EVIDENCE_LIMIT,
+            SignatureFormat::DomainSeparated,
         );
         let weights = &[Weight(ALICE_W), Weight(BOB_W), Weight(CAROL_W)];
         let mut state = State::new(weights, params, vec![]);
@@ -433,6 +433,25 @@ impl<C: Context> Highway<C> {
         })
     }
 
+    /// Handles the signature requested via `Effect::RequestSignature` for the message with the
+    /// given hash, or `None` if signing failed.
+    pub(crate) fn on_signature(
+        &mut self,
+        hash: &C::Hash,
+        maybe_signature: Option<C::Signature>,
+        now: Timestamp,
+    ) -> Vec<Effect<C>> {
+        let instance_id = self.instance_id;
+        self.map_active_validator(
+            |av, state| av.on_signature(hash, maybe_signature, state, instance_id),
+            now,
+        )
+        .unwrap_or_else(|| {
+            debug!(%hash, "ignoring signature: validator has been deactivated");
+            vec![]
+        })
+    }
+
     pub(crate) fn validators(&self) -> &Validators<C::ValidatorId> {
         &self.validators
     }
@@ -511,7 +530,9 @@ impl<C: Context> Highway<C> {
                     result.extend(self.add_valid_vertex(vv.clone(), timestamp))
                 }
                 Effect::WeAreFaulty(_) => self.deactivate_validator(),
-                Effect::ScheduleTimer(_) | Effect::RequestNewBlock { .. } => (),
+                Effect::ScheduleTimer(_)
+                | Effect::RequestNewBlock { .. }
+                | Effect::RequestSignature(_) => (),
             }
         }
         result.extend(effects);
@@ -528,7 +549,7 @@ impl<C: Context> Highway<C> {
                 if unit.wire_unit().instance_id != self.instance_id {
                     return Err(UnitError::InstanceId.into());
                 }
-                if !C::verify_signature(&unit.hash(), v_id, &unit.signature) {
+                if !unit.verify_signature(v_id, self.state.params().signature_format()) {
                     return Err(UnitError::Signature.into());
                 }
                 Ok(self.state.pre_validate_unit(unit)?)
@@ -541,6 +562,7 @@ impl<C: Context> Highway<C> {
                 if endorsements.endorsers.is_empty() {
                     return Err(EndorsementError::Empty.into());
                 }
+                let signature_format = self.state.params().signature_format();
                 for (creator, signature) in endorsements.endorsers.iter() {
                     let v_id = self
                         .validators
@@ -550,13 +572,18 @@ impl<C: Context> Highway<C> {
                         return Err(EndorsementError::Banned.into());
                     }
                     let endorsement: Endorsement<C> = Endorsement::new(unit, *creator);
-                    if !C::verify_signature(&endorsement.hash(), v_id, &signature) {
+                    if !C::verify_signature(&endorsement.hash(), v_id, &signature, signature_format)
+                    {
                         return Err(EndorsementError::Signature.into());
                     }
                 }
                 Ok(())
             }
-            Vertex::Ping(ping) => ping.validate(&self.validators, &self.instance_id),
+            Vertex::Ping(ping) => ping.validate(
+                &self.validators,
+                &self.instance_id,
+                self.state.params().signature_format(),
+            ),
         }
     }
 
@@ -734,7 +761,7 @@ pub(crate) mod tests {
         assert_eq!(Err(expected), highway.pre_validate_vertex(invalid_vertex));
 
         let hwunit = wunit.into_hashed();
-        let valid_signature = CAROL_SEC.sign(&hwunit.hash());
+        let valid_signature = CAROL_SEC.sign(&hwunit.hash()).unwrap();
         let correct_signature_unit = SignedWireUnit {
             hashed_wire_unit: hwunit,
             signature: valid_signature,
@@ -924,7 +951,7 @@ pub(crate) mod tests {
         // Ping by validator that is not bonded, with an index that is outside of boundaries of the
         // state.
         let ping: Vertex<TestContext> =
-            Vertex::Ping(Ping::new(DAN, now, TEST_INSTANCE_ID, &DAN_SEC));
+            Vertex::Ping(Ping::new(DAN, now, TEST_INSTANCE_ID, &DAN_SEC).unwrap());
         assert!(
             DAN.0 >= WEIGHTS.len() as u32,
             "should use validator that is not bonded"
@@ -14,6 +14,7 @@ use crate::{
         },
         traits::{Context, ValidatorSecret},
     },
+    crypto::signer::SignatureFormat,
     types::Timestamp,
 };
 
@@ -142,15 +143,42 @@ where
 }
 
 impl<C: Context> SignedWireUnit<C> {
+    #[cfg(test)]
     pub(crate) fn new(
         hashed_wire_unit: HashedWireUnit<C>,
         secret_key: &C::ValidatorSecret,
     ) -> Self {
-        let signature = secret_key.sign(&hashed_wire_unit.hash);
-        SignedWireUnit {
+        Self::try_new(hashed_wire_unit, secret_key).expect("should sign unit")
+    }
+
+    /// Signs the unit, or returns `None` if the signer fails or refuses to sign it.
+    pub(crate) fn try_new(
+        hashed_wire_unit: HashedWireUnit<C>,
+        secret_key: &C::ValidatorSecret,
+    ) -> Option<Self> {
+        let seq_number = hashed_wire_unit.wire_unit().seq_number;
+        let signature = secret_key.sign_unit(&hashed_wire_unit.hash, seq_number)?;
+        Some(SignedWireUnit {
             hashed_wire_unit,
             signature,
-        }
+        })
+    }
+
+    /// Returns whether the unit is correctly signed by the validator with the given ID.
+    pub(crate) fn verify_signature(
+        &self,
+        validator_id: &C::ValidatorId,
+        signature_format: SignatureFormat,
+    ) -> bool {
+        let wunit = self.wire_unit();
+        C::verify_unit_signature(
+            &self.hash(),
+            &wunit.instance_id,
+            wunit.seq_number,
+            validator_id,
+            &self.signature,
+            signature_format,
+        )
     }
 
     pub(crate) fn wire_unit(&self) -> &WireUnit<C> {
@@ -331,14 +359,29 @@ where
 }
 
 impl<C: Context> Ping<C> {
-    /// Creates a new signed ping.
+    /// Creates a new signed ping, or returns `None` if signing fails.
     pub(crate) fn new(
         creator: ValidatorIndex,
         timestamp: Timestamp,
         instance_id: C::InstanceId,
         sk: &C::ValidatorSecret,
+    ) -> Option<Self> {
+        let signature = sk.sign(&Self::hash(creator, timestamp, instance_id))?;
+        Some(Self::new_with_signature(
+            creator,
+            timestamp,
+            instance_id,
+            signature,
+        ))
+    }
+
+    /// Creates a new ping with the given signature of its `hash`.
+    pub(crate) fn new_with_signature(
+        creator: ValidatorIndex,
+        timestamp: Timestamp,
+        instance_id: C::InstanceId,
+        signature: C::Signature,
     ) -> Self {
-        let signature = sk.sign(&Self::hash(creator, timestamp, instance_id));
         Ping {
             creator,
             timestamp,
@@ -362,6 +405,7 @@ impl<C: Context> Ping<C> {
         &self,
         validators: &Validators<C::ValidatorId>,
         our_instance_id: &C::InstanceId,
+        signature_format: SignatureFormat,
     ) -> Result<(), VertexError> {
         let Ping {
             creator,
@@ -374,14 +418,18 @@ impl<C: Context> Ping<C> {
         }
         let v_id = validators.id(self.creator).ok_or(PingError::Creator)?;
         let hash = Self::hash(*creator, *timestamp, *instance_id);
-        if !C::verify_signature(&hash, v_id, signature) {
+        if !C::verify_signature(&hash, v_id, signature, signature_format) {
             return Err(PingError::Signature.into());
         }
         Ok(())
     }
 
     /// Computes the hash of a ping, i.e. of the creator and timestamp.
-    fn hash(creator: ValidatorIndex, timestamp: Timestamp, instance_id: C::InstanceId) -> C::Hash {
+    pub(crate) fn hash(
+        creator: ValidatorIndex,
+        timestamp: Timestamp,
+        instance_id: C::InstanceId,
+    ) -> C::Hash {
         let bytes = bincode::serialize(&(creator, timestamp, instance_id)).expect("serialize Ping");
         <C as Context>::hash(&bytes)
     }
@@ -38,6 +38,7 @@ use crate::{
         traits::{ConsensusValueT, Context, ValidatorSecret},
         BlockContext,
     },
+    crypto::signer::SignatureFormat,
     types::Timestamp,
     NodeRng,
 };
@@ -130,6 +131,7 @@ impl From<Effect<TestContext>> for HighwayMessage {
                 HighwayMessage::RequestBlock(block_context)
             }
             Effect::WeAreFaulty(fault) => HighwayMessage::WeAreFaulty(Box::new(fault)),
+            Effect::RequestSignature(_) => unreachable!("test validators sign synchronously"),
         }
     }
 }
@@ -722,6 +724,7 @@ fn test_params() -> Params {
         Timestamp::zero(),
         Timestamp::zero(), // Length depends only on block number.
         TEST_ENDORSEMENT_EVIDENCE_LIMIT,
+        SignatureFormat::DomainSeparated,
     )
 }
 
@@ -1033,8 +1036,8 @@ impl ValidatorSecret for TestSecret {
     type Hash = HashWrapper;
     type Signature = SignatureWrapper;
 
-    fn sign(&self, data: &Self::Hash) -> Self::Signature {
-        SignatureWrapper(data.0 + self.0)
+    fn sign(&self, data: &Self::Hash) -> Option<Self::Signature> {
+        Some(SignatureWrapper(data.0 + self.0))
     }
 }
 
@@ -1056,6 +1059,7 @@ impl Context for TestContext {
         hash: &Self::Hash,
         public_key: &Self::ValidatorId,
         signature: &<Self::ValidatorSecret as ValidatorSecret>::Signature,
+        _format: SignatureFormat,
     ) -> bool {
         let computed_signature = hash.0 + public_key.0;
         computed_signature == signature.0
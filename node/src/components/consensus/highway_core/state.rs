@@ -286,6 +286,18 @@ impl<C: Context> State<C> {
         })
     }
 
+    /// Returns the indices of all validators whose endorsements of `unit` we have, whether or not
+    /// they are enough for the unit to count as endorsed.
+    pub(crate) fn endorsers(&self, unit: &C::Hash) -> Vec<ValidatorIndex> {
+        if let Some(signatures) = self.endorsements.get(unit) {
+            signatures.iter_some().map(|(idx, _)| idx).collect()
+        } else if let Some(signatures) = self.incomplete_endorsements.get(unit) {
+            signatures.keys().cloned().collect()
+        } else {
+            Vec::new()
+        }
+    }
+
     /// Returns whether evidence against validator nr. `idx` is known.
     pub(crate) fn has_evidence(&self, idx: ValidatorIndex) -> bool {
         self.maybe_evidence(idx).is_some()
@@ -358,6 +370,11 @@ impl<C: Context> State<C> {
         self.units.get(hash)
     }
 
+    /// Returns an iterator over all units, by hash, in no particular order.
+    pub(crate) fn iter_units(&self) -> impl Iterator<Item = (&C::Hash, &Unit<C>)> {
+        self.units.iter()
+    }
+
     /// Returns whether the unit with the given hash is known.
     pub(crate) fn has_unit(&self, hash: &C::Hash) -> bool {
         self.units.contains_key(hash)
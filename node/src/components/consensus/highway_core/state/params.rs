@@ -1,6 +1,7 @@
 use datasize::DataSize;
 
 use super::{round_len, TimeDiff, Timestamp};
+use crate::crypto::signer::SignatureFormat;
 
 /// Protocol parameters for Highway.
 #[derive(Debug, DataSize, Clone)]
@@ -15,6 +16,7 @@ pub(crate) struct Params {
     start_timestamp: Timestamp,
     end_timestamp: Timestamp,
     endorsement_evidence_limit: u64,
+    signature_format: SignatureFormat,
 }
 
 impl Params {
@@ -36,6 +38,7 @@ impl Params {
     /// * `end_height`, `end_timestamp`: The last block will be the first one that has at least the
     ///   specified height _and_ is no earlier than the specified timestamp. No children of this
     ///   block can be proposed.
+    /// * `signature_format`: The format of the bytes signed for units, endorsements and pings.
     #[allow(clippy::too_many_arguments)] // FIXME
     pub(crate) fn new(
         seed: u64,
@@ -48,6 +51,7 @@ impl Params {
         start_timestamp: Timestamp,
         end_timestamp: Timestamp,
         endorsement_evidence_limit: u64,
+        signature_format: SignatureFormat,
     ) -> Params {
         assert!(
             reduced_block_reward <= block_reward,
@@ -64,6 +68,7 @@ impl Params {
             start_timestamp,
             end_timestamp,
             endorsement_evidence_limit,
+            signature_format,
         }
     }
 
@@ -126,6 +131,11 @@ impl Params {
     pub(crate) fn endorsement_evidence_limit(&self) -> u64 {
         self.endorsement_evidence_limit
     }
+
+    /// Returns the format of the bytes signed for units, endorsements and pings.
+    pub(crate) fn signature_format(&self) -> SignatureFormat {
+        self.signature_format
+    }
 }
 
 #[cfg(test)]
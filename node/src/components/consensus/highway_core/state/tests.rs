@@ -10,13 +10,18 @@ use datasize::DataSize;
 use rand::{Rng, RngCore};
 
 use super::*;
-use crate::components::consensus::{
-    highway_core::{
-        evidence::EvidenceError,
-        highway::Dependency,
-        highway_testing::{TEST_BLOCK_REWARD, TEST_ENDORSEMENT_EVIDENCE_LIMIT, TEST_INSTANCE_ID},
+use crate::{
+    components::consensus::{
+        highway_core::{
+            evidence::EvidenceError,
+            highway::Dependency,
+            highway_testing::{
+                TEST_BLOCK_REWARD, TEST_ENDORSEMENT_EVIDENCE_LIMIT, TEST_INSTANCE_ID,
+            },
+        },
+        traits::{ConsensusValueT, ValidatorSecret},
     },
-    traits::{ConsensusValueT, ValidatorSecret},
+    crypto::signer::SignatureFormat,
 };
 
 pub(crate) const WEIGHTS: &[Weight] = &[Weight(3), Weight(4), Weight(5)];
@@ -48,8 +53,8 @@ impl ValidatorSecret for TestSecret {
     type Hash = u64;
     type Signature = u64;
 
-    fn sign(&self, data: &Self::Hash) -> Self::Signature {
-        data + u64::from(self.0)
+    fn sign(&self, data: &Self::Hash) -> Option<Self::Signature> {
+        Some(data + u64::from(self.0))
     }
 }
 
@@ -96,6 +101,7 @@ impl Context for TestContext {
         hash: &Self::Hash,
         public_key: &Self::ValidatorId,
         signature: &<Self::ValidatorSecret as ValidatorSecret>::Signature,
+        _format: SignatureFormat,
     ) -> bool {
         let computed_signature = hash + u64::from(*public_key);
         computed_signature == *signature
@@ -145,6 +151,7 @@ pub(crate) fn test_params(seed: u64) -> Params {
         Timestamp::from(0),
         Timestamp::from(0),
         TEST_ENDORSEMENT_EVIDENCE_LIMIT,
+        SignatureFormat::DomainSeparated,
     )
 }
 
@@ -263,6 +270,7 @@ fn ban_and_mark_faulty() -> Result<(), AddUnitError<TestContext>> {
         Timestamp::zero(),
         Timestamp::from(u64::MAX),
         TEST_ENDORSEMENT_EVIDENCE_LIMIT,
+        SignatureFormat::DomainSeparated,
     );
     // Everyone already knows Alice is faulty, so she is banned.
     let mut state = State::new(WEIGHTS, params, vec![ALICE]);
@@ -794,6 +802,36 @@ fn conflicting_endorsements() -> Result<(), AddUnitError<TestContext>> {
         evidence.validate(&validators, &TEST_INSTANCE_ID, state.params())
     );
 
+    // The evidence can also be validated knowing only Bob's and Alice's IDs.
+    assert_eq!(Some(ALICE), evidence.endorsed_creator());
+    assert_eq!(
+        Ok(()),
+        evidence.validate_for_perpetrator(
+            &BOB.0,
+            Some(&ALICE.0),
+            &TEST_INSTANCE_ID,
+            SignatureFormat::DomainSeparated
+        )
+    );
+    assert_eq!(
+        Err(EvidenceError::UnknownPerpetrator),
+        evidence.validate_for_perpetrator(
+            &BOB.0,
+            None,
+            &TEST_INSTANCE_ID,
+            SignatureFormat::DomainSeparated
+        )
+    );
+    assert_eq!(
+        Err(EvidenceError::Signature),
+        evidence.validate_for_perpetrator(
+            &BOB.0,
+            Some(&CAROL.0),
+            &TEST_INSTANCE_ID,
+            SignatureFormat::DomainSeparated
+        )
+    );
+
     let limit = TEST_ENDORSEMENT_EVIDENCE_LIMIT as usize;
 
     let mut a = vec![a0, a1, a2];
@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{BTreeMap, HashMap},
     fmt,
     hash::Hash,
     iter::FromIterator,
@@ -10,8 +10,11 @@ use std::{
 use datasize::DataSize;
 use derive_more::{AsRef, From};
 use itertools::Itertools;
+use num_traits::AsPrimitive;
 use serde::{Deserialize, Serialize};
 
+use casper_types::U512;
+
 use super::Weight;
 use crate::utils::ds;
 
@@ -73,6 +76,13 @@ impl<VID: Eq + Hash> Validators<VID> {
         })
     }
 
+    /// Returns the weight of the validator with the given index, or zero if it doesn't exist.
+    pub(crate) fn weight(&self, idx: ValidatorIndex) -> Weight {
+        self.validators
+            .get(idx.0 as usize)
+            .map_or(Weight(0), Validator::weight)
+    }
+
     pub(crate) fn get_index(&self, id: &VID) -> Option<ValidatorIndex> {
         self.index_by_id.get(id).cloned()
     }
@@ -109,6 +119,24 @@ impl<VID: Eq + Hash> Validators<VID> {
     }
 }
 
+impl<VID: Ord + Hash + Clone> Validators<VID> {
+    /// Creates the validator map from the given stakes, scaled down so that the total weight fits
+    /// into a `u64`.
+    pub(crate) fn from_stakes(stakes: BTreeMap<VID, U512>) -> Self {
+        let sum_stakes: U512 = stakes.values().copied().sum();
+        assert!(
+            !sum_stakes.is_zero(),
+            "cannot start era with total weight 0"
+        );
+        // Scale down by  sum / u64::MAX,  rounded up.
+        // If we round up the divisor, the resulting sum is guaranteed to be  <= u64::MAX.
+        let scaling_factor = (sum_stakes + U512::from(u64::MAX) - 1) / U512::from(u64::MAX);
+        let scale_stake =
+            |(key, stake): (VID, U512)| (key, AsPrimitive::<u64>::as_(stake / scaling_factor));
+        stakes.into_iter().map(scale_stake).collect()
+    }
+}
+
 impl<VID: Ord + Hash + Clone, W: Into<Weight>> FromIterator<(VID, W)> for Validators<VID> {
     fn from_iter<I: IntoIterator<Item = (VID, W)>>(ii: I) -> Validators<VID> {
         let mut validators: Vec<_> = ii.into_iter().map(Validator::from).collect();
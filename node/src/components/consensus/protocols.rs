@@ -1 +1,50 @@
 pub(crate) mod highway;
+pub(crate) mod simple_bft;
+
+use std::collections::{BTreeMap, HashSet};
+
+use casper_types::U512;
+
+use crate::{
+    components::consensus::{
+        config::{Config, ProtocolConfig},
+        consensus_protocol::{ConsensusProtocol, ProtocolOutcomes},
+        traits::{Context, NodeIdT},
+    },
+    crypto::signer::SignatureFormat,
+    types::{chainspec::ConsensusProtocolName, Timestamp},
+};
+
+use self::{highway::HighwayProtocol, simple_bft::SimpleBftProtocol};
+
+/// Creates a new boxed instance of the consensus protocol selected in the chainspec.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(crate) fn new_consensus_protocol<I: NodeIdT, C: Context + 'static>(
+    instance_id: C::InstanceId,
+    validator_stakes: BTreeMap<C::ValidatorId, U512>,
+    slashed: &HashSet<C::ValidatorId>,
+    protocol_config: &ProtocolConfig,
+    config: &Config,
+    prev_cp: Option<&dyn ConsensusProtocol<I, C>>,
+    era_start_time: Timestamp,
+    seed: u64,
+    signature_format: SignatureFormat,
+    now: Timestamp,
+) -> (Box<dyn ConsensusProtocol<I, C>>, ProtocolOutcomes<I, C>) {
+    let new_boxed = match protocol_config.consensus_protocol {
+        ConsensusProtocolName::Highway => HighwayProtocol::<I, C>::new_boxed,
+        ConsensusProtocolName::SimpleBft => SimpleBftProtocol::<I, C>::new_boxed,
+    };
+    new_boxed(
+        instance_id,
+        validator_stakes,
+        slashed,
+        protocol_config,
+        config,
+        prev_cp,
+        era_start_time,
+        seed,
+        signature_format,
+        now,
+    )
+}
@@ -1,6 +1,9 @@
 pub(crate) mod config;
+pub(crate) mod era_fault;
 mod participation;
+mod performance;
 mod round_success_meter;
+mod state_dump;
 mod synchronizer;
 #[cfg(test)]
 mod tests;
@@ -15,7 +18,6 @@ use std::{
 
 use datasize::DataSize;
 use itertools::Itertools;
-use num_traits::AsPrimitive;
 use serde::{Deserialize, Serialize};
 use tracing::{error, info, trace, warn};
 
@@ -24,7 +26,9 @@ use casper_types::{system::auction::BLOCK_REWARD, U512};
 use crate::{
     components::consensus::{
         config::{Config, ProtocolConfig},
-        consensus_protocol::{BlockContext, ConsensusProtocol, ProtocolOutcome, ProtocolOutcomes},
+        consensus_protocol::{
+            BlockContext, ConsensusProtocol, ProtocolOutcome, ProtocolOutcomes, SerializedEvidence,
+        },
         highway_core::{
             active_validator::Effect as AvEffect,
             finality_detector::{FinalityDetector, FttExceeded},
@@ -39,11 +43,15 @@ use crate::{
         traits::{ConsensusValueT, Context, NodeIdT},
         ActionId, TimerId,
     },
+    crypto::signer::SignatureFormat,
     types::{TimeDiff, Timestamp},
 };
 
 pub use self::config::Config as HighwayConfig;
-use self::{round_success_meter::RoundSuccessMeter, synchronizer::Synchronizer};
+use self::{
+    performance::PerformanceCache, round_success_meter::RoundSuccessMeter,
+    synchronizer::Synchronizer,
+};
 
 /// Never allow more than this many units in a piece of evidence for conflicting endorsements,
 /// even if eras are longer than this.
@@ -90,6 +98,10 @@ where
     standstill_timeout: TimeDiff,
     /// Log inactive or faulty validators periodically, with this interval.
     log_participation_interval: TimeDiff,
+    /// The validator performance as of the last finalized block, so that repeated queries don't
+    /// recompute it.
+    #[data_size(skip)]
+    performance_cache: PerformanceCache<C>,
 }
 
 impl<I: NodeIdT, C: Context + 'static> HighwayProtocol<I, C> {
@@ -104,22 +116,12 @@ impl<I: NodeIdT, C: Context + 'static> HighwayProtocol<I, C> {
         prev_cp: Option<&dyn ConsensusProtocol<I, C>>,
         era_start_time: Timestamp,
         seed: u64,
+        signature_format: SignatureFormat,
         now: Timestamp,
     ) -> (Box<dyn ConsensusProtocol<I, C>>, ProtocolOutcomes<I, C>) {
         let validators_count = validator_stakes.len();
-        let sum_stakes: U512 = validator_stakes.iter().map(|(_, stake)| *stake).sum();
-        assert!(
-            !sum_stakes.is_zero(),
-            "cannot start era with total weight 0"
-        );
-        // For Highway, we need u64 weights. Scale down by  sum / u64::MAX,  rounded up.
-        // If we round up the divisor, the resulting sum is guaranteed to be  <= u64::MAX.
-        let scaling_factor = (sum_stakes + U512::from(u64::MAX) - 1) / U512::from(u64::MAX);
-        let scale_stake = |(key, stake): (C::ValidatorId, U512)| {
-            (key, AsPrimitive::<u64>::as_(stake / scaling_factor))
-        };
-        let mut validators: Validators<C::ValidatorId> =
-            validator_stakes.into_iter().map(scale_stake).collect();
+        // For Highway, we need u64 weights.
+        let mut validators = Validators::from_stakes(validator_stakes);
 
         for vid in slashed {
             validators.ban(vid);
@@ -180,6 +182,7 @@ impl<I: NodeIdT, C: Context + 'static> HighwayProtocol<I, C> {
             era_start_time,
             era_start_time + protocol_config.era_duration,
             endorsement_evidence_limit,
+            signature_format,
         );
 
         let outcomes = Self::initialize_timers(now, era_start_time, &config.highway);
@@ -202,6 +205,7 @@ impl<I: NodeIdT, C: Context + 'static> HighwayProtocol<I, C> {
             last_panorama,
             standstill_timeout: config.highway.standstill_timeout,
             log_participation_interval: config.highway.log_participation_interval,
+            performance_cache: Default::default(),
         });
 
         (hw_proto, outcomes)
@@ -269,6 +273,7 @@ impl<I: NodeIdT, C: Context + 'static> HighwayProtocol<I, C> {
                 error!("this validator is faulty: {:?}", fault);
                 vec![ProtocolOutcome::WeAreFaulty]
             }
+            AvEffect::RequestSignature(request) => vec![ProtocolOutcome::RequestSignature(request)],
         }
     }
 
@@ -842,6 +847,16 @@ where
         }
     }
 
+    fn handle_signature(
+        &mut self,
+        hash: C::Hash,
+        maybe_signature: Option<C::Signature>,
+        now: Timestamp,
+    ) -> ProtocolOutcomes<I, C> {
+        let effects = self.highway.on_signature(&hash, maybe_signature, now);
+        self.process_av_effects(effects, now)
+    }
+
     fn activate_validator(
         &mut self,
         our_id: C::ValidatorId,
@@ -873,6 +888,22 @@ where
         self.highway.has_evidence(vid)
     }
 
+    fn serialized_evidence(&self, vid: &C::ValidatorId) -> Option<SerializedEvidence<C>> {
+        let validators = self.highway.validators();
+        let evidence = self
+            .highway
+            .state()
+            .maybe_evidence(validators.get_index(vid)?)?;
+        let endorsed_creator = match evidence.endorsed_creator() {
+            Some(idx) => Some(validators.id(idx)?.clone()),
+            None => None,
+        };
+        Some(SerializedEvidence {
+            bytes: bincode::serialize(evidence).expect("should serialize evidence"),
+            endorsed_creator,
+        })
+    }
+
     fn mark_faulty(&mut self, vid: &C::ValidatorId) {
         self.highway.mark_faulty(vid);
     }
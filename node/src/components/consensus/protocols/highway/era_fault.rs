@@ -0,0 +1,80 @@
+//! Serialization and verification of Highway evidence outside of a running consensus instance.
+
+use std::collections::BTreeSet;
+
+use casper_types::{PublicKey, SecretKey};
+
+use crate::{
+    components::consensus::{
+        cl_context::ClContext,
+        highway_core::{
+            evidence::Evidence,
+            highway::{SignedWireUnit, WireUnit},
+            state::Panorama,
+            validators::ValidatorIndex,
+        },
+    },
+    crypto::{
+        self,
+        hash::Digest,
+        signer::{self, SignatureFormat},
+    },
+    types::{EraFaultError, Timestamp},
+};
+
+/// Deserializes the Highway evidence and validates it as proof that the validator with the given
+/// public key is faulty in the consensus instance `instance_id`, whose messages are signed in the
+/// given format.
+///
+/// Evidence of endorsing conflicting units also requires the public key of the units' creator.
+pub(crate) fn validate_serialized_evidence(
+    serialized_evidence: &[u8],
+    public_key: &PublicKey,
+    maybe_endorsed_creator: Option<&PublicKey>,
+    instance_id: &Digest,
+    signature_format: SignatureFormat,
+) -> Result<(), EraFaultError> {
+    let evidence: Evidence<ClContext> = bincode::deserialize(serialized_evidence)?;
+    evidence.validate_for_perpetrator(
+        public_key,
+        maybe_endorsed_creator,
+        instance_id,
+        signature_format,
+    )?;
+    Ok(())
+}
+
+/// Returns serialized evidence of the validator with the given key creating two units with the
+/// same sequence number in the consensus instance `instance_id`, signed in the given format.
+///
+/// The validator is assumed to be the only one in the instance.
+pub(crate) fn serialized_equivocation(
+    secret_key: &SecretKey,
+    public_key: &PublicKey,
+    instance_id: Digest,
+    timestamps: (Timestamp, Timestamp),
+    signature_format: SignatureFormat,
+) -> Vec<u8> {
+    let signed_unit = |timestamp| {
+        let wire_unit = WireUnit::<ClContext> {
+            panorama: Panorama::new(1),
+            creator: ValidatorIndex(0),
+            instance_id,
+            value: None,
+            seq_number: 0,
+            timestamp,
+            round_exp: 14,
+            endorsed: BTreeSet::new(),
+        };
+        let hashed_wire_unit = wire_unit.into_hashed();
+        let bytes =
+            signer::unit_bytes_to_sign(signature_format, &instance_id, 0, &hashed_wire_unit.hash());
+        let signature = crypto::sign(bytes, secret_key, public_key);
+        SignedWireUnit {
+            hashed_wire_unit,
+            signature,
+        }
+    };
+    let evidence = Evidence::Equivocation(signed_unit(timestamps.0), signed_unit(timestamps.1));
+    bincode::serialize(&evidence).expect("should serialize evidence")
+}
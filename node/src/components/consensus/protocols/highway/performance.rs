@@ -0,0 +1,76 @@
+//! Reports on the validators' participation, for operators.
+
+use std::cell::RefCell;
+
+use datasize::DataSize;
+
+use casper_types::EraId;
+
+use crate::{
+    components::consensus::{
+        cl_context::ClContext,
+        highway_core::{
+            finality_detector::rewards::{self, RoundStats},
+            validators::ValidatorMap,
+        },
+        traits::Context,
+    },
+    types::{EraValidatorPerformance, ValidatorPerformance},
+};
+
+use super::HighwayProtocol;
+
+/// The round statistics and rewards for the last finalized block, computed on the first request
+/// after that block was finalized.
+pub(super) type PerformanceCache<C> = RefCell<
+    Option<(
+        <C as Context>::Hash,
+        ValidatorMap<RoundStats>,
+        ValidatorMap<u64>,
+    )>,
+>;
+
+impl<I: DataSize> HighwayProtocol<I, ClContext> {
+    /// Returns each validator's participation in the rounds that the last finalized block pays out
+    /// rewards for, and the rewards assigned to them.
+    pub(crate) fn validator_performance(&self, era_id: EraId) -> EraValidatorPerformance {
+        let state = self.highway.state();
+        let mut cache = self.performance_cache.borrow_mut();
+        match self.finality_detector.last_finalized() {
+            Some(bhash) if cache.as_ref().map_or(true, |(hash, _, _)| hash != bhash) => {
+                let stats = rewards::round_stats(state, bhash);
+                let rewards = rewards::compute_rewards(state, bhash);
+                *cache = Some((*bhash, stats, rewards));
+            }
+            _ => (),
+        }
+        let stats_and_rewards = |idx| {
+            cache
+                .as_ref()
+                .map_or((RoundStats::default(), 0), |(_, stats, rewards)| {
+                    (stats[idx], rewards[idx])
+                })
+        };
+        let validators = self
+            .highway
+            .validators()
+            .enumerate_ids()
+            .map(|(idx, public_key)| {
+                let (stats, assigned_rewards) = stats_and_rewards(idx);
+                ValidatorPerformance {
+                    public_key: public_key.clone(),
+                    weight: state.weight(idx).0,
+                    faulty: state.is_faulty(idx),
+                    last_seen: state.last_seen(idx),
+                    round_exponent: state.panorama()[idx]
+                        .correct()
+                        .map(|hash| state.unit(hash).round_exp),
+                    rounds_participated: stats.participated,
+                    rounds_missed: stats.missed,
+                    assigned_rewards,
+                }
+            })
+            .collect();
+        EraValidatorPerformance { era_id, validators }
+    }
+}
@@ -0,0 +1,96 @@
+//! Snapshots of the protocol state, for debugging.
+
+use datasize::DataSize;
+use itertools::Itertools;
+
+use casper_types::EraId;
+
+use crate::{
+    components::consensus::{
+        cl_context::ClContext,
+        highway_core::state::{Observation, Panorama},
+    },
+    types::{HighwayStateDump, ObservationDump, SummitDump, UnitDump, ValidatorDump},
+};
+
+use super::HighwayProtocol;
+
+impl<I: DataSize> HighwayProtocol<I, ClContext> {
+    /// Returns a snapshot of the protocol state: all units, and the finality detector's progress.
+    pub(crate) fn dump_state(&self, era_id: EraId) -> HighwayStateDump {
+        let state = self.highway.state();
+        let validators = self
+            .highway
+            .validators()
+            .enumerate_ids()
+            .map(|(idx, public_key)| ValidatorDump {
+                index: idx.0,
+                public_key: public_key.clone(),
+                weight: state.weight(idx).0,
+                faulty: state.is_faulty(idx),
+            })
+            .collect();
+
+        let units = state
+            .iter_units()
+            .sorted_by_key(|(_, unit)| (unit.timestamp, unit.creator, unit.seq_number))
+            .map(|(hash, unit)| UnitDump {
+                hash: *hash,
+                creator: unit.creator.0,
+                seq_number: unit.seq_number,
+                timestamp: unit.timestamp,
+                round_exp: unit.round_exp,
+                block: unit.block,
+                proposed_height: state.maybe_block(hash).map(|block| block.height),
+                panorama: dump_panorama(&unit.panorama),
+                endorsed: unit.endorsed.iter().cloned().collect(),
+                endorsements: state.endorsers(hash).into_iter().map(|idx| idx.0).collect(),
+            })
+            .collect();
+
+        let mut finalized_blocks: Vec<_> = self
+            .finality_detector
+            .last_finalized()
+            .into_iter()
+            .flat_map(|bhash| state.ancestor_hashes(bhash).chain(Some(bhash)))
+            .cloned()
+            .collect();
+        finalized_blocks.sort_by_key(|bhash| state.block(bhash).height);
+
+        // The blocks in the fork choice that are not finalized yet, with their summit levels.
+        let next_height = finalized_blocks.len() as u64;
+        let mut summits: Vec<_> = state
+            .fork_choice(state.panorama())
+            .into_iter()
+            .flat_map(|bhash| Some(bhash).into_iter().chain(state.ancestor_hashes(bhash)))
+            .take_while(|bhash| state.block(bhash).height >= next_height)
+            .map(|bhash| SummitDump {
+                block: *bhash,
+                height: state.block(bhash).height,
+                level: self.finality_detector.summit_level(bhash, state),
+            })
+            .collect();
+        summits.reverse();
+
+        HighwayStateDump {
+            era_id,
+            instance_id: *self.highway.instance_id(),
+            fault_tolerance_threshold: self.finality_detector.fault_tolerance_threshold().0,
+            validators,
+            units,
+            finalized_blocks,
+            summits,
+        }
+    }
+}
+
+fn dump_panorama(panorama: &Panorama<ClContext>) -> Vec<ObservationDump> {
+    panorama
+        .iter()
+        .map(|observation| match observation {
+            Observation::None => ObservationDump::None,
+            Observation::Correct(hash) => ObservationDump::Correct(*hash),
+            Observation::Faulty => ObservationDump::Faulty,
+        })
+        .collect()
+}
@@ -3,7 +3,7 @@ use std::{collections::BTreeSet, sync::Arc};
 use datasize::DataSize;
 use derive_more::Display;
 
-use casper_types::{PublicKey, U512};
+use casper_types::{EraId, PublicKey, U512};
 
 use crate::{
     components::consensus::{
@@ -19,13 +19,13 @@ use crate::{
             State,
         },
         protocols::highway::{
-            config::Config as HighwayConfig, HighwayMessage, ACTION_ID_VERTEX,
+            config::Config as HighwayConfig, HighwayMessage, HighwayProtocol, ACTION_ID_VERTEX,
             TIMER_ID_STANDSTILL_ALERT,
         },
         tests::utils::{new_test_chainspec, ALICE_PUBLIC_KEY, ALICE_SECRET_KEY, BOB_PUBLIC_KEY},
         traits::Context,
-        HighwayProtocol,
     },
+    crypto::signer::SignatureFormat,
     types::{ProtoBlock, TimeDiff, Timestamp},
 };
 
@@ -49,6 +49,7 @@ where
         0.into(),
         Timestamp::from(u64::MAX),
         highway_testing::TEST_ENDORSEMENT_EVIDENCE_LIMIT,
+        SignatureFormat::DomainSeparated,
     );
     let weights = weights.into_iter().map(|w| w.into()).collect::<Vec<_>>();
     state::State::new(weights, params, vec![])
@@ -73,6 +74,7 @@ where
     let chainspec = new_test_chainspec(weights.clone());
     let config = Config {
         secret_key_path: Default::default(),
+        remote_signer: None,
         highway: HighwayConfig {
             unit_hashes_folder: Default::default(),
             pending_vertex_timeout: "1min".parse().unwrap(),
@@ -94,6 +96,7 @@ where
         None,
         start_timestamp,
         0,
+        SignatureFormat::DomainSeparated,
         start_timestamp,
     );
     // We expect for messages:
@@ -302,3 +305,55 @@ fn detect_doppelganger() {
     }
     panic!("failed to return DoppelgangerDetected effect");
 }
+
+#[test]
+fn dump_state_with_finalized_block() {
+    let creator: ValidatorIndex = ValidatorIndex(0);
+    let validators = vec![(ALICE_PUBLIC_KEY.clone(), 100)];
+    let now = Timestamp::zero();
+    let wunit: WireUnit<ClContext> = WireUnit {
+        panorama: Panorama::from(vec![N]),
+        creator,
+        instance_id: ClContext::hash(INSTANCE_ID_DATA),
+        value: Some(CandidateBlock::new(
+            ProtoBlock::new(vec![], vec![], now, false),
+            vec![],
+            None,
+        )),
+        seq_number: 0,
+        timestamp: now,
+        round_exp: 14,
+        endorsed: BTreeSet::new(),
+    };
+    let alice_keypair: Keypair = Keypair::from(Arc::new(ALICE_SECRET_KEY.clone()));
+    let signed_wunit = SignedWireUnit::new(wunit.into_hashed(), &alice_keypair);
+    let unit_hash = signed_wunit.hash();
+    let highway_message: HighwayMessage<ClContext> =
+        HighwayMessage::NewVertex(Vertex::Unit(signed_wunit));
+
+    let mut highway_protocol = new_test_highway_protocol(validators, vec![]);
+    let msg = bincode::serialize(&highway_message).unwrap();
+    let mut outcomes = highway_protocol.handle_message(NodeId(123), msg, now);
+    while let Some(outcome) = outcomes.pop() {
+        if let ProtocolOutcome::QueueAction(ACTION_ID_VERTEX) = outcome {
+            outcomes.extend(highway_protocol.handle_action(ACTION_ID_VERTEX, now))
+        }
+    }
+
+    let highway = highway_protocol
+        .as_any()
+        .downcast_ref::<HighwayProtocol<NodeId, ClContext>>()
+        .expect("should be a Highway instance");
+    let dump = highway.dump_state(EraId::from(1));
+    assert_eq!(dump.era_id, EraId::from(1));
+    assert_eq!(dump.validators.len(), 1);
+    assert_eq!(dump.units.len(), 1);
+    assert_eq!(dump.units[0].hash, unit_hash);
+    assert_eq!(dump.units[0].proposed_height, Some(0));
+    assert_eq!(dump.finalized_blocks, vec![unit_hash]);
+    assert!(dump.summits.is_empty());
+
+    let dot = dump.to_dot();
+    assert!(dot.starts_with("digraph"));
+    assert!(dot.contains(&format!("\"{:x}\" [shape=box, style=filled", unit_hash)));
+}
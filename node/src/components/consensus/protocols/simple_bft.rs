@@ -0,0 +1,1124 @@
+//! # Simple BFT
+//!
+//! A round-based Byzantine fault tolerant consensus protocol, much simpler than Highway. It serves
+//! as a reference implementation of `ConsensusProtocol`.
+//!
+//! The values are finalized one height at a time. Each height is decided in one or more rounds,
+//! and in each round a leader, chosen round-robin, proposes a value. The validators vote on it in
+//! two phases:
+//! * Once a validator has validated the proposal, it broadcasts a _prevote_ for it.
+//! * Once a validator has seen prevotes for a value from more than two thirds of the total weight,
+//!   it _locks_ on that value and broadcasts a _precommit_ for it.
+//! * A value with precommits from more than two thirds of the total weight is finalized.
+//!
+//! If a round doesn't finalize a value before its timeout, the validators move on to the next
+//! round, with the next leader. A locked validator only prevotes for a different value if that
+//! value received a quorum of prevotes in a later round than the one it locked in. Since two
+//! quorums always have an honest validator in common, this guarantees that no two different values
+//! get finalized at the same height, as long as less than a third of the weight is faulty.
+//!
+//! The round timeouts grow linearly with the round number, so that the rounds eventually become
+//! long enough for an honest leader's proposal to get finalized.
+
+mod message;
+#[cfg(test)]
+mod tests;
+
+use std::{
+    any::Any,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fmt::{self, Debug},
+    path::PathBuf,
+};
+
+use datasize::DataSize;
+use tracing::{debug, error, info, trace, warn};
+
+use casper_types::{system::auction::BLOCK_REWARD, U512};
+
+use crate::{
+    components::consensus::{
+        config::{Config, ProtocolConfig},
+        consensus_protocol::{
+            BlockContext, ConsensusProtocol, FinalizedBlock, ProtocolOutcome, ProtocolOutcomes,
+            SerializedEvidence, TerminalBlockData,
+        },
+        highway_core::{
+            state,
+            validators::{ValidatorIndex, Validators},
+            Weight,
+        },
+        traits::{ConsensusValueT, Context, NodeIdT},
+        ActionId, TimerId,
+    },
+    crypto::signer::SignatureFormat,
+    types::{TimeDiff, Timestamp},
+};
+
+use self::message::{Content, Evidence, Message, MessageError, SignedMessage, ValueHash, VoteKind};
+
+/// The timer for the end of the current round.
+const TIMER_ID_ROUND_TIMEOUT: TimerId = TimerId(0);
+/// The timer for proposing a value, as the current round's leader.
+const TIMER_ID_PROPOSE: TimerId = TimerId(1);
+
+/// Messages for heights more than this far ahead of ours are dropped.
+const MAX_FUTURE_HEIGHTS: u64 = 10;
+/// Messages for rounds more than this far ahead of ours are dropped.
+const MAX_FUTURE_ROUNDS: u32 = 10;
+/// The maximum number of heights a single sync response covers. The peer would drop messages
+/// further ahead anyway, and requests more once it has caught up.
+const MAX_SYNC_HEIGHTS: u64 = MAX_FUTURE_HEIGHTS + 1;
+
+/// The proposals and votes for a single height.
+#[derive(DataSize, Debug)]
+struct HeightState<C: Context> {
+    /// The leaders' proposals, by round.
+    proposals: BTreeMap<u32, SignedMessage<C>>,
+    /// The votes, by round, kind and creator.
+    votes: BTreeMap<(u32, VoteKind), BTreeMap<ValidatorIndex, SignedMessage<C>>>,
+}
+
+impl<C: Context> Default for HeightState<C> {
+    fn default() -> Self {
+        HeightState {
+            proposals: BTreeMap::new(),
+            votes: BTreeMap::new(),
+        }
+    }
+}
+
+impl<C: Context> HeightState<C> {
+    /// Returns the message in the same slot as `msg`, if any.
+    fn existing(&self, msg: &SignedMessage<C>) -> Option<&SignedMessage<C>> {
+        match msg.vote() {
+            None => self.proposals.get(&msg.round),
+            Some((kind, _)) => self
+                .votes
+                .get(&(msg.round, kind))
+                .and_then(|votes| votes.get(&msg.creator)),
+        }
+    }
+
+    /// Adds the message, replacing any existing one in the same slot.
+    fn insert(&mut self, msg: SignedMessage<C>) {
+        match msg.vote() {
+            None => {
+                self.proposals.insert(msg.round, msg);
+            }
+            Some((kind, _)) => {
+                self.votes
+                    .entry((msg.round, kind))
+                    .or_default()
+                    .insert(msg.creator, msg);
+            }
+        }
+    }
+
+    /// Returns whether the validator has cast a vote of the given kind in the round.
+    fn has_voted(&self, round: u32, kind: VoteKind, creator: ValidatorIndex) -> bool {
+        self.votes
+            .get(&(round, kind))
+            .map_or(false, |votes| votes.contains_key(&creator))
+    }
+
+    /// Returns the hash of the value that received a quorum of votes of the given kind in the
+    /// round, if any.
+    fn quorum_value(
+        &self,
+        round: u32,
+        kind: VoteKind,
+        validators: &Validators<C::ValidatorId>,
+        quorum: Weight,
+    ) -> Option<ValueHash<C>> {
+        let mut weights: BTreeMap<ValueHash<C>, Weight> = BTreeMap::new();
+        for (idx, vote) in self.votes.get(&(round, kind))? {
+            if let Some((_, hash)) = vote.vote() {
+                let weight = weights.entry(*hash).or_default();
+                *weight = weight.saturating_add(validators.weight(*idx));
+            }
+        }
+        weights
+            .into_iter()
+            .find(|(_, weight)| *weight >= quorum)
+            .map(|(hash, _)| hash)
+    }
+
+    /// Returns the total weight of the validators who voted in the round.
+    fn round_weight(&self, round: u32, validators: &Validators<C::ValidatorId>) -> Weight {
+        let creators: BTreeSet<ValidatorIndex> = [VoteKind::Prevote, VoteKind::Precommit]
+            .iter()
+            .filter_map(|kind| self.votes.get(&(round, *kind)))
+            .flat_map(|votes| votes.keys().copied())
+            .collect();
+        creators
+            .into_iter()
+            .map(|idx| validators.weight(idx))
+            .fold(Weight(0), Weight::saturating_add)
+    }
+
+    /// Returns the rounds in which there were any votes.
+    fn vote_rounds(&self) -> BTreeSet<u32> {
+        self.votes.keys().map(|(round, _)| *round).collect()
+    }
+
+    /// Returns a proposal with the given value, if any.
+    fn proposal_with_value(&self, hash: &ValueHash<C>) -> Option<&SignedMessage<C>> {
+        self.proposals.values().find(|proposal| {
+            proposal
+                .proposal()
+                .map_or(false, |value| value.hash() == *hash)
+        })
+    }
+
+    /// Returns the votes of the given kind for the value in the round.
+    fn votes_for(&self, round: u32, kind: VoteKind, hash: &ValueHash<C>) -> Vec<SignedMessage<C>> {
+        self.votes
+            .get(&(round, kind))
+            .into_iter()
+            .flat_map(|votes| votes.values())
+            .filter(|vote| vote.vote().map_or(false, |(_, h)| h == hash))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns an iterator over all proposals and votes.
+    fn messages(&self) -> impl Iterator<Item = &SignedMessage<C>> {
+        self.proposals
+            .values()
+            .chain(self.votes.values().flat_map(|votes| votes.values()))
+    }
+}
+
+/// A finalized value, together with the proposal and precommits that prove its finality.
+#[derive(DataSize, Debug)]
+struct FinalizedEntry<C: Context> {
+    value: C::ConsensusValue,
+    proposal: SignedMessage<C>,
+    precommits: Vec<SignedMessage<C>>,
+}
+
+/// A step of the protocol that returns `None` if it didn't change the state.
+type Step<I, C> = fn(&mut SimpleBftProtocol<I, C>, Timestamp) -> Option<ProtocolOutcomes<I, C>>;
+
+/// Our own validator index and secret key, if we are an active validator.
+#[derive(DataSize)]
+struct ActiveValidator<C: Context> {
+    idx: ValidatorIndex,
+    secret: C::ValidatorSecret,
+}
+
+impl<C: Context> Debug for ActiveValidator<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ActiveValidator")
+            .field("idx", &self.idx)
+            .finish()
+    }
+}
+
+#[derive(DataSize, Debug)]
+pub(crate) struct SimpleBftProtocol<I, C>
+where
+    I: DataSize,
+    C: Context,
+{
+    instance_id: C::InstanceId,
+    validators: Validators<C::ValidatorId>,
+    /// The indices of the validators that are not banned, in the order in which they take turns
+    /// as leaders.
+    leaders: Vec<ValidatorIndex>,
+    /// The minimum weight of a quorum: more than two thirds of the total weight.
+    quorum: Weight,
+    /// The minimum delay between a block and its child. The round timeouts are multiples of this.
+    block_interval: TimeDiff,
+    era_start_time: Timestamp,
+    era_end_time: Timestamp,
+    minimum_era_height: u64,
+    /// The format of the bytes signed for messages.
+    signature_format: SignatureFormat,
+    /// The current height, i.e. the number of values finalized so far.
+    height: u64,
+    /// The current round in the current height.
+    round: u32,
+    /// The time at which the current round's leader proposes a value.
+    proposal_time: Timestamp,
+    /// The time at which we move on to the next round, unless a value gets finalized first.
+    round_deadline: Timestamp,
+    active_validator: Option<ActiveValidator<C>>,
+    /// While paused we don't propose or vote.
+    paused: bool,
+    /// The round and hash of the value we are locked on in the current height.
+    lock: Option<(u32, ValueHash<C>)>,
+    /// The height and round for which we requested a value to propose.
+    requested_block: Option<(u64, u32)>,
+    /// Whether we already asked a peer for the messages we missed in the current round.
+    sync_requested: bool,
+    /// The proposals and votes for the current and future heights.
+    heights: BTreeMap<u64, HeightState<C>>,
+    /// The values finalized so far, by height.
+    finalized: Vec<FinalizedEntry<C>>,
+    /// The peers from which we received the proposed values, for validation.
+    value_senders: HashMap<ValueHash<C>, I>,
+    /// Values for which we requested validation.
+    pending_values: HashSet<ValueHash<C>>,
+    /// Values that passed validation.
+    valid_values: HashSet<ValueHash<C>>,
+    /// Values that failed validation.
+    invalid_values: HashSet<ValueHash<C>>,
+    /// Direct evidence of equivocation, by perpetrator.
+    evidence: BTreeMap<ValidatorIndex, Evidence<C>>,
+    /// Banned validators, validators with direct evidence, and validators with evidence in other
+    /// eras.
+    faulty: BTreeSet<ValidatorIndex>,
+    /// Validators with direct evidence that has already been included in a finalized block.
+    reported_equivocators: BTreeSet<ValidatorIndex>,
+    /// The validators that have sent us any proposal or vote.
+    participants: BTreeSet<ValidatorIndex>,
+    /// The accumulated rewards for precommitting finalized values, in fractions of the block
+    /// reward.
+    rewards: BTreeMap<ValidatorIndex, u64>,
+    /// Whether the terminal block has been finalized.
+    finished: bool,
+    /// Whether the faulty validators' weight exceeds the fault tolerance threshold.
+    ftt_exceeded: bool,
+    evidence_only: bool,
+    received_messages: bool,
+}
+
+impl<I: NodeIdT, C: Context + 'static> SimpleBftProtocol<I, C> {
+    /// Creates a new boxed `SimpleBftProtocol` instance.
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    pub(crate) fn new_boxed(
+        instance_id: C::InstanceId,
+        validator_stakes: BTreeMap<C::ValidatorId, U512>,
+        slashed: &HashSet<C::ValidatorId>,
+        protocol_config: &ProtocolConfig,
+        _config: &Config,
+        _prev_cp: Option<&dyn ConsensusProtocol<I, C>>,
+        era_start_time: Timestamp,
+        seed: u64,
+        signature_format: SignatureFormat,
+        now: Timestamp,
+    ) -> (Box<dyn ConsensusProtocol<I, C>>, ProtocolOutcomes<I, C>) {
+        let mut validators = Validators::from_stakes(validator_stakes);
+        for vid in slashed {
+            validators.ban(vid);
+        }
+        let faulty: BTreeSet<ValidatorIndex> = validators.iter_banned_idx().collect();
+        let mut leaders: Vec<ValidatorIndex> = validators
+            .enumerate_ids()
+            .map(|(idx, _)| idx)
+            .filter(|idx| !faulty.contains(idx))
+            .collect();
+        // Use the seed to decide who goes first, so that it's not always the same validator.
+        if let Some(offset) = seed.checked_rem(leaders.len() as u64) {
+            leaders.rotate_left(offset as usize);
+        }
+        let quorum = quorum_weight(validators.total_weight());
+        let block_interval =
+            state::round_len(protocol_config.highway_config.minimum_round_exponent);
+
+        info!(?quorum, %block_interval, "initializing simple BFT instance");
+
+        let mut sbft = SimpleBftProtocol {
+            instance_id,
+            validators,
+            leaders,
+            quorum,
+            block_interval,
+            era_start_time,
+            era_end_time: era_start_time + protocol_config.era_duration,
+            minimum_era_height: protocol_config.minimum_era_height,
+            signature_format,
+            height: 0,
+            round: 0,
+            proposal_time: era_start_time,
+            round_deadline: era_start_time,
+            active_validator: None,
+            paused: false,
+            lock: None,
+            requested_block: None,
+            sync_requested: false,
+            heights: BTreeMap::new(),
+            finalized: Vec::new(),
+            value_senders: HashMap::new(),
+            pending_values: HashSet::new(),
+            valid_values: HashSet::new(),
+            invalid_values: HashSet::new(),
+            evidence: BTreeMap::new(),
+            faulty,
+            reported_equivocators: BTreeSet::new(),
+            participants: BTreeSet::new(),
+            rewards: BTreeMap::new(),
+            finished: false,
+            ftt_exceeded: false,
+            evidence_only: false,
+            received_messages: false,
+        };
+        let outcomes = sbft.enter_round(0, now);
+        (Box::new(sbft), outcomes)
+    }
+
+    /// Returns the leader of the given round, or `None` if all validators are banned.
+    fn leader(&self, height: u64, round: u32) -> Option<ValidatorIndex> {
+        let count = self.leaders.len() as u64;
+        let idx = height.wrapping_add(round.into()).checked_rem(count)?;
+        self.leaders.get(idx as usize).copied()
+    }
+
+    /// Returns the timeout for the given round.
+    fn round_timeout(&self, round: u32) -> TimeDiff {
+        self.block_interval
+            .saturating_mul(u64::from(round).saturating_add(1))
+    }
+
+    /// Returns our validator index, if we are an active validator and not paused.
+    fn our_idx(&self) -> Option<ValidatorIndex> {
+        if self.paused || self.finished || self.evidence_only {
+            return None;
+        }
+        self.active_validator.as_ref().map(|av| av.idx)
+    }
+
+    /// Returns the last finalized value, if any.
+    fn parent(&self) -> Option<&C::ConsensusValue> {
+        self.finalized.last().map(|entry| &entry.value)
+    }
+
+    /// Returns whether the weight is more than a third of the total weight.
+    fn exceeds_third(&self, weight: Weight) -> bool {
+        u128::from(weight).saturating_mul(3) > u128::from(self.validators.total_weight())
+    }
+
+    /// Moves on to the given round in the current height, and schedules the timers.
+    fn enter_round(&mut self, round: u32, now: Timestamp) -> ProtocolOutcomes<I, C> {
+        self.round = round;
+        self.sync_requested = false;
+        // In the first round, the leader waits until the block interval has passed.
+        self.proposal_time = if round == 0 {
+            let earliest = self.parent().map_or(self.era_start_time, |parent| {
+                parent.timestamp() + self.block_interval
+            });
+            now.max(earliest)
+        } else {
+            now
+        };
+        self.round_deadline = self.proposal_time + self.round_timeout(round);
+        trace!(
+            height = self.height,
+            round,
+            deadline = %self.round_deadline,
+            "entering round"
+        );
+        let mut outcomes = vec![ProtocolOutcome::ScheduleTimer(
+            self.round_deadline,
+            TIMER_ID_ROUND_TIMEOUT,
+        )];
+        if self.proposal_time > now {
+            outcomes.push(ProtocolOutcome::ScheduleTimer(
+                self.proposal_time,
+                TIMER_ID_PROPOSE,
+            ));
+        }
+        outcomes
+    }
+
+    /// Returns whether the value is a valid proposal in the current height: whether it has the
+    /// right parent and timestamp, and has been validated if necessary.
+    fn is_acceptable(&self, value: &C::ConsensusValue, now: Timestamp) -> bool {
+        let timestamp_ok = match self.parent() {
+            None => value.parent().is_none() && value.timestamp() >= self.era_start_time,
+            Some(parent) => {
+                value.parent() == Some(&parent.hash()) && value.timestamp() > parent.timestamp()
+            }
+        };
+        timestamp_ok
+            && value.timestamp() <= now + self.block_interval
+            && (!value.needs_validation() || self.valid_values.contains(&value.hash()))
+    }
+
+    /// Returns the value with the given hash, if it was proposed in the current height and is
+    /// acceptable.
+    fn acceptable_value(&self, hash: &ValueHash<C>, now: Timestamp) -> Option<C::ConsensusValue> {
+        let value = self
+            .heights
+            .get(&self.height)?
+            .proposal_with_value(hash)?
+            .proposal()?;
+        if self.is_acceptable(value, now) {
+            Some(value.clone())
+        } else {
+            None
+        }
+    }
+
+    /// Adds a proposal or vote whose signature has already been checked, and returns evidence if
+    /// it conflicts with one we already have.
+    fn store_message(&mut self, msg: SignedMessage<C>) -> ProtocolOutcomes<I, C> {
+        let state = self.heights.entry(msg.height).or_default();
+        let evidence = match state.existing(&msg) {
+            Some(existing) if existing.content == msg.content => return vec![],
+            Some(existing) => Evidence(existing.clone(), msg),
+            None => {
+                self.participants.insert(msg.creator);
+                state.insert(msg);
+                return vec![];
+            }
+        };
+        self.add_evidence(evidence)
+    }
+
+    /// Signs a new message in the current height and round, adds it to our state and gossips it.
+    fn create_message(&mut self, content: Content<C>) -> ProtocolOutcomes<I, C> {
+        let av = match &self.active_validator {
+            Some(av) => av,
+            None => return vec![],
+        };
+        let msg = match SignedMessage::create(
+            self.instance_id,
+            self.height,
+            self.round,
+            av.idx,
+            content,
+            &av.secret,
+        ) {
+            Some(msg) => msg,
+            None => {
+                error!(
+                    height = self.height,
+                    round = self.round,
+                    "failed to sign message"
+                );
+                return vec![];
+            }
+        };
+        let mut outcomes = vec![ProtocolOutcome::CreatedGossipMessage(
+            Message::Signed(msg.clone()).serialize(),
+        )];
+        outcomes.extend(self.store_message(msg));
+        outcomes
+    }
+
+    /// Adds new evidence, and returns the resulting outcomes.
+    fn add_evidence(&mut self, evidence: Evidence<C>) -> ProtocolOutcomes<I, C> {
+        let idx = evidence.perpetrator();
+        if self.evidence.contains_key(&idx) {
+            return vec![];
+        }
+        let vid = match self.validators.id(idx) {
+            Some(vid) => vid.clone(),
+            None => {
+                error!(?evidence, "evidence against unknown validator");
+                return vec![];
+            }
+        };
+        let mut outcomes = vec![];
+        if self.active_validator.as_ref().map(|av| av.idx) == Some(idx) {
+            error!(
+                "received a message from a doppelganger. \
+                 Are you running multiple nodes with the same validator key?",
+            );
+            self.active_validator = None;
+            outcomes.push(ProtocolOutcome::DoppelgangerDetected);
+        }
+        info!(?vid, "validator equivocated");
+        let msg = Message::Evidence(evidence.clone());
+        outcomes.push(ProtocolOutcome::CreatedGossipMessage(msg.serialize()));
+        outcomes.push(ProtocolOutcome::NewEvidence(vid));
+        self.evidence.insert(idx, evidence);
+        self.faulty.insert(idx);
+        outcomes.extend(self.check_ftt());
+        outcomes
+    }
+
+    /// Returns `FttExceeded` the first time the faulty validators reach a third of the weight.
+    fn check_ftt(&mut self) -> ProtocolOutcomes<I, C> {
+        let faulty_weight = self
+            .faulty
+            .iter()
+            .map(|idx| self.validators.weight(*idx))
+            .fold(Weight(0), Weight::saturating_add);
+        let total_weight = u128::from(self.validators.total_weight());
+        if self.ftt_exceeded || u128::from(faulty_weight).saturating_mul(3) < total_weight {
+            return vec![];
+        }
+        warn!(?faulty_weight, "fault tolerance threshold exceeded");
+        self.ftt_exceeded = true;
+        vec![ProtocolOutcome::FttExceeded]
+    }
+
+    /// Makes as much progress as possible: finalizes values, moves to later rounds, proposes and
+    /// votes.
+    fn update(&mut self, now: Timestamp) -> ProtocolOutcomes<I, C> {
+        let mut outcomes = vec![];
+        loop {
+            let mut progress = false;
+            let steps: [Step<I, C>; 4] = [
+                Self::try_finalize,
+                Self::catch_up_round,
+                Self::try_propose,
+                Self::try_vote,
+            ];
+            for step in &steps {
+                if let Some(step_outcomes) = step(self, now) {
+                    outcomes.extend(step_outcomes);
+                    progress = true;
+                }
+            }
+            if !progress {
+                break;
+            }
+        }
+        outcomes.extend(self.request_validation());
+        outcomes
+    }
+
+    /// Finalizes the current height's value if it has a quorum of precommits. Returns `None` if
+    /// the state didn't change.
+    fn try_finalize(&mut self, now: Timestamp) -> Option<ProtocolOutcomes<I, C>> {
+        if self.finished || self.evidence_only {
+            return None;
+        }
+        let state = self.heights.get(&self.height)?;
+        let (round, hash) = state.vote_rounds().into_iter().find_map(|round| {
+            state
+                .quorum_value(round, VoteKind::Precommit, &self.validators, self.quorum)
+                .map(|hash| (round, hash))
+        })?;
+        let proposal = match state.proposal_with_value(&hash) {
+            Some(proposal) => proposal.clone(),
+            None => {
+                // A quorum has seen the value, but we haven't: Ask our peers for it.
+                if self.sync_requested {
+                    return None;
+                }
+                self.sync_requested = true;
+                let msg = Message::<C>::SyncRequest {
+                    height: self.height,
+                };
+                return Some(vec![ProtocolOutcome::CreatedGossipMessage(msg.serialize())]);
+            }
+        };
+        let value = proposal.proposal()?.clone();
+        if value.needs_validation() && !self.valid_values.contains(&hash) {
+            // We need to wait for validation before we can execute it.
+            return None;
+        }
+        let precommits = state.votes_for(round, VoteKind::Precommit, &hash);
+        let proposer = self.validators.id(proposal.creator)?.clone();
+
+        // Reward every validator whose precommit contributed to finality.
+        let total_weight = u128::from(self.validators.total_weight());
+        for precommit in &precommits {
+            let weight = u128::from(self.validators.weight(precommit.creator));
+            #[allow(clippy::integer_arithmetic)] // The weight is at most the (nonzero) total.
+            let reward = (u128::from(BLOCK_REWARD) * weight / total_weight) as u64;
+            let entry = self.rewards.entry(precommit.creator).or_default();
+            *entry = entry.saturating_add(reward);
+        }
+
+        let height = self.height;
+        let timestamp = value.timestamp();
+        let terminal =
+            height.saturating_add(1) >= self.minimum_era_height && timestamp >= self.era_end_time;
+        let terminal_block_data = if terminal {
+            Some(self.terminal_block_data())
+        } else {
+            None
+        };
+        // Every equivocator is only reported in the first block finalized after its evidence.
+        let new_equivocators: Vec<ValidatorIndex> = self
+            .evidence
+            .keys()
+            .filter(|idx| !self.reported_equivocators.contains(idx))
+            .copied()
+            .collect();
+        self.reported_equivocators
+            .extend(new_equivocators.iter().copied());
+        let equivocators = new_equivocators
+            .into_iter()
+            .filter_map(|idx| self.validators.id(idx))
+            .cloned()
+            .collect();
+        info!(height, round, ?hash, terminal, "finalized value");
+        let fb = FinalizedBlock {
+            value: value.clone(),
+            timestamp,
+            height,
+            equivocators,
+            terminal_block_data,
+            proposer,
+        };
+        let mut outcomes = vec![ProtocolOutcome::FinalizedBlock(fb)];
+
+        self.finalized.push(FinalizedEntry {
+            value,
+            proposal,
+            precommits,
+        });
+        self.height = height.saturating_add(1);
+        self.heights = self.heights.split_off(&self.height);
+        self.lock = None;
+        self.requested_block = None;
+        if terminal {
+            self.finished = true;
+        } else {
+            outcomes.extend(self.enter_round(0, now));
+        }
+        Some(outcomes)
+    }
+
+    /// Returns the rewards and inactive validators, to be included in the terminal block.
+    fn terminal_block_data(&self) -> TerminalBlockData<C> {
+        let rewards = self
+            .rewards
+            .iter()
+            .filter_map(|(idx, reward)| Some((self.validators.id(*idx)?.clone(), *reward)))
+            .collect();
+        let inactive_validators = self
+            .validators
+            .enumerate_ids()
+            .filter(|(idx, _)| !self.participants.contains(idx) && !self.faulty.contains(idx))
+            .map(|(_, vid)| vid.clone())
+            .collect();
+        TerminalBlockData {
+            rewards,
+            inactive_validators,
+        }
+    }
+
+    /// Moves on to a later round if more than a third of the weight has already voted in it,
+    /// since that means at least one honest validator's timeout has passed. Returns `None` if
+    /// the state didn't change.
+    fn catch_up_round(&mut self, now: Timestamp) -> Option<ProtocolOutcomes<I, C>> {
+        if self.finished || self.evidence_only {
+            return None;
+        }
+        let state = self.heights.get(&self.height)?;
+        let round = state
+            .vote_rounds()
+            .into_iter()
+            .rev()
+            .take_while(|round| *round > self.round)
+            .find(|round| self.exceeds_third(state.round_weight(*round, &self.validators)))?;
+        debug!(height = self.height, round, "catching up with later round");
+        Some(self.enter_round(round, now))
+    }
+
+    /// If we are the current round's leader, proposes a value: either the latest one that got a
+    /// quorum of prevotes, or a new block. Returns `None` if the state didn't change.
+    fn try_propose(&mut self, now: Timestamp) -> Option<ProtocolOutcomes<I, C>> {
+        let our_idx = self.our_idx()?;
+        if self.leader(self.height, self.round) != Some(our_idx)
+            || now < self.proposal_time
+            || self.requested_block == Some((self.height, self.round))
+        {
+            return None;
+        }
+        let round = self.round;
+        let valid_hash = match self.heights.get(&self.height) {
+            Some(state) if state.proposals.contains_key(&round) => return None,
+            Some(state) => state
+                .vote_rounds()
+                .into_iter()
+                .rev()
+                .filter(|r| *r < round)
+                .find_map(|r| {
+                    state.quorum_value(r, VoteKind::Prevote, &self.validators, self.quorum)
+                }),
+            None => None,
+        };
+        let valid_value = valid_hash
+            .or_else(|| self.lock.map(|(_, hash)| hash))
+            .and_then(|hash| self.acceptable_value(&hash, now));
+        if let Some(value) = valid_value {
+            return Some(self.create_message(Content::Proposal(value)));
+        }
+        self.requested_block = Some((self.height, self.round));
+        let block_context = BlockContext::new(now, self.height);
+        Some(vec![ProtocolOutcome::CreateNewBlock {
+            block_context,
+            past_values: vec![],
+            parent_value: self.parent().cloned(),
+        }])
+    }
+
+    /// Votes in the current round, if we haven't already: precommits a value with a quorum of
+    /// prevotes, or prevotes the leader's proposal. Returns `None` if the state didn't change.
+    fn try_vote(&mut self, now: Timestamp) -> Option<ProtocolOutcomes<I, C>> {
+        let our_idx = self.our_idx()?;
+        let round = self.round;
+        let state = self.heights.get(&self.height)?;
+
+        if !state.has_voted(round, VoteKind::Precommit, our_idx) {
+            if let Some(hash) =
+                state.quorum_value(round, VoteKind::Prevote, &self.validators, self.quorum)
+            {
+                if self.acceptable_value(&hash, now).is_some() {
+                    self.lock = Some((round, hash));
+                    return Some(self.create_message(Content::Vote(VoteKind::Precommit, hash)));
+                }
+            }
+        }
+
+        if state.has_voted(round, VoteKind::Prevote, our_idx) {
+            return None;
+        }
+        let value = state.proposals.get(&round)?.proposal()?;
+        let hash = value.hash();
+        if !self.is_acceptable(value, now) {
+            return None;
+        }
+        // If we are locked on a different value, we only unlock if there was a quorum of
+        // prevotes for this one in a later round.
+        let lock_allows = match self.lock {
+            None => true,
+            Some((_, lock_hash)) if lock_hash == hash => true,
+            Some((lock_round, _)) => (lock_round.saturating_add(1)..round).any(|r| {
+                state.quorum_value(r, VoteKind::Prevote, &self.validators, self.quorum)
+                    == Some(hash)
+            }),
+        };
+        if !lock_allows {
+            return None;
+        }
+        Some(self.create_message(Content::Vote(VoteKind::Prevote, hash)))
+    }
+
+    /// Requests validation of the current height's proposals that we haven't validated yet.
+    fn request_validation(&mut self) -> ProtocolOutcomes<I, C> {
+        let state = match self.heights.get(&self.height) {
+            Some(state) => state,
+            None => return vec![],
+        };
+        let mut outcomes = vec![];
+        for proposal in state.proposals.values() {
+            let value = match proposal.proposal() {
+                Some(value) if value.needs_validation() => value,
+                _ => continue,
+            };
+            let hash = value.hash();
+            if self.valid_values.contains(&hash)
+                || self.invalid_values.contains(&hash)
+                || self.pending_values.contains(&hash)
+            {
+                continue;
+            }
+            let sender = match self.value_senders.get(&hash) {
+                Some(sender) => sender.clone(),
+                None => continue,
+            };
+            self.pending_values.insert(hash);
+            outcomes.push(ProtocolOutcome::ValidateConsensusValue {
+                sender,
+                consensus_value: value.clone(),
+                ancestor_values: self
+                    .finalized
+                    .iter()
+                    .rev()
+                    .map(|entry| entry.value.clone())
+                    .collect(),
+            });
+        }
+        outcomes
+    }
+
+    /// Handles an incoming proposal or vote.
+    fn handle_signed_message(
+        &mut self,
+        sender: I,
+        msg: SignedMessage<C>,
+        now: Timestamp,
+    ) -> Result<ProtocolOutcomes<I, C>, MessageError> {
+        msg.validate(&self.instance_id, &self.validators, self.signature_format)?;
+        if msg.proposal().is_some() && self.leader(msg.height, msg.round) != Some(msg.creator) {
+            return Err(MessageError::Leader);
+        }
+        if msg.height < self.height {
+            trace!(height = msg.height, "ignoring message for finalized height");
+            return Ok(vec![]);
+        }
+        if msg.height > self.height.saturating_add(MAX_FUTURE_HEIGHTS)
+            || (msg.height == self.height
+                && msg.round > self.round.saturating_add(MAX_FUTURE_ROUNDS))
+        {
+            debug!(
+                height = msg.height,
+                round = msg.round,
+                "dropping message too far in the future"
+            );
+            return Ok(vec![]);
+        }
+        let mut outcomes = vec![];
+        if msg.height > self.height && !self.sync_requested {
+            // We seem to be missing some messages. Ask the sender for them.
+            self.sync_requested = true;
+            let request = Message::<C>::SyncRequest {
+                height: self.height,
+            };
+            outcomes.push(ProtocolOutcome::CreatedTargetedMessage(
+                request.serialize(),
+                sender.clone(),
+            ));
+        }
+        if let Some(value) = msg.proposal() {
+            self.value_senders.entry(value.hash()).or_insert(sender);
+        }
+        outcomes.extend(self.store_message(msg));
+        outcomes.extend(self.update(now));
+        Ok(outcomes)
+    }
+
+    /// Returns the messages the sender needs to catch up from the given height, covering at most
+    /// `MAX_SYNC_HEIGHTS` heights.
+    fn handle_sync_request(&self, sender: I, height: u64) -> ProtocolOutcomes<I, C> {
+        let end_height = height.saturating_add(MAX_SYNC_HEIGHTS);
+        let finalized = self
+            .finalized
+            .iter()
+            .skip(height as usize)
+            .take(MAX_SYNC_HEIGHTS as usize)
+            .flat_map(|entry| std::iter::once(&entry.proposal).chain(&entry.precommits));
+        let pending = self
+            .heights
+            .range(height..end_height)
+            .flat_map(|(_, state)| state.messages());
+        let signed = finalized
+            .chain(pending)
+            .map(|msg| Message::Signed(msg.clone()));
+        let evidence = self
+            .evidence
+            .values()
+            .map(|evidence| Message::Evidence(evidence.clone()));
+        signed
+            .chain(evidence)
+            .map(|msg| ProtocolOutcome::CreatedTargetedMessage(msg.serialize(), sender.clone()))
+            .collect()
+    }
+}
+
+impl<I, C> ConsensusProtocol<I, C> for SimpleBftProtocol<I, C>
+where
+    I: NodeIdT,
+    C: Context + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn handle_message(
+        &mut self,
+        sender: I,
+        msg: Vec<u8>,
+        now: Timestamp,
+    ) -> ProtocolOutcomes<I, C> {
+        let message: Message<C> = match bincode::deserialize(msg.as_slice()) {
+            Ok(message) => message,
+            Err(err) => {
+                return vec![ProtocolOutcome::InvalidIncomingMessage(
+                    msg,
+                    sender,
+                    err.into(),
+                )];
+            }
+        };
+        self.received_messages = true;
+        let result = match message {
+            Message::Evidence(evidence) => evidence
+                .validate(&self.instance_id, &self.validators, self.signature_format)
+                .map(|()| self.add_evidence(evidence)),
+            _ if self.evidence_only => Ok(vec![]),
+            Message::Signed(signed_msg) => {
+                self.handle_signed_message(sender.clone(), signed_msg, now)
+            }
+            Message::SyncRequest { height } => Ok(self.handle_sync_request(sender.clone(), height)),
+        };
+        match result {
+            Ok(outcomes) => outcomes,
+            Err(err) => vec![ProtocolOutcome::InvalidIncomingMessage(
+                msg,
+                sender,
+                err.into(),
+            )],
+        }
+    }
+
+    fn handle_is_current(&self) -> ProtocolOutcomes<I, C> {
+        // Ask our peers for the messages in the current height that we might have missed.
+        let msg = Message::<C>::SyncRequest {
+            height: self.height,
+        };
+        vec![ProtocolOutcome::CreatedGossipMessage(msg.serialize())]
+    }
+
+    fn handle_timer(&mut self, now: Timestamp, timer_id: TimerId) -> ProtocolOutcomes<I, C> {
+        if self.finished || self.evidence_only {
+            return vec![];
+        }
+        match timer_id {
+            TIMER_ID_ROUND_TIMEOUT => {
+                if now < self.round_deadline {
+                    return vec![]; // The timer belongs to an earlier round.
+                }
+                debug!(height = self.height, round = self.round, "round timed out");
+                let mut outcomes = self.enter_round(self.round.saturating_add(1), now);
+                outcomes.extend(self.handle_is_current());
+                outcomes.extend(self.update(now));
+                outcomes
+            }
+            TIMER_ID_PROPOSE => self.update(now),
+            _ => unreachable!("unexpected timer ID"),
+        }
+    }
+
+    fn handle_action(&mut self, _action_id: ActionId, _now: Timestamp) -> ProtocolOutcomes<I, C> {
+        unreachable!("simple BFT doesn't queue actions")
+    }
+
+    fn propose(
+        &mut self,
+        value: C::ConsensusValue,
+        block_context: BlockContext,
+        now: Timestamp,
+    ) -> ProtocolOutcomes<I, C> {
+        if self.requested_block != Some((block_context.height(), self.round))
+            || self.our_idx().is_none()
+        {
+            warn!(?block_context, "ignoring proposal for an outdated round");
+            return vec![];
+        }
+        self.valid_values.insert(value.hash());
+        let mut outcomes = self.create_message(Content::Proposal(value));
+        outcomes.extend(self.update(now));
+        outcomes
+    }
+
+    fn resolve_validity(
+        &mut self,
+        value: &C::ConsensusValue,
+        valid: bool,
+        now: Timestamp,
+    ) -> ProtocolOutcomes<I, C> {
+        let hash = value.hash();
+        self.pending_values.remove(&hash);
+        if valid {
+            self.valid_values.insert(hash);
+            self.update(now)
+        } else {
+            warn!(?hash, "proposed value is invalid");
+            self.invalid_values.insert(hash);
+            vec![]
+        }
+    }
+
+    fn handle_signature(
+        &mut self,
+        hash: C::Hash,
+        _maybe_signature: Option<C::Signature>,
+        _now: Timestamp,
+    ) -> ProtocolOutcomes<I, C> {
+        // Simple BFT signs its messages itself and never requests signatures.
+        warn!(?hash, "unexpected signature");
+        vec![]
+    }
+
+    fn activate_validator(
+        &mut self,
+        our_id: C::ValidatorId,
+        secret: C::ValidatorSecret,
+        now: Timestamp,
+        _unit_hash_file: Option<PathBuf>,
+    ) -> ProtocolOutcomes<I, C> {
+        let idx = match self.validators.get_index(&our_id) {
+            Some(idx) if !self.faulty.contains(&idx) => idx,
+            _ => {
+                warn!(
+                    ?our_id,
+                    "not activating: not a correct validator in this era"
+                );
+                return vec![];
+            }
+        };
+        self.active_validator = Some(ActiveValidator { idx, secret });
+        self.update(now)
+    }
+
+    fn deactivate_validator(&mut self) {
+        self.active_validator = None;
+    }
+
+    fn set_evidence_only(&mut self) {
+        self.heights.clear();
+        self.finalized.clear();
+        self.value_senders.clear();
+        self.pending_values.clear();
+        self.valid_values.clear();
+        self.invalid_values.clear();
+        self.evidence_only = true;
+    }
+
+    fn has_evidence(&self, vid: &C::ValidatorId) -> bool {
+        self.validators
+            .get_index(vid)
+            .map_or(false, |idx| self.evidence.contains_key(&idx))
+    }
+
+    /// Always returns `None`: `EraFault`s are verified as Highway evidence, so this protocol's
+    /// evidence is not persisted. It is still gossiped and served via `request_evidence`.
+    fn serialized_evidence(&self, _vid: &C::ValidatorId) -> Option<SerializedEvidence<C>> {
+        None
+    }
+
+    fn mark_faulty(&mut self, vid: &C::ValidatorId) {
+        if let Some(idx) = self.validators.get_index(vid) {
+            self.faulty.insert(idx);
+        }
+    }
+
+    fn request_evidence(&self, sender: I, vid: &C::ValidatorId) -> ProtocolOutcomes<I, C> {
+        self.validators
+            .get_index(vid)
+            .and_then(|idx| self.evidence.get(&idx))
+            .map(|evidence| {
+                let msg = Message::Evidence(evidence.clone());
+                ProtocolOutcome::CreatedTargetedMessage(msg.serialize(), sender)
+            })
+            .into_iter()
+            .collect()
+    }
+
+    /// Sets the pause status: While paused we don't propose or vote.
+    fn set_paused(&mut self, paused: bool) {
+        self.paused = paused;
+    }
+
+    fn validators_with_evidence(&self) -> Vec<&C::ValidatorId> {
+        self.evidence
+            .keys()
+            .filter_map(|idx| self.validators.id(*idx))
+            .collect()
+    }
+
+    fn has_received_messages(&self) -> bool {
+        self.received_messages
+    }
+
+    fn is_active(&self) -> bool {
+        self.active_validator.is_some()
+    }
+
+    fn instance_id(&self) -> &C::InstanceId {
+        &self.instance_id
+    }
+
+    fn next_round_length(&self) -> Option<TimeDiff> {
+        Some(self.round_timeout(self.round))
+    }
+}
+
+/// Returns the minimum weight of a quorum: more than two thirds of the total weight.
+fn quorum_weight(total_weight: Weight) -> Weight {
+    #[allow(clippy::integer_arithmetic)] // The total weight is a u64, so this can't overflow.
+    let quorum = u128::from(total_weight) * 2 / 3 + 1;
+    Weight(quorum as u64)
+}
@@ -0,0 +1,212 @@
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    components::consensus::{
+        highway_core::validators::{ValidatorIndex, Validators},
+        traits::{ConsensusValueT, Context, ValidatorSecret},
+    },
+    crypto::signer::SignatureFormat,
+};
+
+/// The hash of a consensus value.
+pub(super) type ValueHash<C> = <<C as Context>::ConsensusValue as ConsensusValueT>::Hash;
+
+/// An error due to an invalid incoming message.
+#[derive(Debug, Error, PartialEq)]
+pub(crate) enum MessageError {
+    #[error("The message is for a different consensus protocol instance.")]
+    InstanceId,
+    #[error("The creator is not a validator.")]
+    Creator,
+    #[error("The signature is invalid.")]
+    Signature,
+    #[error("The proposal was not created by the round's leader.")]
+    Leader,
+    #[error("The evidence consists of two messages that don't conflict.")]
+    NotConflicting,
+}
+
+/// The two phases of voting in a round.
+#[derive(
+    Clone, Copy, DataSize, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+pub(super) enum VoteKind {
+    /// A vote for a proposal the validator considers valid.
+    Prevote,
+    /// A vote for a value that received a quorum of prevotes in the same round.
+    Precommit,
+}
+
+/// The signed content of a message.
+#[derive(Clone, DataSize, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "C::ConsensusValue: Serialize",
+    deserialize = "C::ConsensusValue: Deserialize<'de>",
+))]
+pub(super) enum Content<C: Context> {
+    /// The leader's proposed value for the round.
+    Proposal(C::ConsensusValue),
+    /// A vote for the value with the given hash.
+    Vote(VoteKind, ValueHash<C>),
+}
+
+/// A proposal or vote, signed by its creator.
+#[derive(Clone, DataSize, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "C::ConsensusValue: Serialize",
+    deserialize = "C::ConsensusValue: Deserialize<'de>",
+))]
+pub(super) struct SignedMessage<C: Context> {
+    pub(super) instance_id: C::InstanceId,
+    pub(super) height: u64,
+    pub(super) round: u32,
+    pub(super) creator: ValidatorIndex,
+    pub(super) content: Content<C>,
+    pub(super) signature: C::Signature,
+}
+
+impl<C: Context> SignedMessage<C> {
+    /// Creates and signs a new message, or returns `None` if signing failed.
+    pub(super) fn create(
+        instance_id: C::InstanceId,
+        height: u64,
+        round: u32,
+        creator: ValidatorIndex,
+        content: Content<C>,
+        secret: &C::ValidatorSecret,
+    ) -> Option<Self> {
+        let hash = Self::hash_of(&instance_id, height, round, creator, &content);
+        let signature = secret.sign(&hash)?;
+        Some(SignedMessage {
+            instance_id,
+            height,
+            round,
+            creator,
+            content,
+            signature,
+        })
+    }
+
+    /// Checks that the message belongs to this instance and was signed by its creator.
+    pub(super) fn validate(
+        &self,
+        instance_id: &C::InstanceId,
+        validators: &Validators<C::ValidatorId>,
+        signature_format: SignatureFormat,
+    ) -> Result<(), MessageError> {
+        if self.instance_id != *instance_id {
+            return Err(MessageError::InstanceId);
+        }
+        let creator = validators.id(self.creator).ok_or(MessageError::Creator)?;
+        let hash = Self::hash_of(
+            &self.instance_id,
+            self.height,
+            self.round,
+            self.creator,
+            &self.content,
+        );
+        if !C::verify_signature(&hash, creator, &self.signature, signature_format) {
+            return Err(MessageError::Signature);
+        }
+        Ok(())
+    }
+
+    /// Returns the proposed value, if this is a proposal.
+    pub(super) fn proposal(&self) -> Option<&C::ConsensusValue> {
+        match &self.content {
+            Content::Proposal(value) => Some(value),
+            Content::Vote(..) => None,
+        }
+    }
+
+    /// Returns the kind of vote and the value hash, if this is a vote.
+    pub(super) fn vote(&self) -> Option<(VoteKind, &ValueHash<C>)> {
+        match &self.content {
+            Content::Proposal(_) => None,
+            Content::Vote(kind, hash) => Some((*kind, hash)),
+        }
+    }
+
+    /// Returns whether `other` is a different message in the same slot, i.e. a proposal or vote
+    /// of the same kind, by the same creator, for the same height and round.
+    ///
+    /// An honest validator never signs two conflicting messages.
+    pub(super) fn conflicts_with(&self, other: &SignedMessage<C>) -> bool {
+        let same_kind = match (&self.content, &other.content) {
+            (Content::Proposal(_), Content::Proposal(_)) => true,
+            (Content::Vote(kind0, _), Content::Vote(kind1, _)) => kind0 == kind1,
+            (Content::Proposal(_), Content::Vote(..))
+            | (Content::Vote(..), Content::Proposal(_)) => false,
+        };
+        same_kind
+            && self.instance_id == other.instance_id
+            && self.height == other.height
+            && self.round == other.round
+            && self.creator == other.creator
+            && self.content != other.content
+    }
+
+    fn hash_of(
+        instance_id: &C::InstanceId,
+        height: u64,
+        round: u32,
+        creator: ValidatorIndex,
+        content: &Content<C>,
+    ) -> C::Hash {
+        let data = (instance_id, height, round, creator, content);
+        C::hash(&bincode::serialize(&data).expect("should serialize message content"))
+    }
+}
+
+/// Proof that a validator signed two conflicting messages.
+#[derive(Clone, DataSize, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "C::ConsensusValue: Serialize",
+    deserialize = "C::ConsensusValue: Deserialize<'de>",
+))]
+pub(super) struct Evidence<C: Context>(pub(super) SignedMessage<C>, pub(super) SignedMessage<C>);
+
+impl<C: Context> Evidence<C> {
+    /// Returns the index of the faulty validator.
+    pub(super) fn perpetrator(&self) -> ValidatorIndex {
+        self.0.creator
+    }
+
+    /// Checks that both messages are valid and conflict with each other.
+    pub(super) fn validate(
+        &self,
+        instance_id: &C::InstanceId,
+        validators: &Validators<C::ValidatorId>,
+        signature_format: SignatureFormat,
+    ) -> Result<(), MessageError> {
+        if !self.0.conflicts_with(&self.1) {
+            return Err(MessageError::NotConflicting);
+        }
+        self.0.validate(instance_id, validators, signature_format)?;
+        self.1.validate(instance_id, validators, signature_format)
+    }
+}
+
+/// A message sent over the network.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "C::ConsensusValue: Serialize",
+    deserialize = "C::ConsensusValue: Deserialize<'de>",
+))]
+pub(super) enum Message<C: Context> {
+    /// A proposal or vote.
+    Signed(SignedMessage<C>),
+    /// Evidence that a validator equivocated.
+    Evidence(Evidence<C>),
+    /// A request for the messages the recipient has for the given height and a limited number of
+    /// later ones.
+    SyncRequest { height: u64 },
+}
+
+impl<C: Context> Message<C> {
+    pub(super) fn serialize(&self) -> Vec<u8> {
+        bincode::serialize(self).expect("should serialize message")
+    }
+}
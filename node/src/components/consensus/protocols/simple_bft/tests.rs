@@ -0,0 +1,272 @@
+use std::{collections::HashSet, sync::Arc};
+
+use datasize::DataSize;
+use derive_more::Display;
+
+use casper_types::{PublicKey, U512};
+
+use super::{
+    message::{Content, Message, SignedMessage, VoteKind},
+    SimpleBftProtocol, MAX_SYNC_HEIGHTS, TIMER_ID_PROPOSE,
+};
+use crate::{
+    components::consensus::{
+        candidate_block::CandidateBlock,
+        cl_context::{ClContext, Keypair},
+        config::Config,
+        consensus_protocol::{ConsensusProtocol, FinalizedBlock, ProtocolOutcome},
+        tests::utils::{
+            new_test_chainspec, ALICE_PUBLIC_KEY, ALICE_SECRET_KEY, BOB_PRIVATE_KEY, BOB_PUBLIC_KEY,
+        },
+        traits::{ConsensusValueT, Context},
+    },
+    crypto::signer::SignatureFormat,
+    types::{Chainspec, ProtoBlock, Timestamp},
+};
+
+#[derive(DataSize, Debug, Ord, PartialOrd, Copy, Clone, Display, Hash, Eq, PartialEq)]
+struct NodeId(u8);
+
+const INSTANCE_ID_DATA: &[u8; 1] = &[123u8; 1];
+
+fn new_test_simple_bft_protocol(
+    weights: Vec<(PublicKey, U512)>,
+) -> Box<dyn ConsensusProtocol<NodeId, ClContext>> {
+    let chainspec = new_test_chainspec(weights.clone());
+    new_test_simple_bft_protocol_with_chainspec(weights, &chainspec)
+}
+
+fn new_test_simple_bft_protocol_with_chainspec(
+    weights: Vec<(PublicKey, U512)>,
+    chainspec: &Chainspec,
+) -> Box<dyn ConsensusProtocol<NodeId, ClContext>> {
+    let (sbft, _outcomes) = SimpleBftProtocol::<NodeId, ClContext>::new_boxed(
+        ClContext::hash(INSTANCE_ID_DATA),
+        weights.into_iter().collect(),
+        &HashSet::new(),
+        &chainspec.into(),
+        &Config::default(),
+        None,
+        0.into(),
+        0,
+        SignatureFormat::DomainSeparated,
+        0.into(),
+    );
+    sbft
+}
+
+#[test]
+fn handle_message_parse_error() {
+    let mut sbft = new_test_simple_bft_protocol(vec![(ALICE_PUBLIC_KEY.clone(), 100.into())]);
+    let sender = NodeId(123);
+    let msg = vec![];
+    let mut outcomes = sbft.handle_message(sender, msg.clone(), Timestamp::zero());
+    assert_eq!(outcomes.len(), 1);
+    match outcomes.pop() {
+        Some(ProtocolOutcome::InvalidIncomingMessage(invalid_msg, offending_sender, _err)) => {
+            assert_eq!(
+                invalid_msg, msg,
+                "Invalid message is not message that was sent."
+            );
+            assert_eq!(offending_sender, sender, "Unexpected sender.");
+        }
+        outcome => panic!("Unexpected protocol outcome {:?}", outcome),
+    }
+}
+
+#[test]
+fn single_validator_finalizes_own_proposal() {
+    let mut sbft = new_test_simple_bft_protocol(vec![(ALICE_PUBLIC_KEY.clone(), 100.into())]);
+    let now = Timestamp::zero();
+    let alice_keypair = Keypair::from(Arc::new(ALICE_SECRET_KEY.clone()));
+    let outcomes = sbft.activate_validator(ALICE_PUBLIC_KEY.clone(), alice_keypair, now, None);
+    let block_context = outcomes
+        .iter()
+        .find_map(|outcome| match outcome {
+            ProtocolOutcome::CreateNewBlock { block_context, .. } => Some(block_context.clone()),
+            _ => None,
+        })
+        .expect("the only validator should be asked for a block");
+    assert_eq!(0, block_context.height());
+
+    let proto_block = ProtoBlock::new(vec![], vec![], block_context.timestamp(), false);
+    let value = CandidateBlock::new(proto_block, vec![], None);
+    let outcomes = sbft.propose(value.clone(), block_context, now);
+    let finalized = outcomes
+        .iter()
+        .find_map(|outcome| match outcome {
+            ProtocolOutcome::FinalizedBlock(fb) => Some(fb),
+            _ => None,
+        })
+        .expect("the proposal should be finalized immediately");
+    assert_eq!(value, finalized.value);
+    assert_eq!(0, finalized.height);
+    assert_eq!(*ALICE_PUBLIC_KEY, finalized.proposer);
+    assert!(finalized.terminal_block_data.is_none());
+}
+
+#[test]
+fn conflicting_votes_are_evidence() {
+    let weights = vec![
+        (ALICE_PUBLIC_KEY.clone(), 100.into()),
+        (BOB_PUBLIC_KEY.clone(), 100.into()),
+    ];
+    let mut sbft = new_test_simple_bft_protocol(weights);
+    let instance_id = ClContext::hash(INSTANCE_ID_DATA);
+    let bob_idx = sbft
+        .as_any()
+        .downcast_ref::<SimpleBftProtocol<NodeId, ClContext>>()
+        .unwrap()
+        .validators
+        .get_index(&BOB_PUBLIC_KEY)
+        .unwrap();
+    let bob_keypair = Keypair::from(Arc::new(BOB_PRIVATE_KEY.clone()));
+    let vote = |data: &[u8]| {
+        let content = Content::Vote(VoteKind::Prevote, ClContext::hash(data));
+        let signed = SignedMessage::create(instance_id, 0, 0, bob_idx, content, &bob_keypair)
+            .expect("signing should succeed");
+        Message::Signed(signed).serialize()
+    };
+
+    let now = Timestamp::zero();
+    let sender = NodeId(1);
+    let outcomes = sbft.handle_message(sender, vote(&[1]), now);
+    assert!(!outcomes
+        .iter()
+        .any(|outcome| matches!(outcome, ProtocolOutcome::NewEvidence(_))));
+    assert!(!sbft.has_evidence(&BOB_PUBLIC_KEY));
+
+    let outcomes = sbft.handle_message(sender, vote(&[2]), now);
+    assert!(outcomes.iter().any(
+        |outcome| matches!(outcome, ProtocolOutcome::NewEvidence(vid) if *vid == *BOB_PUBLIC_KEY)
+    ));
+    assert!(sbft.has_evidence(&BOB_PUBLIC_KEY));
+    assert!(!sbft.has_evidence(&ALICE_PUBLIC_KEY));
+    // Simple BFT evidence can't be verified as an `EraFault`, so it must not be persisted.
+    assert!(sbft.serialized_evidence(&BOB_PUBLIC_KEY).is_none());
+}
+
+/// Waits for the `CreateNewBlock` outcome, proposes a value and returns the finalized block and
+/// the time of the proposal.
+fn propose_and_finalize(
+    sbft: &mut Box<dyn ConsensusProtocol<NodeId, ClContext>>,
+    mut outcomes: Vec<ProtocolOutcome<NodeId, ClContext>>,
+    mut now: Timestamp,
+) -> (
+    FinalizedBlock<ClContext>,
+    Vec<ProtocolOutcome<NodeId, ClContext>>,
+    Timestamp,
+) {
+    let (block_context, parent_value) = loop {
+        let maybe_create = outcomes.iter().find_map(|outcome| match outcome {
+            ProtocolOutcome::CreateNewBlock {
+                block_context,
+                parent_value,
+                ..
+            } => Some((block_context.clone(), parent_value.clone())),
+            _ => None,
+        });
+        if let Some(create) = maybe_create {
+            break create;
+        }
+        now = outcomes
+            .iter()
+            .find_map(|outcome| match outcome {
+                ProtocolOutcome::ScheduleTimer(timestamp, TIMER_ID_PROPOSE) => Some(*timestamp),
+                _ => None,
+            })
+            .expect("should schedule the proposal");
+        outcomes = sbft.handle_timer(now, TIMER_ID_PROPOSE);
+    };
+    let proto_block = ProtoBlock::new(vec![], vec![], block_context.timestamp(), false);
+    let parent = parent_value.map(|value| value.hash());
+    let value = CandidateBlock::new(proto_block, vec![], parent);
+    let outcomes = sbft.propose(value, block_context, now);
+    let finalized = outcomes
+        .iter()
+        .find_map(|outcome| match outcome {
+            ProtocolOutcome::FinalizedBlock(fb) => Some(fb.clone()),
+            _ => None,
+        })
+        .expect("the proposal should be finalized immediately");
+    (finalized, outcomes, now)
+}
+
+#[test]
+fn equivocators_are_reported_only_once() {
+    // Alice has enough weight to finalize values on her own.
+    let weights = vec![
+        (ALICE_PUBLIC_KEY.clone(), 100.into()),
+        (BOB_PUBLIC_KEY.clone(), 1.into()),
+    ];
+    let mut sbft = new_test_simple_bft_protocol(weights);
+    let instance_id = ClContext::hash(INSTANCE_ID_DATA);
+    let bob_idx = sbft
+        .as_any()
+        .downcast_ref::<SimpleBftProtocol<NodeId, ClContext>>()
+        .unwrap()
+        .validators
+        .get_index(&BOB_PUBLIC_KEY)
+        .unwrap();
+    let bob_keypair = Keypair::from(Arc::new(BOB_PRIVATE_KEY.clone()));
+    let vote = |data: &[u8]| {
+        let content = Content::Vote(VoteKind::Prevote, ClContext::hash(data));
+        let signed = SignedMessage::create(instance_id, 0, 0, bob_idx, content, &bob_keypair)
+            .expect("signing should succeed");
+        Message::Signed(signed).serialize()
+    };
+
+    let now = Timestamp::zero();
+    let sender = NodeId(1);
+    sbft.handle_message(sender, vote(&[1]), now);
+    sbft.handle_message(sender, vote(&[2]), now);
+    assert!(sbft.has_evidence(&BOB_PUBLIC_KEY));
+
+    let alice_keypair = Keypair::from(Arc::new(ALICE_SECRET_KEY.clone()));
+    let outcomes = sbft.activate_validator(ALICE_PUBLIC_KEY.clone(), alice_keypair, now, None);
+    let (finalized, outcomes, now) = propose_and_finalize(&mut sbft, outcomes, now);
+    assert_eq!(0, finalized.height);
+    assert_eq!(vec![BOB_PUBLIC_KEY.clone()], finalized.equivocators);
+
+    let (finalized, _, _) = propose_and_finalize(&mut sbft, outcomes, now);
+    assert_eq!(1, finalized.height);
+    assert!(finalized.equivocators.is_empty());
+}
+
+#[test]
+fn sync_response_is_limited() {
+    let weights = vec![(ALICE_PUBLIC_KEY.clone(), 100.into())];
+    let mut chainspec = new_test_chainspec(weights.clone());
+    let heights = MAX_SYNC_HEIGHTS + 2;
+    chainspec.core_config.minimum_era_height = heights + 1;
+    let mut sbft = new_test_simple_bft_protocol_with_chainspec(weights, &chainspec);
+
+    let mut now = Timestamp::zero();
+    let alice_keypair = Keypair::from(Arc::new(ALICE_SECRET_KEY.clone()));
+    let mut outcomes = sbft.activate_validator(ALICE_PUBLIC_KEY.clone(), alice_keypair, now, None);
+    for height in 0..heights {
+        let (finalized, new_outcomes, new_now) = propose_and_finalize(&mut sbft, outcomes, now);
+        assert_eq!(height, finalized.height);
+        outcomes = new_outcomes;
+        now = new_now;
+    }
+
+    let sender = NodeId(1);
+    let mut count_sync_response = |height: u64| {
+        let request = Message::<ClContext>::SyncRequest { height }.serialize();
+        sbft.handle_message(sender, request, now)
+            .into_iter()
+            .filter(|outcome| {
+                matches!(outcome, ProtocolOutcome::CreatedTargetedMessage(_, peer) if *peer == sender)
+            })
+            .count()
+    };
+    // The last finalized height, and nothing after it.
+    let per_height = count_sync_response(heights - 1);
+    assert!(per_height > 0);
+    // Every height has the same messages, but only `MAX_SYNC_HEIGHTS` of them are sent.
+    assert_eq!(
+        per_height * MAX_SYNC_HEIGHTS as usize,
+        count_sync_response(0)
+    );
+}
@@ -2,5 +2,7 @@
 pub(crate) mod consensus_des_testing;
 /// Message queue.
 pub(crate) mod queue;
+/// Scenarios run against every consensus protocol implementation.
+pub(crate) mod scenarios;
 /// Miscellaneous code shared among consensus tests
 pub(crate) mod utils;
@@ -10,7 +10,7 @@ use super::queue::{MessageT, Queue, QueueEntry};
 use crate::types::Timestamp;
 
 /// Enum defining recipients of the message.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub(crate) enum Target {
     SingleValidator(ValidatorId),
     AllExcept(ValidatorId),
@@ -0,0 +1,870 @@
+//! Scenarios that every `ConsensusProtocol` implementation must pass, run in the discrete event
+//! simulator.
+//!
+//! Unlike `highway_testing`, which drives `Highway` directly, these tests only use the
+//! `ConsensusProtocol` interface, so the same scenarios run against every protocol that can be
+//! selected in the chainspec. Each `Scenario` declares the faulty validators, network partitions
+//! and clock skews, and checks the safety and liveness properties when it runs.
+
+#![allow(clippy::integer_arithmetic)] // In tests, overflows panic anyway.
+
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::DefaultHasher, BTreeMap, BTreeSet, HashSet, VecDeque},
+    hash::{Hash, Hasher},
+};
+
+use datasize::DataSize;
+use itertools::Itertools;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use tracing::{trace, warn};
+
+use casper_types::{PublicKey, U512};
+
+use super::{
+    consensus_des_testing::{Message, Node, Target, TargetedMessage, ValidatorId, VirtualNet},
+    queue::QueueEntry,
+    utils::new_test_chainspec,
+};
+use crate::{
+    components::consensus::{
+        config::{Config, ProtocolConfig},
+        consensus_protocol::{ConsensusProtocol, ProtocolOutcome, ProtocolOutcomes},
+        highway_core::state,
+        protocols::new_consensus_protocol,
+        traits::{ConsensusValueT, Context, ValidatorSecret},
+        ActionId, TimerId,
+    },
+    crypto::signer::SignatureFormat,
+    logging::{self, LoggingConfig, LoggingFormat},
+    types::{chainspec::ConsensusProtocolName, TimeDiff, Timestamp},
+    NodeRng,
+};
+
+const TEST_INSTANCE_ID: u64 = 42;
+const TEST_MIN_ROUND_EXP: u8 = 12;
+const TEST_MAX_ROUND_EXP: u8 = 13;
+const VALIDATOR_COUNT: u64 = 10;
+/// The number of values every correct node must finalize.
+const CV_COUNT: usize = 10;
+/// After a partition heals, every correct node must finalize a value within this many rounds.
+const MAX_ROUNDS_AFTER_HEAL: u64 = 10;
+/// The clock skew of the nodes whose clocks are off, in milliseconds.
+const CLOCK_SKEW: u64 = 1000;
+/// The range of network delays, in milliseconds.
+const MIN_DELAY: u64 = 10;
+const MAX_DELAY: u64 = 500;
+/// Tests fail if they haven't finished after this many milliseconds of simulated time.
+const TEST_TIMEOUT: u64 = 60 * 60 * 1000;
+
+/// A block in the simulated chain.
+#[derive(Clone, DataSize, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+struct TestValue {
+    height: u64,
+    timestamp: Timestamp,
+    parent: Option<u64>,
+    proposer: u64,
+    /// Distinguishes the values proposed by an equivocator's two instances.
+    instance: usize,
+}
+
+impl ConsensusValueT for TestValue {
+    type Hash = u64;
+
+    fn hash(&self) -> Self::Hash {
+        let mut hasher = DefaultHasher::new();
+        Hash::hash(self, &mut hasher);
+        hasher.finish()
+    }
+
+    fn needs_validation(&self) -> bool {
+        true
+    }
+
+    fn timestamp(&self) -> Timestamp {
+        self.timestamp
+    }
+
+    fn parent(&self) -> Option<&Self::Hash> {
+        self.parent.as_ref()
+    }
+}
+
+#[derive(Clone, DataSize, Debug, Eq, PartialEq, Hash)]
+struct TestSecret(u64);
+
+impl ValidatorSecret for TestSecret {
+    type Hash = u64;
+    type Signature = u64;
+
+    fn sign(&self, hash: &u64) -> Option<u64> {
+        Some(hash.wrapping_add(self.0))
+    }
+}
+
+#[derive(Clone, DataSize, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+struct TestContext;
+
+impl Context for TestContext {
+    type ConsensusValue = TestValue;
+    type ValidatorId = ValidatorId;
+    type ValidatorSecret = TestSecret;
+    type Signature = u64;
+    type Hash = u64;
+    type InstanceId = u64;
+
+    fn hash(data: &[u8]) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        hasher.write(data);
+        hasher.finish()
+    }
+
+    fn verify_signature(
+        hash: &u64,
+        public_key: &ValidatorId,
+        signature: &u64,
+        _format: SignatureFormat,
+    ) -> bool {
+        *signature == hash.wrapping_add(public_key.0)
+    }
+}
+
+/// A message in the simulated network.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+enum Payload {
+    /// A timer scheduled by one of the node's protocol instances.
+    Timer { instance: usize, timer_id: u8 },
+    /// An action queued by one of the node's protocol instances.
+    Action { instance: usize, action_id: u8 },
+    /// A serialized consensus protocol message from another node.
+    Protocol(Vec<u8>),
+}
+
+impl PartialOrd for Payload {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Payload {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let mut hasher0 = DefaultHasher::new();
+        let mut hasher1 = DefaultHasher::new();
+        self.hash(&mut hasher0);
+        other.hash(&mut hasher1);
+        hasher0.finish().cmp(&hasher1.finish())
+    }
+}
+
+type Protocol = Box<dyn ConsensusProtocol<ValidatorId, TestContext>>;
+
+/// How a faulty validator deviates from the protocol.
+#[derive(Clone, Copy, Debug)]
+enum Behavior {
+    /// The validator does not send any messages ever.
+    PermanentlyMute,
+    /// The validator does not send any messages within the interval between the timestamps.
+    TemporarilyMute { from: Timestamp, till: Timestamp },
+    /// The validator runs two instances with the same key, which sign conflicting messages.
+    Equivocate,
+    /// The validator holds back the messages it creates within the interval between the
+    /// timestamps, and sends them all at `till`.
+    Withhold { from: Timestamp, till: Timestamp },
+    /// The validator sends every message a second time, `delay` later.
+    Replay { delay: TimeDiff },
+}
+
+/// The offset of a node's clock from the simulated time.
+#[derive(Clone, Copy, Debug)]
+enum ClockSkew {
+    Ahead(TimeDiff),
+    Behind(TimeDiff),
+}
+
+impl ClockSkew {
+    /// Returns the node's local time at the simulated time `now`.
+    fn local_time(self, now: Timestamp) -> Timestamp {
+        match self {
+            ClockSkew::Ahead(diff) => now + diff,
+            ClockSkew::Behind(diff) => now.saturating_sub(diff),
+        }
+    }
+
+    /// Returns the simulated time at which the node's clock shows `local_time`.
+    fn sim_time(self, local_time: Timestamp) -> Timestamp {
+        match self {
+            ClockSkew::Ahead(diff) => local_time.saturating_sub(diff),
+            ClockSkew::Behind(diff) => local_time + diff,
+        }
+    }
+}
+
+/// A network partition: within the interval between the timestamps, all messages between the
+/// validators in `side` and the others are lost.
+#[derive(Clone, Debug)]
+struct Partition {
+    side: BTreeSet<ValidatorId>,
+    from: Timestamp,
+    till: Timestamp,
+}
+
+impl Partition {
+    /// Returns whether a message from `sender` to `recipient` that arrives at `now` is lost.
+    fn separates(&self, sender: ValidatorId, recipient: ValidatorId, now: Timestamp) -> bool {
+        self.from <= now
+            && now < self.till
+            && self.side.contains(&sender) != self.side.contains(&recipient)
+    }
+}
+
+/// A declarative description of a simulated network: the protocol, the faulty validators, the
+/// nodes' clocks and the network partitions.
+///
+/// Running a scenario checks that:
+/// * no two correct nodes ever finalize conflicting values,
+/// * after each partition heals, every correct node finalizes a new value within
+///   `MAX_ROUNDS_AFTER_HEAL` rounds,
+/// * all correct nodes eventually finalize the same `CV_COUNT` values, and
+/// * they only find evidence against equivocators.
+#[derive(Clone, Debug)]
+struct Scenario {
+    protocol: ConsensusProtocolName,
+    behaviors: BTreeMap<ValidatorId, Behavior>,
+    clock_skews: BTreeMap<ValidatorId, ClockSkew>,
+    partitions: Vec<Partition>,
+}
+
+impl Scenario {
+    /// Creates a scenario without any faults.
+    fn new(protocol: ConsensusProtocolName) -> Self {
+        Scenario {
+            protocol,
+            behaviors: BTreeMap::new(),
+            clock_skews: BTreeMap::new(),
+            partitions: vec![],
+        }
+    }
+
+    /// Makes the given validators faulty. They are given the minimum weight.
+    fn faulty<I: IntoIterator<Item = u64>>(mut self, vids: I, behavior: Behavior) -> Self {
+        let vids = vids.into_iter().map(ValidatorId);
+        self.behaviors.extend(vids.map(|vid| (vid, behavior)));
+        self
+    }
+
+    /// Sets the given validators' clocks off by `skew`.
+    fn clock_skew<I: IntoIterator<Item = u64>>(mut self, vids: I, skew: ClockSkew) -> Self {
+        let vids = vids.into_iter().map(ValidatorId);
+        self.clock_skews.extend(vids.map(|vid| (vid, skew)));
+        self
+    }
+
+    /// Cuts the given validators off from the others between the two timestamps.
+    fn partition<I: IntoIterator<Item = u64>>(
+        mut self,
+        side: I,
+        from: Timestamp,
+        till: Timestamp,
+    ) -> Self {
+        let side = side.into_iter().map(ValidatorId).collect();
+        self.partitions.push(Partition { side, from, till });
+        self
+    }
+
+    /// Runs the scenario and checks its safety and liveness properties.
+    fn run(&self) {
+        init_logging();
+        let mut rng = crate::new_rng();
+        let mut harness = ScenarioHarness::new(&mut rng, self);
+        let max_round_len = state::round_len(TEST_MAX_ROUND_EXP);
+        let heal_times: BTreeSet<Timestamp> = self
+            .partitions
+            .iter()
+            .map(|partition| partition.till)
+            .collect();
+        for heal_time in heal_times {
+            harness.crank_until_time(&mut rng, heal_time);
+            harness.assert_safety();
+            let healed_counts = harness.finalized_counts();
+            let deadline = heal_time + max_round_len * MAX_ROUNDS_AFTER_HEAL;
+            harness.crank_until_before(&mut rng, deadline, |harness| {
+                harness
+                    .finalized_counts()
+                    .iter()
+                    .zip(&healed_counts)
+                    .all(|(count, healed_count)| count > healed_count || *count >= CV_COUNT)
+            });
+        }
+        harness.crank_until_finalized(&mut rng);
+        harness.assert_consistent_chain();
+        let equivocators = self
+            .behaviors
+            .iter()
+            .filter(|(_, behavior)| matches!(behavior, Behavior::Equivocate))
+            .map(|(vid, _)| *vid)
+            .collect();
+        harness.assert_evidence_only_against(&equivocators);
+    }
+}
+
+/// A validator running one protocol instance, or two with the same key if it equivocates.
+struct ScenarioValidator {
+    instances: Vec<Protocol>,
+    behavior: Option<Behavior>,
+    clock_skew: Option<ClockSkew>,
+    /// The validators against which this node has seen evidence.
+    evidence: BTreeSet<ValidatorId>,
+}
+
+impl ScenarioValidator {
+    /// Returns whether the validator's outgoing network messages are dropped at `now`.
+    fn is_mute(&self, now: Timestamp) -> bool {
+        match self.behavior {
+            Some(Behavior::PermanentlyMute) => true,
+            Some(Behavior::TemporarilyMute { from, till }) => from <= now && now <= till,
+            None
+            | Some(Behavior::Equivocate)
+            | Some(Behavior::Withhold { .. })
+            | Some(Behavior::Replay { .. }) => false,
+        }
+    }
+
+    /// Returns the time at which the messages created at `now` are sent.
+    fn send_time(&self, now: Timestamp) -> Timestamp {
+        match self.behavior {
+            Some(Behavior::Withhold { from, till }) if from <= now && now < till => till,
+            _ => now,
+        }
+    }
+
+    /// Returns the delay after which every message is sent again, if the validator replays them.
+    fn replay_delay(&self) -> Option<TimeDiff> {
+        match self.behavior {
+            Some(Behavior::Replay { delay }) => Some(delay),
+            _ => None,
+        }
+    }
+
+    /// Returns the validator's local time at the simulated time `now`.
+    fn local_time(&self, now: Timestamp) -> Timestamp {
+        self.clock_skew.map_or(now, |skew| skew.local_time(now))
+    }
+
+    /// Returns the simulated time at which the validator's clock shows `local_time`.
+    fn sim_time(&self, local_time: Timestamp) -> Timestamp {
+        self.clock_skew
+            .map_or(local_time, |skew| skew.sim_time(local_time))
+    }
+}
+
+type ScenarioNode = Node<TestValue, Payload, ScenarioValidator>;
+
+struct ScenarioHarness {
+    virtual_net: VirtualNet<TestValue, Payload, ScenarioValidator>,
+    partitions: Vec<Partition>,
+}
+
+impl ScenarioHarness {
+    /// Creates a network of `VALIDATOR_COUNT` validators running the scenario's protocol.
+    fn new(rng: &mut NodeRng, scenario: &Scenario) -> Self {
+        let mut chainspec = new_test_chainspec(Vec::<(PublicKey, U512)>::new());
+        chainspec.core_config.consensus_protocol = scenario.protocol;
+        chainspec.core_config.minimum_era_height = CV_COUNT as u64;
+        chainspec.core_config.era_duration = 0.into();
+        chainspec.highway_config.minimum_round_exponent = TEST_MIN_ROUND_EXP;
+        chainspec.highway_config.maximum_round_exponent = TEST_MAX_ROUND_EXP;
+        let protocol_config = ProtocolConfig::from(&chainspec);
+        let config = Config::default();
+
+        // Faulty validators have the minimum weight, so that their share doesn't exceed their
+        // share of the validator count.
+        let stakes: BTreeMap<ValidatorId, U512> = (0..VALIDATOR_COUNT)
+            .map(|i| {
+                let vid = ValidatorId(i);
+                let weight = if scenario.behaviors.contains_key(&vid) {
+                    100
+                } else {
+                    rng.gen_range(100..120)
+                };
+                (vid, U512::from(weight))
+            })
+            .collect();
+
+        let mut initial_outcomes = vec![];
+        let nodes = stakes.keys().map(|vid| {
+            let behavior = scenario.behaviors.get(vid).copied();
+            let clock_skew = scenario.clock_skews.get(vid).copied();
+            let instance_count = if matches!(behavior, Some(Behavior::Equivocate)) {
+                2
+            } else {
+                1
+            };
+            let now =
+                clock_skew.map_or(Timestamp::zero(), |skew| skew.local_time(Timestamp::zero()));
+            let instances = (0..instance_count)
+                .map(|instance| {
+                    let (mut consensus, mut outcomes) = new_consensus_protocol::<_, TestContext>(
+                        TEST_INSTANCE_ID,
+                        stakes.clone(),
+                        &HashSet::new(),
+                        &protocol_config,
+                        &config,
+                        None,
+                        Timestamp::zero(),
+                        0,
+                        SignatureFormat::DomainSeparated,
+                        now,
+                    );
+                    outcomes.extend(consensus.activate_validator(
+                        *vid,
+                        TestSecret(vid.0),
+                        now,
+                        None,
+                    ));
+                    outcomes.extend(consensus.handle_is_current());
+                    initial_outcomes.push((*vid, instance, outcomes));
+                    consensus
+                })
+                .collect();
+            let validator = ScenarioValidator {
+                instances,
+                behavior,
+                clock_skew,
+                evidence: BTreeSet::new(),
+            };
+            Node::new(*vid, validator)
+        });
+        let nodes = nodes.collect_vec();
+        let mut harness = ScenarioHarness {
+            virtual_net: VirtualNet::new(nodes, vec![]),
+            partitions: scenario.partitions.clone(),
+        };
+        for (vid, instance, outcomes) in initial_outcomes {
+            let messages = harness.handle_outcomes(rng, vid, instance, outcomes, Timestamp::zero());
+            harness.virtual_net.dispatch_messages(messages);
+        }
+        harness
+    }
+
+    /// Delivers the next message in the queue, and dispatches the resulting messages.
+    fn crank(&mut self, rng: &mut NodeRng) {
+        let QueueEntry {
+            delivery_time,
+            recipient,
+            message,
+        } = self
+            .virtual_net
+            .pop_message()
+            .expect("protocol timers should keep the queue nonempty");
+        let sender = message.sender;
+        if self
+            .partitions
+            .iter()
+            .any(|partition| partition.separates(sender, recipient, delivery_time))
+        {
+            trace!("partition drops message from {} to {}", sender, recipient);
+            return;
+        }
+        trace!(
+            "delivering at {} to {}: {:?}",
+            delivery_time,
+            recipient,
+            message
+        );
+        let node = self.node_mut(recipient);
+        let now = node.validator().local_time(delivery_time);
+        let instance_outcomes: Vec<(usize, ProtocolOutcomes<ValidatorId, TestContext>)> =
+            match message.payload() {
+                Payload::Timer { instance, timer_id } => {
+                    let consensus = &mut node.validator_mut().instances[*instance];
+                    let outcomes = consensus.handle_timer(now, TimerId(*timer_id));
+                    vec![(*instance, outcomes)]
+                }
+                Payload::Action {
+                    instance,
+                    action_id,
+                } => {
+                    let consensus = &mut node.validator_mut().instances[*instance];
+                    let outcomes = consensus.handle_action(ActionId(*action_id), now);
+                    vec![(*instance, outcomes)]
+                }
+                Payload::Protocol(payload) => node
+                    .validator_mut()
+                    .instances
+                    .iter_mut()
+                    .enumerate()
+                    .map(|(instance, consensus)| {
+                        let outcomes = consensus.handle_message(sender, payload.clone(), now);
+                        (instance, outcomes)
+                    })
+                    .collect(),
+            };
+        for (instance, outcomes) in instance_outcomes {
+            let messages = self.handle_outcomes(rng, recipient, instance, outcomes, delivery_time);
+            self.virtual_net.dispatch_messages(messages);
+        }
+    }
+
+    /// Handles the outcomes of one of the node's protocol instances at the simulated time `now`,
+    /// and returns the resulting network messages with their delivery times.
+    fn handle_outcomes(
+        &mut self,
+        rng: &mut NodeRng,
+        vid: ValidatorId,
+        instance: usize,
+        outcomes: ProtocolOutcomes<ValidatorId, TestContext>,
+        now: Timestamp,
+    ) -> Vec<(TargetedMessage<Payload>, Timestamp)> {
+        let node = self.node_mut(vid);
+        let mute = node.validator().is_mute(now);
+        let local_now = node.validator().local_time(now);
+        let to_self = |payload: Payload| {
+            TargetedMessage::new(Message::new(vid, payload), Target::SingleValidator(vid))
+        };
+        let mut outcomes: VecDeque<_> = outcomes.into();
+        let mut outgoing = vec![];
+        let mut messages = vec![];
+        while let Some(outcome) = outcomes.pop_front() {
+            let consensus = &mut node.validator_mut().instances[instance];
+            match outcome {
+                ProtocolOutcome::CreatedGossipMessage(payload) if !mute => {
+                    outgoing.push((Target::AllExcept(vid), payload));
+                }
+                ProtocolOutcome::CreatedTargetedMessage(payload, recipient) if !mute => {
+                    outgoing.push((Target::SingleValidator(recipient), payload));
+                }
+                ProtocolOutcome::CreatedGossipMessage(_)
+                | ProtocolOutcome::CreatedTargetedMessage(..) => {
+                    trace!("{} is mute; dropping message", vid);
+                }
+                ProtocolOutcome::ScheduleTimer(timestamp, timer_id) => {
+                    let timer_id = timer_id.0;
+                    let sim_time = node.validator().sim_time(timestamp);
+                    messages.push((to_self(Payload::Timer { instance, timer_id }), sim_time));
+                }
+                ProtocolOutcome::QueueAction(action_id) => {
+                    let action_id = action_id.0;
+                    messages.push((
+                        to_self(Payload::Action {
+                            instance,
+                            action_id,
+                        }),
+                        now,
+                    ));
+                }
+                ProtocolOutcome::CreateNewBlock {
+                    block_context,
+                    parent_value,
+                    ..
+                } => {
+                    let value = TestValue {
+                        height: block_context.height(),
+                        timestamp: block_context.timestamp(),
+                        parent: parent_value.as_ref().map(TestValue::hash),
+                        proposer: vid.0,
+                        instance,
+                    };
+                    outcomes.extend(consensus.propose(value, block_context, local_now));
+                }
+                ProtocolOutcome::ValidateConsensusValue {
+                    consensus_value, ..
+                } => {
+                    outcomes.extend(consensus.resolve_validity(&consensus_value, true, local_now));
+                }
+                ProtocolOutcome::FinalizedBlock(finalized_block) => {
+                    // An equivocator's second instance doesn't count as a separate node.
+                    if instance == 0 {
+                        node.push_finalized(finalized_block.value);
+                    }
+                }
+                ProtocolOutcome::NewEvidence(faulty_vid) => {
+                    node.validator_mut().evidence.insert(faulty_vid);
+                }
+                ProtocolOutcome::InvalidIncomingMessage(_, sender, error) => {
+                    warn!(
+                        "{} received an invalid message from {}: {}",
+                        vid, sender, error
+                    );
+                }
+                ProtocolOutcome::FttExceeded => panic!("{} saw the FTT exceeded", vid),
+                ProtocolOutcome::SendEvidence(..)
+                | ProtocolOutcome::WeAreFaulty
+                | ProtocolOutcome::DoppelgangerDetected
+                | ProtocolOutcome::StandstillAlert
+                | ProtocolOutcome::Disconnect(_)
+                | ProtocolOutcome::RequestSignature(_) => (),
+            }
+        }
+        let send_time = node.validator().send_time(now);
+        let replay_delay = node.validator().replay_delay();
+        for (target, payload) in outgoing {
+            let msg = Message::new(vid, Payload::Protocol(payload));
+            if let Some(delay) = replay_delay {
+                let replayed = TargetedMessage::new(msg.clone(), target);
+                messages.push((replayed, send_time + delay + random_delay(rng)));
+            }
+            let targeted = TargetedMessage::new(msg, target);
+            messages.push((targeted, send_time + random_delay(rng)));
+        }
+        messages
+    }
+
+    fn node_mut(&mut self, vid: ValidatorId) -> &mut ScenarioNode {
+        self.virtual_net
+            .node_mut(&vid)
+            .expect("validator should exist")
+    }
+
+    fn correct_nodes(&self) -> impl Iterator<Item = &ScenarioNode> {
+        self.virtual_net
+            .validators()
+            .filter(|node| node.validator().behavior.is_none())
+    }
+
+    /// Returns the number of values finalized by each correct node.
+    fn finalized_counts(&self) -> Vec<usize> {
+        self.correct_nodes()
+            .map(|node| node.finalized_count())
+            .collect()
+    }
+
+    /// Cranks until `f` returns `true`. Panics if that takes longer than the test timeout.
+    fn crank_until<F: Fn(&Self) -> bool>(&mut self, rng: &mut NodeRng, f: F) {
+        self.crank_until_before(rng, Timestamp::from(TEST_TIMEOUT), f)
+    }
+
+    /// Cranks until `f` returns `true`. Panics if that doesn't happen before `deadline`.
+    fn crank_until_before<F: Fn(&Self) -> bool>(
+        &mut self,
+        rng: &mut NodeRng,
+        deadline: Timestamp,
+        f: F,
+    ) {
+        while !f(self) {
+            let next_time = self.virtual_net.peek_message().map(|qe| qe.delivery_time);
+            if next_time.map_or(true, |time| time > deadline) {
+                panic!("condition not met before {}", deadline);
+            }
+            self.crank(rng);
+        }
+    }
+
+    /// Cranks until every correct node has finalized `CV_COUNT` values.
+    fn crank_until_finalized(&mut self, rng: &mut NodeRng) {
+        self.crank_until(rng, |harness| {
+            harness
+                .finalized_counts()
+                .into_iter()
+                .all(|count| count >= CV_COUNT)
+        })
+    }
+
+    /// Cranks until all messages before `timestamp` have been delivered.
+    fn crank_until_time(&mut self, rng: &mut NodeRng, timestamp: Timestamp) {
+        self.crank_until(rng, |harness| {
+            harness
+                .virtual_net
+                .peek_message()
+                .map_or(true, |qe| qe.delivery_time > timestamp)
+        })
+    }
+
+    /// Asserts that no two correct nodes finalized conflicting values, and that each finalized
+    /// value is a child of the previous one.
+    fn assert_safety(&self) {
+        let chains: Vec<Vec<TestValue>> = self
+            .correct_nodes()
+            .map(|node| node.finalized_values().cloned().collect())
+            .collect();
+        for (chain0, chain1) in chains.iter().tuple_combinations() {
+            assert!(
+                chain0
+                    .iter()
+                    .zip(chain1)
+                    .all(|(value0, value1)| value0 == value1),
+                "nodes finalized conflicting values: {:?} and {:?}",
+                chain0,
+                chain1
+            );
+        }
+        for chain in &chains {
+            for (height, value) in chain.iter().enumerate() {
+                let expected_parent = height.checked_sub(1).map(|i| chain[i].hash());
+                assert_eq!(
+                    expected_parent, value.parent,
+                    "{:?} has the wrong parent",
+                    value
+                );
+            }
+        }
+    }
+
+    /// Asserts that all correct nodes finalized the same chain of `CV_COUNT` values.
+    fn assert_consistent_chain(&self) {
+        self.assert_safety();
+        for count in self.finalized_counts() {
+            assert_eq!(CV_COUNT, count);
+        }
+    }
+
+    /// Asserts that no correct node has seen evidence against a correct validator.
+    fn assert_evidence_only_against(&self, faulty: &BTreeSet<ValidatorId>) {
+        for node in self.correct_nodes() {
+            assert!(
+                node.validator().evidence.is_subset(faulty),
+                "{} has evidence against correct validators: {:?}",
+                node.id,
+                node.validator().evidence
+            );
+        }
+    }
+}
+
+fn random_delay(rng: &mut NodeRng) -> TimeDiff {
+    rng.gen_range(MIN_DELAY..MAX_DELAY).into()
+}
+
+fn init_logging() {
+    let _ = logging::init_with_config(&LoggingConfig::new(LoggingFormat::Text, true, true));
+}
+
+fn liveness_no_faults(protocol: ConsensusProtocolName) {
+    Scenario::new(protocol).run();
+}
+
+fn liveness_some_mute(protocol: ConsensusProtocolName) {
+    Scenario::new(protocol)
+        .faulty(0..3, Behavior::PermanentlyMute)
+        .run();
+}
+
+fn liveness_some_equivocate(protocol: ConsensusProtocolName) {
+    Scenario::new(protocol)
+        .faulty(0..1, Behavior::Equivocate)
+        .run();
+}
+
+fn liveness_after_even_partition(protocol: ConsensusProtocolName) {
+    let max_round_len = state::round_len(TEST_MAX_ROUND_EXP);
+    let from = Timestamp::zero() + max_round_len * 2;
+    let till = from + max_round_len * 6;
+    // Neither half has a quorum while the network is split.
+    Scenario::new(protocol).partition(0..5, from, till).run();
+}
+
+fn liveness_after_minority_partition(protocol: ConsensusProtocolName) {
+    let max_round_len = state::round_len(TEST_MAX_ROUND_EXP);
+    let from = Timestamp::zero() + max_round_len * 2;
+    let till = from + max_round_len * 6;
+    // The majority keeps finalizing values, and the minority catches up after the partition.
+    Scenario::new(protocol).partition(0..2, from, till).run();
+}
+
+fn safety_with_byzantine_validators(protocol: ConsensusProtocolName) {
+    let max_round_len = state::round_len(TEST_MAX_ROUND_EXP);
+    let withhold = Behavior::Withhold {
+        from: Timestamp::zero() + max_round_len * 2,
+        till: Timestamp::zero() + max_round_len * 5,
+    };
+    let replay = Behavior::Replay {
+        delay: max_round_len * 3,
+    };
+    Scenario::new(protocol)
+        .faulty(0..1, Behavior::Equivocate)
+        .faulty(1..2, withhold)
+        .faulty(2..3, replay)
+        .run();
+}
+
+fn liveness_with_clock_skew(protocol: ConsensusProtocolName) {
+    let skew = TimeDiff::from(CLOCK_SKEW);
+    Scenario::new(protocol)
+        .clock_skew(0..2, ClockSkew::Ahead(skew))
+        .clock_skew(2..4, ClockSkew::Behind(skew))
+        .run();
+}
+
+fn stall_while_too_many_are_mute(protocol: ConsensusProtocolName) {
+    init_logging();
+    let mut rng = crate::new_rng();
+    let max_round_len = state::round_len(TEST_MAX_ROUND_EXP);
+    let start_mute = Timestamp::zero() + max_round_len * 2;
+    let should_stall = start_mute + max_round_len * 4;
+    let stop_mute = should_stall + max_round_len * 3;
+    let mute = Behavior::TemporarilyMute {
+        from: start_mute,
+        till: stop_mute,
+    };
+    // 40% are too many mute validators to be live, but they are only temporarily mute.
+    let scenario = Scenario::new(protocol).faulty(0..4, mute);
+    let mut harness = ScenarioHarness::new(&mut rng, &scenario);
+
+    harness.crank_until_time(&mut rng, should_stall);
+    let stalled_counts = harness.finalized_counts();
+    assert!(stalled_counts.iter().all(|count| *count < CV_COUNT));
+
+    // Until the mute validators come back online, no new values are finalized.
+    harness.crank_until_time(&mut rng, stop_mute);
+    assert_eq!(stalled_counts, harness.finalized_counts());
+
+    // After that, the network resumes.
+    harness.crank_until_finalized(&mut rng);
+    harness.assert_consistent_chain();
+}
+
+/// Instantiates all scenarios for each of the given protocols.
+macro_rules! scenarios {
+    ($($name:ident: $protocol:expr;)*) => {
+        $(
+            mod $name {
+                use super::*;
+
+                #[test]
+                fn liveness_test_no_faults() {
+                    liveness_no_faults($protocol);
+                }
+
+                #[test]
+                fn liveness_test_some_mute() {
+                    liveness_some_mute($protocol);
+                }
+
+                #[test]
+                fn liveness_test_some_equivocate() {
+                    liveness_some_equivocate($protocol);
+                }
+
+                #[test]
+                fn liveness_test_after_even_partition() {
+                    liveness_after_even_partition($protocol);
+                }
+
+                #[test]
+                fn liveness_test_after_minority_partition() {
+                    liveness_after_minority_partition($protocol);
+                }
+
+                #[test]
+                fn safety_test_byzantine_validators() {
+                    safety_with_byzantine_validators($protocol);
+                }
+
+                #[test]
+                fn liveness_test_clock_skew() {
+                    liveness_with_clock_skew($protocol);
+                }
+
+                #[test]
+                fn stall_if_too_many_are_mute() {
+                    stall_while_too_many_are_mute($protocol);
+                }
+            }
+        )*
+    };
+}
+
+scenarios! {
+    highway: ConsensusProtocolName::Highway;
+    simple_bft: ConsensusProtocolName::SimpleBft;
+}
@@ -6,7 +6,7 @@ use std::{
 use datasize::DataSize;
 use serde::{de::DeserializeOwned, Serialize};
 
-use crate::types::Timestamp;
+use crate::{crypto::signer::SignatureFormat, types::Timestamp};
 
 pub trait NodeIdT: Clone + Display + Debug + Send + Eq + Hash + DataSize + 'static {}
 impl<I> NodeIdT for I where I: Clone + Display + Debug + Send + Eq + Hash + DataSize + 'static {}
@@ -60,7 +60,23 @@ pub(crate) trait ValidatorSecret: Send + DataSize {
 
     type Signature: Eq + PartialEq + Clone + Debug + Hash + Serialize + DeserializeOwned + DataSize;
 
-    fn sign(&self, hash: &Self::Hash) -> Self::Signature;
+    /// Signs the hash, or returns `None` if signing failed.
+    fn sign(&self, hash: &Self::Hash) -> Option<Self::Signature>;
+
+    /// Signs the hash of a new unit with the given sequence number, or returns `None` if signing
+    /// failed or was refused, e.g. because a different unit with that sequence number was signed
+    /// before.
+    fn sign_unit(&self, hash: &Self::Hash, _seq_number: u64) -> Option<Self::Signature> {
+        self.sign(hash)
+    }
+
+    /// Returns whether signing may block, e.g. on a request to a remote signing daemon.
+    ///
+    /// If so, the Highway active validator doesn't sign its messages itself, but requests their
+    /// signatures via `ProtocolOutcome::RequestSignature`.
+    fn may_block(&self) -> bool {
+        false
+    }
 }
 
 /// The collection of types the user can choose for cryptography, IDs, transactions, etc.
@@ -90,9 +106,24 @@ pub(crate) trait Context: Clone + DataSize + Debug + Eq + Ord + Hash + Send {
 
     fn hash(data: &[u8]) -> Self::Hash;
 
+    /// Verifies the signature of the hash, in the signature format of the consensus instance.
     fn verify_signature(
         hash: &Self::Hash,
         public_key: &Self::ValidatorId,
         signature: &<Self::ValidatorSecret as ValidatorSecret>::Signature,
+        format: SignatureFormat,
     ) -> bool;
+
+    /// Verifies the signature of the unit with the given hash, instance ID and sequence number,
+    /// as created by `ValidatorSecret::sign_unit`.
+    fn verify_unit_signature(
+        hash: &Self::Hash,
+        _instance_id: &Self::InstanceId,
+        _seq_number: u64,
+        public_key: &Self::ValidatorId,
+        signature: &<Self::ValidatorSecret as ValidatorSecret>::Signature,
+        format: SignatureFormat,
+    ) -> bool {
+        Self::verify_signature(hash, public_key, signature, format)
+    }
 }
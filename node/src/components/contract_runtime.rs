@@ -168,12 +168,14 @@ pub struct ContractRuntimeMetrics {
     apply_effect: Histogram,
     commit_upgrade: Histogram,
     run_query: Histogram,
+    call_contract: Histogram,
     commit_step: Histogram,
     get_balance: Histogram,
     get_validator_weights: Histogram,
     get_era_validators: Histogram,
     get_era_validator_weights_by_era_id: Histogram,
     get_bids: Histogram,
+    get_auction_proofs: Histogram,
     missing_trie_keys: Histogram,
     put_trie: Histogram,
     read_trie: Histogram,
@@ -199,6 +201,8 @@ const APPLY_EFFECT_NAME: &str = "contract_runtime_apply_commit";
 const APPLY_EFFECT_HELP: &str = "tracking run of engine_state.apply_effect in seconds.";
 const RUN_QUERY_NAME: &str = "contract_runtime_run_query";
 const RUN_QUERY_HELP: &str = "tracking run of engine_state.run_query in seconds.";
+const CALL_CONTRACT_NAME: &str = "contract_runtime_call_contract";
+const CALL_CONTRACT_HELP: &str = "tracking run of engine_state.call_contract in seconds.";
 const COMMIT_STEP_NAME: &str = "contract_runtime_commit_step";
 const COMMIT_STEP_HELP: &str = "tracking run of engine_state.commit_step in seconds.";
 const COMMIT_UPGRADE_NAME: &str = "contract_runtime_commit_upgrade";
@@ -216,6 +220,8 @@ const GET_ERA_VALIDATORS_WEIGHT_BY_ERA_ID_HELP: &str =
     "tracking run of engine_state.get_era_validator_weights_by_era_id in seconds.";
 const GET_BIDS_NAME: &str = "contract_runtime_get_bids";
 const GET_BIDS_HELP: &str = "tracking run of engine_state.get_bids in seconds.";
+const GET_AUCTION_PROOFS_NAME: &str = "contract_runtime_get_auction_proofs";
+const GET_AUCTION_PROOFS_HELP: &str = "tracking run of engine_state.get_auction_proofs in seconds.";
 const READ_TRIE_NAME: &str = "contract_runtime_read_trie";
 const READ_TRIE_HELP: &str = "tracking run of engine_state.read_trie in seconds.";
 const PUT_TRIE_NAME: &str = "contract_runtime_put_trie";
@@ -254,6 +260,11 @@ impl ContractRuntimeMetrics {
                 APPLY_EFFECT_HELP,
             )?,
             run_query: register_histogram_metric(registry, RUN_QUERY_NAME, RUN_QUERY_HELP)?,
+            call_contract: register_histogram_metric(
+                registry,
+                CALL_CONTRACT_NAME,
+                CALL_CONTRACT_HELP,
+            )?,
             commit_step: register_histogram_metric(registry, COMMIT_STEP_NAME, COMMIT_STEP_HELP)?,
             commit_upgrade: register_histogram_metric(
                 registry,
@@ -277,6 +288,11 @@ impl ContractRuntimeMetrics {
                 GET_ERA_VALIDATORS_WEIGHT_BY_ERA_ID_HELP,
             )?,
             get_bids: register_histogram_metric(registry, GET_BIDS_NAME, GET_BIDS_HELP)?,
+            get_auction_proofs: register_histogram_metric(
+                registry,
+                GET_AUCTION_PROOFS_NAME,
+                GET_AUCTION_PROOFS_HELP,
+            )?,
             read_trie: register_histogram_metric(registry, READ_TRIE_NAME, READ_TRIE_HELP)?,
             put_trie: register_histogram_metric(registry, PUT_TRIE_NAME, PUT_TRIE_HELP)?,
             missing_trie_keys: register_histogram_metric(
@@ -359,6 +375,24 @@ where
                         }
                         .ignore()
                     }
+                    ContractRuntimeRequest::CallContract {
+                        call_contract_request,
+                        responder,
+                    } => {
+                        trace!(?call_contract_request, "call contract");
+                        let engine_state = Arc::clone(&self.engine_state);
+                        let metrics = Arc::clone(&self.metrics);
+                        async move {
+                            let correlation_id = CorrelationId::new();
+                            let start = Instant::now();
+                            let result =
+                                engine_state.call_contract(correlation_id, *call_contract_request);
+                            metrics.call_contract.observe(start.elapsed().as_secs_f64());
+                            trace!(?result, "call contract result");
+                            responder.respond(result).await
+                        }
+                        .ignore()
+                    }
                     ContractRuntimeRequest::GetBalance {
                         balance_request,
                         responder,
@@ -532,6 +566,23 @@ where
                                 )
                             })
                     }
+                    ContractRuntimeRequest::SpeculativeExecute {
+                        execute_request,
+                        responder,
+                    } => {
+                        let engine_state = Arc::clone(&self.engine_state);
+                        let metrics = Arc::clone(&self.metrics);
+                        async move {
+                            let result = operations::speculative_execute(
+                                engine_state,
+                                metrics,
+                                *execute_request,
+                            )
+                            .await;
+                            responder.respond(result).await
+                        }
+                        .ignore()
+                    }
                     ContractRuntimeRequest::GetBids {
                         get_bids_request,
                         responder,
@@ -549,6 +600,26 @@ where
                         }
                         .ignore()
                     }
+                    ContractRuntimeRequest::GetAuctionProofs {
+                        get_auction_proofs_request,
+                        responder,
+                    } => {
+                        trace!(?get_auction_proofs_request, "get auction proofs request");
+                        let engine_state = Arc::clone(&self.engine_state);
+                        let metrics = Arc::clone(&self.metrics);
+                        async move {
+                            let correlation_id = CorrelationId::new();
+                            let start = Instant::now();
+                            let result = engine_state
+                                .get_auction_proofs(correlation_id, get_auction_proofs_request);
+                            metrics
+                                .get_auction_proofs
+                                .observe(start.elapsed().as_secs_f64());
+                            trace!(?result, "get auction proofs result");
+                            responder.respond(result).await
+                        }
+                        .ignore()
+                    }
                     ContractRuntimeRequest::MissingTrieKeys {
                         trie_key,
                         responder,
@@ -894,6 +965,9 @@ impl ContractRuntime {
         let metrics = Arc::clone(&self.metrics);
         let protocol_version = self.protocol_version;
         let block_time = state.finalized_block.timestamp().millis();
+        let block_height = state.finalized_block.height();
+        let era_id = state.finalized_block.era_id();
+        let parent_block_hash = *state.parent_hash.inner();
         let proposer = state.finalized_block.proposer();
         async move {
             for deploy in state.remaining_deploys.drain(..) {
@@ -904,6 +978,9 @@ impl ContractRuntime {
                 let execute_request = ExecuteRequest::new(
                     state.state_root_hash.into(),
                     block_time,
+                    block_height,
+                    era_id,
+                    parent_block_hash.into(),
                     vec![deploy_item],
                     protocol_version,
                     proposer.clone(),
@@ -952,12 +1029,16 @@ impl ContractRuntime {
         finalized_block: FinalizedBlock,
         deploys: VecDeque<Deploy>,
     ) -> Effects<Event> {
-        if let Some(state_root_hash) = self.pre_state_hash(&finalized_block) {
+        if let (Some(state_root_hash), Some(parent_hash)) = (
+            self.pre_state_hash(&finalized_block),
+            self.parent_hash(&finalized_block),
+        ) {
             let state = Box::new(RequestState {
                 finalized_block,
                 remaining_deploys: deploys,
                 execution_results: HashMap::new(),
                 state_root_hash,
+                parent_hash,
             });
             self.execute_all_deploys_or_finalize_block_or_step(effect_builder, state)
         } else {
@@ -994,12 +1075,16 @@ impl ContractRuntime {
                 let height = finalized_block.height();
                 debug!("no pre-state hash for height {}", height);
                 // re-check the parent map - the parent might have been executed in the meantime!
-                if let Some(state_root_hash) = self.pre_state_hash(&finalized_block) {
+                if let (Some(state_root_hash), Some(parent_hash)) = (
+                    self.pre_state_hash(&finalized_block),
+                    self.parent_hash(&finalized_block),
+                ) {
                     let state = Box::new(RequestState {
                         finalized_block,
                         remaining_deploys: deploys,
                         execution_results: HashMap::new(),
                         state_root_hash,
+                        parent_hash,
                     });
                     self.execute_all_deploys_or_finalize_block_or_step(effect_builder, state)
                 } else {
@@ -1079,6 +1164,25 @@ impl ContractRuntime {
         }
     }
 
+    /// Returns the hash of the `finalized_block`'s parent, if the parent has been executed.
+    fn parent_hash(&self, finalized_block: &FinalizedBlock) -> Option<BlockHash> {
+        if self.is_initial_block_child(finalized_block) {
+            // As in `create_block`, the genesis child has a default parent hash.
+            Some(
+                self.initial_state
+                    .block_summary
+                    .as_ref()
+                    .map(|summary| summary.hash)
+                    .unwrap_or_else(|| BlockHash::new(Digest::default())),
+            )
+        } else {
+            let parent_block_height = finalized_block.height() - 1;
+            self.parent_map
+                .get(&parent_block_height)
+                .map(|summary| summary.hash)
+        }
+    }
+
     /// Returns true if the `finalized_block` is an immediate child of the initial block, ie.
     /// either genesis or the highest known block at the time of initializing the component.
     fn is_initial_block_child(&self, finalized_block: &FinalizedBlock) -> bool {
@@ -1098,6 +1202,8 @@ pub struct RequestState {
     /// Current state root hash of global storage.  Is initialized with the parent block's
     /// state hash, and is updated after each commit.
     pub state_root_hash: Digest,
+    /// Hash of the parent block.
+    pub parent_hash: BlockHash,
 }
 
 #[derive(DataSize, Debug, Default)]
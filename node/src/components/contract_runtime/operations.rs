@@ -71,6 +71,20 @@ pub(super) async fn commit_execution_effects(
     }
 }
 
+/// Executes the single deploy in the request without committing its effects.
+pub(super) async fn speculative_execute(
+    engine_state: Arc<EngineState<LmdbGlobalState>>,
+    metrics: Arc<ContractRuntimeMetrics>,
+    execute_request: ExecuteRequest,
+) -> Result<ExecutionResult, engine_state::Error> {
+    let execution_results = execute(engine_state, metrics, execute_request).await?;
+    let ee_execution_result = execution_results
+        .into_iter()
+        .exactly_one()
+        .expect("should only be one exec result");
+    Ok(ExecutionResult::from(&ee_execution_result))
+}
+
 pub(super) async fn commit(
     engine_state: Arc<EngineState<LmdbGlobalState>>,
     metrics: Arc<ContractRuntimeMetrics>,
@@ -15,19 +15,28 @@
 //!
 //! This component uses a ring buffer for outbound events providing some robustness against
 //! unintended subscriber disconnects, if a disconnected subscriber re-subscribes before the buffer
-//! has advanced past their last received event.
+//! has advanced past their last received event.  The buffer and the event index are persisted
+//! alongside the node's storage, so event IDs continue across restarts of the node and buffered
+//! events can still be replayed afterwards.  A reconnecting subscriber can resume from a given
+//! event via either the `start_from` query parameter or the standard `Last-Event-ID` header.
+//!
+//! Subscribers may also restrict the events they receive via query parameters filtering by event
+//! type and, for `DeployProcessed` events, by account, deploy hash or contract hash touched.  The
+//! filter is applied server-side to both buffered and new events before they are serialized.
 //!
 //! For details about the SSE model and a list of supported SSEs, see:
 //! <https://github.com/CasperLabs/ceps/blob/master/text/0009-client-api.md#rpcs>
 
 mod config;
 mod event;
+mod event_store;
 mod http_server;
 mod sse_server;
 
-use std::{convert::Infallible, fmt::Debug};
+use std::{convert::Infallible, fmt::Debug, path::Path};
 
 use datasize::DataSize;
+use thiserror::Error;
 use tokio::sync::{
     mpsc::{self, UnboundedSender},
     oneshot,
@@ -44,8 +53,21 @@ use crate::{
 };
 pub use config::Config;
 pub(crate) use event::Event;
+use event_store::EventStore;
 pub use sse_server::SseData;
 
+/// An error starting the event stream server.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Failed to start listening.
+    #[error(transparent)]
+    Listening(#[from] ListeningError),
+
+    /// Failed to open or load the persisted events.
+    #[error("event store error: {0}")]
+    EventStore(#[from] event_store::Error),
+}
+
 /// A helper trait whose bounds represent the requirements for a reactor event that `run_server` can
 /// work with.
 pub trait ReactorEventT: From<Event> + Send {}
@@ -61,10 +83,12 @@ pub(crate) struct EventStreamServer {
 }
 
 impl EventStreamServer {
+    /// Creates a new event stream server, persisting its events in `storage_dir`.
     pub(crate) fn new(
         config: Config,
+        storage_dir: &Path,
         api_version: ProtocolVersion,
-    ) -> Result<Self, ListeningError> {
+    ) -> Result<Self, Error> {
         let required_address = utils::resolve_address(&config.address).map_err(|error| {
             warn!(
                 %error,
@@ -74,6 +98,15 @@ impl EventStreamServer {
             ListeningError::ResolveAddress(error)
         })?;
 
+        // Restore the events persisted before the last shutdown, if any.
+        let event_store = EventStore::new(storage_dir, config.event_stream_buffer_length)?;
+        let (next_event_id, stored_events) = event_store.load()?;
+        info!(
+            next_event_id,
+            stored_event_count = stored_events.len(),
+            "loaded persisted events"
+        );
+
         let (sse_data_sender, sse_data_receiver) = mpsc::unbounded_channel();
 
         // Event stream channels and filter.
@@ -95,6 +128,9 @@ impl EventStreamServer {
         tokio::spawn(http_server::run(
             config,
             api_version,
+            event_store,
+            next_event_id,
+            stored_events,
             server_with_shutdown,
             shutdown_sender,
             sse_data_receiver,
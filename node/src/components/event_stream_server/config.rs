@@ -24,6 +24,9 @@ pub struct Config {
     pub address: String,
 
     /// Number of SSEs to buffer.
+    ///
+    /// The buffered SSEs are also persisted alongside the node's storage so that they can be
+    /// replayed after a restart.
     pub event_stream_buffer_length: u32,
 
     /// The number of events to buffer in the tokio broadcast channel to help slower clients to try
@@ -0,0 +1,222 @@
+//! On-disk persistence of the event stream server's buffer of SSEs.
+//!
+//! The most recent events are kept in a dedicated LMDB database alongside the one used by the
+//! storage component, keyed by their big-endian event ID.  The ID to be assigned to the next event
+//! is kept in a separate metadata database, so that IDs continue monotonically across restarts of
+//! the node and reconnecting clients can have their missed events replayed from disk.
+
+use std::{cmp::Reverse, path::Path};
+
+use lmdb::{Database, DatabaseFlags, Environment, EnvironmentFlags, Transaction, WriteFlags};
+use thiserror::Error;
+
+use super::sse_server::{Id, ServerSentEvent};
+
+/// Filename for the LMDB database holding the persisted events.
+const EVENT_STORE_FILENAME: &str = "sse_events.lmdb";
+/// Maximum size of the event store.
+///
+/// Only a bounded window of events is ever kept, so this is an upper bound well clear of what is
+/// required for the buffer lengths used in practice.
+const MAX_EVENT_STORE_SIZE: usize = 10 * 1024 * 1024 * 1024;
+/// Maximum number of allowed dbs.
+const MAX_DB_COUNT: u32 = 2;
+/// Key in the metadata database under which the ID of the next event is stored.
+const NEXT_EVENT_ID_KEY: &[u8] = b"next_event_id";
+
+/// An error in the event store.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// An error from LMDB.
+    #[error("lmdb error: {0}")]
+    Lmdb(#[from] lmdb::Error),
+    /// An event could not be (de)serialized.
+    #[error("serialization error: {0}")]
+    Serialization(#[from] bincode::Error),
+    /// The stored event ID is corrupt.
+    #[error("stored event ID has invalid length {0}")]
+    InvalidEventId(usize),
+}
+
+/// The on-disk store of the most recent events.
+pub(super) struct EventStore {
+    env: Environment,
+    /// Events keyed by their big-endian ID.
+    event_db: Database,
+    /// Holds the ID of the next event.
+    metadata_db: Database,
+    /// The number of events to keep.
+    window_length: u32,
+}
+
+impl EventStore {
+    /// Opens the event store in the given directory, keeping at most `window_length` events.
+    pub(super) fn new(dir: &Path, window_length: u32) -> Result<Self, Error> {
+        let env = Environment::new()
+            // We manage our own directory.
+            .set_flags(EnvironmentFlags::NO_SUB_DIR | EnvironmentFlags::NO_TLS)
+            .set_max_dbs(MAX_DB_COUNT)
+            .set_map_size(MAX_EVENT_STORE_SIZE)
+            .open(&dir.join(EVENT_STORE_FILENAME))?;
+        let event_db = env.create_db(Some("events"), DatabaseFlags::empty())?;
+        let metadata_db = env.create_db(Some("metadata"), DatabaseFlags::empty())?;
+        Ok(EventStore {
+            env,
+            event_db,
+            metadata_db,
+            window_length,
+        })
+    }
+
+    /// Loads the ID of the next event along with the stored events, oldest first.
+    ///
+    /// Any stored events outside the window, e.g. due to the window having been shortened since
+    /// they were stored, are deleted.
+    pub(super) fn load(&self) -> Result<(Id, Vec<ServerSentEvent>), Error> {
+        let mut txn = self.env.begin_rw_txn()?;
+        let next_event_id = match txn.get(self.metadata_db, &NEXT_EVENT_ID_KEY) {
+            Ok(raw_id) => parse_id(raw_id)?,
+            Err(lmdb::Error::NotFound) => 0,
+            Err(error) => return Err(error.into()),
+        };
+
+        let mut events = vec![];
+        let mut stale_keys = vec![];
+        {
+            let mut cursor = txn.open_ro_cursor(self.event_db)?;
+            // Note: `iter_start` has an undocumented panic if called on an empty database.  We rely
+            //       on the iterator being at the start when created.
+            for (raw_id, raw_event) in cursor.iter() {
+                let id = parse_id(raw_id)?;
+                if self.is_in_window(id, next_event_id) {
+                    events.push(bincode::deserialize::<ServerSentEvent>(raw_event)?);
+                } else {
+                    stale_keys.push(raw_id.to_vec());
+                }
+            }
+        }
+        for key in stale_keys {
+            txn.del(self.event_db, &key, None)?;
+        }
+        txn.commit()?;
+
+        // The keys are ordered by ID, which isn't the order the events were created in if the ID
+        // has wrapped around.
+        events.sort_by_key(|event| {
+            let age = next_event_id.wrapping_sub(event.id.unwrap_or_default());
+            Reverse(age)
+        });
+        Ok((next_event_id, events))
+    }
+
+    /// Stores the given event, deleting the one which has now dropped out of the window.
+    pub(super) fn put(&self, event: &ServerSentEvent) -> Result<(), Error> {
+        let id = match event.id {
+            Some(id) => id,
+            None => return Ok(()),
+        };
+        let raw_event = bincode::serialize(event)?;
+        let mut txn = self.env.begin_rw_txn()?;
+        txn.put(
+            self.event_db,
+            &id.to_be_bytes(),
+            &raw_event,
+            WriteFlags::empty(),
+        )?;
+        txn.put(
+            self.metadata_db,
+            &NEXT_EVENT_ID_KEY,
+            &id.wrapping_add(1).to_be_bytes(),
+            WriteFlags::empty(),
+        )?;
+        let expired_id = id.wrapping_sub(self.window_length);
+        match txn.del(self.event_db, &expired_id.to_be_bytes(), None) {
+            Ok(()) | Err(lmdb::Error::NotFound) => (),
+            Err(error) => return Err(error.into()),
+        }
+        txn.commit()?;
+        Ok(())
+    }
+
+    /// Returns whether the event with the given ID is one of the `window_length` events preceding
+    /// `next_event_id`.
+    fn is_in_window(&self, id: Id, next_event_id: Id) -> bool {
+        next_event_id.wrapping_sub(id).wrapping_sub(1) < self.window_length
+    }
+}
+
+/// Parses a big-endian event ID.
+fn parse_id(raw_id: &[u8]) -> Result<Id, Error> {
+    let mut bytes = [0; 4];
+    if raw_id.len() != bytes.len() {
+        return Err(Error::InvalidEventId(raw_id.len()));
+    }
+    bytes.copy_from_slice(raw_id);
+    Ok(Id::from_be_bytes(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use casper_types::ProtocolVersion;
+
+    use super::*;
+    use crate::components::event_stream_server::SseData;
+
+    fn event(id: Id) -> ServerSentEvent {
+        ServerSentEvent {
+            id: Some(id),
+            data: SseData::ApiVersion(ProtocolVersion::from_parts(1, 0, id)),
+        }
+    }
+
+    #[test]
+    fn should_continue_ids_and_replay_window_after_reopening() {
+        let tempdir = tempfile::tempdir().unwrap();
+
+        {
+            let event_store = EventStore::new(tempdir.path(), 3).unwrap();
+            assert_eq!(event_store.load().unwrap(), (0, vec![]));
+            for id in 0..5 {
+                event_store.put(&event(id)).unwrap();
+            }
+        }
+
+        let event_store = EventStore::new(tempdir.path(), 3).unwrap();
+        assert_eq!(
+            event_store.load().unwrap(),
+            (5, vec![event(2), event(3), event(4)])
+        );
+    }
+
+    #[test]
+    fn should_drop_events_outside_shortened_window() {
+        let tempdir = tempfile::tempdir().unwrap();
+
+        {
+            let event_store = EventStore::new(tempdir.path(), 5).unwrap();
+            for id in 0..5 {
+                event_store.put(&event(id)).unwrap();
+            }
+        }
+
+        let event_store = EventStore::new(tempdir.path(), 2).unwrap();
+        assert_eq!(event_store.load().unwrap(), (5, vec![event(3), event(4)]));
+    }
+
+    #[test]
+    fn should_order_events_by_creation_when_ids_wrap() {
+        let tempdir = tempfile::tempdir().unwrap();
+        let event_store = EventStore::new(tempdir.path(), 4).unwrap();
+        for id in &[Id::MAX - 1, Id::MAX, 0, 1] {
+            event_store.put(&event(*id)).unwrap();
+        }
+
+        assert_eq!(
+            event_store.load().unwrap(),
+            (
+                2,
+                vec![event(Id::MAX - 1), event(Id::MAX), event(0), event(1)]
+            )
+        );
+    }
+}
@@ -4,13 +4,14 @@ use tokio::{
     sync::{broadcast, mpsc, oneshot},
     task,
 };
-use tracing::{info, trace};
+use tracing::{info, trace, warn};
 use wheelbuf::WheelBuf;
 
 use casper_types::ProtocolVersion;
 
 use super::{
-    sse_server::{BroadcastChannelMessage, NewSubscriberInfo, ServerSentEvent},
+    event_store::EventStore,
+    sse_server::{BroadcastChannelMessage, Id, NewSubscriberInfo, ServerSentEvent},
     Config, SseData,
 };
 
@@ -25,9 +26,15 @@ use super::{
 /// * `new_subscriber_info_receiver` is used to notify the server of the details of a new client
 ///   having subscribed to the event stream.  It allows the server to populate that client's stream
 ///   with the requested number of historical events.
+/// * `event_store` persists each event so that the buffer can be restored after a restart.
+/// * `next_event_id` and `stored_events` are the state restored from the `event_store`.
+#[allow(clippy::too_many_arguments)]
 pub(super) async fn run(
     config: Config,
     api_version: ProtocolVersion,
+    event_store: EventStore,
+    next_event_id: Id,
+    stored_events: Vec<ServerSentEvent>,
     server_with_shutdown: impl Future<Output = ()> + Send + 'static,
     server_shutdown_sender: oneshot::Sender<()>,
     mut data_receiver: mpsc::UnboundedReceiver<SseData>,
@@ -36,12 +43,15 @@ pub(super) async fn run(
 ) {
     let server_joiner = task::spawn(server_with_shutdown);
 
-    // Initialize the index and buffer for the SSEs.
-    let mut event_index = 0_u32;
+    // Initialize the index and buffer for the SSEs, continuing from the persisted events.
+    let mut event_index = next_event_id;
     let mut buffer = WheelBuf::new(vec![
         ServerSentEvent::initial_event(api_version);
         config.event_stream_buffer_length as usize
     ]);
+    for event in stored_events {
+        buffer.push(event);
+    }
 
     // Start handling received messages from the two channels; info on new client subscribers and
     // incoming events announced by node components.
@@ -75,6 +85,9 @@ pub(super) async fn run(
                             // Buffer the data and broadcast it to subscribed clients.
                             trace!("Event stream server received {:?}", data);
                             let event = ServerSentEvent { id: Some(event_index), data };
+                            if let Err(error) = event_store.put(&event) {
+                                warn!(%error, "failed to persist event");
+                            }
                             buffer.push(event.clone());
                             let message = BroadcastChannelMessage::ServerSentEvent(event);
                             // This can validly fail if there are no connected clients, so don't log
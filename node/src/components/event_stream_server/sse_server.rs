@@ -1,8 +1,11 @@
 //! Types and functions used by the http server to manage the event-stream.
 
+use std::{collections::HashSet, hash::Hash, str::FromStr};
+
 use datasize::DataSize;
-use futures::{Stream, StreamExt};
+use futures::{future, Stream, StreamExt};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 use tokio::sync::{
     broadcast::{self, error::RecvError},
     mpsc,
@@ -13,19 +16,25 @@ use tokio_stream::wrappers::{
 use tracing::{error, info, trace};
 use warp::{
     filters::BoxedFilter,
+    http::StatusCode,
     sse::{self, Event as WarpServerSentEvent},
     Filter, Reply,
 };
 
-use casper_types::{EraId, ExecutionResult, ProtocolVersion, PublicKey};
+use casper_types::{
+    AsymmetricType, ContractHash, EraId, ExecutionResult, Key, ProtocolVersion, PublicKey,
+};
 
-use crate::types::{Block, BlockHash, DeployHash, FinalitySignature, TimeDiff, Timestamp};
+use crate::{
+    crypto::hash::Digest,
+    types::{Block, BlockHash, DeployHash, FinalitySignature, TimeDiff, Timestamp},
+};
 
 /// The URL path.
 pub const SSE_API_PATH: &str = "events";
 
 /// The "id" field of the events sent on the event stream to clients.
-type Id = u32;
+pub(super) type Id = u32;
 
 /// The "data" field of the events sent on the event stream to clients.
 #[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug, DataSize)]
@@ -60,8 +69,157 @@ pub enum SseData {
     FinalitySignature(Box<FinalitySignature>),
 }
 
+impl SseData {
+    /// Returns the type of the event, or `None` for `ApiVersion` which is never filtered out.
+    fn event_type(&self) -> Option<EventType> {
+        match self {
+            SseData::ApiVersion(_) => None,
+            SseData::BlockAdded { .. } => Some(EventType::BlockAdded),
+            SseData::DeployProcessed { .. } => Some(EventType::DeployProcessed),
+            SseData::Fault { .. } => Some(EventType::Fault),
+            SseData::FinalitySignature(_) => Some(EventType::FinalitySignature),
+        }
+    }
+}
+
+/// The types of event which can be selected via the "event_type" query parameter.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+enum EventType {
+    BlockAdded,
+    DeployProcessed,
+    Fault,
+    FinalitySignature,
+}
+
+impl FromStr for EventType {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "BlockAdded" => Ok(EventType::BlockAdded),
+            "DeployProcessed" => Ok(EventType::DeployProcessed),
+            "Fault" => Ok(EventType::Fault),
+            "FinalitySignature" => Ok(EventType::FinalitySignature),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Error returned when the endpoint's query string contains a value which can't be parsed.
+#[derive(Debug, Error)]
+#[error("invalid value '{value}' for query parameter '{parameter}'")]
+struct InvalidQueryError {
+    parameter: &'static str,
+    value: String,
+}
+
+/// The filter applied to the events sent to a single client, as specified in its query string.
+///
+/// `ApiVersion` events are never filtered out.  If any event types are given, only events of those
+/// types are sent.  If any accounts, deploy hashes or contract hashes are given, `DeployProcessed`
+/// events are only sent if they match at least one of them, where a contract hash matches if the
+/// contract is the key of any of the transforms in the execution result.
+#[derive(Clone, Default, Debug)]
+struct EventFilter {
+    event_types: Option<HashSet<EventType>>,
+    accounts: Option<HashSet<PublicKey>>,
+    deploy_hashes: Option<HashSet<DeployHash>>,
+    /// The formatted `Key::Hash` of each contract hash, as used in the execution result
+    /// transforms.
+    contract_keys: Option<HashSet<String>>,
+}
+
+impl EventFilter {
+    /// Parses the filter from the endpoint's query string.
+    fn from_query(query: &Query) -> Result<Self, InvalidQueryError> {
+        Ok(EventFilter {
+            event_types: parse_list("event_type", &query.event_type, |value| value.parse().ok())?,
+            accounts: parse_list("account", &query.account, |value| {
+                PublicKey::from_hex(value).ok()
+            })?,
+            deploy_hashes: parse_list("deploy_hash", &query.deploy_hash, |value| {
+                Digest::from_hex(value).ok().map(DeployHash::new)
+            })?,
+            contract_keys: parse_list("contract_hash", &query.contract_hash, |value| {
+                ContractHash::from_formatted_str(value)
+                    .ok()
+                    .map(|contract_hash| Key::from(contract_hash).to_formatted_string())
+            })?,
+        })
+    }
+
+    /// Returns whether the given event should be sent to the client.
+    fn matches(&self, data: &SseData) -> bool {
+        let event_type = match data.event_type() {
+            Some(event_type) => event_type,
+            None => return true,
+        };
+        if let Some(event_types) = &self.event_types {
+            if !event_types.contains(&event_type) {
+                return false;
+            }
+        }
+
+        let (deploy_hash, account, execution_result) = match data {
+            SseData::DeployProcessed {
+                deploy_hash,
+                account,
+                execution_result,
+                ..
+            } => (deploy_hash, account, execution_result),
+            _ => return true,
+        };
+        if self.accounts.is_none() && self.deploy_hashes.is_none() && self.contract_keys.is_none() {
+            return true;
+        }
+        let matches_account = self
+            .accounts
+            .as_ref()
+            .map_or(false, |accounts| accounts.contains(&**account));
+        let matches_deploy_hash = self.deploy_hashes.as_ref().map_or(false, |deploy_hashes| {
+            deploy_hashes.contains(&**deploy_hash)
+        });
+        let matches_contract = self.contract_keys.as_ref().map_or(false, |contract_keys| {
+            let effect = match &**execution_result {
+                ExecutionResult::Failure { effect, .. }
+                | ExecutionResult::Success { effect, .. } => effect,
+            };
+            effect
+                .transforms
+                .iter()
+                .any(|transform_entry| contract_keys.contains(&transform_entry.key))
+        });
+        matches_account || matches_deploy_hash || matches_contract
+    }
+}
+
+/// Parses an optional comma-separated list of query string values into a set.
+fn parse_list<T, F>(
+    parameter: &'static str,
+    maybe_list: &Option<String>,
+    parse: F,
+) -> Result<Option<HashSet<T>>, InvalidQueryError>
+where
+    T: Eq + Hash,
+    F: Fn(&str) -> Option<T>,
+{
+    let list = match maybe_list {
+        Some(list) => list,
+        None => return Ok(None),
+    };
+    list.split(',')
+        .map(|value| {
+            parse(value.trim()).ok_or_else(|| InvalidQueryError {
+                parameter,
+                value: value.to_string(),
+            })
+        })
+        .collect::<Result<_, _>>()
+        .map(Some)
+}
+
 /// The components of a single SSE.
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize, Debug)]
 pub(super) struct ServerSentEvent {
     /// The ID should only be `None` where the `data` is `SseData::ApiVersion`.
     pub(super) id: Option<Id>,
@@ -100,10 +258,17 @@ pub(super) struct NewSubscriberInfo {
     pub(super) initial_events_sender: mpsc::UnboundedSender<ServerSentEvent>,
 }
 
-/// The endpoint's query string, e.g. `http://localhost:22777/events?start_from=999`
+/// The endpoint's query string, e.g.
+/// `http://localhost:22777/events?start_from=999&event_type=DeployProcessed&account=01ab..`
+///
+/// Each of the filter parameters takes a comma-separated list of values.
 #[derive(Deserialize, Debug)]
 struct Query {
     start_from: Option<Id>,
+    event_type: Option<String>,
+    account: Option<String>,
+    deploy_hash: Option<String>,
+    contract_hash: Option<String>,
 }
 
 /// Creates the message-passing channels required to run the event-stream server and the warp filter
@@ -125,14 +290,28 @@ pub(super) fn create_channels_and_filter(
 
     let filter = warp::get()
         .and(warp::path(SSE_API_PATH))
-        .and(warp::query().map(move |query: Query| {
+        .and(warp::query())
+        .and(warp::header::optional::<Id>("last-event-id"))
+        .map(move |query: Query, maybe_last_event_id: Option<Id>| {
+            let event_filter = match EventFilter::from_query(&query) {
+                Ok(event_filter) => event_filter,
+                Err(error) => {
+                    info!(%error, "rejecting event stream subscription");
+                    return warp::reply::with_status(error.to_string(), StatusCode::BAD_REQUEST)
+                        .into_response();
+                }
+            };
+
             // Create a channel for the client's handler to receive the stream of initial events.
             let (initial_events_sender, initial_events_receiver) = mpsc::unbounded_channel();
 
             // Supply the server with the sender part of the channel along with the client's
-            // requested starting point.
+            // requested starting point.  A reconnecting client's "Last-Event-ID" header takes
+            // precedence over the query string.
             let new_subscriber_info = NewSubscriberInfo {
-                start_from: query.start_from,
+                start_from: maybe_last_event_id
+                    .map(|last_event_id| last_event_id.wrapping_add(1))
+                    .or(query.start_from),
                 initial_events_sender,
             };
             if new_subscriber_info_sender
@@ -148,8 +327,10 @@ pub(super) fn create_channels_and_filter(
             sse::reply(sse::keep_alive().stream(stream_to_client(
                 initial_events_receiver,
                 ongoing_events_receiver,
+                event_filter,
             )))
-        }))
+            .into_response()
+        })
         .boxed();
 
     (broadcaster, new_subscriber_info_receiver, filter)
@@ -166,13 +347,25 @@ pub(super) fn create_channels_and_filter(
 /// either the client disconnects, or the server shuts down (indicated by sending a `Shutdown`
 /// variant via the channel).  This channel will receive all SSEs created from the moment the client
 /// subscribed to the server's event stream.
+///
+/// Events from both channels which don't match the client's `event_filter` are dropped before
+/// being serialized.
 fn stream_to_client(
     initial_events: mpsc::UnboundedReceiver<ServerSentEvent>,
     ongoing_events: broadcast::Receiver<BroadcastChannelMessage>,
+    event_filter: EventFilter,
 ) -> impl Stream<Item = Result<WarpServerSentEvent, RecvError>> + 'static {
     UnboundedReceiverStream::new(initial_events)
         .map(|event| Ok(BroadcastChannelMessage::ServerSentEvent(event)))
         .chain(BroadcastStream::new(ongoing_events))
+        .filter(move |result| {
+            future::ready(match result {
+                Ok(BroadcastChannelMessage::ServerSentEvent(event)) => {
+                    event_filter.matches(&event.data)
+                }
+                _ => true,
+            })
+        })
         .map(|result| {
             trace!(?result);
             match result {
@@ -202,3 +395,133 @@ fn stream_to_client(
             }
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::Rng;
+
+    use casper_types::{ExecutionEffect, Transform, TransformEntry, U512};
+
+    use super::*;
+    use crate::{crypto::AsymmetricKeyExt, testing::TestRng};
+
+    fn query(
+        event_type: Option<&str>,
+        account: Option<&str>,
+        deploy_hash: Option<&str>,
+        contract_hash: Option<&str>,
+    ) -> Query {
+        Query {
+            start_from: None,
+            event_type: event_type.map(str::to_string),
+            account: account.map(str::to_string),
+            deploy_hash: deploy_hash.map(str::to_string),
+            contract_hash: contract_hash.map(str::to_string),
+        }
+    }
+
+    fn deploy_processed(
+        rng: &mut TestRng,
+        deploy_hash: DeployHash,
+        account: PublicKey,
+        touched_contract: ContractHash,
+    ) -> SseData {
+        let effect = ExecutionEffect {
+            operations: vec![],
+            transforms: vec![TransformEntry {
+                key: Key::from(touched_contract).to_formatted_string(),
+                transform: Transform::Identity,
+            }],
+        };
+        SseData::DeployProcessed {
+            deploy_hash: Box::new(deploy_hash),
+            account: Box::new(account),
+            timestamp: Timestamp::now(),
+            ttl: TimeDiff::from_seconds(60),
+            dependencies: vec![],
+            block_hash: Box::new(BlockHash::random(rng)),
+            execution_result: Box::new(ExecutionResult::Success {
+                effect,
+                transfers: vec![],
+                events: vec![],
+                cost: U512::zero(),
+            }),
+        }
+    }
+
+    #[test]
+    fn should_filter_by_event_type() {
+        let mut rng = TestRng::new();
+        let block = Block::random(&mut rng);
+        let block_added = SseData::BlockAdded {
+            block_hash: *block.hash(),
+            block: Box::new(block),
+        };
+        let fault = SseData::Fault {
+            era_id: EraId::from(1),
+            public_key: PublicKey::random(&mut rng),
+            timestamp: Timestamp::now(),
+        };
+        let api_version = SseData::ApiVersion(ProtocolVersion::V1_0_0);
+
+        let filter =
+            EventFilter::from_query(&query(Some("BlockAdded,Fault"), None, None, None)).unwrap();
+        assert!(filter.matches(&block_added));
+        assert!(filter.matches(&fault));
+        assert!(filter.matches(&api_version));
+
+        let filter = EventFilter::from_query(&query(Some("Fault"), None, None, None)).unwrap();
+        assert!(!filter.matches(&block_added));
+        assert!(filter.matches(&fault));
+        assert!(filter.matches(&api_version));
+    }
+
+    #[test]
+    fn should_filter_deploys_by_account_deploy_hash_or_contract_hash() {
+        let mut rng = TestRng::new();
+        let account = PublicKey::random(&mut rng);
+        let deploy_hash = DeployHash::random(&mut rng);
+        let contract_hash = ContractHash::new(rng.gen());
+        let deploy = deploy_processed(&mut rng, deploy_hash, account.clone(), contract_hash);
+        let other_deploy_hash = DeployHash::random(&mut rng);
+        let other_account = PublicKey::random(&mut rng);
+        let other_contract_hash = ContractHash::new(rng.gen());
+        let other_deploy = deploy_processed(
+            &mut rng,
+            other_deploy_hash,
+            other_account,
+            other_contract_hash,
+        );
+
+        // No filter matches everything.
+        let filter = EventFilter::from_query(&query(None, None, None, None)).unwrap();
+        assert!(filter.matches(&deploy));
+        assert!(filter.matches(&other_deploy));
+
+        let account_hex = account.to_hex();
+        let deploy_hash_hex = hex::encode(deploy_hash.as_ref());
+        let contract_hash_str = contract_hash.to_formatted_string();
+        for filter_query in &[
+            query(None, Some(&account_hex), None, None),
+            query(None, None, Some(&deploy_hash_hex), None),
+            query(None, None, None, Some(&contract_hash_str)),
+            query(Some("DeployProcessed"), Some(&account_hex), None, None),
+        ] {
+            let filter = EventFilter::from_query(filter_query).unwrap();
+            assert!(filter.matches(&deploy), "{:?}", filter_query);
+            assert!(!filter.matches(&other_deploy), "{:?}", filter_query);
+        }
+
+        // Deploy criteria don't apply to other event types.
+        let filter = EventFilter::from_query(&query(None, Some(&account_hex), None, None)).unwrap();
+        assert!(filter.matches(&SseData::ApiVersion(ProtocolVersion::V1_0_0)));
+    }
+
+    #[test]
+    fn should_reject_invalid_query_values() {
+        assert!(EventFilter::from_query(&query(Some("NotAnEvent"), None, None, None)).is_err());
+        assert!(EventFilter::from_query(&query(None, Some("01ab"), None, None)).is_err());
+        assert!(EventFilter::from_query(&query(None, None, Some("xyz"), None)).is_err());
+        assert!(EventFilter::from_query(&query(None, None, None, Some("hash-00"))).is_err());
+    }
+}
@@ -19,6 +19,7 @@ use casper_types::Key;
 use crate::{
     components::{fetcher::event::FetchResponder, Component},
     effect::{
+        announcements::{BlocklistAnnouncement, PeerFailure},
         requests::{ContractRuntimeRequest, LinearChainRequest, NetworkRequest, StorageRequest},
         EffectBuilder, EffectExt, Effects,
     },
@@ -40,6 +41,7 @@ pub trait ReactorEventT<T>:
     + From<ContractRuntimeRequest>
     // Won't be needed when we implement "get block by height" feature in storage.
     + From<LinearChainRequest<NodeId>>
+    + From<BlocklistAnnouncement<NodeId>>
     + Send
     + 'static
 where
@@ -57,6 +59,7 @@ where
         + From<StorageRequest>
         + From<ContractRuntimeRequest>
         + From<LinearChainRequest<NodeId>>
+        + From<BlocklistAnnouncement<NodeId>>
         + Send
         + 'static,
 {
@@ -354,13 +357,29 @@ where
             // We do nothing in the case of having an incoming deploy rejected.
             Event::RejectedRemotely { .. } => Effects::new(),
             Event::AbsentRemotely { id, peer } => {
+                // Honest peers may not have the item yet, so this doesn't affect their reputation.
                 info!(%id, %peer, "element absent on the remote node");
                 self.signal(id, None, peer)
             }
             Event::TimeoutPeer { id, peer } => {
                 info!(%id, %peer, "request timed out");
                 self.metrics.timeouts.inc();
-                self.signal(id, None, peer)
+                let mut effects = Effects::new();
+                // The timeout also fires if the peer did respond in time, in which case we are no
+                // longer waiting for it.
+                let still_waiting = self
+                    .responders()
+                    .get(&id)
+                    .map_or(false, |responders| responders.contains_key(&peer));
+                if still_waiting {
+                    effects.extend(
+                        effect_builder
+                            .announce_peer_failure(peer, PeerFailure::TimedOut)
+                            .ignore(),
+                    );
+                }
+                effects.extend(self.signal(id, None, peer));
+                effects
             }
         }
     }
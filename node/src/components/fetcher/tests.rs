@@ -15,7 +15,7 @@ use super::*;
 use crate::{
     components::{deploy_acceptor, in_memory_network::NetworkController, storage},
     effect::{
-        announcements::{DeployAcceptorAnnouncement, NetworkAnnouncement},
+        announcements::{BlocklistAnnouncement, DeployAcceptorAnnouncement, NetworkAnnouncement},
         Responder,
     },
     protocol::Message,
@@ -106,6 +106,8 @@ reactor!(Reactor {
         // from a client.
         RpcServerAnnouncement -> [deploy_acceptor];
         ChainspecLoaderAnnouncement -> [!];
+        // Peer failures only affect reputation, which this test does not track.
+        BlocklistAnnouncement<NodeId> -> [#];
     }
 });
 
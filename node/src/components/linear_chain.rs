@@ -79,9 +79,11 @@ where
             Outcome::StoreBlockSignatures(block_signatures) => effect_builder
                 .put_signatures_to_storage(block_signatures)
                 .ignore(),
-            Outcome::StoreExecutionResults(block_hash, execution_results) => effect_builder
-                .put_execution_results_to_storage(block_hash, execution_results)
-                .ignore(),
+            Outcome::StoreExecutionResults(block_hash, block_height, execution_results) => {
+                effect_builder
+                    .put_execution_results_to_storage(block_hash, block_height, execution_results)
+                    .ignore()
+            }
             Outcome::StoreBlock(block) => effect_builder
                 .put_block_to_storage(block.clone())
                 .event(move |_| Event::PutBlockResult { block }),
@@ -31,7 +31,7 @@ pub(super) enum Outcome {
     // Store block signatures to storage.
     StoreBlockSignatures(BlockSignatures),
     // Store execution results to storage.
-    StoreExecutionResults(BlockHash, HashMap<DeployHash, ExecutionResult>),
+    StoreExecutionResults(BlockHash, u64, HashMap<DeployHash, ExecutionResult>),
     // Store block.
     StoreBlock(Box<Block>),
     // Read finality signatures for the block from storage.
@@ -240,9 +240,11 @@ impl LinearChain {
             }
         };
         let block_hash = *block.hash();
+        let block_height = block.height();
         outcomes.push(Outcome::StoreBlock(block));
         outcomes.push(Outcome::StoreExecutionResults(
             block_hash,
+            block_height,
             execution_results,
         ));
         outcomes
@@ -388,10 +390,14 @@ mod tests {
             lc.handle_new_block(Box::new(block.clone()), execution_results.clone());
         let block_hash = *block.hash();
         match &*new_block_outcomes {
-            [Outcome::StoreBlock(outcome_block), Outcome::StoreExecutionResults(outcome_block_hash, outcome_execution_results)] =>
-            {
+            [Outcome::StoreBlock(outcome_block), Outcome::StoreExecutionResults(
+                outcome_block_hash,
+                outcome_block_height,
+                outcome_execution_results,
+            )] => {
                 assert_eq!(&**outcome_block, &block);
                 assert_eq!(outcome_block_hash, &block_hash);
+                assert_eq!(*outcome_block_height, block.height());
                 assert_eq!(outcome_execution_results, &execution_results);
             }
             others => panic!("unexpected outcome: {:?}", others),
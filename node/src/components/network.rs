@@ -12,7 +12,7 @@ mod tests;
 mod tests_bulk_gossip;
 
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     env,
     fmt::{self, Debug, Display, Formatter},
     marker::PhantomData,
@@ -1019,6 +1019,10 @@ impl<REv: ReactorEventT<P>, P: PayloadT> Component<REv> for Network<REv, P> {
                         .collect();
                     responder.respond(peers).ignore()
                 }
+                // This network component does not keep track of peer reputations.
+                NetworkInfoRequest::GetPeerReputations { responder } => {
+                    responder.respond(BTreeMap::new()).ignore()
+                }
             },
         }
     }
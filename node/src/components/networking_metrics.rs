@@ -14,6 +14,10 @@ pub(super) struct NetworkingMetrics {
     pub(super) queued_messages: IntGauge,
     /// Number of connected peers.
     pub(super) peers: IntGauge,
+    /// Number of messages sent compressed.
+    pub(super) compressed_messages: IntCounter,
+    /// Total number of bytes saved by compressing messages.
+    pub(super) compression_bytes_saved: IntCounter,
 
     // Potentially temporary metrics, not supported by all networking components:
     /// Number of do-nothing futures that have not finished executing for read requests.
@@ -45,6 +49,14 @@ impl NetworkingMetrics {
             "number of messages waiting to be sent out",
         )?;
         let peers = IntGauge::new("peers", "Number of connected peers.")?;
+        let compressed_messages = IntCounter::new(
+            "net_compressed_messages",
+            "number of messages sent compressed",
+        )?;
+        let compression_bytes_saved = IntCounter::new(
+            "net_compression_bytes_saved",
+            "total number of bytes saved by compressing messages",
+        )?;
 
         let read_futures_in_flight = prometheus::Gauge::new(
             "owm_read_futures_in_flight",
@@ -68,6 +80,8 @@ impl NetworkingMetrics {
         registry.register(Box::new(open_connections.clone()))?;
         registry.register(Box::new(queued_messages.clone()))?;
         registry.register(Box::new(peers.clone()))?;
+        registry.register(Box::new(compressed_messages.clone()))?;
+        registry.register(Box::new(compression_bytes_saved.clone()))?;
 
         registry.register(Box::new(read_futures_in_flight.clone()))?;
         registry.register(Box::new(read_futures_total.clone()))?;
@@ -80,6 +94,8 @@ impl NetworkingMetrics {
             open_connections,
             queued_messages,
             peers,
+            compressed_messages,
+            compression_bytes_saved,
             read_futures_in_flight,
             read_futures_total,
             write_futures_in_flight,
@@ -96,6 +112,8 @@ impl Drop for NetworkingMetrics {
         unregister_metric!(self.registry, self.open_connections);
         unregister_metric!(self.registry, self.queued_messages);
         unregister_metric!(self.registry, self.peers);
+        unregister_metric!(self.registry, self.compressed_messages);
+        unregister_metric!(self.registry, self.compression_bytes_saved);
         unregister_metric!(self.registry, self.read_futures_in_flight);
         unregister_metric!(self.registry, self.read_futures_total);
         unregister_metric!(self.registry, self.write_futures_in_flight);
@@ -17,6 +17,11 @@
 //!     example: curl -X GET 'http://<ip>:8888/status'
 //! /metrics : time series data collected from the internals of the node being queried.
 //!     example: curl -X GET 'http://<ip>:8888/metrics'
+//!
+//! If `enable_debug_endpoints` is set in the config, it additionally supports:
+//! /consensus/dump : the Highway protocol state of an era (by default the current one), as JSON or
+//!     as a graphviz DOT graph.  Takes the optional query parameters `era` and `format`.
+//!     example: curl -X GET 'http://<ip>:8888/consensus/dump?era=5&format=dot'
 
 mod config;
 mod event;
@@ -105,6 +110,7 @@ impl RestServer {
             api_version,
             shutdown_receiver,
             config.qps_limit,
+            config.enable_debug_endpoints,
         ));
 
         Ok(RestServer {
@@ -146,6 +152,13 @@ where
                     text,
                     main_responder: responder,
                 }),
+            Event::RestRequest(RestRequest::DumpConsensusState { era_id, responder }) => {
+                async move {
+                    let dump = effect_builder.dump_consensus_state(era_id).await;
+                    responder.respond(dump).await;
+                }
+                .ignore()
+            }
             Event::GetMetricsResult {
                 text,
                 main_responder,
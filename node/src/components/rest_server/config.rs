@@ -18,6 +18,10 @@ pub struct Config {
 
     /// Max rate limit in qps.
     pub qps_limit: u64,
+
+    /// Whether to serve endpoints intended for debugging, such as dumps of the consensus state.
+    #[serde(default)]
+    pub enable_debug_endpoints: bool,
 }
 
 impl Config {
@@ -26,6 +30,7 @@ impl Config {
         Config {
             address: DEFAULT_ADDRESS.to_string(),
             qps_limit: DEFAULT_QPS_LIMIT,
+            enable_debug_endpoints: false,
         }
     }
 }
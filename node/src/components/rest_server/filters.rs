@@ -1,6 +1,7 @@
 use futures::FutureExt;
-use http::Response;
+use http::{header, Response};
 use hyper::Body;
+use serde::Deserialize;
 use tracing::warn;
 use warp::{
     filters::BoxedFilter,
@@ -10,7 +11,7 @@ use warp::{
     Filter,
 };
 
-use casper_types::ProtocolVersion;
+use casper_types::{EraId, ProtocolVersion};
 
 use super::ReactorEventT;
 use crate::{
@@ -25,6 +26,33 @@ pub const STATUS_API_PATH: &str = "status";
 /// The metrics URL path.
 pub const METRICS_API_PATH: &str = "metrics";
 
+/// The URL path prefix of the consensus debug endpoints.
+pub const CONSENSUS_API_PATH: &str = "consensus";
+
+/// The consensus state dump URL path, below `CONSENSUS_API_PATH`.
+pub const CONSENSUS_DUMP_API_PATH: &str = "dump";
+
+/// The output format of the consensus state dump.
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum DumpFormat {
+    /// The `HighwayStateDump` as JSON.
+    Json,
+    /// The protocol DAG as a graphviz DOT graph.
+    Dot,
+}
+
+/// The consensus state dump endpoint's query string, e.g.
+/// `http://localhost:8888/consensus/dump?era=5&format=dot`
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+struct DumpQuery {
+    /// The era to dump.  Defaults to the current era.
+    era: Option<u64>,
+    /// The output format.  Defaults to JSON.
+    format: Option<DumpFormat>,
+}
+
 pub(super) fn create_status_filter<REv: ReactorEventT>(
     effect_builder: EffectBuilder<REv>,
     api_version: ProtocolVersion,
@@ -72,3 +100,38 @@ pub(super) fn create_metrics_filter<REv: ReactorEventT>(
         })
         .boxed()
 }
+
+pub(super) fn create_consensus_dump_filter<REv: ReactorEventT>(
+    effect_builder: EffectBuilder<REv>,
+) -> BoxedFilter<(Response<Body>,)> {
+    warp::get()
+        .and(warp::path(CONSENSUS_API_PATH))
+        .and(warp::path(CONSENSUS_DUMP_API_PATH))
+        .and(warp::path::end())
+        .and(warp::query())
+        .and_then(move |query: DumpQuery| {
+            let era_id = query.era.map(EraId::from);
+            effect_builder
+                .make_request(
+                    |responder| RestRequest::DumpConsensusState { era_id, responder },
+                    QueueKind::Api,
+                )
+                .map(move |maybe_dump| match maybe_dump {
+                    Some(dump) => match query.format.unwrap_or(DumpFormat::Json) {
+                        DumpFormat::Json => Ok::<_, Rejection>(reply::json(&dump).into_response()),
+                        DumpFormat::Dot => Ok(reply::with_header(
+                            dump.to_dot(),
+                            header::CONTENT_TYPE,
+                            "text/vnd.graphviz",
+                        )
+                        .into_response()),
+                    },
+                    None => Ok(reply::with_status(
+                        "era is not active or does not run Highway",
+                        StatusCode::NOT_FOUND,
+                    )
+                    .into_response()),
+                })
+        })
+        .boxed()
+}
@@ -21,12 +21,19 @@ pub(super) async fn run<REv: ReactorEventT>(
     api_version: ProtocolVersion,
     shutdown_receiver: oneshot::Receiver<()>,
     qps_limit: u64,
+    enable_debug_endpoints: bool,
 ) {
     // REST filters.
     let rest_status = filters::create_status_filter(effect_builder, api_version);
     let rest_metrics = filters::create_metrics_filter(effect_builder);
 
-    let service = warp::service(rest_status.or(rest_metrics));
+    let mut rest_filters = rest_status.or(rest_metrics).unify().boxed();
+    if enable_debug_endpoints {
+        let rest_consensus_dump = filters::create_consensus_dump_filter(effect_builder);
+        rest_filters = rest_filters.or(rest_consensus_dump).unify().boxed();
+    }
+
+    let service = warp::service(rest_filters);
 
     // Start the server, passing a oneshot receiver to allow the server to be shut down gracefully.
     let make_svc =
@@ -9,31 +9,55 @@
 //! This module currently provides both halves of what is required for an API server:
 //! a component implementation that interfaces with other components via being plugged into a
 //! reactor, and an external facing http server that exposes various uri routes and converts
-//! JSON-RPC requests into the appropriate component events.
+//! JSON-RPC requests into the appropriate component events.  JSON-RPC batch requests are split into
+//! their individual requests, up to a configured maximum batch size, and each of these counts
+//! against the server-wide rate limit.
+//!
+//! Each client IP address is rate limited individually, with more expensive methods costing more
+//! of the client's allowance, and methods can be disabled via an allowlist or denylist.
+//!
+//! The same JSON-RPC methods are also served over a WebSocket endpoint, which additionally allows
+//! clients to subscribe to notifications of new blocks and of the execution of given deploys.
+//! These are driven by the same announcements as the event stream server.
 //!
 //! For the list of supported RPC methods, see:
 //! <https://github.com/CasperLabs/ceps/blob/master/text/0009-client-api.md#rpcs>
 
+mod access_control;
+mod batch;
 mod config;
 mod event;
 mod http_server;
+mod metrics;
+mod qps_limit;
 pub mod rpcs;
+mod ws_server;
 
-use std::{convert::Infallible, fmt::Debug};
+use std::{convert::Infallible, fmt::Debug, sync::Arc};
 
 use datasize::DataSize;
 use futures::join;
+use prometheus::Registry;
+use thiserror::Error;
+use tokio::sync::broadcast;
 
 use casper_execution_engine::{
     core::engine_state::{
-        self, BalanceRequest, BalanceResult, GetBidsRequest, GetEraValidatorsError, QueryRequest,
-        QueryResult,
+        self, BalanceRequest, BalanceResult, BlockInfo, CallContractRequest, CallContractResult,
+        ContractIdentifier, DeployItem, ExecuteRequest, GetAuctionProofsRequest, GetBidsRequest,
+        GetEraValidatorsError, QueryRequest, QueryResult,
     },
+    shared::gas::Gas,
     storage::protocol_data::ProtocolData,
 };
-use casper_types::{system::auction::EraValidators, Key, ProtocolVersion, URef};
+use casper_types::{
+    account::AccountHash, system::auction::EraValidators, BlockTime, ExecutionResult, Key,
+    ProtocolVersion, RuntimeArgs, URef, SYSTEM_ACCOUNT, U512,
+};
 
-use self::rpcs::chain::BlockIdentifier;
+use self::{
+    access_control::AccessControl, metrics::RpcServerMetrics, rpcs::chain::BlockIdentifier,
+};
 
 use super::Component;
 use crate::{
@@ -47,13 +71,14 @@ use crate::{
         },
         EffectBuilder, EffectExt, Effects, Responder,
     },
-    types::{NodeId, StatusFeed},
+    types::{BlockHeader, Deploy, NodeId, StatusFeed},
     utils::{self, ListeningError},
     NodeRng,
 };
 
 pub use config::Config;
 pub(crate) use event::Event;
+use ws_server::Notification;
 
 /// A helper trait capturing all of this components Request type dependencies.
 pub trait ReactorEventT:
@@ -87,31 +112,66 @@ impl<REv> ReactorEventT for REv where
 {
 }
 
+/// An error starting the JSON-RPC server.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Failed to start listening.
+    #[error(transparent)]
+    Listening(#[from] ListeningError),
+
+    /// Failed to register the metrics.
+    #[error("prometheus (metrics) error: {0}")]
+    Metrics(#[from] prometheus::Error),
+}
+
 #[derive(DataSize, Debug)]
-pub(crate) struct RpcServer {}
+pub(crate) struct RpcServer {
+    /// Channel sender to pass notifications to the WebSocket subscribers.
+    #[data_size(skip)]
+    notification_sender: broadcast::Sender<Notification>,
+    /// Maximum gas which may be consumed by a single read-only contract call.
+    call_contract_gas_limit: u64,
+}
 
 impl RpcServer {
     pub(crate) fn new<REv>(
         config: Config,
         effect_builder: EffectBuilder<REv>,
         api_version: ProtocolVersion,
-    ) -> Result<Self, ListeningError>
+        registry: &Registry,
+    ) -> Result<Self, Error>
     where
         REv: ReactorEventT,
     {
+        let metrics = RpcServerMetrics::new(registry)?;
+        let access_control = Arc::new(AccessControl::new(&config, metrics));
         let builder = utils::start_listening(&config.address)?;
+        let (notification_sender, _) = broadcast::channel(config.subscription_channel_size);
         tokio::spawn(http_server::run(
             builder,
             effect_builder,
             api_version,
             config.qps_limit,
+            config.max_batch_size,
+            access_control,
+            notification_sender.clone(),
         ));
 
-        Ok(RpcServer {})
+        Ok(RpcServer {
+            notification_sender,
+            call_contract_gas_limit: config.call_contract_gas_limit,
+        })
     }
 }
 
 impl RpcServer {
+    /// Passes the notification to all WebSocket subscribers.
+    fn notify(&mut self, notification: Notification) -> Effects<Event> {
+        // An error only means there are currently no subscribers.
+        let _ = self.notification_sender.send(notification);
+        Effects::new()
+    }
+
     fn handle_protocol_data<REv: ReactorEventT>(
         &mut self,
         effect_builder: EffectBuilder<REv>,
@@ -174,6 +234,90 @@ impl RpcServer {
                 main_responder: responder,
             })
     }
+
+    #[allow(clippy::too_many_arguments)]
+    fn handle_call_contract<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        state_root_hash: Digest,
+        maybe_block_header: Option<BlockHeader>,
+        caller: Option<AccountHash>,
+        contract: ContractIdentifier,
+        entry_point: String,
+        args: RuntimeArgs,
+        protocol_version: ProtocolVersion,
+        responder: Responder<Result<CallContractResult, engine_state::Error>>,
+    ) -> Effects<Event> {
+        let gas_limit = Gas::new(U512::from(self.call_contract_gas_limit));
+        // The call is made as if in the block the state root hash belongs to, at that block's time.
+        let block_info = maybe_block_header
+            .map(|block_header| {
+                BlockInfo::new(
+                    BlockTime::new(block_header.timestamp().millis()),
+                    block_header.height(),
+                    block_header.era_id(),
+                    (*block_header.parent_hash().inner()).into(),
+                )
+            })
+            .unwrap_or_default();
+        let request = CallContractRequest::new(
+            state_root_hash.into(),
+            block_info,
+            protocol_version,
+            caller,
+            contract,
+            entry_point,
+            args,
+            gas_limit,
+        );
+        effect_builder
+            .call_contract(request)
+            .event(move |result| Event::CallContractResult {
+                result,
+                main_responder: responder,
+            })
+    }
+
+    fn handle_speculative_execute<REv: ReactorEventT>(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        state_root_hash: Digest,
+        maybe_block_header: Option<BlockHeader>,
+        deploy: Deploy,
+        protocol_version: ProtocolVersion,
+        responder: Responder<Result<ExecutionResult, engine_state::Error>>,
+    ) -> Effects<Event> {
+        // The deploy is executed as if it were included in the block the state root hash belongs
+        // to, but at the time it was created.  If only the state root hash was given, the block
+        // isn't known and the block height, era and parent hash are left at their defaults.  The
+        // proposer only affects who receives the payment, not the cost of execution.
+        let block_time = deploy.header().timestamp().millis();
+        let (block_height, era_id, parent_block_hash) = maybe_block_header
+            .map(|block_header| {
+                (
+                    block_header.height(),
+                    block_header.era_id(),
+                    (*block_header.parent_hash().inner()).into(),
+                )
+            })
+            .unwrap_or_default();
+        let execute_request = ExecuteRequest::new(
+            state_root_hash.into(),
+            block_time,
+            block_height,
+            era_id,
+            parent_block_hash,
+            vec![DeployItem::from(deploy)],
+            protocol_version,
+            SYSTEM_ACCOUNT,
+        );
+        effect_builder
+            .speculative_execute(execute_request)
+            .event(move |result| Event::SpeculativeExecuteResult {
+                result,
+                main_responder: responder,
+            })
+    }
 }
 
 impl<REv> Component<REv> for RpcServer
@@ -265,11 +409,59 @@ where
                         main_responder: responder,
                     })
             }
+            Event::RpcRequest(RpcRequest::GetAuctionProofs {
+                state_root_hash,
+                protocol_version,
+                responder,
+            }) => {
+                let get_auction_proofs_request =
+                    GetAuctionProofsRequest::new(state_root_hash.into(), protocol_version);
+                effect_builder
+                    .get_auction_proofs(get_auction_proofs_request)
+                    .event(move |result| Event::GetAuctionProofsResult {
+                        result,
+                        main_responder: responder,
+                    })
+            }
             Event::RpcRequest(RpcRequest::GetBalance {
                 state_root_hash,
                 purse_uref,
                 responder,
             }) => self.handle_get_balance(effect_builder, state_root_hash, purse_uref, responder),
+            Event::RpcRequest(RpcRequest::SpeculativeExecute {
+                state_root_hash,
+                block_header,
+                deploy,
+                protocol_version,
+                responder,
+            }) => self.handle_speculative_execute(
+                effect_builder,
+                state_root_hash,
+                block_header.map(|block_header| *block_header),
+                *deploy,
+                protocol_version,
+                responder,
+            ),
+            Event::RpcRequest(RpcRequest::CallContract {
+                state_root_hash,
+                block_header,
+                caller,
+                contract,
+                entry_point,
+                args,
+                protocol_version,
+                responder,
+            }) => self.handle_call_contract(
+                effect_builder,
+                state_root_hash,
+                block_header.map(|block_header| *block_header),
+                caller.map(|account_hash| *account_hash),
+                *contract,
+                entry_point,
+                args,
+                protocol_version,
+                responder,
+            ),
             Event::RpcRequest(RpcRequest::GetDeploy { hash, responder }) => effect_builder
                 .get_deploy_and_metadata_from_storage(hash)
                 .event(move |result| Event::GetDeployResult {
@@ -277,12 +469,43 @@ where
                     result: Box::new(result),
                     main_responder: responder,
                 }),
+            Event::RpcRequest(RpcRequest::GetAccountDeploys {
+                account_hash,
+                offset,
+                limit,
+                responder,
+            }) => effect_builder
+                .get_account_deploys_from_storage(*account_hash, offset, limit)
+                .event(move |result| Event::GetAccountDeploysResult {
+                    account_hash,
+                    result,
+                    main_responder: responder,
+                }),
+            Event::RpcRequest(RpcRequest::GetEraFaults { era_id, responder }) => effect_builder
+                .get_era_faults_from_storage(era_id)
+                .event(move |result| Event::GetEraFaultsResult {
+                    era_id,
+                    result,
+                    main_responder: responder,
+                }),
             Event::RpcRequest(RpcRequest::GetPeers { responder }) => effect_builder
                 .network_peers()
                 .event(move |peers| Event::GetPeersResult {
                     peers,
                     main_responder: responder,
                 }),
+            Event::RpcRequest(RpcRequest::GetPeerReputations { responder }) => async move {
+                let reputations = effect_builder.network_peer_reputations().await;
+                responder.respond(reputations).await;
+            }
+            .ignore(),
+            Event::RpcRequest(RpcRequest::GetValidatorPerformance { era_id, responder }) => {
+                async move {
+                    let performance = effect_builder.get_validator_performance(era_id).await;
+                    responder.respond(performance).await;
+                }
+                .ignore()
+            }
             Event::RpcRequest(RpcRequest::GetStatus { responder }) => async move {
                 let (last_added_block, peers, chainspec_info, consensus_status) = join!(
                     effect_builder.get_highest_block_from_storage(),
@@ -327,15 +550,37 @@ where
                 result,
                 main_responder,
             } => main_responder.respond(result).ignore(),
+            Event::GetAuctionProofsResult {
+                result,
+                main_responder,
+            } => main_responder.respond(result).ignore(),
             Event::GetBalanceResult {
                 result,
                 main_responder,
             } => main_responder.respond(result).ignore(),
+            Event::SpeculativeExecuteResult {
+                result,
+                main_responder,
+            } => main_responder.respond(result).ignore(),
+            Event::CallContractResult {
+                result,
+                main_responder,
+            } => main_responder.respond(result).ignore(),
             Event::GetDeployResult {
                 hash: _,
                 result,
                 main_responder,
             } => main_responder.respond(*result).ignore(),
+            Event::GetAccountDeploysResult {
+                result,
+                main_responder,
+                ..
+            } => main_responder.respond(result).ignore(),
+            Event::GetEraFaultsResult {
+                result,
+                main_responder,
+                ..
+            } => main_responder.respond(result).ignore(),
             Event::GetPeersResult {
                 peers,
                 main_responder,
@@ -344,6 +589,16 @@ where
                 text,
                 main_responder,
             } => main_responder.respond(text).ignore(),
+            Event::BlockAdded(block) => self.notify(Notification::BlockAdded(block)),
+            Event::DeployProcessed {
+                deploy_hash,
+                block_hash,
+                execution_result,
+            } => self.notify(Notification::DeployProcessed {
+                deploy_hash,
+                block_hash,
+                execution_result,
+            }),
         }
     }
 }
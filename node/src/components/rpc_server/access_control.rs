@@ -0,0 +1,417 @@
+//! Per-client rate limiting and method access control for the JSON-RPC server.
+//!
+//! Each client IP address has a token bucket which refills at the configured per-client rate up to
+//! the configured burst limit.  Every request costs the configured weight of its method (1 if not
+//! configured), and is rejected if the client's bucket holds too few tokens to pay for it.
+//! Requests for methods excluded by the allowlist or denylist are rejected without being charged.
+//!
+//! The checks apply to the individual requests of a batch, and to requests received over the
+//! WebSocket endpoint, since both are passed through the same service.  The WebSocket subscription
+//! methods, which are handled by the WebSocket session itself, are checked there.
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    mem,
+    net::IpAddr,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::Instant,
+};
+
+use futures::{future::BoxFuture, FutureExt};
+use http::{Method, Request, Response};
+use hyper::Body;
+use serde_json::Value;
+use tower::Service;
+use tracing::debug;
+
+use super::{
+    http_server::{new_error_response, new_error_response_with_id},
+    metrics::RpcServerMetrics,
+    rpcs::ErrorCode,
+    Config,
+};
+
+/// The maximum number of client buckets tracked before full ones are discarded.
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
+/// The IP address of the client which sent a request, stored in the request's extensions.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct ClientIp(pub(super) IpAddr);
+
+/// The reason a request was rejected.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Rejection {
+    /// The method is not served by this node.
+    MethodNotAllowed,
+    /// The client has exceeded its rate limit.
+    RateLimited,
+}
+
+impl Rejection {
+    pub(super) fn to_error(self) -> warp_json_rpc::Error {
+        match self {
+            Rejection::MethodNotAllowed => warp_json_rpc::Error::custom(
+                ErrorCode::MethodNotAllowed as i64,
+                "method not allowed",
+            ),
+            Rejection::RateLimited => {
+                warp_json_rpc::Error::custom(ErrorCode::RateLimited as i64, "rate limit exceeded")
+            }
+        }
+    }
+}
+
+/// A token bucket holding a client's remaining allowance.
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// Refills the bucket for the time elapsed since it was last refilled.
+    fn refill(&mut self, rate: f64, capacity: f64, now: Instant) {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * rate).min(capacity);
+        self.last_refill = now;
+    }
+}
+
+/// The shared state for rate limiting and access control of all clients.
+#[derive(Debug)]
+pub(super) struct AccessControl {
+    /// Tokens added to each client's bucket per second.
+    client_qps_limit: f64,
+    /// The maximum number of tokens in each client's bucket.
+    client_burst_limit: f64,
+    method_costs: BTreeMap<String, u64>,
+    /// If non-empty, the only methods allowed.
+    allowed_methods: HashSet<String>,
+    denied_methods: HashSet<String>,
+    buckets: Mutex<HashMap<IpAddr, TokenBucket>>,
+    metrics: RpcServerMetrics,
+}
+
+impl AccessControl {
+    pub(super) fn new(config: &Config, metrics: RpcServerMetrics) -> Self {
+        AccessControl {
+            client_qps_limit: config.client_qps_limit as f64,
+            client_burst_limit: config.client_burst_limit as f64,
+            method_costs: config.method_costs.clone(),
+            allowed_methods: config.allowed_methods.iter().cloned().collect(),
+            denied_methods: config.denied_methods.iter().cloned().collect(),
+            buckets: Mutex::new(HashMap::new()),
+            metrics,
+        }
+    }
+
+    /// Checks whether a request for the given method from the given client may be served, charging
+    /// the client for it if so.
+    ///
+    /// Requests which are not JSON-RPC requests, such as WebSocket upgrades, have no method and
+    /// are charged the default cost.
+    pub(super) fn check(
+        &self,
+        maybe_client_ip: Option<IpAddr>,
+        maybe_method: Option<&str>,
+    ) -> Result<(), Rejection> {
+        let result = self.check_at(maybe_client_ip, maybe_method, Instant::now());
+        match result {
+            Err(Rejection::MethodNotAllowed) => self.metrics.disallowed_requests.inc(),
+            Err(Rejection::RateLimited) => self.metrics.rate_limited_requests.inc(),
+            Ok(()) => (),
+        }
+        result
+    }
+
+    fn check_at(
+        &self,
+        maybe_client_ip: Option<IpAddr>,
+        maybe_method: Option<&str>,
+        now: Instant,
+    ) -> Result<(), Rejection> {
+        if let Some(method) = maybe_method {
+            if self.denied_methods.contains(method)
+                || (!self.allowed_methods.is_empty() && !self.allowed_methods.contains(method))
+            {
+                return Err(Rejection::MethodNotAllowed);
+            }
+        }
+
+        let client_ip = match maybe_client_ip {
+            Some(client_ip) if self.client_qps_limit > 0.0 => client_ip,
+            _ => return Ok(()),
+        };
+
+        // Costs above the burst limit are capped, otherwise such requests could never be served.
+        let cost = maybe_method
+            .and_then(|method| self.method_costs.get(method))
+            .map_or(1.0, |cost| *cost as f64)
+            .min(self.client_burst_limit);
+
+        let mut buckets = self.buckets.lock().expect("lock poisoned");
+        if buckets.len() >= MAX_TRACKED_CLIENTS {
+            // Clients with full buckets are indistinguishable from new ones, so can be forgotten.
+            let (rate, capacity) = (self.client_qps_limit, self.client_burst_limit);
+            buckets.retain(|_, bucket| {
+                bucket.refill(rate, capacity, now);
+                bucket.tokens < capacity
+            });
+        }
+        let bucket = buckets.entry(client_ip).or_insert_with(|| TokenBucket {
+            tokens: self.client_burst_limit,
+            last_refill: now,
+        });
+        bucket.refill(self.client_qps_limit, self.client_burst_limit, now);
+        if bucket.tokens < cost {
+            return Err(Rejection::RateLimited);
+        }
+        bucket.tokens -= cost;
+        Ok(())
+    }
+}
+
+/// A service applying the rate limits and method restrictions of `AccessControl` to requests
+/// before passing them to the wrapped service.
+///
+/// Batches must already have been split into their individual requests.
+#[derive(Clone)]
+pub(super) struct AccessControlService<S> {
+    inner: S,
+    access_control: Arc<AccessControl>,
+}
+
+impl<S> AccessControlService<S> {
+    pub(super) fn new(inner: S, access_control: Arc<AccessControl>) -> Self {
+        AccessControlService {
+            inner,
+            access_control,
+        }
+    }
+}
+
+impl<S> Service<Request<Body>> for AccessControlService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    type Response = Response<Body>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Response<Body>, S::Error>>;
+
+    fn poll_ready(&mut self, context: &mut Context<'_>) -> Poll<Result<(), S::Error>> {
+        self.inner.poll_ready(context)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        // Use the instance which has been polled ready, leaving a fresh clone in its place.
+        let clone = self.inner.clone();
+        let inner = mem::replace(&mut self.inner, clone);
+        handle_request(inner, Arc::clone(&self.access_control), request).boxed()
+    }
+}
+
+async fn handle_request<S>(
+    mut inner: S,
+    access_control: Arc<AccessControl>,
+    request: Request<Body>,
+) -> Result<Response<Body>, S::Error>
+where
+    S: Service<Request<Body>, Response = Response<Body>>,
+{
+    let maybe_client_ip = request
+        .extensions()
+        .get::<ClientIp>()
+        .map(|ClientIp(client_ip)| *client_ip);
+
+    if request.method() != Method::POST {
+        if let Err(rejection) = access_control.check(maybe_client_ip, None) {
+            debug!(?maybe_client_ip, ?rejection, "rejected request");
+            return Ok(new_error_response(rejection.to_error()));
+        }
+        return inner.call(request).await;
+    }
+
+    let (parts, body) = request.into_parts();
+    let bytes = match hyper::body::to_bytes(body).await {
+        Ok(bytes) => bytes,
+        Err(_) => return Ok(new_error_response(warp_json_rpc::Error::PARSE_ERROR)),
+    };
+
+    // Requests which fail to parse are passed on, to be rejected with the appropriate error.
+    let maybe_request: Option<Value> = serde_json::from_slice(&bytes).ok();
+    let maybe_method = maybe_request
+        .as_ref()
+        .and_then(|request| request.get("method"))
+        .and_then(Value::as_str);
+    if let Err(rejection) = access_control.check(maybe_client_ip, maybe_method) {
+        debug!(
+            ?maybe_client_ip,
+            ?maybe_method,
+            ?rejection,
+            "rejected request"
+        );
+        let id = maybe_request
+            .as_ref()
+            .and_then(|request| request.get("id"))
+            .cloned()
+            .unwrap_or(Value::Null);
+        return Ok(new_error_response_with_id(id, rejection.to_error()));
+    }
+
+    inner
+        .call(Request::from_parts(parts, Body::from(bytes)))
+        .await
+}
+
+/// A service tagging each request with the IP address of the client which sent it.
+#[derive(Clone)]
+pub(super) struct WithClientIp<S> {
+    inner: S,
+    client_ip: ClientIp,
+}
+
+impl<S> WithClientIp<S> {
+    pub(super) fn new(inner: S, client_ip: IpAddr) -> Self {
+        WithClientIp {
+            inner,
+            client_ip: ClientIp(client_ip),
+        }
+    }
+}
+
+impl<S> Service<Request<Body>> for WithClientIp<S>
+where
+    S: Service<Request<Body>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, context: &mut Context<'_>) -> Poll<Result<(), S::Error>> {
+        self.inner.poll_ready(context)
+    }
+
+    fn call(&mut self, mut request: Request<Body>) -> Self::Future {
+        request.extensions_mut().insert(self.client_ip);
+        self.inner.call(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::Ipv4Addr, time::Duration};
+
+    use prometheus::Registry;
+
+    use super::*;
+
+    const CHEAP_METHOD: &str = "info_get_status";
+    const EXPENSIVE_METHOD: &str = "state_get_auction_info";
+
+    fn new_access_control(config: Config) -> AccessControl {
+        let metrics = RpcServerMetrics::new(&Registry::new()).unwrap();
+        AccessControl::new(&config, metrics)
+    }
+
+    fn config(client_qps_limit: u64, client_burst_limit: u64) -> Config {
+        let mut config = Config::new();
+        config.client_qps_limit = client_qps_limit;
+        config.client_burst_limit = client_burst_limit;
+        config.method_costs = vec![(EXPENSIVE_METHOD.to_string(), 5)]
+            .into_iter()
+            .collect();
+        config
+    }
+
+    fn ip(last_octet: u8) -> Option<IpAddr> {
+        Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, last_octet)))
+    }
+
+    #[test]
+    fn should_limit_each_client_separately() {
+        let access_control = new_access_control(config(1, 3));
+        let now = Instant::now();
+
+        for _ in 0..3 {
+            assert!(access_control
+                .check_at(ip(1), Some(CHEAP_METHOD), now)
+                .is_ok());
+        }
+        assert_eq!(
+            access_control.check_at(ip(1), Some(CHEAP_METHOD), now),
+            Err(Rejection::RateLimited)
+        );
+        assert!(access_control
+            .check_at(ip(2), Some(CHEAP_METHOD), now)
+            .is_ok());
+
+        // After a second, one more request is allowed.
+        let later = now + Duration::from_secs(1);
+        assert!(access_control
+            .check_at(ip(1), Some(CHEAP_METHOD), later)
+            .is_ok());
+        assert_eq!(
+            access_control.check_at(ip(1), Some(CHEAP_METHOD), later),
+            Err(Rejection::RateLimited)
+        );
+    }
+
+    #[test]
+    fn should_charge_method_costs() {
+        let access_control = new_access_control(config(1, 6));
+        let now = Instant::now();
+
+        assert!(access_control
+            .check_at(ip(1), Some(EXPENSIVE_METHOD), now)
+            .is_ok());
+        assert_eq!(
+            access_control.check_at(ip(1), Some(EXPENSIVE_METHOD), now),
+            Err(Rejection::RateLimited)
+        );
+        assert!(access_control
+            .check_at(ip(1), Some(CHEAP_METHOD), now)
+            .is_ok());
+    }
+
+    #[test]
+    fn should_not_limit_when_disabled() {
+        let access_control = new_access_control(config(0, 1));
+        let now = Instant::now();
+
+        for _ in 0..10 {
+            assert!(access_control
+                .check_at(ip(1), Some(EXPENSIVE_METHOD), now)
+                .is_ok());
+        }
+    }
+
+    #[test]
+    fn should_apply_allowlist_and_denylist() {
+        let mut config = config(0, 1);
+        config.denied_methods = vec!["account_put_deploy".to_string()];
+        let access_control = new_access_control(config.clone());
+        let now = Instant::now();
+        assert_eq!(
+            access_control.check_at(ip(1), Some("account_put_deploy"), now),
+            Err(Rejection::MethodNotAllowed)
+        );
+        assert!(access_control
+            .check_at(ip(1), Some(CHEAP_METHOD), now)
+            .is_ok());
+
+        config.denied_methods.clear();
+        config.allowed_methods = vec![CHEAP_METHOD.to_string()];
+        let access_control = new_access_control(config);
+        assert_eq!(
+            access_control.check_at(ip(1), Some(EXPENSIVE_METHOD), now),
+            Err(Rejection::MethodNotAllowed)
+        );
+        assert!(access_control
+            .check_at(ip(1), Some(CHEAP_METHOD), now)
+            .is_ok());
+    }
+}
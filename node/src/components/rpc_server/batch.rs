@@ -0,0 +1,153 @@
+//! Support for JSON-RPC 2.0 batch requests.
+//!
+//! A batch is a JSON array of requests sent in a single HTTP request or WebSocket message.  Each
+//! request in the batch is passed individually to the wrapped service, all of them concurrently,
+//! and the responses are returned together as a JSON array in the order of the requests.
+//!
+//! See <https://www.jsonrpc.org/specification#batch> for details.
+
+use std::{
+    mem,
+    task::{Context, Poll},
+};
+
+use futures::{
+    future::{self, BoxFuture},
+    FutureExt,
+};
+use http::{header::CONTENT_TYPE, Method, Request, Response, StatusCode};
+use hyper::Body;
+use serde_json::Value;
+use tower::Service;
+
+use super::{access_control::ClientIp, http_server::new_error_response, rpcs::ErrorCode};
+
+/// A service handling JSON-RPC batch requests by splitting them into their individual requests.
+///
+/// Requests which are not batches are passed to the wrapped service unaltered.
+#[derive(Clone)]
+pub(super) struct BatchService<S> {
+    inner: S,
+    max_batch_size: usize,
+}
+
+impl<S> BatchService<S> {
+    /// Wraps `inner`, rejecting batches of more than `max_batch_size` requests.
+    pub(super) fn new(inner: S, max_batch_size: usize) -> Self {
+        BatchService {
+            inner,
+            max_batch_size,
+        }
+    }
+}
+
+impl<S> Service<Request<Body>> for BatchService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Future: Send,
+    S::Error: Send,
+{
+    type Response = Response<Body>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Response<Body>, S::Error>>;
+
+    fn poll_ready(&mut self, context: &mut Context<'_>) -> Poll<Result<(), S::Error>> {
+        self.inner.poll_ready(context)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        // Use the instance which has been polled ready, leaving a fresh clone in its place.
+        let clone = self.inner.clone();
+        let inner = mem::replace(&mut self.inner, clone);
+        handle_request(inner, self.max_batch_size, request).boxed()
+    }
+}
+
+async fn handle_request<S>(
+    mut inner: S,
+    max_batch_size: usize,
+    request: Request<Body>,
+) -> Result<Response<Body>, S::Error>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone,
+{
+    if request.method() != Method::POST {
+        return inner.call(request).await;
+    }
+
+    let (parts, body) = request.into_parts();
+    let bytes = match hyper::body::to_bytes(body).await {
+        Ok(bytes) => bytes,
+        Err(_) => return Ok(new_error_response(warp_json_rpc::Error::PARSE_ERROR)),
+    };
+
+    let is_batch = bytes
+        .iter()
+        .find(|byte| !byte.is_ascii_whitespace())
+        .map_or(false, |first_byte| *first_byte == b'[');
+    if !is_batch {
+        return inner
+            .call(Request::from_parts(parts, Body::from(bytes)))
+            .await;
+    }
+
+    let batch: Vec<Value> = match serde_json::from_slice(&bytes) {
+        Ok(batch) => batch,
+        Err(_) => return Ok(new_error_response(warp_json_rpc::Error::PARSE_ERROR)),
+    };
+    if batch.is_empty() {
+        return Ok(new_error_response(warp_json_rpc::Error::INVALID_REQUEST));
+    }
+    if batch.len() > max_batch_size {
+        return Ok(new_error_response(warp_json_rpc::Error::custom(
+            ErrorCode::BatchTooLarge as i64,
+            "batch too large",
+        )));
+    }
+
+    let responses = future::join_all(batch.into_iter().map(|single_request| {
+        let mut builder = Request::builder()
+            .method(Method::POST)
+            .uri(parts.uri.clone());
+        for (name, value) in &parts.headers {
+            builder = builder.header(name, value);
+        }
+        if let Some(client_ip) = parts.extensions.get::<ClientIp>() {
+            builder = builder.extension(*client_ip);
+        }
+        let request = builder.body(Body::from(single_request.to_string()));
+        let mut inner = inner.clone();
+        async move {
+            let request = match request {
+                Ok(request) => request,
+                Err(_) => return Ok(None),
+            };
+            future::poll_fn(|context| inner.poll_ready(context)).await?;
+            let response = inner.call(request).await?;
+            Ok(hyper::body::to_bytes(response.into_body()).await.ok())
+        }
+    }))
+    .await;
+
+    // Notifications receive no response, so are omitted from the batch response.
+    let mut response_bodies = vec![];
+    for response in responses {
+        if let Some(body) = response? {
+            if !body.is_empty() {
+                response_bodies.push(String::from_utf8_lossy(&body).into_owned());
+            }
+        }
+    }
+
+    // If there are no responses at all, nothing is returned rather than an empty array.
+    let body = if response_bodies.is_empty() {
+        Body::empty()
+    } else {
+        Body::from(format!("[{}]", response_bodies.join(",")))
+    };
+    Ok(Response::builder()
+        .status(StatusCode::OK)
+        .header(CONTENT_TYPE, "application/json")
+        .body(body)
+        .unwrap())
+}
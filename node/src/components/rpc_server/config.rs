@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use datasize::DataSize;
 use serde::{Deserialize, Serialize};
 
@@ -7,6 +9,22 @@ use serde::{Deserialize, Serialize};
 const DEFAULT_ADDRESS: &str = "0.0.0.0:0";
 /// Default rate limit in qps.
 const DEFAULT_QPS_LIMIT: u64 = 100;
+/// Default per-client rate limit in request cost units per second.
+const DEFAULT_CLIENT_QPS_LIMIT: u64 = 20;
+/// Default per-client burst size in request cost units.
+const DEFAULT_CLIENT_BURST_LIMIT: u64 = 40;
+/// Default cost weights of the more expensive methods.
+const DEFAULT_METHOD_COSTS: [(&str, u64); 3] = [
+    ("state_get_auction_info", 10),
+    ("state_speculative_exec", 20),
+    ("state_call_contract", 20),
+];
+/// Default maximum number of requests in a single JSON-RPC batch.
+const DEFAULT_MAX_BATCH_SIZE: usize = 100;
+/// Default maximum gas which may be consumed by a single read-only contract call.
+const DEFAULT_CALL_CONTRACT_GAS_LIMIT: u64 = 1_000_000_000;
+/// Default subscription notification channel size.
+const DEFAULT_SUBSCRIPTION_CHANNEL_SIZE: usize = 100;
 
 /// JSON-RPC HTTP server configuration.
 #[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
@@ -16,8 +34,43 @@ pub struct Config {
     /// Address to bind JSON-RPC HTTP server to.
     pub address: String,
 
-    /// Max rate limit in qps.
+    /// Max rate limit in qps across all clients.  Each request of a batch counts individually.
     pub qps_limit: u64,
+
+    /// Rate limit applied to each client IP address, in request cost units per second.  A value
+    /// of 0 disables per-client rate limiting, which is the case if omitted.
+    #[serde(default)]
+    pub client_qps_limit: u64,
+
+    /// Maximum number of request cost units each client IP address can use in a burst.
+    #[serde(default)]
+    pub client_burst_limit: u64,
+
+    /// Cost weights of methods for the per-client rate limit.  Unlisted methods cost 1.
+    #[serde(default)]
+    pub method_costs: BTreeMap<String, u64>,
+
+    /// Methods which are served.  If empty, all methods other than those in `denied_methods` are
+    /// served.
+    #[serde(default)]
+    pub allowed_methods: Vec<String>,
+
+    /// Methods which are never served.
+    #[serde(default)]
+    pub denied_methods: Vec<String>,
+
+    /// Maximum number of requests allowed in a single JSON-RPC batch.
+    #[serde(default = "default_max_batch_size")]
+    pub max_batch_size: usize,
+
+    /// Maximum gas which may be consumed by a single "state_call_contract" request.
+    #[serde(default = "default_call_contract_gas_limit")]
+    pub call_contract_gas_limit: u64,
+
+    /// The number of notifications to buffer for WebSocket subscribers in the tokio broadcast
+    /// channel before slower clients start missing them.
+    #[serde(default = "default_subscription_channel_size")]
+    pub subscription_channel_size: usize,
 }
 
 impl Config {
@@ -26,6 +79,17 @@ impl Config {
         Config {
             address: DEFAULT_ADDRESS.to_string(),
             qps_limit: DEFAULT_QPS_LIMIT,
+            client_qps_limit: DEFAULT_CLIENT_QPS_LIMIT,
+            client_burst_limit: DEFAULT_CLIENT_BURST_LIMIT,
+            method_costs: DEFAULT_METHOD_COSTS
+                .iter()
+                .map(|(method, cost)| (method.to_string(), *cost))
+                .collect(),
+            allowed_methods: vec![],
+            denied_methods: vec![],
+            max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+            call_contract_gas_limit: DEFAULT_CALL_CONTRACT_GAS_LIMIT,
+            subscription_channel_size: DEFAULT_SUBSCRIPTION_CHANNEL_SIZE,
         }
     }
 }
@@ -35,3 +99,15 @@ impl Default for Config {
         Config::new()
     }
 }
+
+fn default_max_batch_size() -> usize {
+    DEFAULT_MAX_BATCH_SIZE
+}
+
+fn default_call_contract_gas_limit() -> u64 {
+    DEFAULT_CALL_CONTRACT_GAS_LIMIT
+}
+
+fn default_subscription_channel_size() -> usize {
+    DEFAULT_SUBSCRIPTION_CHANNEL_SIZE
+}
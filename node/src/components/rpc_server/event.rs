@@ -6,15 +6,22 @@ use std::{
 use derive_more::From;
 
 use casper_execution_engine::{
-    core::engine_state::{self, BalanceResult, GetBidsResult, GetEraValidatorsError, QueryResult},
+    core::engine_state::{
+        self, BalanceResult, CallContractResult, GetAuctionProofsResult, GetBidsResult,
+        GetEraValidatorsError, QueryResult,
+    },
     storage::protocol_data::ProtocolData,
 };
-use casper_types::{system::auction::EraValidators, Transfer};
+use casper_types::{
+    account::AccountHash, system::auction::EraValidators, EraId, ExecutionResult, Transfer,
+};
 
 use crate::{
     effect::{requests::RpcRequest, Responder},
     rpcs::chain::BlockIdentifier,
-    types::{Block, BlockHash, BlockSignatures, Deploy, DeployHash, DeployMetadata, NodeId},
+    types::{
+        Block, BlockHash, BlockSignatures, Deploy, DeployHash, DeployMetadata, EraFault, NodeId,
+    },
 };
 
 #[derive(Debug, From)]
@@ -47,11 +54,33 @@ pub enum Event {
         result: Result<GetBidsResult, engine_state::Error>,
         main_responder: Responder<Result<GetBidsResult, engine_state::Error>>,
     },
+    GetAuctionProofsResult {
+        result: Result<GetAuctionProofsResult, engine_state::Error>,
+        main_responder: Responder<Result<GetAuctionProofsResult, engine_state::Error>>,
+    },
+    CallContractResult {
+        result: Result<CallContractResult, engine_state::Error>,
+        main_responder: Responder<Result<CallContractResult, engine_state::Error>>,
+    },
+    SpeculativeExecuteResult {
+        result: Result<ExecutionResult, engine_state::Error>,
+        main_responder: Responder<Result<ExecutionResult, engine_state::Error>>,
+    },
     GetDeployResult {
         hash: DeployHash,
         result: Box<Option<(Deploy, DeployMetadata)>>,
         main_responder: Responder<Option<(Deploy, DeployMetadata)>>,
     },
+    GetAccountDeploysResult {
+        account_hash: Box<AccountHash>,
+        result: Option<Vec<(DeployHash, u64)>>,
+        main_responder: Responder<Option<Vec<(DeployHash, u64)>>>,
+    },
+    GetEraFaultsResult {
+        era_id: EraId,
+        result: Vec<EraFault>,
+        main_responder: Responder<Vec<EraFault>>,
+    },
     GetPeersResult {
         peers: BTreeMap<NodeId, String>,
         main_responder: Responder<BTreeMap<NodeId, String>>,
@@ -64,6 +93,14 @@ pub enum Event {
         result: Result<BalanceResult, engine_state::Error>,
         main_responder: Responder<Result<BalanceResult, engine_state::Error>>,
     },
+    /// A block was added to the linear chain.
+    BlockAdded(Box<Block>),
+    /// A deploy was executed as part of the given block.
+    DeployProcessed {
+        deploy_hash: DeployHash,
+        block_hash: BlockHash,
+        execution_result: Box<ExecutionResult>,
+    },
 }
 
 impl Display for Event {
@@ -104,17 +141,45 @@ impl Display for Event {
             Event::GetBidsResult { result, .. } => {
                 write!(formatter, "get bids result: {:?}", result)
             }
+            Event::GetAuctionProofsResult { result, .. } => {
+                write!(formatter, "get auction proofs result: {:?}", result)
+            }
             Event::GetBalanceResult { result, .. } => {
                 write!(formatter, "balance result: {:?}", result)
             }
+            Event::CallContractResult { result, .. } => {
+                write!(formatter, "call contract result: {:?}", result)
+            }
+            Event::SpeculativeExecuteResult { result, .. } => {
+                write!(formatter, "speculative execute result: {:?}", result)
+            }
             Event::GetDeployResult { hash, result, .. } => {
                 write!(formatter, "get deploy result for {}: {:?}", hash, result)
             }
+            Event::GetAccountDeploysResult {
+                account_hash,
+                result,
+                ..
+            } => write!(
+                formatter,
+                "get account deploys result for {}: {:?}",
+                account_hash, result
+            ),
+            Event::GetEraFaultsResult { era_id, result, .. } => write!(
+                formatter,
+                "get era faults result for era {}: {}",
+                era_id,
+                result.len()
+            ),
             Event::GetPeersResult { peers, .. } => write!(formatter, "get peers: {}", peers.len()),
             Event::GetMetricsResult { text, .. } => match text {
                 Some(txt) => write!(formatter, "get metrics ({} bytes)", txt.len()),
                 None => write!(formatter, "get metrics (failed)"),
             },
+            Event::BlockAdded(block) => write!(formatter, "block added {}", block.hash()),
+            Event::DeployProcessed { deploy_hash, .. } => {
+                write!(formatter, "deploy processed {}", deploy_hash)
+            }
         }
     }
 }
@@ -1,38 +1,50 @@
-use std::{convert::Infallible, time::Duration};
+use std::{convert::Infallible, sync::Arc};
 
 use futures::future;
 use http::{Response, StatusCode};
 use hyper::{
-    server::{conn::AddrIncoming, Builder},
+    server::{
+        conn::{AddrIncoming, AddrStream},
+        Builder,
+    },
     Body,
 };
 use serde::Serialize;
-use tokio::sync::oneshot;
-use tower::builder::ServiceBuilder;
+use serde_json::Value;
+use tokio::sync::{broadcast, oneshot};
 use tracing::{info, trace};
 use warp::{Filter, Rejection};
 
 use casper_types::ProtocolVersion;
 
 use super::{
+    access_control::{AccessControl, AccessControlService, WithClientIp},
+    batch::BatchService,
+    qps_limit::{QpsLimitService, QpsLimiter},
     rpcs::{self, RpcWithOptionalParamsExt, RpcWithParamsExt, RpcWithoutParamsExt, RPC_API_PATH},
+    ws_server::{self, Notification},
     ReactorEventT,
 };
 use crate::effect::EffectBuilder;
 
 // This is a workaround for not being able to create a `warp_json_rpc::Response` without a
 // `warp_json_rpc::Builder`.
-fn new_error_response(error: warp_json_rpc::Error) -> Response<Body> {
+pub(super) fn new_error_response(error: warp_json_rpc::Error) -> Response<Body> {
+    new_error_response_with_id(Value::Null, error)
+}
+
+/// Creates an error response to the request with the given ID.
+pub(super) fn new_error_response_with_id(id: Value, error: warp_json_rpc::Error) -> Response<Body> {
     #[derive(Serialize)]
     struct JsonRpcErrorResponse {
         jsonrpc: String,
-        id: Option<()>,
+        id: Value,
         error: warp_json_rpc::Error,
     }
 
     let json_response = JsonRpcErrorResponse {
         jsonrpc: "2.0".to_string(),
-        id: None,
+        id,
         error,
     };
 
@@ -50,6 +62,9 @@ pub(super) async fn run<REv: ReactorEventT>(
     effect_builder: EffectBuilder<REv>,
     api_version: ProtocolVersion,
     qps_limit: u64,
+    max_batch_size: usize,
+    access_control: Arc<AccessControl>,
+    notification_sender: broadcast::Sender<Notification>,
 ) {
     // RPC filters.
     let rpc_put_deploy = rpcs::account::PutDeploy::create_filter(effect_builder, api_version);
@@ -59,12 +74,22 @@ pub(super) async fn run<REv: ReactorEventT>(
     let rpc_get_state_root_hash =
         rpcs::chain::GetStateRootHash::create_filter(effect_builder, api_version);
     let rpc_get_item = rpcs::state::GetItem::create_filter(effect_builder, api_version);
+    let rpc_get_dictionary_item =
+        rpcs::state::GetDictionaryItem::create_filter(effect_builder, api_version);
     let rpc_get_balance = rpcs::state::GetBalance::create_filter(effect_builder, api_version);
+    let rpc_speculative_exec =
+        rpcs::state::SpeculativeExec::create_filter(effect_builder, api_version);
+    let rpc_call_contract = rpcs::state::CallContract::create_filter(effect_builder, api_version);
     let rpc_get_deploy = rpcs::info::GetDeploy::create_filter(effect_builder, api_version);
+    let rpc_get_account_deploys =
+        rpcs::info::GetAccountDeploys::create_filter(effect_builder, api_version);
     let rpc_get_peers = rpcs::info::GetPeers::create_filter(effect_builder, api_version);
     let rpc_get_status = rpcs::info::GetStatus::create_filter(effect_builder, api_version);
+    let rpc_get_validator_performance =
+        rpcs::info::GetValidatorPerformance::create_filter(effect_builder, api_version);
     let rpc_get_era_info =
         rpcs::chain::GetEraInfoBySwitchBlock::create_filter(effect_builder, api_version);
+    let rpc_get_era_faults = rpcs::chain::GetEraFaults::create_filter(effect_builder, api_version);
     let rpc_get_auction_info =
         rpcs::state::GetAuctionInfo::create_filter(effect_builder, api_version);
     let rpc_get_rpcs = rpcs::docs::ListRpcs::create_filter(effect_builder, api_version);
@@ -87,30 +112,64 @@ pub(super) async fn run<REv: ReactorEventT>(
     // TODO - we can't catch cases where we should return `warp_json_rpc::Error::INVALID_REQUEST`
     //        (i.e. where the request is JSON, but not valid JSON-RPC).  This will require an
     //        update to or move away from warp_json_rpc.
-    let service = warp_json_rpc::service(
-        rpc_put_deploy
-            .or(rpc_get_block)
-            .or(rpc_get_block_transfers)
-            .or(rpc_get_state_root_hash)
-            .or(rpc_get_item)
-            .or(rpc_get_balance)
-            .or(rpc_get_deploy)
-            .or(rpc_get_peers)
-            .or(rpc_get_status)
-            .or(rpc_get_era_info)
-            .or(rpc_get_auction_info)
-            .or(rpc_get_rpcs)
-            .or(unknown_method)
-            .or(parse_failure),
+    let rpc_filters = rpc_put_deploy
+        .or(rpc_get_block)
+        .or(rpc_get_block_transfers)
+        .or(rpc_get_state_root_hash)
+        .or(rpc_get_item)
+        .or(rpc_get_dictionary_item)
+        .or(rpc_get_balance)
+        .or(rpc_speculative_exec)
+        .or(rpc_call_contract)
+        .or(rpc_get_deploy)
+        .or(rpc_get_account_deploys)
+        .or(rpc_get_peers)
+        .or(rpc_get_status)
+        .or(rpc_get_validator_performance)
+        .or(rpc_get_era_info)
+        .or(rpc_get_era_faults)
+        .or(rpc_get_auction_info)
+        .or(rpc_get_rpcs)
+        .or(unknown_method)
+        .or(parse_failure);
+
+    // Every individual request, whether in a batch or received over the WebSocket endpoint, counts
+    // against the server-wide rate limit.
+    let qps_limiter = Arc::new(QpsLimiter::new(qps_limit));
+
+    // The WebSocket endpoint passes all requests other than subscriptions to the same filters.
+    let websocket = ws_server::create_filter(
+        BatchService::new(
+            AccessControlService::new(
+                QpsLimitService::new(
+                    warp_json_rpc::service(rpc_filters.clone()),
+                    Arc::clone(&qps_limiter),
+                ),
+                Arc::clone(&access_control),
+            ),
+            max_batch_size,
+        ),
+        Arc::clone(&access_control),
+        notification_sender,
     );
 
-    // Start the server, passing a oneshot receiver to allow the server to be shut down gracefully.
-    let make_svc =
-        hyper::service::make_service_fn(move |_| future::ok::<_, Infallible>(service.clone()));
+    let service = BatchService::new(
+        AccessControlService::new(
+            QpsLimitService::new(
+                warp_json_rpc::service(rpc_filters.or(websocket)),
+                qps_limiter,
+            ),
+            access_control,
+        ),
+        max_batch_size,
+    );
 
-    let make_svc = ServiceBuilder::new()
-        .rate_limit(qps_limit, Duration::from_secs(1))
-        .service(make_svc);
+    // Start the server, passing a oneshot receiver to allow the server to be shut down gracefully.
+    // Each connection's service tags its requests with the client's IP address.
+    let make_svc = hyper::service::make_service_fn(move |connection: &AddrStream| {
+        let client_ip = connection.remote_addr().ip();
+        future::ok::<_, Infallible>(WithClientIp::new(service.clone(), client_ip))
+    });
 
     let (shutdown_sender, shutdown_receiver) = oneshot::channel::<()>();
 
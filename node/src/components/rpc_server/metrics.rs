@@ -0,0 +1,41 @@
+use prometheus::{IntCounter, Registry};
+
+use crate::unregister_metric;
+
+/// Metrics for the JSON-RPC server.
+#[derive(Debug)]
+pub(super) struct RpcServerMetrics {
+    /// Number of requests rejected due to the client exceeding its rate limit.
+    pub(super) rate_limited_requests: IntCounter,
+    /// Number of requests rejected due to the method not being allowed.
+    pub(super) disallowed_requests: IntCounter,
+    /// Prometheus registry used to publish metrics.
+    registry: Registry,
+}
+
+impl RpcServerMetrics {
+    pub(super) fn new(registry: &Registry) -> Result<Self, prometheus::Error> {
+        let rate_limited_requests = IntCounter::new(
+            "rpc_server_rate_limited_requests",
+            "number of JSON-RPC requests rejected due to the client exceeding its rate limit",
+        )?;
+        let disallowed_requests = IntCounter::new(
+            "rpc_server_disallowed_requests",
+            "number of JSON-RPC requests rejected due to the method not being allowed",
+        )?;
+        registry.register(Box::new(rate_limited_requests.clone()))?;
+        registry.register(Box::new(disallowed_requests.clone()))?;
+        Ok(RpcServerMetrics {
+            rate_limited_requests,
+            disallowed_requests,
+            registry: registry.clone(),
+        })
+    }
+}
+
+impl Drop for RpcServerMetrics {
+    fn drop(&mut self) {
+        unregister_metric!(self.registry, self.rate_limited_requests);
+        unregister_metric!(self.registry, self.disallowed_requests);
+    }
+}
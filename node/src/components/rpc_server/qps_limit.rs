@@ -0,0 +1,212 @@
+//! Server-wide rate limiting of JSON-RPC requests.
+//!
+//! Every individual request counts against the limit, so each request of a batch and each request
+//! received over the WebSocket endpoint is charged separately.  As with `tower`'s `RateLimit`,
+//! requests beyond the limit in the current one-second window are delayed to the next window with
+//! spare capacity rather than rejected.  Only a bounded number of windows can be reserved ahead
+//! though: requests arriving once those are full are rejected with a rate limit error.
+
+use std::{
+    mem,
+    sync::{Arc, Mutex},
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+use futures::{future::BoxFuture, FutureExt};
+use http::{Request, Response};
+use hyper::Body;
+use serde_json::Value;
+use tower::Service;
+use tracing::debug;
+
+use super::{access_control::Rejection, http_server::new_error_response_with_id};
+
+/// The length of each rate limiting window.
+const WINDOW: Duration = Duration::from_secs(1);
+/// The maximum number of windows beyond the current one for which requests can be delayed.
+const MAX_WINDOWS_AHEAD: u64 = 10;
+
+/// The requests reserved in the current window and beyond.
+#[derive(Debug)]
+struct Reservations {
+    /// The start of the current window.
+    window_start: Instant,
+    /// The number of requests reserved from the start of the current window onwards.
+    count: u64,
+}
+
+/// The shared state for limiting the rate of requests across all clients.
+#[derive(Debug)]
+pub(super) struct QpsLimiter {
+    /// The maximum number of requests served per window.  0 disables the limit.
+    qps_limit: u64,
+    reservations: Mutex<Reservations>,
+}
+
+impl QpsLimiter {
+    pub(super) fn new(qps_limit: u64) -> Self {
+        QpsLimiter {
+            qps_limit,
+            reservations: Mutex::new(Reservations {
+                window_start: Instant::now(),
+                count: 0,
+            }),
+        }
+    }
+
+    /// Reserves a slot for a request arriving at `now`, returning the instant from which it may be
+    /// served, or `None` if all windows which can be reserved ahead are full.
+    fn reserve_at(&self, now: Instant) -> Option<Instant> {
+        if self.qps_limit == 0 {
+            return Some(now);
+        }
+
+        let mut reservations = self.reservations.lock().expect("lock poisoned");
+
+        // Move the current window forwards, releasing the slots of all windows which have passed.
+        let elapsed_windows = (now
+            .saturating_duration_since(reservations.window_start)
+            .as_nanos()
+            / WINDOW.as_nanos()) as u64;
+        if elapsed_windows > 0 {
+            reservations.window_start += WINDOW * elapsed_windows as u32;
+            reservations.count = reservations
+                .count
+                .saturating_sub(elapsed_windows.saturating_mul(self.qps_limit));
+        }
+
+        let windows_ahead = reservations.count / self.qps_limit;
+        if windows_ahead > MAX_WINDOWS_AHEAD {
+            return None;
+        }
+        reservations.count += 1;
+        if windows_ahead == 0 {
+            Some(now)
+        } else {
+            Some(reservations.window_start + WINDOW * windows_ahead as u32)
+        }
+    }
+}
+
+/// A service delaying requests as required by the server-wide rate limit before passing them to
+/// the wrapped service.
+///
+/// Batches must already have been split into their individual requests.
+#[derive(Clone)]
+pub(super) struct QpsLimitService<S> {
+    inner: S,
+    limiter: Arc<QpsLimiter>,
+}
+
+impl<S> QpsLimitService<S> {
+    pub(super) fn new(inner: S, limiter: Arc<QpsLimiter>) -> Self {
+        QpsLimitService { inner, limiter }
+    }
+}
+
+impl<S> Service<Request<Body>> for QpsLimitService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + 'static,
+    S::Future: Send,
+{
+    type Response = Response<Body>;
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Response<Body>, S::Error>>;
+
+    fn poll_ready(&mut self, context: &mut Context<'_>) -> Poll<Result<(), S::Error>> {
+        self.inner.poll_ready(context)
+    }
+
+    fn call(&mut self, request: Request<Body>) -> Self::Future {
+        // Use the instance which has been polled ready, leaving a fresh clone in its place.
+        let clone = self.inner.clone();
+        let mut inner = mem::replace(&mut self.inner, clone);
+        match self.limiter.reserve_at(Instant::now()) {
+            Some(ready_at) => async move {
+                tokio::time::sleep_until(ready_at.into()).await;
+                inner.call(request).await
+            }
+            .boxed(),
+            None => reject(request).map(Ok).boxed(),
+        }
+    }
+}
+
+/// Responds to a request which exceeded the server-wide rate limit with a rate limit error.
+async fn reject(request: Request<Body>) -> Response<Body> {
+    let id = match hyper::body::to_bytes(request.into_body()).await {
+        Ok(bytes) => serde_json::from_slice::<Value>(&bytes)
+            .ok()
+            .and_then(|request| request.get("id").cloned())
+            .unwrap_or(Value::Null),
+        Err(_) => Value::Null,
+    };
+    debug!(?id, "rejected request exceeding server-wide rate limit");
+    new_error_response_with_id(id, Rejection::RateLimited.to_error())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_delay_requests_beyond_limit_to_later_windows() {
+        let limiter = QpsLimiter::new(2);
+        let start = limiter.reservations.lock().unwrap().window_start;
+
+        assert_eq!(limiter.reserve_at(start), Some(start));
+        assert_eq!(limiter.reserve_at(start), Some(start));
+        assert_eq!(limiter.reserve_at(start), Some(start + WINDOW));
+        assert_eq!(limiter.reserve_at(start), Some(start + WINDOW));
+        assert_eq!(limiter.reserve_at(start), Some(start + WINDOW * 2));
+
+        // Once the first window has passed, its slots are released but the reserved ones remain.
+        let later = start + WINDOW;
+        assert_eq!(limiter.reserve_at(later), Some(later + WINDOW));
+
+        // After all reservations have passed, requests are served immediately again.
+        let much_later = start + WINDOW * 10;
+        assert_eq!(limiter.reserve_at(much_later), Some(much_later));
+        assert_eq!(limiter.reserve_at(much_later), Some(much_later));
+        assert_eq!(limiter.reserve_at(much_later), Some(start + WINDOW * 11));
+    }
+
+    #[test]
+    fn should_reject_requests_beyond_reservable_windows() {
+        let limiter = QpsLimiter::new(2);
+        let start = limiter.reservations.lock().unwrap().window_start;
+
+        // The current window and the maximum number of windows ahead can be filled.
+        for window in 0..=MAX_WINDOWS_AHEAD {
+            for _ in 0..2 {
+                assert_eq!(
+                    limiter.reserve_at(start),
+                    Some(start + WINDOW * window as u32)
+                );
+            }
+        }
+        assert_eq!(limiter.reserve_at(start), None);
+
+        // Rejected requests don't hold a reservation, so a slot is available once a window passes.
+        let later = start + WINDOW;
+        assert_eq!(
+            limiter.reserve_at(later),
+            Some(start + WINDOW * (MAX_WINDOWS_AHEAD + 1) as u32)
+        );
+        assert_eq!(
+            limiter.reserve_at(later),
+            Some(start + WINDOW * (MAX_WINDOWS_AHEAD + 1) as u32)
+        );
+        assert_eq!(limiter.reserve_at(later), None);
+    }
+
+    #[test]
+    fn should_not_delay_when_disabled() {
+        let limiter = QpsLimiter::new(0);
+        let now = Instant::now();
+        for _ in 0..10 {
+            assert_eq!(limiter.reserve_at(now), Some(now));
+        }
+    }
+}
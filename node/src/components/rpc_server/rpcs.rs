@@ -36,7 +36,7 @@ pub const RPC_API_PATH: &str = "rpc";
 ///
 /// See <https://www.jsonrpc.org/specification#error_object> for details.
 #[repr(i64)]
-enum ErrorCode {
+pub(super) enum ErrorCode {
     NoSuchDeploy = -32000,
     NoSuchBlock = -32001,
     ParseQueryKey = -32002,
@@ -46,6 +46,16 @@ enum ErrorCode {
     GetBalanceFailed = -32006,
     GetBalanceFailedToExecute = -32007,
     InvalidDeploy = -32008,
+    InvalidStateIdentifier = -32009,
+    AccountDeployIndexDisabled = -32010,
+    TooManySubscriptions = -32011,
+    BatchTooLarge = -32012,
+    SpeculativeExecFailed = -32013,
+    CallContractFailed = -32014,
+    MethodNotAllowed = -32015,
+    RateLimited = -32016,
+    NoSuchEra = -32017,
+    InvalidDictionaryIdentifier = -32018,
 }
 
 #[derive(Debug)]
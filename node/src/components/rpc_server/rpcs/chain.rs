@@ -16,18 +16,19 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 use warp_json_rpc::Builder;
 
-use casper_types::{Key, ProtocolVersion, Transfer};
+use casper_types::{EraId, Key, ProtocolVersion, Transfer};
 
 use super::{
     docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
     Error, ErrorCode, ReactorEventT, RpcRequest, RpcWithOptionalParams, RpcWithOptionalParamsExt,
+    RpcWithParams, RpcWithParamsExt,
 };
 use crate::{
     crypto::hash::Digest,
     effect::EffectBuilder,
     reactor::QueueKind,
     rpcs::common::{self},
-    types::{Block, BlockHash, BlockSignatures, Item, JsonBlock},
+    types::{Block, BlockHash, BlockSignatures, EraFault, Item, JsonBlock},
 };
 pub use era_summary::EraSummary;
 use era_summary::ERA_SUMMARY;
@@ -65,6 +66,13 @@ static GET_ERA_INFO_RESULT: Lazy<GetEraInfoResult> = Lazy::new(|| GetEraInfoResu
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
     era_summary: Some(ERA_SUMMARY.clone()),
 });
+static GET_ERA_FAULTS_PARAMS: Lazy<GetEraFaultsParams> = Lazy::new(|| GetEraFaultsParams {
+    era_id: EraFault::doc_example().era_id,
+});
+static GET_ERA_FAULTS_RESULT: Lazy<GetEraFaultsResult> = Lazy::new(|| GetEraFaultsResult {
+    api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
+    era_faults: vec![EraFault::doc_example().clone()],
+});
 
 /// Identifier for possible ways to retrieve a block.
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, JsonSchema)]
@@ -433,7 +441,73 @@ impl RpcWithOptionalParamsExt for GetEraInfoBySwitchBlock {
     }
 }
 
-async fn get_block<REv: ReactorEventT>(
+/// Params for "chain_get_era_faults" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetEraFaultsParams {
+    /// The era ID.
+    pub era_id: EraId,
+}
+
+impl DocExample for GetEraFaultsParams {
+    fn doc_example() -> &'static Self {
+        &*GET_ERA_FAULTS_PARAMS
+    }
+}
+
+/// Result for "chain_get_era_faults" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetEraFaultsResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: ProtocolVersion,
+    /// The evidence of all faults in the era known to this node.
+    pub era_faults: Vec<EraFault>,
+}
+
+impl DocExample for GetEraFaultsResult {
+    fn doc_example() -> &'static Self {
+        &*GET_ERA_FAULTS_RESULT
+    }
+}
+
+/// "chain_get_era_faults" RPC.
+pub struct GetEraFaults {}
+
+impl RpcWithParams for GetEraFaults {
+    const METHOD: &'static str = "chain_get_era_faults";
+    type RequestParams = GetEraFaultsParams;
+    type ResponseResult = GetEraFaultsResult;
+}
+
+impl RpcWithParamsExt for GetEraFaults {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: ProtocolVersion,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let era_id = params.era_id;
+            let era_faults = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetEraFaults { era_id, responder },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let result = Self::ResponseResult {
+                api_version,
+                era_faults,
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
+pub(super) async fn get_block<REv: ReactorEventT>(
     maybe_id: Option<BlockIdentifier>,
     effect_builder: EffectBuilder<REv>,
 ) -> Result<Option<Block>, warp_json_rpc::Error> {
@@ -20,9 +20,11 @@ use casper_types::ProtocolVersion;
 
 use super::{
     account::PutDeploy,
-    chain::{GetBlock, GetBlockTransfers, GetStateRootHash},
-    info::{GetDeploy, GetPeers, GetStatus},
-    state::{GetAuctionInfo, GetBalance, GetItem},
+    chain::{GetBlock, GetBlockTransfers, GetEraFaults, GetStateRootHash},
+    info::{GetAccountDeploys, GetDeploy, GetPeers, GetStatus, GetValidatorPerformance},
+    state::{
+        CallContract, GetAuctionInfo, GetBalance, GetDictionaryItem, GetItem, SpeculativeExec,
+    },
     Error, ReactorEventT, RpcWithOptionalParams, RpcWithParams, RpcWithoutParams,
     RpcWithoutParamsExt,
 };
@@ -69,8 +71,15 @@ static OPEN_RPC_SCHEMA: Lazy<OpenRpcSchema> = Lazy::new(|| {
 
     schema.push_with_params::<PutDeploy>("receives a Deploy to be executed by the network");
     schema.push_with_params::<GetDeploy>("returns a Deploy from the network");
+    schema.push_with_params::<GetAccountDeploys>(
+        "returns the hashes of Deploys sent by an account, if the node indexes them",
+    );
     schema.push_without_params::<GetPeers>("returns a list of peers connected to the node");
     schema.push_without_params::<GetStatus>("returns the current status of the node");
+    schema.push_with_optional_params::<GetValidatorPerformance>(
+        "returns the validators' participation in the given era, or in the current era if none is \
+        given",
+    );
     schema.push_with_optional_params::<GetBlock>("returns a Block from the network");
     schema.push_with_optional_params::<GetBlockTransfers>(
         "returns all transfers for a Block from the network",
@@ -79,12 +88,23 @@ static OPEN_RPC_SCHEMA: Lazy<OpenRpcSchema> = Lazy::new(|| {
         "returns a state root hash at a given Block",
     );
     schema.push_with_params::<GetItem>("returns a stored value from the network");
+    schema.push_with_params::<GetDictionaryItem>("returns an item from a Dictionary");
     schema.push_with_params::<GetBalance>("returns a purse's balance from the network");
+    schema.push_with_params::<SpeculativeExec>(
+        "executes a Deploy against a given state root without committing its effects",
+    );
+    schema.push_with_params::<CallContract>(
+        "calls a stored contract's entry point against a given state root without committing its \
+        effects, returning the value passed to runtime::ret",
+    );
     schema.push_with_optional_params::<GetEraInfoBySwitchBlock>(
         "returns an EraInfo from the network",
     );
-    schema.push_without_params::<GetAuctionInfo>(
-        "returns the bids and validators as of the most recently added Block",
+    schema.push_with_params::<GetEraFaults>(
+        "returns the evidence of all validator faults in the given era known to this node",
+    );
+    schema.push_with_optional_params::<GetAuctionInfo>(
+        "returns the bids and validators as of a given Block",
     );
 
     schema
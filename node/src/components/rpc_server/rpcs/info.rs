@@ -3,9 +3,9 @@
 // TODO - remove once schemars stops causing warning.
 #![allow(clippy::field_reassign_with_default)]
 
-use std::str;
+use std::{iter, str};
 
-use futures::{future::BoxFuture, FutureExt};
+use futures::{future::BoxFuture, join, FutureExt};
 use http::Response;
 use hyper::Body;
 use once_cell::sync::Lazy;
@@ -14,17 +14,21 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 use warp_json_rpc::Builder;
 
-use casper_types::{ExecutionResult, ProtocolVersion};
+use casper_types::{EraId, ExecutionResult, ProtocolVersion, PublicKey};
 
 use super::{
     docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
-    Error, ErrorCode, ReactorEventT, RpcRequest, RpcWithParams, RpcWithParamsExt, RpcWithoutParams,
-    RpcWithoutParamsExt,
+    Error, ErrorCode, ReactorEventT, RpcRequest, RpcWithOptionalParams, RpcWithOptionalParamsExt,
+    RpcWithParams, RpcWithParamsExt, RpcWithoutParams, RpcWithoutParamsExt,
 };
 use crate::{
+    crypto::AsymmetricKeyExt,
     effect::EffectBuilder,
     reactor::QueueKind,
-    types::{Block, BlockHash, Deploy, DeployHash, GetStatusResult, Item, PeersMap},
+    types::{
+        Block, BlockHash, Deploy, DeployHash, EraValidatorPerformance, GetStatusResult, Item,
+        NodeId, PeersMap,
+    },
 };
 
 static GET_DEPLOY_PARAMS: Lazy<GetDeployParams> = Lazy::new(|| GetDeployParams {
@@ -38,10 +42,38 @@ static GET_DEPLOY_RESULT: Lazy<GetDeployResult> = Lazy::new(|| GetDeployResult {
         result: ExecutionResult::example().clone(),
     }],
 });
-static GET_PEERS_RESULT: Lazy<GetPeersResult> = Lazy::new(|| GetPeersResult {
-    api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
-    peers: GetStatusResult::doc_example().peers.clone(),
+static GET_ACCOUNT_DEPLOYS_PARAMS: Lazy<GetAccountDeploysParams> =
+    Lazy::new(|| GetAccountDeploysParams {
+        public_key: PublicKey::doc_example().clone(),
+        offset: 0,
+        limit: 10,
+    });
+static GET_ACCOUNT_DEPLOYS_RESULT: Lazy<GetAccountDeploysResult> =
+    Lazy::new(|| GetAccountDeploysResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
+        deploys: vec![JsonAccountDeploy {
+            deploy_hash: *Deploy::doc_example().id(),
+            block_height: Block::doc_example().height(),
+        }],
+    });
+static GET_PEERS_RESULT: Lazy<GetPeersResult> = Lazy::new(|| {
+    let node_id = *NodeId::doc_example();
+    let peers = iter::once((node_id, "127.0.0.1:54321".to_string())).collect();
+    let reputations = iter::once((node_id, 42)).collect();
+    GetPeersResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
+        peers: PeersMap::with_reputations(peers, &reputations),
+    }
 });
+static GET_VALIDATOR_PERFORMANCE_PARAMS: Lazy<GetValidatorPerformanceParams> =
+    Lazy::new(|| GetValidatorPerformanceParams {
+        era_id: EraValidatorPerformance::doc_example().era_id,
+    });
+static GET_VALIDATOR_PERFORMANCE_RESULT: Lazy<GetValidatorPerformanceResult> =
+    Lazy::new(|| GetValidatorPerformanceResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
+        performance: EraValidatorPerformance::doc_example().clone(),
+    });
 
 /// Params for "info_get_deploy" RPC request.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
@@ -146,6 +178,118 @@ impl RpcWithParamsExt for GetDeploy {
     }
 }
 
+/// The maximum number of deploys returned by a single "info_get_account_deploys" RPC request.
+pub const MAX_ACCOUNT_DEPLOYS_LIMIT: u32 = 100;
+
+/// Params for "info_get_account_deploys" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetAccountDeploysParams {
+    /// The public key of the account.
+    pub public_key: PublicKey,
+    /// The number of deploys to skip.
+    pub offset: u32,
+    /// The maximum number of deploys to return, capped at 100.
+    pub limit: u32,
+}
+
+impl DocExample for GetAccountDeploysParams {
+    fn doc_example() -> &'static Self {
+        &*GET_ACCOUNT_DEPLOYS_PARAMS
+    }
+}
+
+/// A deploy sent by an account, along with the height of the block in which it was executed.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct JsonAccountDeploy {
+    /// The deploy hash.
+    pub deploy_hash: DeployHash,
+    /// The height of the block in which the deploy was executed.
+    pub block_height: u64,
+}
+
+/// Result for "info_get_account_deploys" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetAccountDeploysResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: ProtocolVersion,
+    /// The deploys sent by the account, in order of the height of the block in which they were
+    /// executed.
+    pub deploys: Vec<JsonAccountDeploy>,
+}
+
+impl DocExample for GetAccountDeploysResult {
+    fn doc_example() -> &'static Self {
+        &*GET_ACCOUNT_DEPLOYS_RESULT
+    }
+}
+
+/// "info_get_account_deploys" RPC.
+pub struct GetAccountDeploys {}
+
+impl RpcWithParams for GetAccountDeploys {
+    const METHOD: &'static str = "info_get_account_deploys";
+    type RequestParams = GetAccountDeploysParams;
+    type ResponseResult = GetAccountDeploysResult;
+}
+
+impl RpcWithParamsExt for GetAccountDeploys {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: ProtocolVersion,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let account_hash = params.public_key.to_account_hash();
+            let limit = params.limit.min(MAX_ACCOUNT_DEPLOYS_LIMIT);
+
+            // Try to get the account's deploys from storage.
+            let maybe_account_deploys = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetAccountDeploys {
+                        account_hash: Box::new(account_hash),
+                        offset: params.offset,
+                        limit,
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let account_deploys = match maybe_account_deploys {
+                Some(account_deploys) => account_deploys,
+                None => {
+                    info!("failed to get deploys of {} from storage", account_hash);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::AccountDeployIndexDisabled as i64,
+                        "account deploy index not enabled",
+                    ))?);
+                }
+            };
+
+            // Return the result.
+            let deploys = account_deploys
+                .into_iter()
+                .map(|(deploy_hash, block_height)| JsonAccountDeploy {
+                    deploy_hash,
+                    block_height,
+                })
+                .collect();
+
+            let result = Self::ResponseResult {
+                api_version,
+                deploys,
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
 /// Result for "info_get_peers" RPC response.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -153,7 +297,7 @@ pub struct GetPeersResult {
     /// The RPC API version.
     #[schemars(with = "String")]
     pub api_version: ProtocolVersion,
-    /// The node ID and network address of each connected peer.
+    /// The node ID, network address and reputation score of each connected peer.
     pub peers: PeersMap,
 }
 
@@ -178,16 +322,20 @@ impl RpcWithoutParamsExt for GetPeers {
         api_version: ProtocolVersion,
     ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
         async move {
-            let peers = effect_builder
-                .make_request(
+            let (peers, reputations) = join!(
+                effect_builder.make_request(
                     |responder| RpcRequest::GetPeers { responder },
                     QueueKind::Api,
+                ),
+                effect_builder.make_request(
+                    |responder| RpcRequest::GetPeerReputations { responder },
+                    QueueKind::Api,
                 )
-                .await;
+            );
 
             let result = Self::ResponseResult {
                 api_version,
-                peers: PeersMap::from(peers),
+                peers: PeersMap::with_reputations(peers, &reputations),
             };
             Ok(response_builder.success(result)?)
         }
@@ -225,3 +373,80 @@ impl RpcWithoutParamsExt for GetStatus {
         .boxed()
     }
 }
+
+/// Params for "info_get_validator_performance" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetValidatorPerformanceParams {
+    /// The era ID.
+    pub era_id: EraId,
+}
+
+impl DocExample for GetValidatorPerformanceParams {
+    fn doc_example() -> &'static Self {
+        &*GET_VALIDATOR_PERFORMANCE_PARAMS
+    }
+}
+
+/// Result for "info_get_validator_performance" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetValidatorPerformanceResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: ProtocolVersion,
+    /// The validators' participation in the era.
+    pub performance: EraValidatorPerformance,
+}
+
+impl DocExample for GetValidatorPerformanceResult {
+    fn doc_example() -> &'static Self {
+        &*GET_VALIDATOR_PERFORMANCE_RESULT
+    }
+}
+
+/// "info_get_validator_performance" RPC.
+pub struct GetValidatorPerformance {}
+
+impl RpcWithOptionalParams for GetValidatorPerformance {
+    const METHOD: &'static str = "info_get_validator_performance";
+    type OptionalRequestParams = GetValidatorPerformanceParams;
+    type ResponseResult = GetValidatorPerformanceResult;
+}
+
+impl RpcWithOptionalParamsExt for GetValidatorPerformance {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        maybe_params: Option<Self::OptionalRequestParams>,
+        api_version: ProtocolVersion,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let era_id = maybe_params.map(|params| params.era_id);
+            let maybe_performance = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetValidatorPerformance { era_id, responder },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let performance = match maybe_performance {
+                Some(performance) => *performance,
+                None => {
+                    info!(?era_id, "no validator performance available");
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::NoSuchEra as i64,
+                        "era not active or not running Highway",
+                    ))?);
+                }
+            };
+
+            let result = Self::ResponseResult {
+                api_version,
+                performance,
+            };
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
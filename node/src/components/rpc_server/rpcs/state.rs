@@ -3,7 +3,7 @@
 // TODO - remove once schemars stops causing warning.
 #![allow(clippy::field_reassign_with_default)]
 
-use std::str;
+use std::{future::Future, str};
 
 use futures::{future::BoxFuture, FutureExt};
 use http::Response;
@@ -14,58 +14,130 @@ use serde::{Deserialize, Serialize};
 use tracing::info;
 use warp_json_rpc::Builder;
 
-use casper_execution_engine::core::engine_state::{BalanceResult, GetBidsResult};
-use casper_types::{bytesrepr::ToBytes, CLValue, Key, ProtocolVersion, URef, U512};
+use casper_execution_engine::{
+    core::engine_state::{
+        BalanceResult, CallContractResult as EngineCallContractResult, ContractIdentifier,
+        GetAuctionProofsResult, GetBidsResult, QueryResult,
+    },
+    shared::stored_value::StoredValue as ExecutionEngineStoredValue,
+};
+use casper_types::{
+    bytesrepr::ToBytes, runtime_args, CLValue, DictionaryValue, ExecutionResult, Key,
+    ProtocolVersion, PublicKey, RuntimeArgs, URef, U512,
+};
 
 use super::{
     docs::{DocExample, DOCS_EXAMPLE_PROTOCOL_VERSION},
     Error, ErrorCode, ReactorEventT, RpcRequest, RpcWithParams, RpcWithParamsExt,
 };
 use crate::{
-    crypto::hash::Digest,
+    crypto::{hash::Digest, AsymmetricKeyExt},
     effect::EffectBuilder,
     reactor::QueueKind,
     rpcs::{
+        chain::{self, BlockIdentifier},
         common::{self, MERKLE_PROOF},
-        RpcWithoutParams, RpcWithoutParamsExt,
+        RpcWithOptionalParams, RpcWithOptionalParamsExt,
     },
     types::{
         json_compatibility::{AuctionState, StoredValue},
-        Block,
+        Block, Deploy,
     },
 };
 
 static GET_ITEM_PARAMS: Lazy<GetItemParams> = Lazy::new(|| GetItemParams {
-    state_root_hash: *Block::doc_example().header().state_root_hash(),
+    state_root_hash: Some(*Block::doc_example().header().state_root_hash()),
+    block_identifier: None,
     key: "deploy-af684263911154d26fa05be9963171802801a0b6aff8f199b7391eacb8edc9e1".to_string(),
     path: vec!["inner".to_string()],
 });
 static GET_ITEM_RESULT: Lazy<GetItemResult> = Lazy::new(|| GetItemResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
+    state_root_hash: *Block::doc_example().header().state_root_hash(),
     stored_value: StoredValue::CLValue(CLValue::from_t(1u64).unwrap()),
     merkle_proof: MERKLE_PROOF.clone(),
 });
+static GET_DICTIONARY_ITEM_PARAMS: Lazy<GetDictionaryItemParams> =
+    Lazy::new(|| GetDictionaryItemParams {
+        state_root_hash: Some(*Block::doc_example().header().state_root_hash()),
+        block_identifier: None,
+        dictionary_identifier: DictionaryIdentifier::URef {
+            seed_uref: "uref-09480c3248ef76b603d386f3f4f8a5f87f597d4eaffd475433f861af187ab5db-007"
+                .to_string(),
+            dictionary_item_key: "a_unique_entry_identifier".to_string(),
+        },
+    });
+static GET_DICTIONARY_ITEM_RESULT: Lazy<GetDictionaryItemResult> =
+    Lazy::new(|| GetDictionaryItemResult {
+        api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
+        state_root_hash: *Block::doc_example().header().state_root_hash(),
+        dictionary_key:
+            "dictionary-67518854aa916c97d4e53df8570c8217ccc259da2721b692102d76acd0ee8d1f"
+                .to_string(),
+        stored_value: StoredValue::Dictionary(DictionaryValue::new(
+            CLValue::from_t(1u64).unwrap(),
+            URef::from_formatted_str(
+                "uref-09480c3248ef76b603d386f3f4f8a5f87f597d4eaffd475433f861af187ab5db-007",
+            )
+            .unwrap(),
+            b"a_unique_entry_identifier".to_vec().into(),
+        )),
+        merkle_proof: MERKLE_PROOF.clone(),
+    });
 static GET_BALANCE_PARAMS: Lazy<GetBalanceParams> = Lazy::new(|| GetBalanceParams {
-    state_root_hash: *Block::doc_example().header().state_root_hash(),
+    state_root_hash: Some(*Block::doc_example().header().state_root_hash()),
+    block_identifier: None,
     purse_uref: "uref-09480c3248ef76b603d386f3f4f8a5f87f597d4eaffd475433f861af187ab5db-007"
         .to_string(),
 });
 static GET_BALANCE_RESULT: Lazy<GetBalanceResult> = Lazy::new(|| GetBalanceResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
+    state_root_hash: *Block::doc_example().header().state_root_hash(),
     balance_value: U512::from(123_456),
     merkle_proof: MERKLE_PROOF.clone(),
 });
+static GET_AUCTION_INFO_PARAMS: Lazy<GetAuctionInfoParams> = Lazy::new(|| GetAuctionInfoParams {
+    block_identifier: BlockIdentifier::Hash(*Block::doc_example().hash()),
+});
 static GET_AUCTION_INFO_RESULT: Lazy<GetAuctionInfoResult> = Lazy::new(|| GetAuctionInfoResult {
     api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
     auction_state: AuctionState::doc_example().clone(),
+    merkle_proof: MERKLE_PROOF.clone(),
+});
+static SPECULATIVE_EXEC_PARAMS: Lazy<SpeculativeExecParams> = Lazy::new(|| SpeculativeExecParams {
+    state_root_hash: Some(*Block::doc_example().header().state_root_hash()),
+    block_identifier: None,
+    deploy: Deploy::doc_example().clone(),
+});
+static SPECULATIVE_EXEC_RESULT: Lazy<SpeculativeExecResult> = Lazy::new(|| SpeculativeExecResult {
+    api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
+    execution_result: ExecutionResult::example().clone(),
+});
+static CALL_CONTRACT_PARAMS: Lazy<CallContractParams> = Lazy::new(|| CallContractParams {
+    state_root_hash: Some(*Block::doc_example().header().state_root_hash()),
+    block_identifier: None,
+    caller: Some(PublicKey::doc_example().clone()),
+    contract: ContractIdentifier::Name("counter".to_string()),
+    entry_point: "get_count".to_string(),
+    args: runtime_args! { "offset" => 1u64 },
+});
+static CALL_CONTRACT_RESULT: Lazy<CallContractResult> = Lazy::new(|| CallContractResult {
+    api_version: DOCS_EXAMPLE_PROTOCOL_VERSION,
+    value: CLValue::from_t(42u64).unwrap(),
+    cost: U512::from(123_456),
 });
 
 /// Params for "state_get_item" RPC request.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct GetItemParams {
-    /// Hash of the state root.
-    pub state_root_hash: Digest,
+    /// Hash of the state root.  Exactly one of this and `block_identifier` must be provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_root_hash: Option<Digest>,
+    /// The block whose state root should be queried.  Exactly one of this and `state_root_hash`
+    /// must be provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_identifier: Option<BlockIdentifier>,
     /// `casper_types::Key` as formatted string.
     pub key: String,
     /// The path components starting from the key as base.
@@ -86,6 +158,8 @@ pub struct GetItemResult {
     /// The RPC API version.
     #[schemars(with = "String")]
     pub api_version: ProtocolVersion,
+    /// Hash of the state root the query was run against.
+    pub state_root_hash: Digest,
     /// The stored value.
     pub stored_value: StoredValue,
     /// The merkle proof.
@@ -129,11 +203,22 @@ impl RpcWithParamsExt for GetItem {
                 }
             };
 
+            let state_root_hash = match resolve_state_root_hash(
+                effect_builder,
+                params.state_root_hash,
+                params.block_identifier,
+            )
+            .await
+            {
+                Ok(state_root_hash) => state_root_hash,
+                Err(error) => return Ok(response_builder.error(error)?),
+            };
+
             // Run the query.
             let query_result = effect_builder
                 .make_request(
                     |responder| RpcRequest::QueryGlobalState {
-                        state_root_hash: params.state_root_hash,
+                        state_root_hash,
                         base_key,
                         path: params.path,
                         responder,
@@ -153,6 +238,160 @@ impl RpcWithParamsExt for GetItem {
 
             let result = Self::ResponseResult {
                 api_version,
+                state_root_hash,
+                stored_value,
+                merkle_proof: hex::encode(proof_bytes),
+            };
+
+            Ok(response_builder.success(result)?)
+        }
+        .boxed()
+    }
+}
+
+/// Identifier of an item in a dictionary.
+#[derive(Serialize, Deserialize, Debug, Clone, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub enum DictionaryIdentifier {
+    /// The dictionary's seed `URef` is found under `dictionary_name` in an account's named keys.
+    AccountNamedKey {
+        /// The account's `Key::Account` as formatted string.
+        key: String,
+        /// The named key under which the dictionary's seed `URef` is stored.
+        dictionary_name: String,
+        /// The key of the item in the dictionary.
+        dictionary_item_key: String,
+    },
+    /// The dictionary's seed `URef` is found under `dictionary_name` in a stored contract's named
+    /// keys.
+    ContractNamedKey {
+        /// The contract's `Key::Hash` as formatted string.
+        key: String,
+        /// The named key under which the dictionary's seed `URef` is stored.
+        dictionary_name: String,
+        /// The key of the item in the dictionary.
+        dictionary_item_key: String,
+    },
+    /// The dictionary is identified by its seed `URef`.
+    URef {
+        /// The dictionary's seed `URef` as formatted string.
+        seed_uref: String,
+        /// The key of the item in the dictionary.
+        dictionary_item_key: String,
+    },
+    /// The item is identified by its `Key::Dictionary` as formatted string.
+    Dictionary(String),
+}
+
+/// Params for "state_get_dictionary_item" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDictionaryItemParams {
+    /// Hash of the state root.  Exactly one of this and `block_identifier` must be provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_root_hash: Option<Digest>,
+    /// The block whose state root should be queried.  Exactly one of this and `state_root_hash`
+    /// must be provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_identifier: Option<BlockIdentifier>,
+    /// The item to query.
+    pub dictionary_identifier: DictionaryIdentifier,
+}
+
+impl DocExample for GetDictionaryItemParams {
+    fn doc_example() -> &'static Self {
+        &*GET_DICTIONARY_ITEM_PARAMS
+    }
+}
+
+/// Result for "state_get_dictionary_item" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDictionaryItemResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: ProtocolVersion,
+    /// Hash of the state root the query was run against.
+    pub state_root_hash: Digest,
+    /// The `Key::Dictionary` under which the item is stored, as formatted string.
+    pub dictionary_key: String,
+    /// The stored value.
+    pub stored_value: StoredValue,
+    /// The merkle proof.
+    pub merkle_proof: String,
+}
+
+impl DocExample for GetDictionaryItemResult {
+    fn doc_example() -> &'static Self {
+        &*GET_DICTIONARY_ITEM_RESULT
+    }
+}
+
+/// "state_get_dictionary_item" RPC.
+pub struct GetDictionaryItem {}
+
+impl RpcWithParams for GetDictionaryItem {
+    const METHOD: &'static str = "state_get_dictionary_item";
+    type RequestParams = GetDictionaryItemParams;
+    type ResponseResult = GetDictionaryItemResult;
+}
+
+impl RpcWithParamsExt for GetDictionaryItem {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: ProtocolVersion,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let state_root_hash = match resolve_state_root_hash(
+                effect_builder,
+                params.state_root_hash,
+                params.block_identifier,
+            )
+            .await
+            {
+                Ok(state_root_hash) => state_root_hash,
+                Err(error) => return Ok(response_builder.error(error)?),
+            };
+
+            let dictionary_key = match resolve_dictionary_key(
+                effect_builder,
+                state_root_hash,
+                params.dictionary_identifier,
+            )
+            .await
+            {
+                Ok(dictionary_key) => dictionary_key,
+                Err(error) => return Ok(response_builder.error(error)?),
+            };
+
+            // Run the query.
+            let query_result = effect_builder
+                .make_request(
+                    |responder| RpcRequest::QueryGlobalState {
+                        state_root_hash,
+                        base_key: dictionary_key,
+                        path: vec![],
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let (stored_value, proof_bytes) = match common::extract_query_result(query_result) {
+                Ok(tuple) => tuple,
+                Err((error_code, error_msg)) => {
+                    info!("{}", error_msg);
+                    return Ok(response_builder
+                        .error(warp_json_rpc::Error::custom(error_code as i64, error_msg))?);
+                }
+            };
+
+            let result = Self::ResponseResult {
+                api_version,
+                state_root_hash,
+                dictionary_key: dictionary_key.to_formatted_string(),
                 stored_value,
                 merkle_proof: hex::encode(proof_bytes),
             };
@@ -167,8 +406,13 @@ impl RpcWithParamsExt for GetItem {
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct GetBalanceParams {
-    /// The hash of state root.
-    pub state_root_hash: Digest,
+    /// The hash of state root.  Exactly one of this and `block_identifier` must be provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_root_hash: Option<Digest>,
+    /// The block whose state root should be queried.  Exactly one of this and `state_root_hash`
+    /// must be provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_identifier: Option<BlockIdentifier>,
     /// Formatted URef.
     pub purse_uref: String,
 }
@@ -186,6 +430,8 @@ pub struct GetBalanceResult {
     /// The RPC API version.
     #[schemars(with = "String")]
     pub api_version: ProtocolVersion,
+    /// Hash of the state root the query was run against.
+    pub state_root_hash: Digest,
     /// The balance value.
     pub balance_value: U512,
     /// The merkle proof.
@@ -229,11 +475,22 @@ impl RpcWithParamsExt for GetBalance {
                 }
             };
 
+            let state_root_hash = match resolve_state_root_hash(
+                effect_builder,
+                params.state_root_hash,
+                params.block_identifier,
+            )
+            .await
+            {
+                Ok(state_root_hash) => state_root_hash,
+                Err(error) => return Ok(response_builder.error(error)?),
+            };
+
             // Get the balance.
             let balance_result = effect_builder
                 .make_request(
                     |responder| RpcRequest::GetBalance {
-                        state_root_hash: params.state_root_hash,
+                        state_root_hash,
                         purse_uref,
                         responder,
                     },
@@ -274,6 +531,7 @@ impl RpcWithParamsExt for GetBalance {
             // Return the result.
             let result = Self::ResponseResult {
                 api_version,
+                state_root_hash,
                 balance_value,
                 merkle_proof,
             };
@@ -283,6 +541,20 @@ impl RpcWithParamsExt for GetBalance {
     }
 }
 
+/// Params for "state_get_auction_info" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetAuctionInfoParams {
+    /// The block identifier.
+    pub block_identifier: BlockIdentifier,
+}
+
+impl DocExample for GetAuctionInfoParams {
+    fn doc_example() -> &'static Self {
+        &*GET_AUCTION_INFO_PARAMS
+    }
+}
+
 /// Result for "state_get_auction_info" RPC response.
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -292,6 +564,8 @@ pub struct GetAuctionInfoResult {
     pub api_version: ProtocolVersion,
     /// The auction state.
     pub auction_state: AuctionState,
+    /// The batched merkle proof of every bid and era validators entry in global state.
+    pub merkle_proof: String,
 }
 
 impl DocExample for GetAuctionInfoResult {
@@ -303,41 +577,33 @@ impl DocExample for GetAuctionInfoResult {
 /// "state_get_auction_info" RPC.
 pub struct GetAuctionInfo {}
 
-impl RpcWithoutParams for GetAuctionInfo {
+impl RpcWithOptionalParams for GetAuctionInfo {
     const METHOD: &'static str = "state_get_auction_info";
+    type OptionalRequestParams = GetAuctionInfoParams;
     type ResponseResult = GetAuctionInfoResult;
 }
 
-impl RpcWithoutParamsExt for GetAuctionInfo {
+impl RpcWithOptionalParamsExt for GetAuctionInfo {
     fn handle_request<REv: ReactorEventT>(
         effect_builder: EffectBuilder<REv>,
         response_builder: Builder,
+        maybe_params: Option<Self::OptionalRequestParams>,
         api_version: ProtocolVersion,
     ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
         async move {
-            let block: Block = {
-                let maybe_block = effect_builder
-                    .make_request(
-                        |responder| RpcRequest::GetBlock {
-                            maybe_id: None,
-                            responder,
-                        },
-                        QueueKind::Api,
-                    )
-                    .await;
-
-                match maybe_block {
-                    None => {
-                        let error_msg =
-                            "get-auction-info failed to get last added block".to_string();
-                        info!("{}", error_msg);
-                        return Ok(response_builder.error(warp_json_rpc::Error::custom(
-                            ErrorCode::NoSuchBlock as i64,
-                            error_msg,
-                        ))?);
-                    }
-                    Some((block, _)) => block,
+            // Get the block, or the most recently added one if none was identified.
+            let maybe_block_id = maybe_params.map(|params| params.block_identifier);
+            let block = match chain::get_block(maybe_block_id, effect_builder).await {
+                Ok(Some(block)) => block,
+                Ok(None) => {
+                    let error_msg = "get-auction-info failed to get last added block".to_string();
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::NoSuchBlock as i64,
+                        error_msg,
+                    ))?);
                 }
+                Err(error) => return Ok(response_builder.error(error)?),
             };
 
             let protocol_version = api_version;
@@ -376,15 +642,597 @@ impl RpcWithoutParamsExt for GetAuctionInfo {
 
             let era_validators = era_validators_result.ok();
 
+            let get_auction_proofs_result = effect_builder
+                .make_request(
+                    |responder| RpcRequest::GetAuctionProofs {
+                        state_root_hash,
+                        protocol_version,
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let proofs = match get_auction_proofs_result {
+                Ok(GetAuctionProofsResult::Success { proofs }) => proofs,
+                Ok(get_auction_proofs_result) => {
+                    let error_msg = format!(
+                        "get-auction-info failed to get proofs: {:?}",
+                        get_auction_proofs_result
+                    );
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::QueryFailed as i64,
+                        error_msg,
+                    ))?);
+                }
+                Err(error) => {
+                    let error_msg = format!("get-auction-info failed to execute: {}", error);
+                    info!("{}", error_msg);
+                    return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::QueryFailedToExecute as i64,
+                        error_msg,
+                    ))?);
+                }
+            };
+
+            let proof_bytes = match proofs.to_bytes() {
+                Ok(proof_bytes) => proof_bytes,
+                Err(error) => {
+                    info!("failed to encode auction proofs: {}", error);
+                    return Ok(response_builder.error(warp_json_rpc::Error::INTERNAL_ERROR)?);
+                }
+            };
+
             let auction_state =
                 AuctionState::new(state_root_hash, block_height, era_validators, maybe_bids);
 
             let result = Self::ResponseResult {
                 api_version,
                 auction_state,
+                merkle_proof: hex::encode(proof_bytes),
             };
             Ok(response_builder.success(result)?)
         }
         .boxed()
     }
 }
+
+/// Params for "state_speculative_exec" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SpeculativeExecParams {
+    /// Hash of the state root to execute against.  At most one of this and `block_identifier` may
+    /// be provided.  If neither is provided, the state root of the latest block is used.  As the
+    /// block isn't known if this is provided, the deploy then sees a block height, era and parent
+    /// hash of zero.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_root_hash: Option<Digest>,
+    /// The block whose state root should be executed against.  At most one of this and
+    /// `state_root_hash` may be provided.  If neither is provided, the latest block is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_identifier: Option<BlockIdentifier>,
+    /// The `Deploy` to execute.
+    pub deploy: Deploy,
+}
+
+impl DocExample for SpeculativeExecParams {
+    fn doc_example() -> &'static Self {
+        &*SPECULATIVE_EXEC_PARAMS
+    }
+}
+
+/// Result for "state_speculative_exec" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SpeculativeExecResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: ProtocolVersion,
+    /// The result of executing the deploy, including its cost.  None of its effects are
+    /// committed.
+    pub execution_result: ExecutionResult,
+}
+
+impl DocExample for SpeculativeExecResult {
+    fn doc_example() -> &'static Self {
+        &*SPECULATIVE_EXEC_RESULT
+    }
+}
+
+/// "state_speculative_exec" RPC.
+pub struct SpeculativeExec {}
+
+impl RpcWithParams for SpeculativeExec {
+    const METHOD: &'static str = "state_speculative_exec";
+    type RequestParams = SpeculativeExecParams;
+    type ResponseResult = SpeculativeExecResult;
+}
+
+impl RpcWithParamsExt for SpeculativeExec {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: ProtocolVersion,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let mut deploy = params.deploy;
+            let deploy_hash = *deploy.id();
+
+            // Only execute deploys which could actually be sent to the network.
+            if let Err(error) = deploy.is_valid() {
+                info!(%deploy_hash, %error, "speculative execution of invalid deploy");
+                return Ok(response_builder.error(warp_json_rpc::Error::custom(
+                    ErrorCode::InvalidDeploy as i64,
+                    error.to_string(),
+                ))?);
+            }
+
+            let resolved_state = match (params.state_root_hash, params.block_identifier) {
+                // Default to the state root of the latest block.
+                (None, None) => {
+                    chain::get_block(None, effect_builder)
+                        .await
+                        .and_then(|maybe_block| {
+                            maybe_block
+                                .map(|block| (*block.state_root_hash(), Some(block)))
+                                .ok_or_else(|| {
+                                    warp_json_rpc::Error::custom(
+                                        ErrorCode::NoSuchBlock as i64,
+                                        "block not known",
+                                    )
+                                })
+                        })
+                }
+                (maybe_state_root_hash, maybe_block_identifier) => {
+                    resolve_state_root_hash_and_block(
+                        effect_builder,
+                        maybe_state_root_hash,
+                        maybe_block_identifier,
+                    )
+                    .await
+                }
+            };
+            let (state_root_hash, maybe_block) = match resolved_state {
+                Ok(resolved_state) => resolved_state,
+                Err(error) => return Ok(response_builder.error(error)?),
+            };
+
+            let execution_result = effect_builder
+                .make_request(
+                    |responder| RpcRequest::SpeculativeExecute {
+                        state_root_hash,
+                        block_header: maybe_block.map(|block| Box::new(block.take_header())),
+                        deploy: Box::new(deploy),
+                        protocol_version: api_version,
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            match execution_result {
+                Ok(execution_result) => {
+                    let result = Self::ResponseResult {
+                        api_version,
+                        execution_result,
+                    };
+                    Ok(response_builder.success(result)?)
+                }
+                Err(error) => {
+                    let error_msg = format!("speculative execution failed to execute: {}", error);
+                    info!(%deploy_hash, "{}", error_msg);
+                    Ok(response_builder.error(warp_json_rpc::Error::custom(
+                        ErrorCode::SpeculativeExecFailed as i64,
+                        error_msg,
+                    ))?)
+                }
+            }
+        }
+        .boxed()
+    }
+}
+
+/// Params for "state_call_contract" RPC request.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CallContractParams {
+    /// Hash of the state root to call the contract against.  Exactly one of this and
+    /// `block_identifier` must be provided.  As the block isn't known if this is provided, the
+    /// contract then sees a block time, height, era and parent hash of zero.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_root_hash: Option<Digest>,
+    /// The block whose state root should be called against.  Exactly one of this and
+    /// `state_root_hash` must be provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_identifier: Option<BlockIdentifier>,
+    /// The account making the call.  Required if the contract is identified by name, in which case
+    /// the name is looked up in this account's named keys.  If not provided, the call is made by
+    /// the system account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caller: Option<PublicKey>,
+    /// The stored contract to call.
+    pub contract: ContractIdentifier,
+    /// The name of the entry point to call.
+    pub entry_point: String,
+    /// The arguments to pass to the entry point.
+    #[serde(default)]
+    pub args: RuntimeArgs,
+}
+
+impl DocExample for CallContractParams {
+    fn doc_example() -> &'static Self {
+        &*CALL_CONTRACT_PARAMS
+    }
+}
+
+/// Result for "state_call_contract" RPC response.
+#[derive(Serialize, Deserialize, Debug, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CallContractResult {
+    /// The RPC API version.
+    #[schemars(with = "String")]
+    pub api_version: ProtocolVersion,
+    /// The value passed to `runtime::ret` by the entry point, or the unit value if it returned
+    /// without calling it.
+    pub value: CLValue,
+    /// The gas consumed by the call.
+    pub cost: U512,
+}
+
+impl DocExample for CallContractResult {
+    fn doc_example() -> &'static Self {
+        &*CALL_CONTRACT_RESULT
+    }
+}
+
+/// "state_call_contract" RPC.
+pub struct CallContract {}
+
+impl RpcWithParams for CallContract {
+    const METHOD: &'static str = "state_call_contract";
+    type RequestParams = CallContractParams;
+    type ResponseResult = CallContractResult;
+}
+
+impl RpcWithParamsExt for CallContract {
+    fn handle_request<REv: ReactorEventT>(
+        effect_builder: EffectBuilder<REv>,
+        response_builder: Builder,
+        params: Self::RequestParams,
+        api_version: ProtocolVersion,
+    ) -> BoxFuture<'static, Result<Response<Body>, Error>> {
+        async move {
+            let (state_root_hash, maybe_block) = match resolve_state_root_hash_and_block(
+                effect_builder,
+                params.state_root_hash,
+                params.block_identifier,
+            )
+            .await
+            {
+                Ok(resolved_state) => resolved_state,
+                Err(error) => return Ok(response_builder.error(error)?),
+            };
+
+            let caller = params
+                .caller
+                .map(|public_key| Box::new(public_key.to_account_hash()));
+
+            // Make the call.  None of its effects are committed.
+            let call_result = effect_builder
+                .make_request(
+                    |responder| RpcRequest::CallContract {
+                        state_root_hash,
+                        block_header: maybe_block.map(|block| Box::new(block.take_header())),
+                        caller,
+                        contract: Box::new(params.contract),
+                        entry_point: params.entry_point,
+                        args: params.args,
+                        protocol_version: api_version,
+                        responder,
+                    },
+                    QueueKind::Api,
+                )
+                .await;
+
+            let error_msg = match call_result {
+                Ok(EngineCallContractResult::Success { value, cost }) => {
+                    let result = Self::ResponseResult {
+                        api_version,
+                        value,
+                        cost: cost.value(),
+                    };
+                    return Ok(response_builder.success(result)?);
+                }
+                Ok(EngineCallContractResult::RootNotFound) => {
+                    "state root hash not found".to_string()
+                }
+                Err(error) => format!("contract call failed: {}", error),
+            };
+            info!("{}", error_msg);
+            Ok(response_builder.error(warp_json_rpc::Error::custom(
+                ErrorCode::CallContractFailed as i64,
+                error_msg,
+            ))?)
+        }
+        .boxed()
+    }
+}
+
+/// Returns the `Key::Dictionary` under which the item identified by `dictionary_identifier` is
+/// stored, looking up the dictionary's seed `URef` in global state if required.
+async fn resolve_dictionary_key<REv: ReactorEventT>(
+    effect_builder: EffectBuilder<REv>,
+    state_root_hash: Digest,
+    dictionary_identifier: DictionaryIdentifier,
+) -> Result<Key, warp_json_rpc::Error> {
+    let parse_key = |key: &str| {
+        Key::from_formatted_str(key).map_err(|error| {
+            let error_msg = format!("failed to parse key: {:?}", error);
+            info!("{}", error_msg);
+            warp_json_rpc::Error::custom(ErrorCode::ParseQueryKey as i64, error_msg)
+        })
+    };
+    let invalid_identifier = |error_msg: String| {
+        info!("{}", error_msg);
+        warp_json_rpc::Error::custom(ErrorCode::InvalidDictionaryIdentifier as i64, error_msg)
+    };
+
+    let (base_key, dictionary_name, dictionary_item_key) = match dictionary_identifier {
+        DictionaryIdentifier::AccountNamedKey {
+            key,
+            dictionary_name,
+            dictionary_item_key,
+        } => match parse_key(&key)? {
+            base_key @ Key::Account(_) => (base_key, dictionary_name, dictionary_item_key),
+            _ => return Err(invalid_identifier(format!("{} is not an account key", key))),
+        },
+        DictionaryIdentifier::ContractNamedKey {
+            key,
+            dictionary_name,
+            dictionary_item_key,
+        } => match parse_key(&key)? {
+            base_key @ Key::Hash(_) => (base_key, dictionary_name, dictionary_item_key),
+            _ => return Err(invalid_identifier(format!("{} is not a contract key", key))),
+        },
+        DictionaryIdentifier::URef {
+            seed_uref,
+            dictionary_item_key,
+        } => {
+            let seed_uref = URef::from_formatted_str(&seed_uref).map_err(|error| {
+                let error_msg = format!("failed to parse seed_uref: {:?}", error);
+                info!("{}", error_msg);
+                warp_json_rpc::Error::custom(ErrorCode::ParseQueryKey as i64, error_msg)
+            })?;
+            return Ok(Key::dictionary(seed_uref, dictionary_item_key.as_bytes()));
+        }
+        DictionaryIdentifier::Dictionary(dictionary_key) => {
+            return match parse_key(&dictionary_key)? {
+                dictionary_key @ Key::Dictionary(_) => Ok(dictionary_key),
+                _ => Err(invalid_identifier(format!(
+                    "{} is not a dictionary key",
+                    dictionary_key
+                ))),
+            };
+        }
+    };
+
+    let query_result = effect_builder
+        .make_request(
+            |responder| RpcRequest::QueryGlobalState {
+                state_root_hash,
+                base_key,
+                path: vec![],
+                responder,
+            },
+            QueueKind::Api,
+        )
+        .await;
+
+    let named_keys = match query_result {
+        Ok(QueryResult::Success { value, .. }) => match *value {
+            ExecutionEngineStoredValue::Account(account) => account.named_keys().clone(),
+            ExecutionEngineStoredValue::Contract(contract) => contract.take_named_keys(),
+            _ => {
+                return Err(invalid_identifier(format!(
+                    "{} is neither an account nor a contract",
+                    base_key
+                )))
+            }
+        },
+        Ok(query_result) => {
+            return Err(invalid_identifier(format!(
+                "state query failed: {:?}",
+                query_result
+            )))
+        }
+        Err(error) => {
+            let error_msg = format!("state query failed to execute: {:?}", error);
+            info!("{}", error_msg);
+            return Err(warp_json_rpc::Error::custom(
+                ErrorCode::QueryFailedToExecute as i64,
+                error_msg,
+            ));
+        }
+    };
+
+    match named_keys.get(&dictionary_name) {
+        Some(Key::URef(seed_uref)) => {
+            Ok(Key::dictionary(*seed_uref, dictionary_item_key.as_bytes()))
+        }
+        Some(_) => Err(invalid_identifier(format!(
+            "named key {} is not a dictionary seed uref",
+            dictionary_name
+        ))),
+        None => Err(invalid_identifier(format!(
+            "no named key {} under {}",
+            dictionary_name, base_key
+        ))),
+    }
+}
+
+/// Returns `maybe_state_root_hash`, or the state root hash of the block identified by
+/// `maybe_block_identifier`.
+///
+/// Exactly one of the two must be provided.
+async fn resolve_state_root_hash<REv: ReactorEventT>(
+    effect_builder: EffectBuilder<REv>,
+    maybe_state_root_hash: Option<Digest>,
+    maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<Digest, warp_json_rpc::Error> {
+    resolve_state_root_hash_and_block(
+        effect_builder,
+        maybe_state_root_hash,
+        maybe_block_identifier,
+    )
+    .await
+    .map(|(state_root_hash, _)| state_root_hash)
+}
+
+/// As `resolve_state_root_hash`, but also returns the identified block, or `None` if the state
+/// root hash was provided directly.
+async fn resolve_state_root_hash_and_block<REv: ReactorEventT>(
+    effect_builder: EffectBuilder<REv>,
+    maybe_state_root_hash: Option<Digest>,
+    maybe_block_identifier: Option<BlockIdentifier>,
+) -> Result<(Digest, Option<Block>), warp_json_rpc::Error> {
+    resolve_state_root_hash_with(
+        maybe_state_root_hash,
+        maybe_block_identifier,
+        |block_identifier| chain::get_block(Some(block_identifier), effect_builder),
+    )
+    .await
+}
+
+/// Implements `resolve_state_root_hash_and_block`, using `get_block` to look up the identified
+/// block.
+async fn resolve_state_root_hash_with<F, Fut>(
+    maybe_state_root_hash: Option<Digest>,
+    maybe_block_identifier: Option<BlockIdentifier>,
+    get_block: F,
+) -> Result<(Digest, Option<Block>), warp_json_rpc::Error>
+where
+    F: FnOnce(BlockIdentifier) -> Fut,
+    Fut: Future<Output = Result<Option<Block>, warp_json_rpc::Error>>,
+{
+    match (maybe_state_root_hash, maybe_block_identifier) {
+        (Some(state_root_hash), None) => Ok((state_root_hash, None)),
+        (None, Some(block_identifier)) => match get_block(block_identifier).await? {
+            Some(block) => Ok((*block.state_root_hash(), Some(block))),
+            None => Err(warp_json_rpc::Error::custom(
+                ErrorCode::NoSuchBlock as i64,
+                "block not known",
+            )),
+        },
+        _ => {
+            let error_msg = "exactly one of state_root_hash and block_identifier must be provided";
+            info!("{}", error_msg);
+            Err(warp_json_rpc::Error::custom(
+                ErrorCode::InvalidStateIdentifier as i64,
+                error_msg,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+
+    use casper_types::EraId;
+
+    use super::*;
+    use crate::{testing::TestRng, types::BlockHash};
+
+    fn random_blocks(rng: &mut TestRng, count: u64) -> Vec<Block> {
+        (0..count)
+            .map(|height| Block::random_with_specifics(rng, EraId::from(0), height, false))
+            .collect()
+    }
+
+    fn error_code(error: &warp_json_rpc::Error) -> i64 {
+        serde_json::to_value(error).expect("should serialize error")["code"]
+            .as_i64()
+            .expect("should have error code")
+    }
+
+    fn resolve(
+        blocks: &[Block],
+        maybe_state_root_hash: Option<Digest>,
+        maybe_block_identifier: Option<BlockIdentifier>,
+    ) -> Result<(Digest, Option<Block>), warp_json_rpc::Error> {
+        let get_block = |block_identifier| {
+            let maybe_block = blocks
+                .iter()
+                .find(|block| match block_identifier {
+                    BlockIdentifier::Hash(hash) => *block.hash() == hash,
+                    BlockIdentifier::Height(height) => block.height() == height,
+                })
+                .cloned();
+            async move { Ok(maybe_block) }
+        };
+        block_on(resolve_state_root_hash_with(
+            maybe_state_root_hash,
+            maybe_block_identifier,
+            get_block,
+        ))
+    }
+
+    #[test]
+    fn should_resolve_state_root_hash_by_block_height_and_hash() {
+        let mut rng = TestRng::new();
+        let blocks = random_blocks(&mut rng, 3);
+        let block = &blocks[1];
+
+        let by_height = resolve(&blocks, None, Some(BlockIdentifier::Height(block.height())));
+        assert_eq!(
+            by_height.unwrap(),
+            (*block.state_root_hash(), Some(block.clone()))
+        );
+
+        let by_hash = resolve(&blocks, None, Some(BlockIdentifier::Hash(*block.hash())));
+        assert_eq!(
+            by_hash.unwrap(),
+            (*block.state_root_hash(), Some(block.clone()))
+        );
+    }
+
+    #[test]
+    fn should_return_given_state_root_hash() {
+        let mut rng = TestRng::new();
+        let state_root_hash = Digest::random(&mut rng);
+        assert_eq!(
+            resolve(&[], Some(state_root_hash), None).unwrap(),
+            (state_root_hash, None)
+        );
+    }
+
+    #[test]
+    fn should_fail_to_resolve_missing_block() {
+        let mut rng = TestRng::new();
+        let blocks = random_blocks(&mut rng, 1);
+        let missing_hash = BlockHash::new(Digest::random(&mut rng));
+
+        let error = resolve(&blocks, None, Some(BlockIdentifier::Hash(missing_hash))).unwrap_err();
+        assert_eq!(error_code(&error), ErrorCode::NoSuchBlock as i64);
+    }
+
+    #[test]
+    fn should_require_exactly_one_of_state_root_hash_and_block_identifier() {
+        let mut rng = TestRng::new();
+        let blocks = random_blocks(&mut rng, 1);
+        let block = &blocks[0];
+
+        let error = resolve(&blocks, None, None).unwrap_err();
+        assert_eq!(error_code(&error), ErrorCode::InvalidStateIdentifier as i64);
+
+        let error = resolve(
+            &blocks,
+            Some(*block.state_root_hash()),
+            Some(BlockIdentifier::Hash(*block.hash())),
+        )
+        .unwrap_err();
+        assert_eq!(error_code(&error), ErrorCode::InvalidStateIdentifier as i64);
+    }
+}
@@ -0,0 +1,553 @@
+//! WebSocket transport for the JSON-RPC server.
+//!
+//! Each text message received over the WebSocket is treated as a JSON-RPC request.  Requests for
+//! the subscription methods below are handled by the WebSocket session itself; all other requests,
+//! including batches, are forwarded to the same handlers which serve the HTTP endpoint, so every
+//! RPC is available over either transport.
+//!
+//! * `subscribe_new_blocks` notifies the client of every block added to the linear chain.
+//! * `subscribe_deploy` notifies the client once, when the given deploy has been executed.
+//! * `unsubscribe` cancels the given subscription.
+//!
+//! The subscription methods are subject to the same per-client rate limits and method allowlist and
+//! denylist as all other methods.
+//!
+//! Both subscribe methods return the ID of the new subscription.  Notifications are sent as
+//! JSON-RPC requests without an "id", having the method `subscription` and params holding the
+//! subscription ID and the notification data as `result`.
+
+use std::{collections::HashMap, sync::Arc};
+
+use futures::{future, SinkExt, StreamExt};
+use http::{header::CONTENT_TYPE, Method, Request, Response};
+use hyper::Body;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tower::Service;
+use tracing::{debug, info};
+use warp::{
+    filters::BoxedFilter,
+    ws::{Message, WebSocket, Ws},
+    Filter, Reply,
+};
+
+use casper_types::ExecutionResult;
+
+use super::{
+    access_control::{AccessControl, ClientIp},
+    rpcs::{ErrorCode, RPC_API_PATH},
+};
+use crate::types::{Block, BlockHash, DeployHash};
+
+/// The URL path.
+pub const WS_API_PATH: &str = "ws";
+
+/// The JSON-RPC method subscribing to new blocks.
+const SUBSCRIBE_NEW_BLOCKS_METHOD: &str = "subscribe_new_blocks";
+/// The JSON-RPC method subscribing to the execution of a given deploy.
+const SUBSCRIBE_DEPLOY_METHOD: &str = "subscribe_deploy";
+/// The JSON-RPC method cancelling a subscription.
+const UNSUBSCRIBE_METHOD: &str = "unsubscribe";
+/// The JSON-RPC method of notifications pushed to subscribers.
+const NOTIFICATION_METHOD: &str = "subscription";
+/// The methods handled by the WebSocket session rather than the common JSON-RPC handlers.
+const SUBSCRIPTION_METHODS: [&str; 3] = [
+    SUBSCRIBE_NEW_BLOCKS_METHOD,
+    SUBSCRIBE_DEPLOY_METHOD,
+    UNSUBSCRIBE_METHOD,
+];
+/// The maximum number of concurrent subscriptions per WebSocket connection.
+const MAX_SUBSCRIPTIONS_PER_CONNECTION: usize = 100;
+
+/// The ID of a subscription, unique within a single WebSocket connection.
+type SubscriptionId = u64;
+
+/// Data about an event on the node which can be pushed to WebSocket subscribers.
+#[derive(Clone, Debug)]
+pub(super) enum Notification {
+    /// A block was added to the linear chain.
+    BlockAdded(Box<Block>),
+    /// A deploy was executed as part of the given block.
+    DeployProcessed {
+        deploy_hash: DeployHash,
+        block_hash: BlockHash,
+        execution_result: Box<ExecutionResult>,
+    },
+}
+
+/// A subscription held by a WebSocket client.
+#[derive(Debug, PartialEq)]
+enum Subscription {
+    NewBlocks,
+    Deploy(DeployHash),
+}
+
+/// The parts of a JSON-RPC request required to handle the subscription methods.
+#[derive(Deserialize)]
+struct JsonRpcRequest {
+    #[serde(default)]
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Option<Value>,
+}
+
+/// Params for the "subscribe_deploy" method.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SubscribeDeployParams {
+    deploy_hash: DeployHash,
+}
+
+/// Params for the "unsubscribe" method.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct UnsubscribeParams {
+    subscription: SubscriptionId,
+}
+
+/// The subscriptions of a single WebSocket connection.
+#[derive(Default)]
+struct Session {
+    subscriptions: HashMap<SubscriptionId, Subscription>,
+    next_subscription_id: SubscriptionId,
+}
+
+impl Session {
+    /// Handles the request if it is for one of the subscription methods, returning the serialized
+    /// response.
+    ///
+    /// Returns `None` if the request is for any other method, or can't be parsed, in which case it
+    /// should be handled by the common JSON-RPC handlers.
+    fn handle_request(
+        &mut self,
+        text: &str,
+        access_control: &AccessControl,
+        maybe_client_ip: Option<ClientIp>,
+    ) -> Option<String> {
+        let request: JsonRpcRequest = serde_json::from_str(text).ok()?;
+        if !SUBSCRIPTION_METHODS.contains(&request.method.as_str()) {
+            return None;
+        }
+
+        let maybe_client_ip = maybe_client_ip.map(|ClientIp(client_ip)| client_ip);
+        if let Err(rejection) = access_control.check(maybe_client_ip, Some(&request.method)) {
+            debug!(?maybe_client_ip, method = %request.method, ?rejection, "rejected request");
+            return Some(error_response(request.id, rejection.to_error()));
+        }
+
+        let response = match request.method.as_str() {
+            SUBSCRIBE_NEW_BLOCKS_METHOD => match request.params {
+                None => self.subscribe(request.id, Subscription::NewBlocks),
+                Some(_) => error_response(request.id, warp_json_rpc::Error::INVALID_PARAMS),
+            },
+            SUBSCRIBE_DEPLOY_METHOD => {
+                match parse_params::<SubscribeDeployParams>(request.params) {
+                    Some(params) => {
+                        self.subscribe(request.id, Subscription::Deploy(params.deploy_hash))
+                    }
+                    None => error_response(request.id, warp_json_rpc::Error::INVALID_PARAMS),
+                }
+            }
+            UNSUBSCRIBE_METHOD => match parse_params::<UnsubscribeParams>(request.params) {
+                Some(params) => {
+                    let removed = self.subscriptions.remove(&params.subscription).is_some();
+                    success_response(request.id, json!(removed))
+                }
+                None => error_response(request.id, warp_json_rpc::Error::INVALID_PARAMS),
+            },
+            _ => return None,
+        };
+        Some(response)
+    }
+
+    fn subscribe(&mut self, id: Value, subscription: Subscription) -> String {
+        if self.subscriptions.len() >= MAX_SUBSCRIPTIONS_PER_CONNECTION {
+            return error_response(
+                id,
+                warp_json_rpc::Error::custom(
+                    ErrorCode::TooManySubscriptions as i64,
+                    "too many subscriptions",
+                ),
+            );
+        }
+        let subscription_id = self.next_subscription_id;
+        self.next_subscription_id += 1;
+        let _ = self.subscriptions.insert(subscription_id, subscription);
+        success_response(id, json!(subscription_id))
+    }
+
+    /// Returns the serialized notifications due to the subscriptions matching the given event.
+    ///
+    /// Deploy subscriptions are removed once notified, as a deploy is only ever executed once.
+    fn notify(&mut self, notification: &Notification) -> Vec<String> {
+        let (matching_ids, result) = match notification {
+            Notification::BlockAdded(block) => {
+                let ids =
+                    self.subscription_ids(|subscription| *subscription == Subscription::NewBlocks);
+                let result = json!({ "block_hash": block.hash(), "block": block });
+                (ids, result)
+            }
+            Notification::DeployProcessed {
+                deploy_hash,
+                block_hash,
+                execution_result,
+            } => {
+                let ids = self.subscription_ids(|subscription| {
+                    *subscription == Subscription::Deploy(*deploy_hash)
+                });
+                for id in &ids {
+                    let _ = self.subscriptions.remove(id);
+                }
+                let result = json!({
+                    "deploy_hash": deploy_hash,
+                    "block_hash": block_hash,
+                    "execution_result": execution_result,
+                });
+                (ids, result)
+            }
+        };
+
+        matching_ids
+            .into_iter()
+            .map(|subscription_id| {
+                json!({
+                    "jsonrpc": "2.0",
+                    "method": NOTIFICATION_METHOD,
+                    "params": { "subscription": subscription_id, "result": result },
+                })
+                .to_string()
+            })
+            .collect()
+    }
+
+    fn subscription_ids<F: Fn(&Subscription) -> bool>(&self, predicate: F) -> Vec<SubscriptionId> {
+        let mut ids: Vec<_> = self
+            .subscriptions
+            .iter()
+            .filter(|(_, subscription)| predicate(subscription))
+            .map(|(id, _)| *id)
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+}
+
+fn parse_params<T: for<'de> Deserialize<'de>>(maybe_params: Option<Value>) -> Option<T> {
+    serde_json::from_value(maybe_params?).ok()
+}
+
+fn success_response(id: Value, result: Value) -> String {
+    json!({ "jsonrpc": "2.0", "id": id, "result": result }).to_string()
+}
+
+fn error_response(id: Value, error: warp_json_rpc::Error) -> String {
+    json!({ "jsonrpc": "2.0", "id": id, "error": error }).to_string()
+}
+
+/// Passes the request to the common JSON-RPC handlers as if it had been received over HTTP,
+/// returning the serialized response.
+async fn forward_request<S>(
+    mut rpc_service: S,
+    maybe_client_ip: Option<ClientIp>,
+    text: String,
+) -> Option<String>
+where
+    S: Service<Request<Body>, Response = Response<Body>>,
+{
+    let mut request = Request::builder()
+        .method(Method::POST)
+        .uri(format!("/{}", RPC_API_PATH))
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(text))
+        .ok()?;
+    if let Some(client_ip) = maybe_client_ip {
+        request.extensions_mut().insert(client_ip);
+    }
+    future::poll_fn(|context| rpc_service.poll_ready(context))
+        .await
+        .ok()?;
+    let response = rpc_service.call(request).await.ok()?;
+    let body = hyper::body::to_bytes(response.into_body()).await.ok()?;
+    String::from_utf8(body.to_vec()).ok()
+}
+
+/// Serves JSON-RPC requests and subscriptions over the given WebSocket until it is closed.
+async fn run_session<S>(
+    websocket: WebSocket,
+    rpc_service: S,
+    access_control: Arc<AccessControl>,
+    maybe_client_ip: Option<ClientIp>,
+    mut notification_receiver: broadcast::Receiver<Notification>,
+) where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone,
+{
+    let (mut ws_sender, mut ws_receiver) = websocket.split();
+    let mut session = Session::default();
+
+    loop {
+        let outgoing = tokio::select! {
+            maybe_message = ws_receiver.next() => {
+                let message = match maybe_message {
+                    Some(Ok(message)) => message,
+                    Some(Err(error)) => {
+                        debug!(%error, "websocket error");
+                        break;
+                    }
+                    None => break,
+                };
+                if message.is_close() {
+                    break;
+                }
+                // Ignore pings, pongs and binary messages.
+                let text = match message.to_str() {
+                    Ok(text) => text,
+                    Err(()) => continue,
+                };
+                match session.handle_request(text, &access_control, maybe_client_ip) {
+                    Some(response) => vec![response],
+                    None => forward_request(rpc_service.clone(), maybe_client_ip, text.to_string())
+                        .await
+                        .into_iter()
+                        .collect(),
+                }
+            }
+            result = notification_receiver.recv() => match result {
+                Ok(notification) => session.notify(&notification),
+                Err(RecvError::Lagged(skipped)) => {
+                    info!(skipped, "websocket client lagged, notifications skipped");
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            }
+        };
+
+        for text in outgoing {
+            if ws_sender.send(Message::text(text)).await.is_err() {
+                return;
+            }
+        }
+    }
+
+    let _ = ws_sender.close().await;
+}
+
+/// Creates the warp filter upgrading requests on the WebSocket path.
+///
+/// JSON-RPC requests other than the subscription methods are passed to `rpc_service`.
+pub(super) fn create_filter<S>(
+    rpc_service: S,
+    access_control: Arc<AccessControl>,
+    notification_sender: broadcast::Sender<Notification>,
+) -> BoxedFilter<(Response<Body>,)>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Clone + Send + Sync + 'static,
+    S::Future: Send,
+{
+    warp::path(WS_API_PATH)
+        .and(warp::path::end())
+        .and(warp::ws())
+        .and(warp::ext::optional::<ClientIp>())
+        .map(move |ws: Ws, maybe_client_ip: Option<ClientIp>| {
+            let rpc_service = rpc_service.clone();
+            let access_control = Arc::clone(&access_control);
+            let notification_receiver = notification_sender.subscribe();
+            ws.on_upgrade(move |websocket| {
+                run_session(
+                    websocket,
+                    rpc_service,
+                    access_control,
+                    maybe_client_ip,
+                    notification_receiver,
+                )
+            })
+            .into_response()
+        })
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use prometheus::Registry;
+    use rand::Rng;
+
+    use super::*;
+    use crate::{
+        components::rpc_server::{metrics::RpcServerMetrics, Config},
+        testing::TestRng,
+    };
+
+    fn new_access_control(config: Config) -> AccessControl {
+        let metrics = RpcServerMetrics::new(&Registry::new()).unwrap();
+        AccessControl::new(&config, metrics)
+    }
+
+    fn unrestricted() -> AccessControl {
+        let mut config = Config::new();
+        config.client_qps_limit = 0;
+        new_access_control(config)
+    }
+
+    fn client_ip() -> Option<ClientIp> {
+        Some(ClientIp(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))))
+    }
+
+    fn request(method: &str, params: Option<Value>) -> String {
+        let mut request = json!({ "jsonrpc": "2.0", "id": 1, "method": method });
+        if let Some(params) = params {
+            request["params"] = params;
+        }
+        request.to_string()
+    }
+
+    fn result_of(response: &str) -> Value {
+        serde_json::from_str::<Value>(response).unwrap()["result"].clone()
+    }
+
+    #[test]
+    fn should_not_handle_other_methods() {
+        let access_control = unrestricted();
+        let mut session = Session::default();
+        assert!(session
+            .handle_request(
+                &request("info_get_status", None),
+                &access_control,
+                client_ip()
+            )
+            .is_none());
+        assert!(session
+            .handle_request("not json", &access_control, client_ip())
+            .is_none());
+    }
+
+    #[test]
+    fn should_notify_new_block_subscribers() {
+        let mut rng = TestRng::new();
+        let access_control = unrestricted();
+        let mut session = Session::default();
+        let response = session
+            .handle_request(
+                &request(SUBSCRIBE_NEW_BLOCKS_METHOD, None),
+                &access_control,
+                client_ip(),
+            )
+            .unwrap();
+        assert_eq!(result_of(&response), json!(0));
+
+        let block = Block::random(&mut rng);
+        let notifications = session.notify(&Notification::BlockAdded(Box::new(block.clone())));
+        assert_eq!(notifications.len(), 1);
+        let notification: Value = serde_json::from_str(&notifications[0]).unwrap();
+        assert_eq!(notification["method"], json!(NOTIFICATION_METHOD));
+        assert_eq!(notification["params"]["subscription"], json!(0));
+        assert_eq!(
+            notification["params"]["result"]["block_hash"],
+            json!(block.hash())
+        );
+
+        let response = session
+            .handle_request(
+                &request(UNSUBSCRIBE_METHOD, Some(json!({ "subscription": 0 }))),
+                &access_control,
+                client_ip(),
+            )
+            .unwrap();
+        assert_eq!(result_of(&response), json!(true));
+        assert!(session
+            .notify(&Notification::BlockAdded(Box::new(block)))
+            .is_empty());
+    }
+
+    #[test]
+    fn should_notify_deploy_subscribers_once() {
+        let mut rng = TestRng::new();
+        let access_control = unrestricted();
+        let mut session = Session::default();
+        let deploy_hash = DeployHash::random(&mut rng);
+        let other_deploy_hash = DeployHash::random(&mut rng);
+        let params = Some(json!({ "deploy_hash": deploy_hash }));
+        let response = session
+            .handle_request(
+                &request(SUBSCRIBE_DEPLOY_METHOD, params),
+                &access_control,
+                client_ip(),
+            )
+            .unwrap();
+        assert_eq!(result_of(&response), json!(0));
+
+        let mut processed = |deploy_hash| Notification::DeployProcessed {
+            deploy_hash,
+            block_hash: BlockHash::random(&mut rng),
+            execution_result: Box::new(rng.gen()),
+        };
+        assert!(session.notify(&processed(other_deploy_hash)).is_empty());
+        assert_eq!(session.notify(&processed(deploy_hash)).len(), 1);
+        assert!(session.notify(&processed(deploy_hash)).is_empty());
+    }
+
+    #[test]
+    fn should_reject_invalid_params() {
+        let access_control = unrestricted();
+        let mut session = Session::default();
+        let response = session
+            .handle_request(
+                &request(
+                    SUBSCRIBE_DEPLOY_METHOD,
+                    Some(json!({ "deploy_hash": "not a hash" })),
+                ),
+                &access_control,
+                client_ip(),
+            )
+            .unwrap();
+        let response: Value = serde_json::from_str(&response).unwrap();
+        assert_eq!(response["error"]["code"], json!(-32602));
+        assert!(session.subscriptions.is_empty());
+    }
+
+    #[test]
+    fn should_apply_access_control_to_subscription_methods() {
+        let mut config = Config::new();
+        config.client_qps_limit = 0;
+        config.denied_methods = vec![SUBSCRIBE_DEPLOY_METHOD.to_string()];
+        let access_control = new_access_control(config);
+        let mut session = Session::default();
+        let params = Some(json!({
+            "deploy_hash": DeployHash::random(&mut TestRng::new())
+        }));
+        let response = session
+            .handle_request(
+                &request(SUBSCRIBE_DEPLOY_METHOD, params),
+                &access_control,
+                client_ip(),
+            )
+            .unwrap();
+        let response: Value = serde_json::from_str(&response).unwrap();
+        assert_eq!(
+            response["error"]["code"],
+            json!(ErrorCode::MethodNotAllowed as i64)
+        );
+        assert!(session.subscriptions.is_empty());
+
+        let mut config = Config::new();
+        config.client_qps_limit = 1;
+        config.client_burst_limit = 1;
+        let access_control = new_access_control(config);
+        let subscribe = request(SUBSCRIBE_NEW_BLOCKS_METHOD, None);
+        let response = session
+            .handle_request(&subscribe, &access_control, client_ip())
+            .unwrap();
+        assert_eq!(result_of(&response), json!(0));
+        let response = session
+            .handle_request(&subscribe, &access_control, client_ip())
+            .unwrap();
+        let response: Value = serde_json::from_str(&response).unwrap();
+        assert_eq!(
+            response["error"]["code"],
+            json!(ErrorCode::RateLimited as i64)
+        );
+        assert_eq!(session.subscriptions.len(), 1);
+    }
+}
@@ -31,13 +31,29 @@
 //! On losing an incoming or outgoing connection for a given peer, the other connection is closed.
 //! No explicit reconnect is attempted. Instead, if the peer is still online, the normal gossiping
 //! process will cause both peers to connect again.
+//!
+//! # Reputation
+//!
+//! Every peer we connected to is recorded in a peer store, optionally persisted across restarts,
+//! together with a reputation score. Transgressions lower the score, and peers whose score is too
+//! low are not connected to until it has decayed far enough.
+//!
+//! # Pinned peers
+//!
+//! Peers can be pinned by their address and TLS key fingerprint, in which case the node always
+//! keeps a connection to them. Together with not advertising our own address and refusing
+//! connections from other peers, this allows running a validator that is only reachable through
+//! its sentry nodes.
 
 mod chain_info;
+mod compression;
 mod config;
 mod error;
 mod event;
 mod gossiped_address;
 mod message;
+mod outgoing_queue;
+mod peer_store;
 #[cfg(test)]
 mod tests;
 
@@ -64,55 +80,63 @@ use futures::{
     stream::{SplitSink, SplitStream},
     FutureExt, SinkExt, StreamExt,
 };
-use once_cell::sync::Lazy;
 use openssl::{error::ErrorStack as OpenSslErrorStack, pkey, ssl::Ssl};
 use pkey::{PKey, Private};
-use prometheus::{IntGauge, Registry};
+use prometheus::Registry;
 use rand::seq::IteratorRandom;
 use serde::{de::DeserializeOwned, Serialize};
 use thiserror::Error;
-use tokio::{
-    net::TcpStream,
-    sync::{
-        mpsc::{self, UnboundedReceiver, UnboundedSender},
-        watch,
-    },
-    task::JoinHandle,
-};
+use tokio::{net::TcpStream, sync::watch, task::JoinHandle};
 use tokio_openssl::SslStream;
 use tokio_serde::{formats::SymmetricalBincode, SymmetricallyFramed};
 use tokio_util::codec::{Framed, LengthDelimitedCodec};
 use tracing::{debug, error, info, trace, warn};
 
-use self::error::Result;
-pub(crate) use self::{event::Event, gossiped_address::GossipedAddress, message::Message};
+use self::{
+    compression::OutgoingCompression,
+    error::Result,
+    message::{Capabilities, CAPABILITIES_MIN_WIRE_VERSION},
+    outgoing_queue::{OutgoingQueueMetrics, OutgoingReceiver, OutgoingSender, PushError},
+    peer_store::{PeerStore, Transgression},
+};
+pub(crate) use self::{
+    event::Event,
+    gossiped_address::GossipedAddress,
+    message::Message,
+    outgoing_queue::{Classify, MessageClass},
+};
 use crate::{
     components::{
         network::ENABLE_LIBP2P_NET_ENV_VAR, networking_metrics::NetworkingMetrics, Component,
     },
     effect::{
-        announcements::{BlocklistAnnouncement, NetworkAnnouncement},
+        announcements::{BlocklistAnnouncement, NetworkAnnouncement, PeerFailure},
         requests::{NetworkInfoRequest, NetworkRequest},
         EffectBuilder, EffectExt, EffectResultExt, Effects,
     },
     reactor::{EventQueueHandle, Finalize, QueueKind, ReactorEvent},
     tls::{self, TlsCert, ValidationError},
-    types::{NodeId, TimeDiff, Timestamp},
-    utils, NodeRng,
+    types::{NodeId, Timestamp},
+    utils::{self, WithDir},
+    NodeRng,
 };
 use chain_info::ChainInfo;
 pub use config::Config;
 pub use error::Error;
 
 const MAX_ASYMMETRIC_CONNECTION_SEEN: u16 = 4;
-static BLOCKLIST_RETAIN_DURATION: Lazy<TimeDiff> =
-    Lazy::new(|| Duration::from_secs(60 * 10).into());
+/// The number of stored peers, besides the known addresses, to connect to when (re)connecting.
+const STORED_PEERS_TO_CONNECT: usize = 20;
 
 #[derive(DataSize, Debug)]
 pub(crate) struct OutgoingConnection<P> {
-    #[data_size(skip)] // Unfortunately, there is no way to inspect an `UnboundedSender`.
-    sender: UnboundedSender<Message<P>>,
+    #[data_size(skip)] // Unfortunately, there is no way to inspect the queued messages.
+    sender: OutgoingSender<P>,
     peer_address: SocketAddr,
+    /// Set once the peer has advertised support for zstd compression, if we support it as well.
+    peer_supports_zstd: Arc<AtomicBool>,
+    /// Whether our capabilities have been queued on this connection.
+    capabilities_sent: bool,
 
     // for keeping track of connection asymmetry, tracking the number of times we've seen this
     // connection be asymmetric.
@@ -152,9 +176,14 @@ where
     incoming: HashMap<NodeId, IncomingConnection>,
     /// Outgoing network connections' messages.
     outgoing: HashMap<NodeId, OutgoingConnection<P>>,
+    /// The capabilities announced by peers on their incoming connections. These can arrive before
+    /// our outgoing connection to the peer is set up.
+    peer_capabilities: HashMap<NodeId, Capabilities>,
 
-    /// List of addresses which this node will avoid connecting to and the time they were added.
-    blocklist: HashMap<SocketAddr, Timestamp>,
+    /// The peers we have connected to before and their reputation, which decides which addresses
+    /// this node will avoid connecting to.
+    #[data_size(skip)]
+    peer_store: PeerStore,
 
     /// Pending outgoing connections: ones for which we are currently trying to make a connection.
     pending: HashMap<SocketAddr, Instant>,
@@ -180,14 +209,20 @@ where
     /// Networking metrics.
     #[data_size(skip)]
     net_metrics: NetworkingMetrics,
+    /// Metrics of the outgoing message queues.
+    #[data_size(skip)]
+    outgoing_queue_metrics: OutgoingQueueMetrics,
 
     /// Known addresses for this node.
     known_addresses: HashSet<SocketAddr>,
+    /// Addresses and node IDs of the peers this node always keeps connected to. Their addresses
+    /// are also known addresses.
+    pinned_peers: HashMap<SocketAddr, NodeId>,
 }
 
 impl<REv, P> SmallNetwork<REv, P>
 where
-    P: Serialize + DeserializeOwned + Clone + Debug + Display + Send + 'static,
+    P: Serialize + DeserializeOwned + Clone + Debug + Display + Classify + Send + 'static,
     REv: ReactorEvent + From<Event<P>> + From<NetworkAnnouncement<NodeId, P>>,
 {
     /// Creates a new small network component instance.
@@ -197,12 +232,19 @@ where
     #[allow(clippy::type_complexity)]
     pub(crate) fn new<C: Into<ChainInfo>>(
         event_queue: EventQueueHandle<REv>,
-        cfg: Config,
+        cfg: WithDir<Config>,
         registry: &Registry,
         small_network_identity: SmallNetworkIdentity,
         chain_info_source: C,
         notify: bool,
     ) -> Result<(SmallNetwork<REv, P>, Effects<Event<P>>)> {
+        let peer_store_path = cfg
+            .value()
+            .peer_store_path
+            .as_ref()
+            .map(|path| cfg.with_dir(path.clone()));
+        let (_, cfg) = cfg.into_parts();
+
         let mut known_addresses = HashSet::new();
         for address in &cfg.known_addresses {
             match utils::resolve_address(address) {
@@ -217,6 +259,19 @@ where
             }
         }
 
+        let mut pinned_peers = HashMap::new();
+        for pinned_peer in &cfg.pinned_peers {
+            match utils::resolve_address(&pinned_peer.address) {
+                Ok(pinned_address) => {
+                    known_addresses.insert(pinned_address);
+                    pinned_peers.insert(pinned_address, NodeId::from(pinned_peer.fingerprint));
+                }
+                Err(err) => {
+                    warn!(address=%pinned_peer.address, %err, "failed to resolve pinned peer address");
+                }
+            }
+        }
+
         // Assert we have at least one known address in the config.
         if known_addresses.is_empty() {
             warn!("no known addresses provided via config or all failed DNS resolution");
@@ -227,10 +282,15 @@ where
             utils::resolve_address(&cfg.public_address).map_err(Error::ResolveAddr)?;
 
         let our_id = NodeId::from(&small_network_identity);
+        if let NodeId::Tls(fingerprint) = our_id {
+            // Operators need the full fingerprint to pin this node on other nodes.
+            info!(%our_id, fingerprint=%hex::encode(fingerprint.as_ref()), "our TLS key fingerprint");
+        }
         let secret_key = small_network_identity.secret_key;
         let certificate = small_network_identity.tls_certificate;
 
         let chain_info = Arc::new(chain_info_source.into());
+        let peer_store = PeerStore::load(peer_store_path, cfg.reputation_half_life);
 
         // If the env var "CASPER_ENABLE_LIBP2P_NET" is defined, exit without starting the
         // server.
@@ -238,6 +298,7 @@ where
             let model = SmallNetwork {
                 cfg,
                 known_addresses,
+                pinned_peers,
                 certificate,
                 secret_key,
                 public_address,
@@ -246,19 +307,22 @@ where
                 event_queue,
                 incoming: HashMap::new(),
                 outgoing: HashMap::new(),
+                peer_capabilities: HashMap::new(),
                 pending: HashMap::new(),
-                blocklist: HashMap::new(),
+                peer_store,
                 chain_info,
                 shutdown_sender: None,
                 shutdown_receiver: watch::channel(()).1,
                 server_join_handle: None,
                 is_stopped: Arc::new(AtomicBool::new(true)),
                 net_metrics: NetworkingMetrics::new(&Registry::default())?,
+                outgoing_queue_metrics: OutgoingQueueMetrics::new(&Registry::default())?,
             };
             return Ok((model, Effects::new()));
         }
 
         let net_metrics = NetworkingMetrics::new(&registry)?;
+        let outgoing_queue_metrics = OutgoingQueueMetrics::new(&registry)?;
 
         // We can now create a listener.
         let bind_address = utils::resolve_address(&cfg.bind_address).map_err(Error::ResolveAddr)?;
@@ -306,6 +370,7 @@ where
         let mut model = SmallNetwork {
             cfg,
             known_addresses,
+            pinned_peers,
             certificate,
             secret_key,
             public_address,
@@ -314,14 +379,16 @@ where
             event_queue,
             incoming: HashMap::new(),
             outgoing: HashMap::new(),
+            peer_capabilities: HashMap::new(),
             pending: HashMap::new(),
-            blocklist: HashMap::new(),
+            peer_store,
             chain_info,
             shutdown_sender: Some(server_shutdown_sender),
             shutdown_receiver,
             server_join_handle: Some(server_join_handle),
             is_stopped: Arc::new(AtomicBool::new(false)),
             net_metrics,
+            outgoing_queue_metrics,
         };
 
         // Bootstrap process.
@@ -330,7 +397,7 @@ where
         // We kick things off by adding effects to connect to all known addresses. This will
         // automatically attempt to repeat the connection process if it fails (see
         // `connect_to_known_addresses` for details).
-        let mut effects = model.connect_to_known_addresses();
+        let mut effects = model.connect_to_known_addresses(effect_builder);
 
         // Start broadcasting our public listening address.
         effects.extend(
@@ -349,14 +416,26 @@ where
         }
     }
 
-    /// Try to establish a connection to all known addresses in the configuration.
+    /// Try to establish a connection to all known addresses in the configuration, and to the peers
+    /// with the best reputation from the peer store.
     ///
-    /// Will schedule another reconnection if no DNS addresses could be resolved.
-    fn connect_to_known_addresses(&mut self) -> Effects<Event<P>> {
+    /// Known addresses that are banned are skipped unless they are pinned. Will schedule another
+    /// reconnection if no known address could be connected to.
+    fn connect_to_known_addresses(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+    ) -> Effects<Event<P>> {
         let mut effects = Effects::new();
 
         let now = Instant::now();
+        let timestamp = Timestamp::now();
         for &address in &self.known_addresses {
+            if !self.pinned_peers.contains_key(&address)
+                && self.peer_store.is_banned(&address, timestamp)
+            {
+                debug!(our_id=%self.our_id, %address, "not connecting to banned known address");
+                continue;
+            }
             self.pending.insert(address, now);
 
             // Add an effect to connect to the known address.
@@ -381,6 +460,14 @@ where
             );
         }
 
+        let stored_peers = self
+            .peer_store
+            .best_peers(STORED_PEERS_TO_CONNECT, timestamp);
+        for address in stored_peers {
+            effects.extend(self.connect_to_peer_if_required(address));
+        }
+
+        effects.extend(self.reconnect_if_not_connected_to_any_known_addresses(effect_builder));
         effects
     }
 
@@ -421,11 +508,20 @@ where
     fn send_message(&self, dest: NodeId, msg: Message<P>) {
         // Try to send the message.
         if let Some(connection) = self.outgoing.get(&dest) {
-            if let Err(msg) = connection.sender.send(msg) {
-                // We lost the connection, but that fact has not reached us yet.
-                warn!(our_id=%self.our_id, %dest, ?msg, "dropped outgoing message, lost connection");
-            } else {
-                self.net_metrics.queued_messages.inc();
+            match connection.sender.push(msg) {
+                Ok(()) => (),
+                Err(PushError::Disconnected(msg)) => {
+                    // We lost the connection, but that fact has not reached us yet.
+                    warn!(our_id=%self.our_id, %dest, ?msg, "dropped outgoing message, lost connection");
+                }
+                Err(PushError::GossipFull(msg)) => {
+                    // Gossip is shed first under pressure, this is expected.
+                    debug!(our_id=%self.our_id, %dest, ?msg, "dropped outgoing gossip, queue full");
+                }
+                Err(PushError::Overflow(class, msg)) => {
+                    // The sender task terminates, and the connection is handled as lost.
+                    warn!(our_id=%self.our_id, %dest, %class, ?msg, "outgoing queue full, disconnecting");
+                }
             }
         } else {
             // We are not connected, so the reconnection is likely already in progress.
@@ -493,6 +589,11 @@ where
                     return Effects::new();
                 }
 
+                if self.cfg.accept_only_pinned_peers && !self.is_pinned(&peer_id) {
+                    info!(our_id=%self.our_id, %peer_id, %peer_address, "refusing incoming connection from peer that is not pinned");
+                    return Effects::new();
+                }
+
                 // If the peer has already disconnected, allow the connection to drop.
                 if let Err(error) = transport.get_ref().peer_addr() {
                     debug!(
@@ -536,6 +637,7 @@ where
                         self.shutdown_receiver.clone(),
                         self.our_id,
                         peer_id,
+                        self.chain_info.maximum_net_message_size,
                     )
                     .event(move |result| Event::IncomingClosed {
                         result,
@@ -588,15 +690,39 @@ where
             return self.reconnect_if_not_connected_to_any_known_addresses(effect_builder);
         }
 
+        if let Some(pinned_id) = self.pinned_peers.get(&peer_address) {
+            if *pinned_id != peer_id {
+                warn!(our_id=%self.our_id, %peer_id, %pinned_id, %peer_address, "pinned peer has wrong ID, dropping connection");
+                return self.reconnect_if_not_connected_to_any_known_addresses(effect_builder);
+            }
+        }
+
+        self.peer_store
+            .record_connection(peer_address, peer_id, Timestamp::now());
+
         // The stream is only used to receive a single handshake message and then dropped.
         let (sink, stream) =
             framed::<P>(transport, self.chain_info.maximum_net_message_size).split();
         debug!(our_id=%self.our_id, %peer_id, %peer_address, "established outgoing connection");
 
-        let (sender, receiver) = mpsc::unbounded_channel();
+        let (sender, receiver) = outgoing_queue::channel(
+            &self.cfg.outgoing_queue,
+            &self.outgoing_queue_metrics,
+            self.net_metrics.queued_messages.clone(),
+        );
+        // The peer may have announced its capabilities on its own connection to us already.
+        let peer_supports_zstd = Arc::new(AtomicBool::new(self.peer_supports_zstd(&peer_id)));
+        let compression = OutgoingCompression::new(
+            Arc::clone(&peer_supports_zstd),
+            self.cfg.compression_threshold,
+            self.net_metrics.compressed_messages.clone(),
+            self.net_metrics.compression_bytes_saved.clone(),
+        );
         let connection = OutgoingConnection {
             peer_address,
             sender,
+            peer_supports_zstd,
+            capabilities_sent: false,
             times_seen_asymmetric: 0,
         };
         if self.outgoing.insert(peer_id, connection).is_some() {
@@ -611,16 +737,12 @@ where
         let handshake = self.chain_info.create_handshake(self.public_address);
 
         effects.extend(
-            message_sender(
-                receiver,
-                sink,
-                self.net_metrics.queued_messages.clone(),
-                handshake,
-            )
-            .event(move |result| Event::OutgoingFailed {
-                peer_id: Box::new(Some(peer_id)),
-                peer_address: Box::new(peer_address),
-                error: Box::new(result.err().map(Into::into)),
+            message_sender(receiver, sink, handshake, compression).event(move |result| {
+                Event::OutgoingFailed {
+                    peer_id: Box::new(Some(peer_id)),
+                    peer_address: Box::new(peer_address),
+                    error: Box::new(result.err().map(Into::into)),
+                }
             }),
         );
         effects.extend(
@@ -679,8 +801,9 @@ where
         &mut self,
         effect_builder: EffectBuilder<REv>,
         peer_id: &NodeId,
-        add_to_blocklist: bool,
+        ban: bool,
     ) -> Effects<Event<P>> {
+        let _ = self.peer_capabilities.remove(peer_id);
         if let Some(incoming) = self.incoming.remove(&peer_id) {
             trace!(our_id=%self.our_id, %peer_id, "removing peer from the incoming connections");
             let _ = self.pending.remove(&incoming.peer_address);
@@ -691,22 +814,33 @@ where
         }
         if let Some(outgoing) = self.outgoing.remove(&peer_id) {
             trace!(our_id=%self.our_id, %peer_id, "removing peer from the outgoing connections");
-            if add_to_blocklist && !self.known_addresses.contains(&outgoing.peer_address) {
-                info!(our_id=%self.our_id, %peer_id, "blocklisting peer");
-                self.blocklist
-                    .insert(outgoing.peer_address, Timestamp::now());
+            let now = Timestamp::now();
+            self.peer_store.record_seen(outgoing.peer_address, now);
+            if ban && !self.pinned_peers.contains_key(&outgoing.peer_address) {
+                info!(our_id=%self.our_id, %peer_id, "banning peer");
+                self.peer_store.record_transgression(
+                    outgoing.peer_address,
+                    Transgression::Offense,
+                    now,
+                );
             }
         }
 
         self.reconnect_if_not_connected_to_any_known_addresses(effect_builder)
     }
 
-    /// Gossips our public listening address, and schedules the next such gossip round.
+    /// Gossips our public listening address unless configured not to, and schedules the next such
+    /// gossip round.
     fn gossip_our_address(&mut self, effect_builder: EffectBuilder<REv>) -> Effects<Event<P>> {
-        let our_address = GossipedAddress::new(self.public_address);
-        let mut effects = effect_builder
-            .announce_gossip_our_address(our_address)
-            .ignore();
+        let mut effects = Effects::new();
+        if self.cfg.advertise_address {
+            let our_address = GossipedAddress::new(self.public_address);
+            effects.extend(
+                effect_builder
+                    .announce_gossip_our_address(our_address)
+                    .ignore(),
+            );
+        }
         effects.extend(
             effect_builder
                 .set_timeout(self.cfg.gossip_interval)
@@ -766,6 +900,7 @@ where
                 network_name,
                 public_address,
                 protocol_version,
+                wire_version,
             } => {
                 if network_name != self.chain_info.network_name {
                     info!(
@@ -782,6 +917,19 @@ where
                     return remove;
                 }
 
+                // Tell the peer which optional features we support, if it can decode that. The peer
+                // also sends a handshake on our outgoing connection, so this happens even if its
+                // first handshake arrives before that connection is set up.
+                if wire_version >= CAPABILITIES_MIN_WIRE_VERSION {
+                    let capabilities = self.capabilities();
+                    if let Some(outgoing) = self.outgoing.get_mut(&peer_id) {
+                        if !outgoing.capabilities_sent {
+                            outgoing.capabilities_sent = true;
+                            self.send_message(peer_id, Message::Capabilities(capabilities));
+                        }
+                    }
+                }
+
                 // This speeds up the connection process, but masks potential bugs in the gossiper.
                 let effects = self.connect_to_peer_if_required(public_address);
                 self.update_peers_metric();
@@ -791,26 +939,63 @@ where
             Message::Payload(payload) => effect_builder
                 .announce_message_received(peer_id, payload)
                 .ignore(),
+            Message::CompressedPayload(_) => {
+                // The message reader decompresses payloads before passing them on.
+                error!(our_id=%self.our_id, %peer_id, "unexpected compressed payload");
+                Effects::new()
+            }
+            Message::Capabilities(capabilities) => {
+                if !self.incoming.contains_key(&peer_id) {
+                    debug!(our_id=%self.our_id, %peer_id, "ignoring capabilities of disconnected peer");
+                    return Effects::new();
+                }
+                debug!(our_id=%self.our_id, %peer_id, %capabilities, "received capabilities");
+                let _ = self.peer_capabilities.insert(peer_id, capabilities);
+                // Otherwise they are applied once the outgoing connection is set up.
+                if let Some(outgoing) = self.outgoing.get(&peer_id) {
+                    let supports_zstd = self.peer_supports_zstd(&peer_id);
+                    outgoing
+                        .peer_supports_zstd
+                        .store(supports_zstd, Ordering::SeqCst);
+                }
+                Effects::new()
+            }
+        }
+    }
+
+    /// Returns the optional wire format features we advertise to peers.
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            zstd_compression: self.cfg.enable_compression,
         }
     }
 
+    /// Returns whether messages to the peer should be compressed: whether both of us support it.
+    ///
+    /// Peers that haven't sent their capabilities are treated as supporting none of them.
+    fn peer_supports_zstd(&self, peer_id: &NodeId) -> bool {
+        self.cfg.enable_compression
+            && self
+                .peer_capabilities
+                .get(peer_id)
+                .map_or(false, |capabilities| capabilities.zstd_compression)
+    }
+
     fn update_peers_metric(&mut self) {
         self.net_metrics.peers.set(self.peers().len() as i64);
     }
 
     fn connect_to_peer_if_required(&mut self, peer_address: SocketAddr) -> Effects<Event<P>> {
-        let now = Timestamp::now();
-        self.blocklist
-            .retain(|_, ts| *ts > now - *BLOCKLIST_RETAIN_DURATION);
         if self.pending.contains_key(&peer_address)
-            || self.blocklist.contains_key(&peer_address)
+            || (!self.pinned_peers.contains_key(&peer_address)
+                && self.peer_store.is_banned(&peer_address, Timestamp::now()))
             || self
                 .outgoing
                 .iter()
                 .any(|(_peer_id, connection)| connection.peer_address == peer_address)
         {
-            // We're already trying to connect, are connected, or the connection is on the blocklist
-            // - do nothing.
+            // We're already trying to connect, are connected, or the peer is banned - do nothing.
+            // Pinned peers are never banned.
             Effects::new()
         } else {
             // We need to connect.
@@ -882,6 +1067,62 @@ where
         ret
     }
 
+    /// Returns the current reputation score of each connected peer we have one for.
+    fn peer_reputations(&self) -> BTreeMap<NodeId, i64> {
+        let now = Timestamp::now();
+        self.outgoing
+            .iter()
+            .filter_map(|(node_id, connection)| {
+                let score = self.peer_store.score(&connection.peer_address, now)?;
+                Some((*node_id, score.round() as i64))
+            })
+            .collect()
+    }
+
+    /// Returns whether the given peer is one this node always keeps connected to.
+    fn is_pinned(&self, peer_id: &NodeId) -> bool {
+        self.pinned_peers
+            .values()
+            .any(|pinned_id| pinned_id == peer_id)
+    }
+
+    /// Connects to all pinned peers we are neither connected nor trying to connect to.
+    fn connect_to_pinned_peers(&mut self) -> Effects<Event<P>> {
+        let pinned_addresses: Vec<SocketAddr> = self.pinned_peers.keys().copied().collect();
+        let mut effects = Effects::new();
+        for address in pinned_addresses {
+            effects.extend(self.connect_to_peer_if_required(address));
+        }
+        effects
+    }
+
+    /// Lowers the reputation of a peer that failed to serve a request, and disconnects from it if
+    /// it is banned as a result.
+    fn handle_peer_failure(
+        &mut self,
+        effect_builder: EffectBuilder<REv>,
+        peer_id: NodeId,
+        failure: PeerFailure,
+    ) -> Effects<Event<P>> {
+        let peer_address = match self.outgoing.get(&peer_id) {
+            Some(outgoing) => outgoing.peer_address,
+            None => {
+                debug!(our_id=%self.our_id, %peer_id, %failure, "ignoring failure of disconnected peer");
+                return Effects::new();
+            }
+        };
+        let banned = self.peer_store.record_transgression(
+            peer_address,
+            Transgression::from(failure),
+            Timestamp::now(),
+        );
+        if banned && !self.pinned_peers.contains_key(&peer_address) {
+            info!(our_id=%self.our_id, %peer_id, %failure, "disconnecting from peer with too low reputation");
+            return self.remove(effect_builder, &peer_id, false);
+        }
+        Effects::new()
+    }
+
     /// Returns whether or not this node has been disconnected from all known nodes.
     fn is_not_connected_to_any_known_address(&self) -> bool {
         for &known_address in &self.known_addresses {
@@ -915,7 +1156,13 @@ where
     P: Send + 'static,
 {
     fn finalize(mut self) -> BoxFuture<'static, ()> {
+        let save_peer_store = self.peer_store.save();
+
         async move {
+            if let Some(save_peer_store) = save_peer_store {
+                save_peer_store.await;
+            }
+
             // Close the shutdown socket, causing the server to exit.
             drop(self.shutdown_sender.take());
 
@@ -940,7 +1187,7 @@ where
 impl<REv, P> Component<REv> for SmallNetwork<REv, P>
 where
     REv: ReactorEvent + From<Event<P>> + From<NetworkAnnouncement<NodeId, P>>,
-    P: Serialize + DeserializeOwned + Clone + Debug + Display + Send + 'static,
+    P: Serialize + DeserializeOwned + Clone + Debug + Display + Classify + Send + 'static,
 {
     type Event = Event<P>;
     type ConstructionError = Infallible;
@@ -955,7 +1202,7 @@ where
             Event::IsolationReconnection => {
                 if self.is_not_connected_to_any_known_address() {
                     info!("still isolated after grace time, attempting to reconnect to all known_nodes");
-                    self.connect_to_known_addresses()
+                    self.connect_to_known_addresses(effect_builder)
                 } else {
                     info!("would attempt to reconnect, but no longer isolated. not reconnecting");
                     Effects::new()
@@ -1042,19 +1289,34 @@ where
                 NetworkInfoRequest::GetPeers { responder } => {
                     responder.respond(self.peers()).ignore()
                 }
+                NetworkInfoRequest::GetPeerReputations { responder } => {
+                    responder.respond(self.peer_reputations()).ignore()
+                }
             },
             Event::GossipOurAddress => {
                 let mut effects = self.gossip_our_address(effect_builder);
+                if let Some(save_peer_store) = self.peer_store.save() {
+                    effects.extend(save_peer_store.ignore());
+                }
                 effects.extend(self.enforce_symmetric_connections(effect_builder));
+                effects.extend(self.connect_to_pinned_peers());
                 effects
             }
             Event::PeerAddressReceived(gossiped_address) => {
+                // Peers that are not pinned could not connect back to us, so the connection would
+                // be dropped as asymmetric.
+                if self.cfg.accept_only_pinned_peers {
+                    return Effects::new();
+                }
                 self.connect_to_peer_if_required(gossiped_address.into())
             }
             Event::BlocklistAnnouncement(BlocklistAnnouncement::OffenseCommitted(ref peer_id)) => {
-                warn!(%peer_id, "adding peer to blocklist after transgression");
+                warn!(%peer_id, "banning peer after transgression");
                 self.remove(effect_builder, peer_id, true)
             }
+            Event::BlocklistAnnouncement(BlocklistAnnouncement::PeerFailed { peer, failure }) => {
+                self.handle_peer_failure(effect_builder, *peer, failure)
+            }
         }
     }
 }
@@ -1238,6 +1500,7 @@ async fn message_reader<REv, P>(
     mut shutdown_receiver: watch::Receiver<()>,
     our_id: NodeId,
     peer_id: NodeId,
+    maximum_net_message_size: u32,
 ) -> io::Result<()>
 where
     P: DeserializeOwned + Send + Display,
@@ -1245,6 +1508,13 @@ where
 {
     let read_messages = async move {
         while let Some(msg_result) = stream.next().await {
+            let msg_result = msg_result.and_then(|msg| match msg {
+                Message::CompressedPayload(compressed) => {
+                    compression::decompress(&compressed, maximum_net_message_size)
+                        .map(Message::Payload)
+                }
+                msg => Ok(msg),
+            });
             match msg_result {
                 Ok(msg) => {
                     debug!(%our_id, %msg, %peer_id, "message received");
@@ -1286,27 +1556,33 @@ where
 
 /// Network message sender.
 ///
-/// Reads from a channel and sends all messages, until the stream is closed or an error occurs.
+/// Reads from the peer's outgoing queue and sends all messages, until the queue is closed or an
+/// error occurs.
 ///
 /// Initially sends a handshake including the `chainspec_hash` as a final handshake step.  If the
 /// recipient's `chainspec_hash` doesn't match, the connection will be closed.
+///
+/// Payloads are compressed once the recipient has advertised support for it.
 async fn message_sender<P>(
-    mut queue: UnboundedReceiver<Message<P>>,
+    mut queue: OutgoingReceiver<P>,
     mut sink: SplitSink<FramedTransport<P>, Message<P>>,
-    counter: IntGauge,
     handshake: Message<P>,
+    compression: OutgoingCompression,
 ) -> Result<()>
 where
     P: Serialize + Send,
 {
     sink.send(handshake).await.map_err(Error::MessageNotSent)?;
     while let Some(payload) = queue.recv().await {
-        counter.dec();
+        let payload = compression.apply(payload);
         // We simply error-out if the sink fails, it means that our connection broke.
         sink.send(payload).await.map_err(Error::MessageNotSent)?;
     }
 
-    Ok(())
+    match queue.overflowed() {
+        Some(class) => Err(Error::OutgoingQueueOverflow(class)),
+        None => Ok(()),
+    }
 }
 
 /// Transport type alias for base encrypted connections.
@@ -8,7 +8,7 @@ use std::net::SocketAddr;
 use casper_types::ProtocolVersion;
 use datasize::DataSize;
 
-use super::Message;
+use super::{message::WIRE_VERSION, Message};
 use crate::types::Chainspec;
 
 /// Data retained from the chainspec by the small networking component.
@@ -42,6 +42,7 @@ impl ChainInfo {
             network_name: self.network_name.clone(),
             public_address,
             protocol_version: self.protocol_version,
+            wire_version: WIRE_VERSION,
         }
     }
 }
@@ -0,0 +1,175 @@
+//! Optional zstd compression of payloads, negotiated via `Message::Capabilities`.
+//!
+//! A payload is only compressed if the receiving peer advertised support for it, the serialized
+//! payload exceeds the configured threshold and compressing it actually saves space. Otherwise it
+//! is sent as a plain `Message::Payload`, which every peer understands.
+
+use std::{
+    io::{self, Read},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use prometheus::IntCounter;
+use serde::{de::DeserializeOwned, Serialize};
+use tracing::warn;
+
+use super::Message;
+
+/// The zstd compression level used for outgoing payloads.
+const COMPRESSION_LEVEL: i32 = 3;
+
+/// Compression settings of a single outgoing connection.
+pub(super) struct OutgoingCompression {
+    /// Set once the peer has advertised support for zstd compression in its capabilities.
+    peer_supports_zstd: Arc<AtomicBool>,
+    /// Serialized payloads up to this size in bytes are never compressed.
+    threshold: u32,
+    /// Number of payloads sent compressed.
+    compressed_messages: IntCounter,
+    /// Total number of bytes saved by compression.
+    bytes_saved: IntCounter,
+}
+
+impl OutgoingCompression {
+    /// Creates the compression settings for a new outgoing connection.
+    pub(super) fn new(
+        peer_supports_zstd: Arc<AtomicBool>,
+        threshold: u32,
+        compressed_messages: IntCounter,
+        bytes_saved: IntCounter,
+    ) -> Self {
+        OutgoingCompression {
+            peer_supports_zstd,
+            threshold,
+            compressed_messages,
+            bytes_saved,
+        }
+    }
+
+    /// Replaces a large payload with its compressed form, if the peer supports it.
+    pub(super) fn apply<P: Serialize>(&self, msg: Message<P>) -> Message<P> {
+        if !self.peer_supports_zstd.load(Ordering::SeqCst) {
+            return msg;
+        }
+        let result = match &msg {
+            Message::Payload(payload) => compress(payload, self.threshold),
+            Message::Handshake { .. }
+            | Message::CompressedPayload(_)
+            | Message::Capabilities(_) => Ok(None),
+        };
+        match result {
+            Ok(Some((compressed, saved))) => {
+                self.compressed_messages.inc();
+                self.bytes_saved.inc_by(saved as u64);
+                Message::CompressedPayload(compressed)
+            }
+            Ok(None) => msg,
+            Err(err) => {
+                warn!(%err, "failed to compress payload, sending it uncompressed");
+                msg
+            }
+        }
+    }
+}
+
+/// Serializes and compresses a payload.
+///
+/// Returns the compressed bytes and the number of bytes saved, or `None` if the serialized payload
+/// does not exceed `threshold` or does not get any smaller.
+fn compress<P: Serialize>(payload: &P, threshold: u32) -> io::Result<Option<(Vec<u8>, usize)>> {
+    let serialized = bincode::serialize(payload).map_err(invalid_data)?;
+    if serialized.len() <= threshold as usize {
+        return Ok(None);
+    }
+    let compressed = zstd::stream::encode_all(serialized.as_slice(), COMPRESSION_LEVEL)?;
+    if compressed.len() >= serialized.len() {
+        return Ok(None);
+    }
+    let saved = serialized.len() - compressed.len();
+    Ok(Some((compressed, saved)))
+}
+
+/// Decompresses and deserializes a payload.
+///
+/// Fails if the decompressed payload is larger than `maximum_size` bytes, so that a peer cannot
+/// circumvent the maximum message size by sending a small but highly compressed message.
+pub(super) fn decompress<P: DeserializeOwned>(
+    compressed: &[u8],
+    maximum_size: u32,
+) -> io::Result<P> {
+    let mut serialized = Vec::new();
+    zstd::stream::read::Decoder::new(compressed)?
+        .take(u64::from(maximum_size) + 1)
+        .read_to_end(&mut serialized)?;
+    if serialized.len() > maximum_size as usize {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "decompressed payload exceeds maximum message size",
+        ));
+    }
+    bincode::deserialize(&serialized).map_err(invalid_data)
+}
+
+fn invalid_data(err: bincode::Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outgoing_compression(peer_supports_zstd: bool, threshold: u32) -> OutgoingCompression {
+        OutgoingCompression::new(
+            Arc::new(AtomicBool::new(peer_supports_zstd)),
+            threshold,
+            IntCounter::new("compressed", "compressed").unwrap(),
+            IntCounter::new("saved", "saved").unwrap(),
+        )
+    }
+
+    #[test]
+    fn large_payloads_are_compressed_and_restored() {
+        let payload = vec![7u8; 10_000];
+        let compression = outgoing_compression(true, 1_000);
+
+        let compressed = match compression.apply(Message::Payload(payload.clone())) {
+            Message::CompressedPayload(compressed) => compressed,
+            other => panic!("expected compressed payload, got {:?}", other),
+        };
+        assert!(compressed.len() < payload.len());
+        assert_eq!(compression.compressed_messages.get(), 1);
+        assert!(compression.bytes_saved.get() > 0);
+
+        let decompressed: Vec<u8> = decompress(&compressed, 1_000_000).unwrap();
+        assert_eq!(decompressed, payload);
+    }
+
+    #[test]
+    fn payloads_are_sent_plain_without_peer_support_or_below_threshold() {
+        let payload = vec![7u8; 10_000];
+
+        let no_support = outgoing_compression(false, 1_000);
+        assert!(matches!(
+            no_support.apply(Message::Payload(payload.clone())),
+            Message::Payload(_)
+        ));
+
+        let high_threshold = outgoing_compression(true, 100_000);
+        assert!(matches!(
+            high_threshold.apply(Message::Payload(payload)),
+            Message::Payload(_)
+        ));
+        assert_eq!(high_threshold.compressed_messages.get(), 0);
+    }
+
+    #[test]
+    fn oversized_decompressed_payloads_are_rejected() {
+        let payload = vec![7u8; 10_000];
+        let (compressed, _) = compress(&payload, 0).unwrap().unwrap();
+
+        assert!(decompress::<Vec<u8>>(&compressed, 1_000).is_err());
+    }
+}
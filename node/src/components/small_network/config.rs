@@ -1,11 +1,12 @@
 #[cfg(test)]
 use std::net::{Ipv4Addr, SocketAddr};
-use std::time::Duration;
+use std::{path::PathBuf, time::Duration};
 
 use datasize::DataSize;
 use serde::{Deserialize, Serialize};
 
-use crate::types::TimeDiff;
+use super::outgoing_queue::Config as OutgoingQueueConfig;
+use crate::{tls::KeyFingerprint, types::TimeDiff};
 
 /// Default binding address.
 ///
@@ -20,6 +21,12 @@ const DEFAULT_PUBLIC_ADDRESS: &str = "127.0.0.1:0";
 /// Default interval for gossiping network addresses.
 const DEFAULT_GOSSIP_INTERVAL: Duration = Duration::from_secs(30);
 
+/// Default size in bytes above which messages are compressed.
+const DEFAULT_COMPRESSION_THRESHOLD: u32 = 4096;
+
+/// Default half-life of peers' reputation scores.
+const DEFAULT_REPUTATION_HALF_LIFE: TimeDiff = TimeDiff::from_seconds(10 * 60);
+
 // Default values for networking configuration:
 impl Default for Config {
     fn default() -> Self {
@@ -32,6 +39,14 @@ impl Default for Config {
             isolation_reconnect_delay: TimeDiff::from_seconds(2),
             initial_gossip_delay: TimeDiff::from_seconds(5),
             max_addr_pending_time: TimeDiff::from_seconds(60),
+            enable_compression: true,
+            compression_threshold: DEFAULT_COMPRESSION_THRESHOLD,
+            outgoing_queue: OutgoingQueueConfig::default(),
+            peer_store_path: None,
+            reputation_half_life: default_reputation_half_life(),
+            pinned_peers: Vec::new(),
+            advertise_address: true,
+            accept_only_pinned_peers: false,
         }
     }
 }
@@ -60,6 +75,87 @@ pub struct Config {
     pub initial_gossip_delay: TimeDiff,
     /// Maximum allowed time for an address to be kept in the pending set.
     pub max_addr_pending_time: TimeDiff,
+    /// Whether to advertise and use zstd compression with peers that support it.
+    #[serde(default = "default_enable_compression")]
+    pub enable_compression: bool,
+    /// Size in bytes of a serialized message above which it is compressed.
+    #[serde(default = "default_compression_threshold")]
+    pub compression_threshold: u32,
+    /// Capacities and weights of the per-peer outgoing message queues.
+    #[serde(default)]
+    pub outgoing_queue: OutgoingQueueConfig,
+    /// File in which the known peers and their reputation are persisted across restarts.
+    ///
+    /// If it is a relative path, it is relative to the directory of the config file. If not set,
+    /// the peers are not persisted.
+    #[serde(default)]
+    pub peer_store_path: Option<PathBuf>,
+    /// Time it takes for a peer's reputation score to decay to half its value.
+    #[serde(default = "default_reputation_half_life")]
+    pub reputation_half_life: TimeDiff,
+    /// Peers this node always keeps connected to, e.g. the sentries of a private validator.
+    #[serde(default)]
+    pub pinned_peers: Vec<PinnedPeer>,
+    /// Whether to gossip our public address to the network.
+    #[serde(default = "default_advertise_address")]
+    pub advertise_address: bool,
+    /// Whether to refuse connections from peers that are not pinned.
+    #[serde(default)]
+    pub accept_only_pinned_peers: bool,
+}
+
+/// A peer the node always keeps connected to, identified by the fingerprint of its TLS key.
+#[derive(DataSize, Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PinnedPeer {
+    /// The peer's public listening address.
+    pub address: String,
+    /// The hex-encoded fingerprint of the peer's TLS public key, which is its node ID.
+    #[serde(with = "fingerprint_as_hex")]
+    pub fingerprint: KeyFingerprint,
+}
+
+/// (De)serializes a `KeyFingerprint` as a hex string.
+mod fingerprint_as_hex {
+    use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serializer};
+
+    use crate::tls::KeyFingerprint;
+
+    pub(super) fn serialize<S: Serializer>(
+        fingerprint: &KeyFingerprint,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(fingerprint.as_ref()))
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<KeyFingerprint, D::Error> {
+        let hex_value = String::deserialize(deserializer)?;
+        let bytes = hex::decode(hex_value).map_err(D::Error::custom)?;
+        if bytes.len() != KeyFingerprint::LENGTH {
+            return Err(D::Error::custom("wrong length"));
+        }
+        let mut array = [0_u8; KeyFingerprint::LENGTH];
+        array.copy_from_slice(bytes.as_slice());
+        Ok(KeyFingerprint::from(array))
+    }
+}
+
+fn default_enable_compression() -> bool {
+    true
+}
+
+fn default_compression_threshold() -> u32 {
+    DEFAULT_COMPRESSION_THRESHOLD
+}
+
+fn default_advertise_address() -> bool {
+    true
+}
+
+fn default_reputation_half_life() -> TimeDiff {
+    DEFAULT_REPUTATION_HALF_LIFE
 }
 
 #[cfg(test)]
@@ -104,3 +200,27 @@ impl Config {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pinned_peer_toml_roundtrip() {
+        let fingerprint = KeyFingerprint::from([7u8; KeyFingerprint::LENGTH]);
+        let encoded = format!(
+            "address = '127.0.0.1:34553'\nfingerprint = '{}'\n",
+            hex::encode(fingerprint.as_ref())
+        );
+        let pinned_peer: PinnedPeer = toml::from_str(&encoded).unwrap();
+        assert_eq!(pinned_peer.address, "127.0.0.1:34553");
+        assert_eq!(pinned_peer.fingerprint, fingerprint);
+
+        let reencoded = toml::to_string(&pinned_peer).unwrap();
+        let decoded: PinnedPeer = toml::from_str(&reencoded).unwrap();
+        assert_eq!(decoded.fingerprint, fingerprint);
+
+        let too_short = "address = '127.0.0.1:34553'\nfingerprint = '0707'\n";
+        assert!(toml::from_str::<PinnedPeer>(too_short).is_err());
+    }
+}
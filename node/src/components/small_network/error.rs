@@ -4,6 +4,7 @@ use openssl::{error::ErrorStack, ssl};
 use serde::Serialize;
 use thiserror::Error;
 
+use super::MessageClass;
 use crate::{tls::ValidationError, utils::ResolveAddressError};
 
 pub(super) type Result<T> = result::Result<T, Error>;
@@ -122,6 +123,9 @@ pub enum Error {
         #[from]
         anyhow::Error,
     ),
+    /// A non-gossip outgoing queue to the peer was full.
+    #[error("outgoing {0} queue overflowed")]
+    OutgoingQueueOverflow(#[serde(skip_serializing)] MessageClass),
     /// Server has stopped.
     #[error("failed to create outgoing connection as server has stopped")]
     ServerStopped,
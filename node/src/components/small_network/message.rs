@@ -4,7 +4,8 @@ use std::{
 };
 
 use casper_types::ProtocolVersion;
-use serde::{Deserialize, Serialize};
+use datasize::DataSize;
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// The default protocol version to use in absence of one in the protocol version field.
 #[inline]
@@ -12,6 +13,56 @@ fn default_protocol_version() -> ProtocolVersion {
     ProtocolVersion::V1_0_0
 }
 
+/// The version of the wire format this node speaks, announced in the handshake.
+///
+/// It is independent of the chainspec's protocol version, and must be bumped whenever a message is
+/// added that older nodes cannot decode.
+pub(super) const WIRE_VERSION: WireVersion = WireVersion(1);
+
+/// The lowest wire version of nodes that understand `Message::Capabilities` and
+/// `Message::CompressedPayload`.
+///
+/// Older nodes close the connection on any message they cannot decode, so these are only ever sent
+/// to peers whose handshake announced at least this version.
+pub(super) const CAPABILITIES_MIN_WIRE_VERSION: WireVersion = WireVersion(1);
+
+/// The version of the wire format a node speaks.
+///
+/// Nodes that predate it don't send it in their handshake, and are treated as version 0.
+#[derive(
+    Copy, Clone, DataSize, Debug, Default, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+pub struct WireVersion(pub u32);
+
+impl<'de> Deserialize<'de> for WireVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Bincode ignores `#[serde(default)]`: a handshake from an older node simply ends before
+        // this field, which surfaces as an error here.
+        Ok(u32::deserialize(deserializer).map_or_else(|_| WireVersion::default(), WireVersion))
+    }
+}
+
+impl Display for WireVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Optional features of the wire format a node supports.
+///
+/// Peers that do not send a `Message::Capabilities` are assumed to support none of them.
+#[derive(Copy, Clone, DataSize, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Capabilities {
+    /// Whether the node can read payloads compressed with zstd.
+    pub zstd_compression: bool,
+}
+
+impl Display for Capabilities {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "zstd: {}", self.zstd_compression)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum Message<P> {
     Handshake {
@@ -22,8 +73,20 @@ pub enum Message<P> {
         /// Protocol version the node is speaking.
         #[serde(default = "default_protocol_version")]
         protocol_version: ProtocolVersion,
+        /// Wire format version the node is speaking.
+        #[serde(default)]
+        wire_version: WireVersion,
     },
     Payload(P),
+    /// A serialized payload, compressed with zstd.
+    ///
+    /// Only ever sent to peers that advertised `zstd_compression` in their capabilities.
+    CompressedPayload(Vec<u8>),
+    /// The optional wire format features the node supports.
+    ///
+    /// Sent once after the handshake, and only to peers with at least
+    /// `CAPABILITIES_MIN_WIRE_VERSION`.
+    Capabilities(Capabilities),
 }
 
 impl<P: Display> Display for Message<P> {
@@ -33,12 +96,17 @@ impl<P: Display> Display for Message<P> {
                 network_name,
                 public_address,
                 protocol_version,
+                wire_version,
             } => write!(
                 f,
-                "handshake: {}, public addr: {}, protocol_version: {}",
-                network_name, public_address, protocol_version,
+                "handshake: {}, public addr: {}, protocol_version: {}, wire_version: {}",
+                network_name, public_address, protocol_version, wire_version,
             ),
             Message::Payload(payload) => write!(f, "payload: {}", payload),
+            Message::CompressedPayload(compressed) => {
+                write!(f, "compressed payload: {} bytes", compressed.len())
+            }
+            Message::Capabilities(capabilities) => write!(f, "capabilities: {}", capabilities),
         }
     }
 }
@@ -47,14 +115,16 @@ impl<P: Display> Display for Message<P> {
 // We use a variety of weird names in these tests.
 #[allow(non_camel_case_types)]
 mod tests {
-    use std::net::SocketAddr;
+    use std::{net::SocketAddr, pin::Pin};
 
+    use bytes::BytesMut;
     use casper_types::ProtocolVersion;
     use serde::{de::DeserializeOwned, Deserialize, Serialize};
+    use tokio_serde::{formats::SymmetricalBincode, Deserializer, Serializer};
 
     use crate::protocol;
 
-    use super::Message;
+    use super::{Capabilities, Message, WireVersion, WIRE_VERSION};
 
     /// Version 1.0.0 network level message.
     ///
@@ -158,6 +228,7 @@ mod tests {
             network_name: "example-handshake".to_string(),
             public_address: ([12, 34, 56, 78], 12346).into(),
             protocol_version: ProtocolVersion::from_parts(5, 6, 7),
+            wire_version: WIRE_VERSION,
         };
 
         let legacy_handshake: V1_0_0_Message = roundtrip_message(&modern_handshake);
@@ -190,13 +261,15 @@ mod tests {
                 network_name,
                 public_address,
                 protocol_version,
+                wire_version,
             } => {
                 assert_eq!(network_name, "example-handshake");
                 assert_eq!(public_address, ([12, 34, 56, 78], 12346).into());
                 assert_eq!(protocol_version, ProtocolVersion::V1_0_0);
+                assert_eq!(wire_version, WireVersion(0));
             }
-            Message::Payload(_) => {
-                panic!("did not expect modern handshake to deserialize to payload")
+            other => {
+                panic!("did not expect {:?} as the deserialized product", other);
             }
         }
     }
@@ -210,13 +283,130 @@ mod tests {
                 network_name,
                 public_address,
                 protocol_version,
+                wire_version,
             } => {
                 assert_eq!(network_name, "serialization-test");
                 assert_eq!(public_address, ([12, 34, 56, 78], 12346).into());
                 assert_eq!(protocol_version, ProtocolVersion::V1_0_0);
+                assert_eq!(wire_version, WireVersion(0));
+            }
+            other => {
+                panic!("did not expect {:?} as the deserialized product", other);
             }
-            Message::Payload(_) => {
-                panic!("did not expect modern handshake to deserialize to payload")
+        }
+    }
+
+    /// Version 1.1.0 network level message, as sent by nodes without support for capabilities.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub enum V1_1_0_Message {
+        Handshake {
+            /// Network we are connected to.
+            network_name: String,
+            /// The public address of the node connecting.
+            public_address: SocketAddr,
+            /// Protocol version the node is speaking.
+            protocol_version: ProtocolVersion,
+        },
+        Payload(protocol::Message),
+    }
+
+    /// Given a message `from` of type `F`, serializes it with the bincode codec used on the wire,
+    /// then deserializes it as `T`.
+    fn bincode_roundtrip_message<F, T>(from: &F) -> Result<T, std::io::Error>
+    where
+        F: Serialize + Unpin,
+        T: DeserializeOwned + Unpin,
+    {
+        let serialized = Pin::new(&mut SymmetricalBincode::<F>::default())
+            .serialize(from)
+            .expect("message serialization failed");
+        Pin::new(&mut SymmetricalBincode::<T>::default())
+            .deserialize(&BytesMut::from(&serialized[..]))
+    }
+
+    #[test]
+    fn v1_1_0_and_current_handshakes_are_compatible_on_the_wire() {
+        let legacy_handshake = V1_1_0_Message::Handshake {
+            network_name: "example-handshake".to_string(),
+            public_address: ([12, 34, 56, 78], 12346).into(),
+            protocol_version: ProtocolVersion::from_parts(1, 1, 0),
+        };
+
+        let modern_handshake: Message<protocol::Message> =
+            bincode_roundtrip_message(&legacy_handshake).expect("should decode legacy handshake");
+        match &modern_handshake {
+            Message::Handshake {
+                network_name,
+                public_address,
+                protocol_version,
+                wire_version,
+            } => {
+                assert_eq!(network_name, "example-handshake");
+                assert_eq!(*public_address, ([12, 34, 56, 78], 12346).into());
+                assert_eq!(*protocol_version, ProtocolVersion::from_parts(1, 1, 0));
+                assert_eq!(*wire_version, WireVersion(0));
+            }
+            other => {
+                panic!("did not expect {:?} as the deserialized product", other);
+            }
+        }
+
+        let roundtripped: V1_1_0_Message =
+            bincode_roundtrip_message(&modern_handshake).expect("should decode current handshake");
+        match roundtripped {
+            V1_1_0_Message::Handshake {
+                network_name,
+                public_address,
+                protocol_version,
+            } => {
+                assert_eq!(network_name, "example-handshake");
+                assert_eq!(public_address, ([12, 34, 56, 78], 12346).into());
+                assert_eq!(protocol_version, ProtocolVersion::from_parts(1, 1, 0));
+            }
+            other => {
+                panic!("did not expect {:?} as the deserialized product", other);
+            }
+        }
+    }
+
+    #[test]
+    fn current_handshake_announces_wire_version_on_the_wire() {
+        let handshake = Message::<protocol::Message>::Handshake {
+            network_name: "example-handshake".to_string(),
+            public_address: ([12, 34, 56, 78], 12346).into(),
+            protocol_version: ProtocolVersion::from_parts(1, 1, 0),
+            wire_version: WIRE_VERSION,
+        };
+
+        let roundtripped: Message<protocol::Message> =
+            bincode_roundtrip_message(&handshake).expect("should decode current handshake");
+        match roundtripped {
+            Message::Handshake { wire_version, .. } => assert_eq!(wire_version, WIRE_VERSION),
+            other => {
+                panic!("did not expect {:?} as the deserialized product", other);
+            }
+        }
+
+        // Older nodes ignore the wire version.
+        let legacy_handshake: V1_1_0_Message =
+            bincode_roundtrip_message(&handshake).expect("should decode current handshake");
+        assert!(matches!(legacy_handshake, V1_1_0_Message::Handshake { .. }));
+    }
+
+    #[test]
+    fn v1_1_0_cannot_decode_capabilities() {
+        // This is why capabilities are only sent to peers with a recent enough wire version.
+        let capabilities = Message::<protocol::Message>::Capabilities(Capabilities {
+            zstd_compression: true,
+        });
+        assert!(bincode_roundtrip_message::<_, V1_1_0_Message>(&capabilities).is_err());
+
+        let roundtripped: Message<protocol::Message> =
+            bincode_roundtrip_message(&capabilities).expect("should decode capabilities");
+        match roundtripped {
+            Message::Capabilities(Capabilities { zstd_compression }) => assert!(zstd_compression),
+            other => {
+                panic!("did not expect {:?} as the deserialized product", other);
             }
         }
     }
@@ -0,0 +1,528 @@
+//! Per-peer queues for outgoing messages, split into priority lanes.
+//!
+//! Every outgoing connection has one bounded queue ("lane") per `MessageClass`. The sender task
+//! takes messages from the lanes in a weighted round-robin manner, so that a burst of messages of
+//! one class can only delay messages of another class by a bounded amount. If the gossip lane is
+//! full, new gossip is dropped. Other messages must not be lost, so if any other lane is full the
+//! peer is too slow to keep up: the queue is closed, which disconnects it.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::{self, Debug, Display, Formatter},
+    sync::Arc,
+};
+
+use datasize::DataSize;
+use enum_iterator::IntoEnumIterator;
+use parking_lot::Mutex;
+use prometheus::{IntCounter, IntGauge, Registry};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Notify;
+
+use super::Message;
+use crate::unregister_metric;
+
+/// The class of an outgoing message, determining the lane it is queued in.
+///
+/// Lanes are polled in the order they are declared here.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, IntoEnumIterator)]
+pub enum MessageClass {
+    /// Consensus protocol messages.
+    Consensus,
+    /// Finality signatures.
+    FinalitySignatures,
+    /// Requests for items from peers, and responses to them.
+    Fetch,
+    /// Gossip about new deploys and addresses.
+    Gossip,
+}
+
+impl MessageClass {
+    fn metrics_name(self) -> &'static str {
+        match self {
+            MessageClass::Consensus => "consensus",
+            MessageClass::FinalitySignatures => "finality_signatures",
+            MessageClass::Fetch => "fetch",
+            MessageClass::Gossip => "gossip",
+        }
+    }
+}
+
+impl Display for MessageClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.metrics_name())
+    }
+}
+
+/// A payload that can be sorted into one of the outgoing lanes.
+pub trait Classify {
+    /// Returns the class of the message, determining its priority.
+    fn classify(&self) -> MessageClass;
+}
+
+impl<P: Classify> Classify for Message<P> {
+    fn classify(&self) -> MessageClass {
+        match self {
+            Message::Payload(payload) => payload.classify(),
+            // Handshakes are sent before any queued message, and payloads are only compressed
+            // after leaving the queue, so these are never queued in practice. Capabilities are
+            // small and needed early, so they share the consensus lane.
+            Message::Handshake { .. }
+            | Message::CompressedPayload(_)
+            | Message::Capabilities(_) => MessageClass::Consensus,
+        }
+    }
+}
+
+/// Capacity and scheduling weight of a single lane.
+#[derive(Copy, Clone, DataSize, Debug, Deserialize, Serialize)]
+// Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
+#[serde(deny_unknown_fields)]
+pub struct LaneConfig {
+    /// Maximum number of messages waiting in the lane for each peer.
+    pub capacity: u32,
+    /// Maximum number of messages sent from the lane before moving on to the next one.
+    ///
+    /// A weight of `0` is treated as `1`.
+    pub weight: u32,
+}
+
+/// Configuration of the per-peer outgoing lanes.
+#[derive(Copy, Clone, DataSize, Debug, Deserialize, Serialize)]
+// Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// The lane for consensus messages.
+    pub consensus: LaneConfig,
+    /// The lane for finality signatures.
+    pub finality_signatures: LaneConfig,
+    /// The lane for item requests and responses.
+    pub fetch: LaneConfig,
+    /// The lane for deploy and address gossip.
+    pub gossip: LaneConfig,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            consensus: LaneConfig {
+                capacity: 10_000,
+                weight: 8,
+            },
+            finality_signatures: LaneConfig {
+                capacity: 5_000,
+                weight: 4,
+            },
+            fetch: LaneConfig {
+                capacity: 5_000,
+                weight: 2,
+            },
+            gossip: LaneConfig {
+                capacity: 1_000,
+                weight: 1,
+            },
+        }
+    }
+}
+
+impl Config {
+    fn lane(&self, class: MessageClass) -> LaneConfig {
+        match class {
+            MessageClass::Consensus => self.consensus,
+            MessageClass::FinalitySignatures => self.finality_signatures,
+            MessageClass::Fetch => self.fetch,
+            MessageClass::Gossip => self.gossip,
+        }
+    }
+}
+
+/// Metrics of the outgoing lanes, summed over all peers.
+pub(super) struct OutgoingQueueMetrics {
+    /// Number of messages waiting in each lane.
+    depths: HashMap<MessageClass, IntGauge>,
+    /// Number of messages dropped because their lane was full, including those discarded when a
+    /// lane overflowed.
+    dropped: HashMap<MessageClass, IntCounter>,
+    /// Registry instance.
+    registry: Registry,
+}
+
+impl OutgoingQueueMetrics {
+    /// Creates and registers the lane metrics.
+    pub(super) fn new(registry: &Registry) -> Result<Self, prometheus::Error> {
+        let mut depths = HashMap::new();
+        let mut dropped = HashMap::new();
+        for class in MessageClass::into_enum_iter() {
+            let depth = IntGauge::new(
+                format!("net_outgoing_queue_{}_count", class.metrics_name()),
+                format!("number of {} messages waiting to be sent out", class),
+            )?;
+            let dropped_count = IntCounter::new(
+                format!("net_outgoing_queue_{}_dropped", class.metrics_name()),
+                format!("number of {} messages dropped due to a full queue", class),
+            )?;
+            registry.register(Box::new(depth.clone()))?;
+            registry.register(Box::new(dropped_count.clone()))?;
+            depths.insert(class, depth);
+            dropped.insert(class, dropped_count);
+        }
+        Ok(OutgoingQueueMetrics {
+            depths,
+            dropped,
+            registry: registry.clone(),
+        })
+    }
+}
+
+impl Drop for OutgoingQueueMetrics {
+    fn drop(&mut self) {
+        for depth in self.depths.values() {
+            unregister_metric!(self.registry, depth);
+        }
+        for dropped in self.dropped.values() {
+            unregister_metric!(self.registry, dropped);
+        }
+    }
+}
+
+/// The reason a message could not be queued.
+#[derive(Debug)]
+pub(super) enum PushError<P> {
+    /// The gossip lane is full, and the message was dropped.
+    GossipFull(Message<P>),
+    /// The message's lane is full and it is not gossip, so the queue has been closed.
+    Overflow(MessageClass, Message<P>),
+    /// The sender task has terminated, i.e. the connection is lost.
+    Disconnected(Message<P>),
+}
+
+/// A single lane.
+struct Lane<P> {
+    queue: VecDeque<Message<P>>,
+    capacity: usize,
+    weight: usize,
+    depth: IntGauge,
+    dropped: IntCounter,
+}
+
+/// All lanes of one peer, and the round-robin state.
+struct Lanes<P> {
+    /// The lanes, indexed in `MessageClass` order.
+    lanes: Vec<Lane<P>>,
+    /// The index of the lane currently being polled.
+    active: usize,
+    /// Number of messages the active lane may still send before moving on to the next one.
+    tickets: usize,
+    /// Whether the `OutgoingSender` has been dropped.
+    sender_dropped: bool,
+    /// Whether the `OutgoingReceiver` has been dropped.
+    receiver_dropped: bool,
+    /// The class of the non-gossip lane that overflowed, if any.
+    overflowed: Option<MessageClass>,
+    /// Number of messages waiting in any lane of any peer.
+    queued: IntGauge,
+}
+
+impl<P> Lanes<P> {
+    /// Returns whether the queue no longer accepts messages.
+    fn is_closed(&self) -> bool {
+        self.receiver_dropped || self.overflowed.is_some()
+    }
+
+    /// Discards all waiting messages, counting them as dropped if `count_dropped` is set.
+    fn clear(&mut self, count_dropped: bool) {
+        for lane in &mut self.lanes {
+            let len = lane.queue.len() as i64;
+            lane.depth.sub(len);
+            self.queued.sub(len);
+            if count_dropped {
+                lane.dropped.inc_by(len as u64);
+            }
+            lane.queue.clear();
+        }
+    }
+
+    /// Pops the next message according to the lanes' weights.
+    fn pop(&mut self) -> Option<Message<P>> {
+        if self.lanes.iter().all(|lane| lane.queue.is_empty()) {
+            return None;
+        }
+        loop {
+            let lane = &mut self.lanes[self.active];
+            if self.tickets > 0 {
+                if let Some(msg) = lane.queue.pop_front() {
+                    self.tickets -= 1;
+                    lane.depth.dec();
+                    self.queued.dec();
+                    return Some(msg);
+                }
+            }
+            self.active = (self.active + 1) % self.lanes.len();
+            self.tickets = self.lanes[self.active].weight;
+        }
+    }
+}
+
+/// State shared by the sending and receiving end of a peer's lanes.
+struct Shared<P> {
+    lanes: Mutex<Lanes<P>>,
+    /// Notifies the receiver about new messages or the sender being dropped.
+    notify: Notify,
+}
+
+/// Creates the lanes for a new outgoing connection.
+///
+/// `queued` is the total number of messages waiting to be sent to any peer.
+pub(super) fn channel<P>(
+    config: &Config,
+    metrics: &OutgoingQueueMetrics,
+    queued: IntGauge,
+) -> (OutgoingSender<P>, OutgoingReceiver<P>) {
+    let lanes: Vec<_> = MessageClass::into_enum_iter()
+        .map(|class| {
+            let lane_config = config.lane(class);
+            Lane {
+                queue: VecDeque::new(),
+                capacity: lane_config.capacity as usize,
+                weight: lane_config.weight.max(1) as usize,
+                depth: metrics.depths[&class].clone(),
+                dropped: metrics.dropped[&class].clone(),
+            }
+        })
+        .collect();
+    let tickets = lanes[0].weight;
+    let shared = Arc::new(Shared {
+        lanes: Mutex::new(Lanes {
+            lanes,
+            active: 0,
+            tickets,
+            sender_dropped: false,
+            receiver_dropped: false,
+            overflowed: None,
+            queued,
+        }),
+        notify: Notify::new(),
+    });
+    (
+        OutgoingSender(Arc::clone(&shared)),
+        OutgoingReceiver(shared),
+    )
+}
+
+/// The sending end of a peer's lanes.
+pub(super) struct OutgoingSender<P>(Arc<Shared<P>>);
+
+impl<P> Debug for OutgoingSender<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("OutgoingSender").finish()
+    }
+}
+
+impl<P: Classify> OutgoingSender<P> {
+    /// Queues a message in the lane of its class.
+    ///
+    /// If the lane is full, gossip is dropped, while any other message closes the queue.
+    pub(super) fn push(&self, msg: Message<P>) -> Result<(), PushError<P>> {
+        let class = msg.classify();
+        let result = {
+            let mut lanes = self.0.lanes.lock();
+            if lanes.is_closed() {
+                return Err(PushError::Disconnected(msg));
+            }
+            let lane = &mut lanes.lanes[class as usize];
+            if lane.queue.len() < lane.capacity {
+                lane.queue.push_back(msg);
+                lane.depth.inc();
+                lanes.queued.inc();
+                Ok(())
+            } else if class == MessageClass::Gossip {
+                lane.dropped.inc();
+                return Err(PushError::GossipFull(msg));
+            } else {
+                lane.dropped.inc();
+                lanes.overflowed = Some(class);
+                lanes.clear(true);
+                Err(PushError::Overflow(class, msg))
+            }
+        };
+        // Wake the receiver, either to send the new message or to close the connection.
+        self.0.notify.notify_one();
+        result
+    }
+}
+
+impl<P> Drop for OutgoingSender<P> {
+    fn drop(&mut self) {
+        self.0.lanes.lock().sender_dropped = true;
+        self.0.notify.notify_one();
+    }
+}
+
+/// The receiving end of a peer's lanes.
+pub(super) struct OutgoingReceiver<P>(Arc<Shared<P>>);
+
+impl<P> OutgoingReceiver<P> {
+    /// Waits for the next message.
+    ///
+    /// Returns `None` once the sending end has been dropped and all lanes are empty, or a lane
+    /// overflowed.
+    pub(super) async fn recv(&mut self) -> Option<Message<P>> {
+        loop {
+            {
+                let mut lanes = self.0.lanes.lock();
+                if lanes.overflowed.is_some() {
+                    return None;
+                }
+                if let Some(msg) = lanes.pop() {
+                    return Some(msg);
+                }
+                if lanes.sender_dropped {
+                    return None;
+                }
+            }
+            self.0.notify.notified().await;
+        }
+    }
+
+    /// Returns the class of the lane that overflowed and closed the queue, if any.
+    pub(super) fn overflowed(&self) -> Option<MessageClass> {
+        self.0.lanes.lock().overflowed
+    }
+}
+
+impl<P> Drop for OutgoingReceiver<P> {
+    fn drop(&mut self) {
+        let mut lanes = self.0.lanes.lock();
+        lanes.receiver_dropped = true;
+        lanes.clear(false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct TestPayload(MessageClass, u8);
+
+    impl Classify for TestPayload {
+        fn classify(&self) -> MessageClass {
+            self.0
+        }
+    }
+
+    fn lane(capacity: u32, weight: u32) -> LaneConfig {
+        LaneConfig { capacity, weight }
+    }
+
+    fn test_config() -> Config {
+        Config {
+            consensus: lane(10, 2),
+            finality_signatures: lane(10, 1),
+            fetch: lane(10, 1),
+            gossip: lane(2, 1),
+        }
+    }
+
+    fn push(sender: &OutgoingSender<TestPayload>, class: MessageClass, id: u8) {
+        sender
+            .push(Message::Payload(TestPayload(class, id)))
+            .expect("should queue message");
+    }
+
+    async fn recv_id(receiver: &mut OutgoingReceiver<TestPayload>) -> u8 {
+        match receiver.recv().await {
+            Some(Message::Payload(TestPayload(_, id))) => id,
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn should_respect_weights() {
+        let metrics = OutgoingQueueMetrics::new(&Registry::new()).unwrap();
+        let queued = IntGauge::new("queued", "queued").unwrap();
+        let (sender, mut receiver) = channel(&test_config(), &metrics, queued.clone());
+
+        push(&sender, MessageClass::Gossip, 1);
+        push(&sender, MessageClass::Gossip, 2);
+        for id in 3..7 {
+            push(&sender, MessageClass::Consensus, id);
+        }
+        push(&sender, MessageClass::FinalitySignatures, 7);
+        assert_eq!(metrics.depths[&MessageClass::Consensus].get(), 4);
+        assert_eq!(queued.get(), 7);
+
+        let mut ids = Vec::new();
+        for _ in 0..7 {
+            ids.push(recv_id(&mut receiver).await);
+        }
+        assert_eq!(ids, vec![3, 4, 7, 1, 5, 6, 2]);
+        assert_eq!(metrics.depths[&MessageClass::Consensus].get(), 0);
+        assert_eq!(queued.get(), 0);
+
+        // Once the sender is dropped and all messages are sent, the receiver terminates.
+        push(&sender, MessageClass::Fetch, 8);
+        drop(sender);
+        assert_eq!(recv_id(&mut receiver).await, 8);
+        assert!(receiver.recv().await.is_none());
+    }
+
+    #[test]
+    fn should_drop_gossip_if_lane_is_full() {
+        let metrics = OutgoingQueueMetrics::new(&Registry::new()).unwrap();
+        let queued = IntGauge::new("queued", "queued").unwrap();
+        let (sender, receiver) = channel::<TestPayload>(&test_config(), &metrics, queued.clone());
+
+        push(&sender, MessageClass::Gossip, 1);
+        push(&sender, MessageClass::Gossip, 2);
+        assert!(matches!(
+            sender.push(Message::Payload(TestPayload(MessageClass::Gossip, 3))),
+            Err(PushError::GossipFull(_))
+        ));
+        assert_eq!(metrics.dropped[&MessageClass::Gossip].get(), 1);
+
+        // Other lanes are unaffected.
+        push(&sender, MessageClass::Consensus, 4);
+        assert_eq!(queued.get(), 3);
+
+        // Messages still waiting when the connection is lost are no longer counted as queued.
+        drop(receiver);
+        assert!(matches!(
+            sender.push(Message::Payload(TestPayload(MessageClass::Consensus, 5))),
+            Err(PushError::Disconnected(_))
+        ));
+        assert_eq!(metrics.depths[&MessageClass::Gossip].get(), 0);
+        assert_eq!(queued.get(), 0);
+    }
+
+    #[tokio::test]
+    async fn should_close_queue_if_other_lane_is_full() {
+        let metrics = OutgoingQueueMetrics::new(&Registry::new()).unwrap();
+        let queued = IntGauge::new("queued", "queued").unwrap();
+        let config = Config {
+            fetch: lane(1, 1),
+            ..test_config()
+        };
+        let (sender, mut receiver) = channel::<TestPayload>(&config, &metrics, queued.clone());
+
+        push(&sender, MessageClass::Consensus, 1);
+        push(&sender, MessageClass::Fetch, 2);
+        assert!(matches!(
+            sender.push(Message::Payload(TestPayload(MessageClass::Fetch, 3))),
+            Err(PushError::Overflow(MessageClass::Fetch, _))
+        ));
+
+        // The waiting messages are discarded, and the receiver terminates so the peer is
+        // disconnected.
+        assert_eq!(metrics.dropped[&MessageClass::Fetch].get(), 2);
+        assert_eq!(metrics.dropped[&MessageClass::Consensus].get(), 1);
+        assert_eq!(metrics.depths[&MessageClass::Consensus].get(), 0);
+        assert_eq!(queued.get(), 0);
+        assert!(receiver.recv().await.is_none());
+        assert_eq!(receiver.overflowed(), Some(MessageClass::Fetch));
+        assert!(matches!(
+            sender.push(Message::Payload(TestPayload(MessageClass::Consensus, 4))),
+            Err(PushError::Disconnected(_))
+        ));
+    }
+}
@@ -0,0 +1,358 @@
+//! A persistent record of the peers we have connected to and how well they behaved.
+//!
+//! Peers are identified by their public listening address. Each of them has a reputation score,
+//! which is raised by successful connections and lowered by transgressions. The score decays
+//! towards zero with the configured half-life, so a peer that misbehaved is banned for a while and
+//! then gradually forgiven. When reconnecting, peers with the best scores are tried first.
+
+use std::{
+    cmp,
+    collections::HashMap,
+    fs, io,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use futures::Future;
+use serde::{Deserialize, Serialize};
+use tokio::task;
+use tracing::{debug, info, warn};
+
+use crate::{
+    effect::announcements::PeerFailure,
+    types::{NodeId, TimeDiff, Timestamp},
+};
+
+/// Peers whose score is at or below this value are not connected to.
+const BAN_THRESHOLD: f64 = -100.0;
+
+/// The highest score a peer can reach through successful connections.
+const MAX_SCORE: f64 = 100.0;
+
+/// The score a peer gains with every successful connection.
+const CONNECTION_REWARD: f64 = 1.0;
+
+/// The maximum number of peers kept in the store. Those seen least recently are evicted first.
+const MAX_STORED_PEERS: usize = 1000;
+
+/// A peer's misbehavior, lowering its reputation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Transgression {
+    /// The peer committed an offense, e.g. by sending an invalid message.
+    Offense,
+    /// The peer did not respond to a request in time.
+    SlowResponse,
+}
+
+impl Transgression {
+    /// Returns the amount by which the transgression lowers the peer's score.
+    fn penalty(self) -> f64 {
+        match self {
+            // Even a peer with the maximum score is banned for at least one half-life.
+            Transgression::Offense => MAX_SCORE - 2.0 * BAN_THRESHOLD,
+            Transgression::SlowResponse => 10.0,
+        }
+    }
+}
+
+impl From<PeerFailure> for Transgression {
+    fn from(failure: PeerFailure) -> Self {
+        match failure {
+            PeerFailure::TimedOut => Transgression::SlowResponse,
+        }
+    }
+}
+
+/// Everything we remember about a single peer.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PeerRecord {
+    /// The peer's public listening address.
+    address: SocketAddr,
+    /// The node ID the peer had when we last connected to it.
+    node_id: Option<NodeId>,
+    /// The last time we were connected to the peer.
+    last_seen: Timestamp,
+    /// The number of outgoing connections to the peer that were successfully established.
+    successful_connections: u64,
+    /// The reputation score as of `score_updated`.
+    score: f64,
+    /// The time the score was last changed.
+    score_updated: Timestamp,
+}
+
+impl PeerRecord {
+    fn new(address: SocketAddr, now: Timestamp) -> Self {
+        PeerRecord {
+            address,
+            node_id: None,
+            last_seen: now,
+            successful_connections: 0,
+            score: 0.0,
+            score_updated: now,
+        }
+    }
+
+    /// Returns the score at the given time, decayed towards zero since its last update.
+    fn score_at(&self, now: Timestamp, half_life: TimeDiff) -> f64 {
+        let elapsed = now.saturating_diff(self.score_updated).millis() as f64;
+        let half_life = half_life.millis().max(1) as f64;
+        self.score * 0.5f64.powf(elapsed / half_life)
+    }
+
+    /// Adds `delta` to the current score.
+    fn adjust_score(&mut self, delta: f64, now: Timestamp, half_life: TimeDiff) {
+        self.score = (self.score_at(now, half_life) + delta).min(MAX_SCORE);
+        self.score_updated = now;
+    }
+}
+
+/// The peer database, optionally backed by a JSON file.
+#[derive(Debug)]
+pub(super) struct PeerStore {
+    /// The file the store is persisted to, if any.
+    path: Option<PathBuf>,
+    /// The time it takes for a score to decay to half its value.
+    half_life: TimeDiff,
+    /// The records of all peers we remember, by public listening address.
+    peers: HashMap<SocketAddr, PeerRecord>,
+    /// Whether there are changes that have not been saved yet.
+    dirty: bool,
+    /// Whether the store is currently being written to its file.
+    saving: Arc<AtomicBool>,
+}
+
+impl PeerStore {
+    /// Loads the peer store from the given file.
+    ///
+    /// Starts out empty if there is no path, no such file or the file cannot be read.
+    pub(super) fn load(path: Option<PathBuf>, half_life: TimeDiff) -> Self {
+        let peers = match path.as_deref().map(read_records) {
+            None => HashMap::new(),
+            Some(Ok(records)) => {
+                info!(count = records.len(), "loaded peer store");
+                records
+                    .into_iter()
+                    .map(|record| (record.address, record))
+                    .collect()
+            }
+            Some(Err(err)) if err.kind() == io::ErrorKind::NotFound => {
+                debug!("no peer store found, starting with an empty one");
+                HashMap::new()
+            }
+            Some(Err(err)) => {
+                warn!(%err, "could not read peer store, starting with an empty one");
+                HashMap::new()
+            }
+        };
+        PeerStore {
+            path,
+            half_life,
+            peers,
+            dirty: false,
+            saving: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Returns a future writing the peer store to its file on the blocking thread pool, if it has
+    /// changed since it was last saved.
+    ///
+    /// Returns `None` if there is nothing to save, or if an earlier write is still in progress, in
+    /// which case the changes are written by a later call.
+    pub(super) fn save(&mut self) -> Option<impl Future<Output = ()> + Send + 'static> {
+        let path = match (&self.path, self.dirty) {
+            (Some(path), true) => path.clone(),
+            _ => return None,
+        };
+        if self.saving.swap(true, Ordering::SeqCst) {
+            debug!("peer store is still being saved, deferring");
+            return None;
+        }
+        let mut records: Vec<PeerRecord> = self.peers.values().cloned().collect();
+        records.sort_by(|a, b| b.last_seen.cmp(&a.last_seen));
+        records.truncate(MAX_STORED_PEERS);
+        // If the write fails, it is retried once the store changes again.
+        self.dirty = false;
+        let saving = Arc::clone(&self.saving);
+        Some(async move {
+            let count = records.len();
+            let display_path = path.display().to_string();
+            match task::spawn_blocking(move || write_records(&path, &records)).await {
+                Ok(Ok(())) => debug!(count, "saved peer store"),
+                Ok(Err(err)) => warn!(%err, path = %display_path, "could not save peer store"),
+                Err(err) => warn!(%err, "failed to join peer store task"),
+            }
+            saving.store(false, Ordering::SeqCst);
+        })
+    }
+
+    /// Records a successfully established connection to a peer.
+    pub(super) fn record_connection(
+        &mut self,
+        address: SocketAddr,
+        node_id: NodeId,
+        now: Timestamp,
+    ) {
+        let half_life = self.half_life;
+        let record = self
+            .peers
+            .entry(address)
+            .or_insert_with(|| PeerRecord::new(address, now));
+        record.node_id = Some(node_id);
+        record.last_seen = now;
+        record.successful_connections += 1;
+        record.adjust_score(CONNECTION_REWARD, now, half_life);
+        self.dirty = true;
+    }
+
+    /// Updates the time a peer was last seen, if it is in the store.
+    pub(super) fn record_seen(&mut self, address: SocketAddr, now: Timestamp) {
+        if let Some(record) = self.peers.get_mut(&address) {
+            record.last_seen = now;
+            self.dirty = true;
+        }
+    }
+
+    /// Lowers a peer's reputation and returns whether it is banned now.
+    pub(super) fn record_transgression(
+        &mut self,
+        address: SocketAddr,
+        transgression: Transgression,
+        now: Timestamp,
+    ) -> bool {
+        let half_life = self.half_life;
+        let record = self
+            .peers
+            .entry(address)
+            .or_insert_with(|| PeerRecord::new(address, now));
+        record.adjust_score(-transgression.penalty(), now, half_life);
+        self.dirty = true;
+        record.score <= BAN_THRESHOLD
+    }
+
+    /// Returns the peer's current reputation score, if it is in the store.
+    pub(super) fn score(&self, address: &SocketAddr, now: Timestamp) -> Option<f64> {
+        self.peers
+            .get(address)
+            .map(|record| record.score_at(now, self.half_life))
+    }
+
+    /// Returns whether the peer's reputation is too low to connect to it.
+    pub(super) fn is_banned(&self, address: &SocketAddr, now: Timestamp) -> bool {
+        self.score(address, now)
+            .map_or(false, |score| score <= BAN_THRESHOLD)
+    }
+
+    /// Returns the addresses of up to `count` peers that are not banned, best scores first.
+    pub(super) fn best_peers(&self, count: usize, now: Timestamp) -> Vec<SocketAddr> {
+        let mut candidates: Vec<(f64, Timestamp, SocketAddr)> = self
+            .peers
+            .values()
+            .map(|record| {
+                let score = record.score_at(now, self.half_life);
+                (score, record.last_seen, record.address)
+            })
+            .filter(|(score, _, _)| *score > BAN_THRESHOLD)
+            .collect();
+        candidates.sort_by(|a, b| {
+            b.0.partial_cmp(&a.0)
+                .unwrap_or(cmp::Ordering::Equal)
+                .then_with(|| b.1.cmp(&a.1))
+        });
+        candidates
+            .into_iter()
+            .take(count)
+            .map(|(_, _, address)| address)
+            .collect()
+    }
+}
+
+fn read_records(path: &Path) -> io::Result<Vec<PeerRecord>> {
+    let contents = fs::read(path)?;
+    serde_json::from_slice(&contents).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// Writes the records to a temporary file first and then moves it into place, so that a crash
+/// cannot leave a truncated store behind.
+fn write_records(path: &Path, records: &[PeerRecord]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let contents = serde_json::to_vec_pretty(records)?;
+    let temp_path = path.with_extension("tmp");
+    fs::write(&temp_path, contents)?;
+    fs::rename(&temp_path, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestRng;
+
+    fn address(port: u16) -> SocketAddr {
+        SocketAddr::from(([127, 0, 0, 1], port))
+    }
+
+    #[test]
+    fn bans_decay_gradually() {
+        let half_life = TimeDiff::from_seconds(60);
+        let mut store = PeerStore::load(None, half_life);
+        let start = Timestamp::zero();
+        let peer = address(1);
+
+        assert!(!store.record_transgression(peer, Transgression::SlowResponse, start));
+        assert!(store.record_transgression(peer, Transgression::Offense, start));
+        assert!(store.is_banned(&peer, start));
+        assert!(store.best_peers(10, start).is_empty());
+
+        // After one half-life the score is -155, after two it is above the threshold again.
+        assert!(store.is_banned(&peer, start + half_life));
+        let later = start + half_life * 2;
+        assert!(!store.is_banned(&peer, later));
+        assert_eq!(store.best_peers(10, later), vec![peer]);
+    }
+
+    #[test]
+    fn best_peers_are_ordered_by_score() {
+        let mut store = PeerStore::load(None, TimeDiff::from_seconds(60));
+        let now = Timestamp::zero();
+        let node_id = NodeId::random_tls(&mut TestRng::new());
+
+        store.record_connection(address(1), node_id, now);
+        store.record_connection(address(2), node_id, now);
+        store.record_connection(address(2), node_id, now);
+        store.record_transgression(address(3), Transgression::SlowResponse, now);
+
+        assert_eq!(
+            store.best_peers(10, now),
+            vec![address(2), address(1), address(3)]
+        );
+        assert_eq!(store.best_peers(1, now), vec![address(2)]);
+    }
+
+    #[tokio::test]
+    async fn store_survives_restart() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("peers.json");
+        let half_life = TimeDiff::from_seconds(60);
+        let now = Timestamp::zero();
+        let node_id = NodeId::random_tls(&mut TestRng::new());
+
+        let mut store = PeerStore::load(Some(path.clone()), half_life);
+        store.record_connection(address(1), node_id, now);
+        store.record_transgression(address(2), Transgression::Offense, now);
+        store.save().expect("store should need saving").await;
+        assert!(
+            store.save().is_none(),
+            "nothing changed since the last save"
+        );
+
+        let reloaded = PeerStore::load(Some(path), half_life);
+        assert_eq!(reloaded.peers, store.peers);
+        assert!(reloaded.is_banned(&address(2), now));
+    }
+}
@@ -7,6 +7,11 @@ use std::{
     collections::{HashMap, HashSet},
     env,
     fmt::{self, Debug, Display, Formatter},
+    net::{Ipv4Addr, SocketAddr},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
     time::{Duration, Instant},
 };
 
@@ -18,7 +23,8 @@ use serde::{Deserialize, Serialize};
 use tracing::{debug, info};
 
 use super::{
-    chain_info::ChainInfo, Config, Event as SmallNetworkEvent, GossipedAddress, SmallNetwork,
+    chain_info::ChainInfo, config::PinnedPeer, peer_store::Transgression, Classify, Config,
+    Event as SmallNetworkEvent, GossipedAddress, MessageClass, SmallNetwork,
 };
 use crate::{
     components::{
@@ -28,9 +34,12 @@ use crate::{
         Component,
     },
     effect::{
-        announcements::{ControlAnnouncement, GossiperAnnouncement, NetworkAnnouncement},
+        announcements::{
+            BlocklistAnnouncement, ControlAnnouncement, GossiperAnnouncement, NetworkAnnouncement,
+            PeerFailure,
+        },
         requests::{NetworkRequest, StorageRequest},
-        EffectBuilder, Effects,
+        EffectBuilder, EffectExt, Effects,
     },
     protocol,
     reactor::{self, EventQueueHandle, Finalize, Reactor, Runner},
@@ -39,8 +48,8 @@ use crate::{
         network::{Network, NetworkedReactor},
         ConditionCheckReactor,
     },
-    types::NodeId,
-    utils::Source,
+    types::{NodeId, TimeDiff, Timestamp},
+    utils::{Source, WithDir},
     NodeRng,
 };
 
@@ -91,6 +100,12 @@ impl From<NetworkRequest<NodeId, protocol::Message>> for Event {
     }
 }
 
+impl From<BlocklistAnnouncement<NodeId>> for Event {
+    fn from(announcement: BlocklistAnnouncement<NodeId>) -> Self {
+        Event::SmallNet(SmallNetworkEvent::from(announcement))
+    }
+}
+
 impl From<StorageRequest> for Event {
     fn from(_request: StorageRequest) -> Self {
         unreachable!()
@@ -107,6 +122,8 @@ impl Display for Event {
 enum Message {
     #[from]
     AddressGossiper(gossiper::Message<GossipedAddress>),
+    /// Arbitrary data sent directly by a test.
+    Data(Vec<u8>),
 }
 
 impl Display for Message {
@@ -115,6 +132,15 @@ impl Display for Message {
     }
 }
 
+impl Classify for Message {
+    fn classify(&self) -> MessageClass {
+        match self {
+            Message::AddressGossiper(_) => MessageClass::Gossip,
+            Message::Data(_) => MessageClass::Fetch,
+        }
+    }
+}
+
 /// Test reactor.
 ///
 /// Runs a single small network.
@@ -138,7 +164,7 @@ impl Reactor for TestReactor {
         let small_network_identity = SmallNetworkIdentity::new()?;
         let (net, effects) = SmallNetwork::new(
             event_queue,
-            cfg,
+            WithDir::new(env::temp_dir(), cfg),
             registry,
             small_network_identity,
             ChainInfo::create_for_testing(),
@@ -189,6 +215,8 @@ impl Reactor for TestReactor {
                     Message::AddressGossiper(message) => {
                         Event::AddressGossiper(gossiper::Event::MessageReceived { sender, message })
                     }
+                    // Tests check for received data via the announcement itself.
+                    Message::Data(_) => return Effects::new(),
                 };
                 self.dispatch_event(effect_builder, rng, reactor_event)
             }
@@ -282,6 +310,54 @@ fn network_started(net: &Network<TestReactor>) -> bool {
         .all(|peers| !peers.is_empty())
 }
 
+/// Returns the local address the node with the given port listens on.
+fn local_address(port: u16) -> SocketAddr {
+    SocketAddr::from((Ipv4Addr::LOCALHOST, port))
+}
+
+/// Returns the configuration pinning the node with the given ID, listening on the given port.
+fn pinned_peer(node_id: NodeId, port: u16) -> PinnedPeer {
+    let fingerprint = match node_id {
+        NodeId::Tls(fingerprint) => fingerprint,
+        NodeId::P2p(_) => panic!("small network nodes have TLS node IDs"),
+    };
+    PinnedPeer {
+        address: local_address(port).to_string(),
+        fingerprint,
+    }
+}
+
+/// Cranks the given node through two rounds of gossiping its own address, returning the addresses
+/// it announced to be gossiped.
+async fn gossiped_own_addresses(
+    net: &mut Network<TestReactor>,
+    node_id: &NodeId,
+    rng: &mut NodeRng,
+) -> Vec<GossipedAddress> {
+    let gossiped = Arc::new(Mutex::new(Vec::new()));
+    let rounds = Arc::new(AtomicUsize::new(0));
+    let gossiped_clone = Arc::clone(&gossiped);
+    net.crank_until(
+        node_id,
+        rng,
+        move |event: &Event| match event {
+            Event::NetworkAnnouncement(NetworkAnnouncement::GossipOurAddress(address)) => {
+                gossiped_clone.lock().unwrap().push(*address);
+                false
+            }
+            // The announcement of the first round has been handled once the second round starts.
+            Event::SmallNet(SmallNetworkEvent::GossipOurAddress) => {
+                rounds.fetch_add(1, Ordering::SeqCst) == 1
+            }
+            _ => false,
+        },
+        Duration::from_secs(10),
+    )
+    .await;
+    let addresses = gossiped.lock().unwrap().clone();
+    addresses
+}
+
 /// Run a two-node network five times.
 ///
 /// Ensures that network cleanup and basic networking works.
@@ -450,3 +526,378 @@ async fn check_varying_size_network_connects() {
         net.finalize().await;
     }
 }
+
+/// Check that a node accepting only pinned peers refuses the connection of any other peer.
+#[tokio::test]
+async fn refuses_incoming_connections_from_peers_that_are_not_pinned() {
+    // If the env var "CASPER_ENABLE_LIBP2P_NET" is defined, exit without running the test.
+    if env::var(ENABLE_LIBP2P_NET_ENV_VAR).is_ok() {
+        return;
+    }
+
+    init_logging();
+
+    let mut rng = crate::new_rng();
+
+    let first_node_port = testing::unused_port_on_localhost();
+    let mut net = Network::new();
+
+    let (first_node_id, _) = net
+        .add_node_with_config(
+            Config {
+                accept_only_pinned_peers: true,
+                ..Config::default_local_net_first_node(first_node_port)
+            },
+            &mut rng,
+        )
+        .await
+        .unwrap();
+    let (second_node_id, _) = net
+        .add_node_with_config(Config::default_local_net(first_node_port), &mut rng)
+        .await
+        .unwrap();
+
+    // Wait until the first node has completed the TLS handshake with the second one.
+    net.crank_until(
+        &first_node_id,
+        &mut rng,
+        move |event: &Event| match event {
+            Event::SmallNet(SmallNetworkEvent::IncomingHandshakeCompleted { result, .. }) => {
+                matches!(**result, Ok((ref peer_id, _)) if *peer_id == second_node_id)
+            }
+            _ => false,
+        },
+        Duration::from_secs(10),
+    )
+    .await;
+
+    let first_net = &net.nodes()[&first_node_id].reactor().inner().net;
+    assert!(
+        !first_net.incoming.contains_key(&second_node_id),
+        "connection from a peer that is not pinned should have been refused"
+    );
+
+    net.finalize().await;
+}
+
+/// Check that a node gossips its public address, unless configured not to advertise it.
+#[tokio::test]
+async fn gossips_public_address_only_if_advertised() {
+    // If the env var "CASPER_ENABLE_LIBP2P_NET" is defined, exit without running the test.
+    if env::var(ENABLE_LIBP2P_NET_ENV_VAR).is_ok() {
+        return;
+    }
+
+    init_logging();
+
+    let mut rng = crate::new_rng();
+
+    for &advertise_address in &[true, false] {
+        let port = testing::unused_port_on_localhost();
+        let mut net = Network::new();
+
+        let (node_id, _) = net
+            .add_node_with_config(
+                Config {
+                    advertise_address,
+                    initial_gossip_delay: TimeDiff::from(Duration::from_millis(100)),
+                    ..Config::default_local_net_first_node(port)
+                },
+                &mut rng,
+            )
+            .await
+            .unwrap();
+
+        let gossiped = gossiped_own_addresses(&mut net, &node_id, &mut rng).await;
+        if advertise_address {
+            let public_address = net.nodes()[&node_id].reactor().inner().net.public_address;
+            assert_eq!(gossiped, vec![GossipedAddress::new(public_address)]);
+        } else {
+            assert!(gossiped.is_empty(), "unexpectedly gossiped {:?}", gossiped);
+        }
+
+        net.finalize().await;
+    }
+}
+
+/// Check that a node reconnects to a pinned peer, even if the peer's reputation is too low.
+#[tokio::test]
+async fn reconnects_to_banned_pinned_peer() {
+    // If the env var "CASPER_ENABLE_LIBP2P_NET" is defined, exit without running the test.
+    if env::var(ENABLE_LIBP2P_NET_ENV_VAR).is_ok() {
+        return;
+    }
+
+    init_logging();
+
+    let mut rng = crate::new_rng();
+
+    let first_node_port = testing::unused_port_on_localhost();
+    let pinned_node_port = testing::unused_port_on_localhost();
+    let mut net = Network::new();
+
+    net.add_node_with_config(
+        Config::default_local_net_first_node(first_node_port),
+        &mut rng,
+    )
+    .await
+    .unwrap();
+    let (pinned_node_id, _) = net
+        .add_node_with_config(
+            Config {
+                known_addresses: vec![local_address(first_node_port).to_string()],
+                ..Config::default_local_net_first_node(pinned_node_port)
+            },
+            &mut rng,
+        )
+        .await
+        .unwrap();
+    let (node_id, _) = net
+        .add_node_with_config(
+            Config {
+                pinned_peers: vec![pinned_peer(pinned_node_id, pinned_node_port)],
+                ..Config::default_local_net(first_node_port)
+            },
+            &mut rng,
+        )
+        .await
+        .unwrap();
+
+    let timeout = Duration::from_secs(20);
+    let blocklist = HashSet::new();
+    net.settle_on(
+        &mut rng,
+        |nodes| network_is_complete(&blocklist, nodes),
+        timeout,
+    )
+    .await;
+
+    // Ban the pinned peer and disconnect from it. The node stays connected to the first node, so
+    // it only reconnects because the peer is pinned.
+    let small_net = &mut net
+        .nodes_mut()
+        .get_mut(&node_id)
+        .unwrap()
+        .reactor_mut()
+        .inner_mut()
+        .net;
+    while !small_net.peer_store.record_transgression(
+        local_address(pinned_node_port),
+        Transgression::Offense,
+        Timestamp::now(),
+    ) {}
+    net.process_injected_effect_on(&node_id, |effect_builder| {
+        effect_builder
+            .announce_disconnect_from_peer(pinned_node_id)
+            .ignore()
+    })
+    .await;
+
+    let is_connected_to_pinned_node =
+        |nodes: &HashMap<NodeId, Runner<ConditionCheckReactor<TestReactor>>>| {
+            let small_net = &nodes[&node_id].reactor().inner().net;
+            small_net.outgoing.contains_key(&pinned_node_id)
+        };
+    net.settle_on(
+        &mut rng,
+        |nodes| !is_connected_to_pinned_node(nodes),
+        timeout,
+    )
+    .await;
+    net.settle_on(&mut rng, is_connected_to_pinned_node, timeout)
+        .await;
+
+    net.finalize().await;
+}
+
+/// Check that two nodes negotiate compression in their handshakes and exchange compressed
+/// payloads.
+#[tokio::test]
+async fn exchanges_compressed_payloads() {
+    // If the env var "CASPER_ENABLE_LIBP2P_NET" is defined, exit without running the test.
+    if env::var(ENABLE_LIBP2P_NET_ENV_VAR).is_ok() {
+        return;
+    }
+
+    init_logging();
+
+    let mut rng = crate::new_rng();
+
+    let first_node_port = testing::unused_port_on_localhost();
+    let mut net = Network::new();
+
+    let (first_node_id, _) = net
+        .add_node_with_config(
+            Config::default_local_net_first_node(first_node_port),
+            &mut rng,
+        )
+        .await
+        .unwrap();
+    let (second_node_id, _) = net
+        .add_node_with_config(Config::default_local_net(first_node_port), &mut rng)
+        .await
+        .unwrap();
+
+    // Both nodes speak the current wire version, so they tell each other they support zstd.
+    let supports_zstd = |nodes: &HashMap<NodeId, Runner<ConditionCheckReactor<TestReactor>>>,
+                         node_id: &NodeId,
+                         peer_id: &NodeId| {
+        nodes[node_id]
+            .reactor()
+            .inner()
+            .net
+            .outgoing
+            .get(peer_id)
+            .map_or(false, |outgoing| {
+                outgoing.peer_supports_zstd.load(Ordering::SeqCst)
+            })
+    };
+    let timeout = Duration::from_secs(20);
+    net.settle_on(
+        &mut rng,
+        |nodes| {
+            supports_zstd(nodes, &first_node_id, &second_node_id)
+                && supports_zstd(nodes, &second_node_id, &first_node_id)
+        },
+        timeout,
+    )
+    .await;
+
+    // Well above the compression threshold, and highly compressible.
+    let data = vec![7; 64 * 1024];
+    let first_net = &net.nodes()[&first_node_id].reactor().inner().net;
+    first_net.send_message(
+        second_node_id,
+        super::Message::Payload(Message::Data(data.clone())),
+    );
+
+    net.crank_until(
+        &second_node_id,
+        &mut rng,
+        move |event: &Event| match event {
+            Event::NetworkAnnouncement(NetworkAnnouncement::MessageReceived {
+                sender,
+                payload: Message::Data(received),
+            }) => *sender == first_node_id && *received == data,
+            _ => false,
+        },
+        timeout,
+    )
+    .await;
+
+    let first_net = &net.nodes()[&first_node_id].reactor().inner().net;
+    assert_eq!(first_net.net_metrics.compressed_messages.get(), 1);
+
+    net.finalize().await;
+}
+
+/// Check that a bootstrap node that repeatedly times out is banned like any other peer that is not
+/// pinned, and not reconnected to while the ban lasts.
+#[tokio::test]
+async fn bans_bootstrap_node_that_keeps_timing_out() {
+    // If the env var "CASPER_ENABLE_LIBP2P_NET" is defined, exit without running the test.
+    if env::var(ENABLE_LIBP2P_NET_ENV_VAR).is_ok() {
+        return;
+    }
+
+    init_logging();
+
+    let mut rng = crate::new_rng();
+
+    let first_node_port = testing::unused_port_on_localhost();
+    let mut net = Network::new();
+
+    let (first_node_id, _) = net
+        .add_node_with_config(
+            Config::default_local_net_first_node(first_node_port),
+            &mut rng,
+        )
+        .await
+        .unwrap();
+    let (node_id, _) = net
+        .add_node_with_config(
+            Config {
+                isolation_reconnect_delay: TimeDiff::from(Duration::from_millis(100)),
+                ..Config::default_local_net(first_node_port)
+            },
+            &mut rng,
+        )
+        .await
+        .unwrap();
+
+    let timeout = Duration::from_secs(20);
+    let blocklist = HashSet::new();
+    net.settle_on(
+        &mut rng,
+        |nodes| network_is_complete(&blocklist, nodes),
+        timeout,
+    )
+    .await;
+
+    // Report the bootstrap node's requests as timed out until it is banned.
+    let is_banned = |net: &Network<TestReactor>| {
+        net.nodes()[&node_id]
+            .reactor()
+            .inner()
+            .net
+            .peer_store
+            .is_banned(&local_address(first_node_port), Timestamp::now())
+    };
+    while !is_banned(&net) {
+        net.process_injected_effect_on(&node_id, |effect_builder| {
+            effect_builder
+                .announce_peer_failure(first_node_id, PeerFailure::TimedOut)
+                .ignore()
+        })
+        .await;
+        net.crank_until(
+            &node_id,
+            &mut rng,
+            |event: &Event| {
+                matches!(
+                    event,
+                    Event::SmallNet(SmallNetworkEvent::BlocklistAnnouncement(
+                        BlocklistAnnouncement::PeerFailed { .. }
+                    ))
+                )
+            },
+            timeout,
+        )
+        .await;
+    }
+
+    let is_connected_to_first_node =
+        |nodes: &HashMap<NodeId, Runner<ConditionCheckReactor<TestReactor>>>| {
+            let small_net = &nodes[&node_id].reactor().inner().net;
+            small_net.outgoing.contains_key(&first_node_id)
+                || small_net
+                    .pending
+                    .contains_key(&local_address(first_node_port))
+        };
+    net.settle_on(
+        &mut rng,
+        |nodes| !is_connected_to_first_node(nodes),
+        timeout,
+    )
+    .await;
+
+    // The node is isolated now, but doesn't reconnect to the banned bootstrap node.
+    net.crank_until(
+        &node_id,
+        &mut rng,
+        |event: &Event| {
+            matches!(
+                event,
+                Event::SmallNet(SmallNetworkEvent::IsolationReconnection)
+            )
+        },
+        timeout,
+    )
+    .await;
+    assert!(
+        !is_connected_to_first_node(net.nodes()),
+        "should not reconnect to banned bootstrap node"
+    );
+
+    net.finalize().await;
+}
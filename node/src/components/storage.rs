@@ -7,7 +7,8 @@
 //! * storing and loading deploys,
 //! * [temporary until refactored] holding `DeployMetadata` for each deploy,
 //! * holding a read-only copy of the chainspec,
-//! * keeping an index of blocks by height and
+//! * keeping an index of blocks by height,
+//! * optionally keeping an index of executed deploys by the account which sent them and
 //! * [unimplemented] managing disk usage by pruning blocks and deploys from storage.
 //!
 //! Any I/O performed by the component is done on the event handling thread, this is on purpose as
@@ -31,6 +32,10 @@
 //! The current implementation keeps only in-memory indices, which are not persisted, based upon the
 //! estimate that they are reasonably quick to rebuild on start-up and do not take up much memory.
 //!
+//! The one exception is the optional account deploy index, which would require a scan of every
+//! stored deploy to rebuild and is hence kept on disk. It maps the hash of an account to the
+//! deploys it sent, in order of the height of the block in which they were executed.
+//!
 //! ## Errors
 //!
 //! The storage component itself is panic free and in general reports three classes of errors:
@@ -41,12 +46,13 @@ mod lmdb_ext;
 mod tests;
 
 #[cfg(test)]
-use std::{collections::BTreeSet, convert::TryFrom};
+use std::collections::BTreeSet;
 use std::{
-    collections::{btree_map::Entry, BTreeMap, HashSet},
+    collections::{btree_map::Entry, BTreeMap, HashMap, HashSet},
+    convert::{TryFrom, TryInto},
     fmt::{self, Display, Formatter},
     fs, io, mem,
-    path::PathBuf,
+    path::{Path, PathBuf},
 };
 
 use datasize::DataSize;
@@ -62,7 +68,9 @@ use thiserror::Error;
 use tracing::{debug, error, info};
 
 use casper_execution_engine::shared::newtypes::Blake2bHash;
-use casper_types::{EraId, ExecutionResult, ProtocolVersion, Transfer, Transform};
+use casper_types::{
+    account::AccountHash, EraId, ExecutionResult, ProtocolVersion, Transfer, Transform,
+};
 
 use super::Component;
 #[cfg(test)]
@@ -76,7 +84,7 @@ use crate::{
     reactor::ReactorEvent,
     types::{
         Block, BlockBody, BlockHash, BlockHeader, BlockHeaderWithMetadata, BlockSignatures, Deploy,
-        DeployHash, DeployHeader, DeployMetadata, TimeDiff,
+        DeployHash, DeployHeader, DeployMetadata, EraFault, LegacyDeployMetadata, TimeDiff,
     },
     utils::WithDir,
     NodeRng,
@@ -102,7 +110,12 @@ const DEFAULT_MAX_DEPLOY_METADATA_STORE_SIZE: usize = 300 * GIB;
 /// Default max state store size.
 const DEFAULT_MAX_STATE_STORE_SIZE: usize = 10 * GIB;
 /// Maximum number of allowed dbs.
-const MAX_DB_COUNT: u32 = 7;
+const MAX_DB_COUNT: u32 = 10;
+/// Name of the database holding deploy metadata serialized by versions which didn't record contract
+/// events in execution results.
+const LEGACY_DEPLOY_METADATA_DB_NAME: &str = "deploy_metadata";
+/// Name of the database indexing executed deploys by the account which sent them.
+const ACCOUNT_DEPLOY_DB_NAME: &str = "account_deploys";
 
 /// OS-specific lmdb flags.
 #[cfg(not(target_os = "macos"))]
@@ -193,6 +206,17 @@ pub struct Storage {
     /// The state storage database.
     #[data_size(skip)]
     state_store_db: Database,
+    /// The account deploy index database, if enabled.
+    ///
+    /// Keyed by account hash, each entry holds one value per deploy sent by the account, being the
+    /// big-endian height of the block in which it was executed followed by the deploy hash.
+    #[data_size(skip)]
+    account_deploy_db: Option<Database>,
+    /// The era fault database.
+    ///
+    /// Keyed by the big-endian era ID, each entry holds the evidence of all faults in that era.
+    #[data_size(skip)]
+    era_fault_db: Database,
     /// A map of block height to block ID.
     block_height_index: BTreeMap<u64, BlockHash>,
     /// A map of era ID to switch block ID.
@@ -268,10 +292,21 @@ impl Storage {
         let block_header_db = env.create_db(Some("block_header"), DatabaseFlags::empty())?;
         let block_metadata_db = env.create_db(Some("block_metadata"), DatabaseFlags::empty())?;
         let deploy_db = env.create_db(Some("deploys"), DatabaseFlags::empty())?;
-        let deploy_metadata_db = env.create_db(Some("deploy_metadata"), DatabaseFlags::empty())?;
+        let deploy_metadata_db =
+            env.create_db(Some("deploy_metadata_v2"), DatabaseFlags::empty())?;
         let transfer_db = env.create_db(Some("transfer"), DatabaseFlags::empty())?;
         let state_store_db = env.create_db(Some("state_store"), DatabaseFlags::empty())?;
         let block_body_db = env.create_db(Some("block_body"), DatabaseFlags::empty())?;
+        let era_fault_db = env.create_db(Some("era_faults"), DatabaseFlags::empty())?;
+        let account_deploy_db = if config.enable_account_deploy_index {
+            Some(env.create_db(Some(ACCOUNT_DEPLOY_DB_NAME), DatabaseFlags::DUP_SORT)?)
+        } else {
+            // Drop any stale index so that it is rebuilt in full if re-enabled later.
+            clear_account_deploy_db(&env)?;
+            None
+        };
+
+        migrate_legacy_deploy_metadata(&env, &deploy_metadata_db)?;
 
         // We now need to restore the block-height index. Log messages allow timing here.
         info!("reindexing block store");
@@ -281,6 +316,7 @@ impl Storage {
         let mut cursor = block_txn.open_rw_cursor(block_header_db)?;
 
         let mut deleted_block_hashes = HashSet::new();
+        let mut deleted_block_heights = HashSet::new();
         // Note: `iter_start` has an undocumented panic if called on an empty database. We rely on
         //       the iterator being at the start when created.
         for (raw_key, raw_val) in cursor.iter() {
@@ -291,6 +327,7 @@ impl Storage {
                 // reverted.
                 if block.era_id() >= invalid_era && block.protocol_version() < protocol_version {
                     let _ = deleted_block_hashes.insert(block.hash());
+                    let _ = deleted_block_heights.insert(block.height());
                     cursor.del(WriteFlags::empty())?;
                     continue;
                 }
@@ -316,6 +353,19 @@ impl Storage {
         initialize_block_body_db(&env, &block_body_db, &deleted_block_hashes_raw)?;
         initialize_block_metadata_db(&env, &block_metadata_db, &deleted_block_hashes_raw)?;
         initialize_deploy_metadata_db(&env, &deploy_metadata_db, &deleted_block_hashes)?;
+        if let Some(account_deploy_db) = account_deploy_db.as_ref() {
+            initialize_account_deploy_db(&env, account_deploy_db, &deleted_block_heights)?;
+            backfill_account_deploy_db(
+                &env,
+                account_deploy_db,
+                &deploy_db,
+                &deploy_metadata_db,
+                &block_height_index,
+            )?;
+        }
+        if let Some(invalid_era) = hard_reset_to_start_of_era {
+            initialize_era_fault_db(&env, &era_fault_db, invalid_era)?;
+        }
 
         Ok(Storage {
             root,
@@ -327,6 +377,8 @@ impl Storage {
             deploy_metadata_db,
             transfer_db,
             state_store_db,
+            account_deploy_db,
+            era_fault_db,
             block_height_index,
             switch_block_era_id_index,
         })
@@ -517,6 +569,7 @@ impl Storage {
                 .ignore(),
             StorageRequest::PutExecutionResults {
                 block_hash,
+                block_height,
                 execution_results,
                 responder,
             } => {
@@ -563,6 +616,25 @@ impl Storage {
                         "failed to write deploy metadata for block_hash {} deploy_hash {}",
                         block_hash, deploy_hash
                     );
+
+                    if let Some(account_deploy_db) = self.account_deploy_db {
+                        match self.get_deploy_header(&mut txn, &deploy_hash)? {
+                            Some(deploy_header) => {
+                                let account_hash = deploy_header.account().to_account_hash();
+                                let entry = account_deploy_entry(block_height, &deploy_hash);
+                                txn.put(
+                                    account_deploy_db,
+                                    &account_hash,
+                                    &entry,
+                                    WriteFlags::empty(),
+                                )?;
+                            }
+                            None => debug!(
+                                %deploy_hash,
+                                "not indexing execution result of unknown deploy"
+                            ),
+                        }
+                    }
                 }
 
                 let was_written =
@@ -696,6 +768,47 @@ impl Storage {
             StorageRequest::GetFinalizedDeploys { ttl, responder } => {
                 responder.respond(self.get_finalized_deploys(ttl)?).ignore()
             }
+            StorageRequest::GetAccountDeploys {
+                account_hash,
+                offset,
+                limit,
+                responder,
+            } => {
+                let result = self.get_account_deploys(
+                    &mut self.env.begin_ro_txn()?,
+                    &account_hash,
+                    offset,
+                    limit,
+                )?;
+                responder.respond(result).ignore()
+            }
+            StorageRequest::PutEraFault {
+                era_fault,
+                responder,
+            } => {
+                let key = era_fault.era_id.value().to_be_bytes();
+                let mut txn = self.env.begin_rw_txn()?;
+                let mut era_faults: Vec<EraFault> =
+                    txn.get_value(self.era_fault_db, &key)?.unwrap_or_default();
+                let outcome = if era_faults
+                    .iter()
+                    .any(|existing| existing.public_key == era_fault.public_key)
+                {
+                    false
+                } else {
+                    era_faults.push(*era_fault);
+                    txn.put_value(self.era_fault_db, &key, &era_faults, true)?
+                };
+                txn.commit()?;
+                responder.respond(outcome).ignore()
+            }
+            StorageRequest::GetEraFaults { era_id, responder } => {
+                let era_faults: Option<Vec<EraFault>> = self
+                    .env
+                    .begin_ro_txn()?
+                    .get_value(self.era_fault_db, &era_id.value().to_be_bytes())?;
+                responder.respond(era_faults.unwrap_or_default()).ignore()
+            }
         })
     }
 
@@ -991,6 +1104,42 @@ impl Storage {
         Ok(tx.get_value(self.block_metadata_db, block_hash)?)
     }
 
+    /// Retrieves up to `limit` of the deploys sent by the given account, skipping the first
+    /// `offset` of them, along with the heights of the blocks in which they were executed.
+    ///
+    /// Returns `None` if the account deploy index is disabled.
+    fn get_account_deploys<Tx: Transaction>(
+        &self,
+        tx: &mut Tx,
+        account_hash: &AccountHash,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Option<Vec<(DeployHash, u64)>>, Error> {
+        let account_deploy_db = match self.account_deploy_db {
+            Some(account_deploy_db) => account_deploy_db,
+            None => return Ok(None),
+        };
+        // Note: `iter_dup_of` panics if the key is not present, so we check for it first.
+        match tx.get(account_deploy_db, account_hash) {
+            Ok(_) => (),
+            Err(lmdb::Error::NotFound) => return Ok(Some(Vec::new())),
+            Err(err) => return Err(err.into()),
+        }
+        let mut cursor = tx.open_ro_cursor(account_deploy_db)?;
+        let account_deploys = cursor
+            .iter_dup_of(account_hash)
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|(_, raw_entry)| parse_account_deploy_entry(raw_entry))
+            .collect::<Result<_, _>>()?;
+        Ok(Some(account_deploys))
+    }
+
+    /// Returns the path of the directory holding the storage database.
+    pub(crate) fn root_path(&self) -> &Path {
+        &self.root
+    }
+
     /// Get the lmdb environment
     #[cfg(test)]
     pub(crate) fn env(&self) -> &Environment {
@@ -1038,6 +1187,38 @@ fn insert_to_block_header_indices(
     Ok(())
 }
 
+/// Length of a value in the account deploy index database.
+const ACCOUNT_DEPLOY_ENTRY_LENGTH: usize = 8 + Digest::LENGTH;
+
+/// Encodes an entry of the account deploy index.
+///
+/// The block height is encoded in big-endian so that LMDB orders the entries of an account by it.
+fn account_deploy_entry(block_height: u64, deploy_hash: &DeployHash) -> Vec<u8> {
+    let mut entry = Vec::with_capacity(ACCOUNT_DEPLOY_ENTRY_LENGTH);
+    entry.extend_from_slice(&block_height.to_be_bytes());
+    entry.extend_from_slice(deploy_hash.as_ref());
+    entry
+}
+
+/// Decodes an entry of the account deploy index.
+fn parse_account_deploy_entry(raw_entry: &[u8]) -> Result<(DeployHash, u64), LmdbExtError> {
+    if raw_entry.len() != ACCOUNT_DEPLOY_ENTRY_LENGTH {
+        return Err(LmdbExtError::DataCorrupted(Box::new(
+            AccountDeployEntryLengthError(raw_entry.len()),
+        )));
+    }
+    let (raw_height, raw_deploy_hash) = raw_entry.split_at(8);
+    let block_height = u64::from_be_bytes(raw_height.try_into().expect("should have 8 bytes"));
+    let deploy_hash =
+        DeployHash::new(Digest::try_from(raw_deploy_hash).expect("should have digest length"));
+    Ok((deploy_hash, block_height))
+}
+
+/// An entry of the account deploy index had an unexpected length.
+#[derive(Debug, Error)]
+#[error("account deploy index entry has invalid length {0}")]
+struct AccountDeployEntryLengthError(usize);
+
 /// On-disk storage configuration.
 #[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -1062,6 +1243,11 @@ pub struct Config {
     ///
     /// The size should be a multiple of the OS page size.
     max_state_store_size: usize,
+    /// Whether to keep an index of executed deploys by the account which sent them.
+    ///
+    /// If enabled on an existing database, deploys executed beforehand are indexed on startup.
+    #[serde(default)]
+    enable_account_deploy_index: bool,
 }
 
 impl Default for Config {
@@ -1073,6 +1259,7 @@ impl Default for Config {
             max_deploy_store_size: DEFAULT_MAX_DEPLOY_STORE_SIZE,
             max_deploy_metadata_store_size: DEFAULT_MAX_DEPLOY_METADATA_STORE_SIZE,
             max_state_store_size: DEFAULT_MAX_STATE_STORE_SIZE,
+            enable_account_deploy_index: false,
         }
     }
 }
@@ -1262,6 +1449,39 @@ fn initialize_block_metadata_db(
     Ok(())
 }
 
+/// Moves the deploy metadata persisted by versions which didn't record contract events into the
+/// deploy metadata database, converting it to the current format.
+fn migrate_legacy_deploy_metadata(
+    env: &Environment,
+    deploy_metadata_db: &Database,
+) -> Result<(), LmdbExtError> {
+    let legacy_db = match env.open_db(Some(LEGACY_DEPLOY_METADATA_DB_NAME)) {
+        Ok(legacy_db) => legacy_db,
+        Err(lmdb::Error::NotFound) => return Ok(()),
+        Err(err) => return Err(err.into()),
+    };
+
+    info!("migrating legacy deploy metadata");
+    let read_txn = env.begin_ro_txn()?;
+    let mut write_txn = env.begin_rw_txn()?;
+    let mut cursor = read_txn.open_ro_cursor(legacy_db)?;
+    let mut migrated_count = 0;
+    for (raw_key, raw_val) in cursor.iter() {
+        let legacy_metadata: LegacyDeployMetadata = lmdb_ext::deserialize(raw_val)?;
+        let deploy_metadata = DeployMetadata::from(legacy_metadata);
+        let _ = write_txn.put_value(*deploy_metadata_db, &raw_key, &deploy_metadata, true)?;
+        migrated_count += 1;
+    }
+    drop(cursor);
+    read_txn.commit()?;
+
+    write_txn.clear_db(legacy_db)?;
+    write_txn.commit()?;
+
+    info!(migrated_count, "legacy deploy metadata migrated");
+    Ok(())
+}
+
 /// Purges stale entries from the deploy metadata database.
 fn initialize_deploy_metadata_db(
     env: &Environment,
@@ -1297,3 +1517,131 @@ fn initialize_deploy_metadata_db(
     info!("deploy metadata database initialized");
     Ok(())
 }
+
+/// Purges entries for deleted blocks from the account deploy index database.
+fn initialize_account_deploy_db(
+    env: &Environment,
+    account_deploy_db: &Database,
+    deleted_block_heights: &HashSet<u64>,
+) -> Result<(), LmdbExtError> {
+    if deleted_block_heights.is_empty() {
+        return Ok(());
+    }
+
+    info!("initializing account deploy database");
+    let mut txn = env.begin_rw_txn()?;
+    let mut cursor = txn.open_rw_cursor(*account_deploy_db)?;
+
+    for (_, raw_entry) in cursor.iter() {
+        let (_, block_height) = parse_account_deploy_entry(raw_entry)?;
+        if deleted_block_heights.contains(&block_height) {
+            cursor.del(WriteFlags::empty())?;
+        }
+    }
+
+    drop(cursor);
+    txn.commit()?;
+
+    info!("account deploy database initialized");
+    Ok(())
+}
+
+/// Indexes every deploy with stored execution results if the account deploy index database is
+/// empty, i.e. if the index has just been enabled.
+fn backfill_account_deploy_db(
+    env: &Environment,
+    account_deploy_db: &Database,
+    deploy_db: &Database,
+    deploy_metadata_db: &Database,
+    block_height_index: &BTreeMap<u64, BlockHash>,
+) -> Result<(), LmdbExtError> {
+    let mut write_txn = env.begin_rw_txn()?;
+    let is_empty = write_txn
+        .open_ro_cursor(*account_deploy_db)?
+        .iter()
+        .next()
+        .is_none();
+    if !is_empty {
+        return Ok(());
+    }
+
+    info!("backfilling account deploy database");
+    let block_heights: HashMap<BlockHash, u64> = block_height_index
+        .iter()
+        .map(|(block_height, block_hash)| (*block_hash, *block_height))
+        .collect();
+    let mut read_txn = env.begin_ro_txn()?;
+    let mut indexed_count = 0;
+    {
+        let mut cursor = read_txn.open_ro_cursor(*deploy_metadata_db)?;
+        let entries: Vec<(Vec<u8>, DeployMetadata)> = cursor
+            .iter()
+            .map(|(raw_key, raw_val)| Ok((raw_key.to_vec(), lmdb_ext::deserialize(raw_val)?)))
+            .collect::<Result<_, LmdbExtError>>()?;
+        drop(cursor);
+
+        for (raw_key, deploy_metadata) in entries {
+            let deploy: Deploy = match read_txn.get_value(*deploy_db, &raw_key)? {
+                Some(deploy) => deploy,
+                None => continue,
+            };
+            let account_hash = deploy.header().account().to_account_hash();
+            for block_hash in deploy_metadata.execution_results.keys() {
+                if let Some(block_height) = block_heights.get(block_hash) {
+                    let entry = account_deploy_entry(*block_height, deploy.id());
+                    write_txn.put(
+                        *account_deploy_db,
+                        &account_hash,
+                        &entry,
+                        WriteFlags::empty(),
+                    )?;
+                    indexed_count += 1;
+                }
+            }
+        }
+    }
+    read_txn.commit()?;
+    write_txn.commit()?;
+
+    info!(indexed_count, "account deploy database backfilled");
+    Ok(())
+}
+
+/// Clears the account deploy index database, if it exists.
+fn clear_account_deploy_db(env: &Environment) -> Result<(), LmdbExtError> {
+    let account_deploy_db = match env.open_db(Some(ACCOUNT_DEPLOY_DB_NAME)) {
+        Ok(account_deploy_db) => account_deploy_db,
+        Err(lmdb::Error::NotFound) => return Ok(()),
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut txn = env.begin_rw_txn()?;
+    txn.clear_db(account_deploy_db)?;
+    txn.commit()?;
+    Ok(())
+}
+
+/// Purges the faults recorded in eras from `invalid_era` onwards, as these eras are reset.
+fn initialize_era_fault_db(
+    env: &Environment,
+    era_fault_db: &Database,
+    invalid_era: EraId,
+) -> Result<(), LmdbExtError> {
+    info!("initializing era fault database");
+    let mut txn = env.begin_rw_txn()?;
+    let mut cursor = txn.open_rw_cursor(*era_fault_db)?;
+
+    // Keys are big-endian era IDs, so compare in the same order as the eras themselves.
+    let invalid_era_key = invalid_era.value().to_be_bytes();
+    for (raw_key, _) in cursor.iter() {
+        if raw_key >= &invalid_era_key[..] {
+            cursor.del(WriteFlags::empty())?;
+        }
+    }
+
+    drop(cursor);
+    txn.commit()?;
+
+    info!("era fault database initialized");
+    Ok(())
+}
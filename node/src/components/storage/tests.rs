@@ -2,16 +2,18 @@
 
 use std::{borrow::Cow, collections::HashMap};
 
-use lmdb::Transaction;
+use lmdb::{DatabaseFlags, Transaction, WriteFlags};
 use rand::{prelude::SliceRandom, Rng};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use smallvec::smallvec;
 
-use casper_types::{EraId, ExecutionResult, ProtocolVersion, PublicKey, SecretKey};
+use casper_types::{
+    account::AccountHash, EraId, ExecutionResult, ProtocolVersion, PublicKey, SecretKey,
+};
 
 use super::{Config, Storage};
 use crate::{
-    components::storage::lmdb_ext::WriteTransactionExt,
+    components::{rpc_server::rpcs::docs::DocExample, storage::lmdb_ext::WriteTransactionExt},
     crypto::AsymmetricKeyExt,
     effect::{
         requests::{StateStoreRequest, StorageRequest},
@@ -20,7 +22,7 @@ use crate::{
     testing::{ComponentHarness, TestRng, UnitTestEvent},
     types::{
         Block, BlockHash, BlockHeader, BlockSignatures, Deploy, DeployHash, DeployMetadata,
-        FinalitySignature,
+        EraFault, FinalitySignature, TimeDiff, Timestamp,
     },
     utils::WithDir,
 };
@@ -35,6 +37,14 @@ fn new_config(harness: &ComponentHarness<UnitTestEvent>) -> Config {
         max_deploy_store_size: 50 * MIB,
         max_deploy_metadata_store_size: 50 * MIB,
         max_state_store_size: 50 * MIB,
+        enable_account_deploy_index: false,
+    }
+}
+
+fn new_config_with_account_deploy_index(harness: &ComponentHarness<UnitTestEvent>) -> Config {
+    Config {
+        enable_account_deploy_index: true,
+        ..new_config(harness)
     }
 }
 
@@ -57,7 +67,25 @@ fn storage_fixture(harness: &ComponentHarness<UnitTestEvent>) -> Storage {
 
 /// Storage component test fixture.
 ///
-/// Creates a storage component in a temporary directory, but with a hard reset to a specified era.
+/// Creates a storage component in a temporary directory, with the account deploy index enabled.
+///
+/// # Panics
+///
+/// Panics if setting up the storage fixture fails.
+fn storage_fixture_with_account_deploy_index(harness: &ComponentHarness<UnitTestEvent>) -> Storage {
+    let cfg = new_config_with_account_deploy_index(harness);
+    Storage::new(
+        &WithDir::new(harness.tmp.path(), cfg),
+        None,
+        ProtocolVersion::from_parts(1, 0, 0),
+    )
+    .expect("could not create storage component fixture")
+}
+
+/// Storage component test fixture.
+///
+/// Creates a storage component in a temporary directory, with the account deploy index enabled, but
+/// with a hard reset to a specified era.
 ///
 /// # Panics
 ///
@@ -66,7 +94,7 @@ fn storage_fixture_with_hard_reset(
     harness: &ComponentHarness<UnitTestEvent>,
     reset_era_id: EraId,
 ) -> Storage {
-    let cfg = new_config(harness);
+    let cfg = new_config_with_account_deploy_index(harness);
     Storage::new(
         &WithDir::new(harness.tmp.path(), cfg),
         Some(reset_era_id),
@@ -272,11 +300,13 @@ fn put_execution_results(
     harness: &mut ComponentHarness<UnitTestEvent>,
     storage: &mut Storage,
     block_hash: BlockHash,
+    block_height: u64,
     execution_results: HashMap<DeployHash, ExecutionResult>,
 ) {
     let response = harness.send_request(storage, move |responder| {
         StorageRequest::PutExecutionResults {
             block_hash: Box::new(block_hash),
+            block_height,
             execution_results,
             responder,
         }
@@ -286,6 +316,57 @@ fn put_execution_results(
     response
 }
 
+/// Loads a page of the deploys sent by an account from a storage component.
+fn get_account_deploys(
+    harness: &mut ComponentHarness<UnitTestEvent>,
+    storage: &mut Storage,
+    account_hash: AccountHash,
+    offset: u32,
+    limit: u32,
+) -> Option<Vec<(DeployHash, u64)>> {
+    let response = harness.send_request(storage, move |responder| {
+        StorageRequest::GetAccountDeploys {
+            account_hash: Box::new(account_hash),
+            offset,
+            limit,
+            responder,
+        }
+        .into()
+    });
+    assert!(harness.is_idle());
+    response
+}
+
+/// Stores evidence of a fault in a storage component.
+fn put_era_fault(
+    harness: &mut ComponentHarness<UnitTestEvent>,
+    storage: &mut Storage,
+    era_fault: EraFault,
+) -> bool {
+    let response = harness.send_request(storage, move |responder| {
+        StorageRequest::PutEraFault {
+            era_fault: Box::new(era_fault),
+            responder,
+        }
+        .into()
+    });
+    assert!(harness.is_idle());
+    response
+}
+
+/// Loads the evidence of all faults in an era from a storage component.
+fn get_era_faults(
+    harness: &mut ComponentHarness<UnitTestEvent>,
+    storage: &mut Storage,
+    era_id: EraId,
+) -> Vec<EraFault> {
+    let response = harness.send_request(storage, move |responder| {
+        StorageRequest::GetEraFaults { era_id, responder }.into()
+    });
+    assert!(harness.is_idle());
+    response
+}
+
 /// Saves state from the storage component.
 fn save_state<T>(
     harness: &mut ComponentHarness<UnitTestEvent>,
@@ -683,7 +764,7 @@ fn store_execution_results_for_two_blocks() {
     let first_result: ExecutionResult = harness.rng.gen();
     let mut first_results = HashMap::new();
     first_results.insert(*deploy.id(), first_result.clone());
-    put_execution_results(&mut harness, &mut storage, block_hash_a, first_results);
+    put_execution_results(&mut harness, &mut storage, block_hash_a, 0, first_results);
 
     // Retrieve and check if correct.
     let (first_deploy, first_metadata) =
@@ -698,7 +779,7 @@ fn store_execution_results_for_two_blocks() {
     let second_result: ExecutionResult = harness.rng.gen();
     let mut second_results = HashMap::new();
     second_results.insert(*deploy.id(), second_result.clone());
-    put_execution_results(&mut harness, &mut storage, block_hash_b, second_results);
+    put_execution_results(&mut harness, &mut storage, block_hash_b, 1, second_results);
 
     // Retrieve the deploy again, should now contain both.
     let (second_deploy, second_metadata) =
@@ -742,6 +823,7 @@ fn store_random_execution_results() {
         storage: &mut Storage,
         expected_outcome: &mut HashMap<DeployHash, HashMap<BlockHash, ExecutionResult>>,
         block_hash: &BlockHash,
+        block_height: u64,
         shared_deploys: &[Deploy],
     ) {
         let unique_count = 3;
@@ -786,7 +868,7 @@ fn store_random_execution_results() {
         assert_eq!(block_results.len(), unique_count + shared_deploys.len());
 
         // Now we can submit the block's execution results.
-        put_execution_results(harness, storage, *block_hash, block_results);
+        put_execution_results(harness, storage, *block_hash, block_height, block_results);
     }
 
     setup_block(
@@ -794,6 +876,7 @@ fn store_random_execution_results() {
         &mut storage,
         &mut expected_outcome,
         &block_hash_a,
+        0,
         &shared_deploys,
     );
 
@@ -802,6 +885,7 @@ fn store_random_execution_results() {
         &mut storage,
         &mut expected_outcome,
         &block_hash_b,
+        1,
         &shared_deploys,
     );
 
@@ -831,10 +915,10 @@ fn store_execution_results_twice_for_same_block_deploy_pair() {
     let mut exec_result_2 = HashMap::new();
     exec_result_2.insert(deploy_hash, harness.rng.gen());
 
-    put_execution_results(&mut harness, &mut storage, block_hash, exec_result_1);
+    put_execution_results(&mut harness, &mut storage, block_hash, 0, exec_result_1);
 
     // Storing a second execution result for the same deploy on the same block should panic.
-    put_execution_results(&mut harness, &mut storage, block_hash, exec_result_2);
+    put_execution_results(&mut harness, &mut storage, block_hash, 0, exec_result_2);
 }
 
 #[test]
@@ -848,10 +932,183 @@ fn store_identical_execution_results() {
     let mut exec_result = HashMap::new();
     exec_result.insert(deploy_hash, harness.rng.gen());
 
-    put_execution_results(&mut harness, &mut storage, block_hash, exec_result.clone());
+    put_execution_results(
+        &mut harness,
+        &mut storage,
+        block_hash,
+        0,
+        exec_result.clone(),
+    );
 
     // We should be fine storing the exact same result twice.
-    put_execution_results(&mut harness, &mut storage, block_hash, exec_result);
+    put_execution_results(&mut harness, &mut storage, block_hash, 0, exec_result);
+}
+
+#[test]
+fn store_and_load_account_deploys() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture_with_account_deploy_index(&harness);
+
+    let secret_key = SecretKey::random(&mut harness.rng);
+    let account_hash = PublicKey::from(&secret_key).to_account_hash();
+
+    // Execute one deploy of the account in each of five blocks, storing them in reverse order of
+    // block height, along with an unrelated deploy.
+    let mut expected = vec![];
+    for block_height in (0..5).rev() {
+        let deploy = Deploy::new(
+            Timestamp::now(),
+            TimeDiff::from_seconds(60),
+            1,
+            vec![],
+            String::from("casper-example"),
+            harness.rng.gen(),
+            harness.rng.gen(),
+            &secret_key,
+        );
+        let other_deploy = Deploy::random(&mut harness.rng);
+        put_deploy(&mut harness, &mut storage, Box::new(deploy.clone()));
+        put_deploy(&mut harness, &mut storage, Box::new(other_deploy.clone()));
+
+        let mut execution_results = HashMap::new();
+        execution_results.insert(*deploy.id(), harness.rng.gen());
+        execution_results.insert(*other_deploy.id(), harness.rng.gen());
+        put_execution_results(
+            &mut harness,
+            &mut storage,
+            BlockHash::random(&mut harness.rng),
+            block_height,
+            execution_results,
+        );
+        expected.insert(0, (*deploy.id(), block_height));
+    }
+
+    // Deploys are returned in order of block height.
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, account_hash, 0, 10),
+        Some(expected.clone())
+    );
+
+    // Pages are cut from the ordered list of deploys.
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, account_hash, 1, 2),
+        Some(expected[1..3].to_vec())
+    );
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, account_hash, 5, 10),
+        Some(vec![])
+    );
+
+    // An unknown account has no deploys.
+    let unknown_account_hash = AccountHash::new(harness.rng.gen());
+    assert_eq!(
+        get_account_deploys(&mut harness, &mut storage, unknown_account_hash, 0, 10),
+        Some(vec![])
+    );
+}
+
+#[test]
+fn account_deploys_not_available_if_index_disabled() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture(&harness);
+
+    let deploy = Deploy::random(&mut harness.rng);
+    put_deploy(&mut harness, &mut storage, Box::new(deploy.clone()));
+    let mut execution_results = HashMap::new();
+    execution_results.insert(*deploy.id(), harness.rng.gen());
+    put_execution_results(
+        &mut harness,
+        &mut storage,
+        BlockHash::random(&mut harness.rng),
+        0,
+        execution_results,
+    );
+
+    let account_hash = deploy.header().account().to_account_hash();
+    assert!(get_account_deploys(&mut harness, &mut storage, account_hash, 0, 10).is_none());
+}
+
+#[test]
+fn account_deploys_backfilled_when_index_enabled() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture(&harness);
+
+    // Execute a deploy in each of three blocks while the index is disabled.
+    let mut expected = vec![];
+    for block_height in 0..3 {
+        let block = random_block_at_height(&mut harness.rng, block_height);
+        let deploy = Deploy::random(&mut harness.rng);
+        put_block(&mut harness, &mut storage, block.clone());
+        put_deploy(&mut harness, &mut storage, Box::new(deploy.clone()));
+        let mut execution_results = HashMap::new();
+        execution_results.insert(*deploy.id(), harness.rng.gen());
+        put_execution_results(
+            &mut harness,
+            &mut storage,
+            *block.hash(),
+            block_height,
+            execution_results,
+        );
+        expected.push(deploy);
+    }
+    drop(storage);
+
+    // Enabling the index indexes the deploys executed beforehand.
+    let mut storage = storage_fixture_with_account_deploy_index(&harness);
+    for (block_height, deploy) in expected.iter().enumerate() {
+        let account_hash = deploy.header().account().to_account_hash();
+        assert_eq!(
+            get_account_deploys(&mut harness, &mut storage, account_hash, 0, 10),
+            Some(vec![(*deploy.id(), block_height as u64)])
+        );
+    }
+}
+
+#[test]
+fn store_and_load_era_faults() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture(&harness);
+
+    let era_id = EraId::from(3);
+    assert!(get_era_faults(&mut harness, &mut storage, era_id).is_empty());
+
+    let mut era_fault1 = EraFault::doc_example().clone();
+    era_fault1.era_id = era_id;
+    let mut era_fault2 = era_fault1.clone();
+    era_fault2.public_key = PublicKey::random(&mut harness.rng);
+
+    assert!(put_era_fault(
+        &mut harness,
+        &mut storage,
+        era_fault1.clone()
+    ));
+    assert!(put_era_fault(
+        &mut harness,
+        &mut storage,
+        era_fault2.clone()
+    ));
+    // A second fault by the same validator in the same era is not stored.
+    let mut duplicate = era_fault1.clone();
+    duplicate.timestamp = Timestamp::now();
+    assert!(!put_era_fault(&mut harness, &mut storage, duplicate));
+
+    assert_eq!(
+        get_era_faults(&mut harness, &mut storage, era_id),
+        vec![era_fault1.clone(), era_fault2.clone()]
+    );
+    assert!(get_era_faults(&mut harness, &mut storage, era_id.successor()).is_empty());
+
+    // The faults should be persisted across instantiations.
+    let (on_disk, rng) = harness.into_parts();
+    let mut harness = ComponentHarness::builder()
+        .on_disk(on_disk)
+        .rng(rng)
+        .build();
+    let mut storage = storage_fixture(&harness);
+    assert_eq!(
+        get_era_faults(&mut harness, &mut storage, era_id),
+        vec![era_fault1, era_fault2]
+    );
 }
 
 /// Example state used in storage.
@@ -967,7 +1224,13 @@ fn persist_blocks_deploys_and_deploy_metadata_across_instantiations() {
     put_block(&mut harness, &mut storage, block.clone());
     let mut execution_results = HashMap::new();
     execution_results.insert(*deploy.id(), execution_result.clone());
-    put_execution_results(&mut harness, &mut storage, *block.hash(), execution_results);
+    put_execution_results(
+        &mut harness,
+        &mut storage,
+        *block.hash(),
+        block.height(),
+        execution_results,
+    );
 
     assert_eq!(
         get_block_at_height(&mut harness, &mut storage, 42).expect("block not indexed properly"),
@@ -1003,12 +1266,91 @@ fn persist_blocks_deploys_and_deploy_metadata_across_instantiations() {
     );
 }
 
+#[test]
+fn migrate_deploy_metadata_stored_before_contract_events() {
+    let mut harness = ComponentHarness::default();
+    let mut storage = storage_fixture(&harness);
+
+    let deploy = Deploy::random(&mut harness.rng);
+    put_deploy(&mut harness, &mut storage, Box::new(deploy.clone()));
+
+    let block_hash = BlockHash::random(&mut harness.rng);
+    let (effect, transfers, cost) = match harness.rng.gen() {
+        ExecutionResult::Failure {
+            effect,
+            transfers,
+            cost,
+            ..
+        }
+        | ExecutionResult::Success {
+            effect,
+            transfers,
+            cost,
+            ..
+        } => (effect, transfers, cost),
+    };
+
+    // Deploy metadata holding a single successful execution result, serialized the way it was
+    // before execution results recorded contract events.
+    let mut legacy_metadata = bincode::serialize(&1u64).unwrap();
+    legacy_metadata.extend(bincode::serialize(&block_hash).unwrap());
+    legacy_metadata.extend(bincode::serialize(&1u32).unwrap());
+    legacy_metadata.extend(bincode::serialize(&effect).unwrap());
+    legacy_metadata.extend(bincode::serialize(&transfers).unwrap());
+    legacy_metadata.extend(bincode::serialize(&cost).unwrap());
+
+    let legacy_db = storage
+        .env
+        .create_db(
+            Some(super::LEGACY_DEPLOY_METADATA_DB_NAME),
+            DatabaseFlags::empty(),
+        )
+        .unwrap();
+    let mut txn = storage.env.begin_rw_txn().unwrap();
+    txn.put(
+        legacy_db,
+        deploy.id(),
+        &legacy_metadata,
+        WriteFlags::empty(),
+    )
+    .unwrap();
+    txn.commit().unwrap();
+    drop(storage);
+
+    let expected_result = ExecutionResult::Success {
+        effect,
+        transfers,
+        events: vec![],
+        cost,
+    };
+
+    // The legacy metadata is migrated when reopening the storage, and still there after doing so
+    // again.
+    for _ in 0..2 {
+        let (on_disk, rng) = harness.into_parts();
+        harness = ComponentHarness::builder()
+            .on_disk(on_disk)
+            .rng(rng)
+            .build();
+        let mut storage = storage_fixture(&harness);
+
+        let (_, deploy_metadata) =
+            get_deploy_and_metadata(&mut harness, &mut storage, *deploy.id())
+                .expect("missing deploy we stored earlier");
+        assert_eq!(deploy_metadata.execution_results.len(), 1);
+        assert_eq!(
+            deploy_metadata.execution_results[&block_hash],
+            expected_result
+        );
+    }
+}
+
 #[test]
 fn should_hard_reset() {
     let blocks_count = 8_usize;
     let blocks_per_era = 3;
     let mut harness = ComponentHarness::default();
-    let mut storage = storage_fixture(&harness);
+    let mut storage = storage_fixture_with_account_deploy_index(&harness);
 
     // Create and store 8 blocks, 0-2 in era 0, 3-5 in era 1, and 6,7 in era 2.
     let blocks: Vec<Block> = (0..blocks_count)
@@ -1045,7 +1387,7 @@ fn should_hard_reset() {
     // and so on.
     let mut deploys = vec![];
     let mut execution_results = vec![];
-    for block_hash in blocks.iter().map(|block| block.hash()) {
+    for block in &blocks {
         let deploy = Deploy::random(&mut harness.rng);
         let execution_result: ExecutionResult = harness.rng.gen();
         let mut exec_results = HashMap::new();
@@ -1054,13 +1396,22 @@ fn should_hard_reset() {
         put_execution_results(
             &mut harness,
             &mut storage,
-            *block_hash,
+            *block.hash(),
+            block.height(),
             exec_results.clone(),
         );
         deploys.push(deploy);
         execution_results.push(exec_results);
     }
 
+    // Record a fault in each era.
+    let era_count = blocks_count / blocks_per_era + 1;
+    for era_id in 0..era_count {
+        let mut era_fault = EraFault::doc_example().clone();
+        era_fault.era_id = EraId::from(era_id as u64);
+        assert!(put_era_fault(&mut harness, &mut storage, era_fault));
+    }
+
     // Check the highest block is #7.
     assert_eq!(
         Some(blocks[blocks_count - 1].clone()),
@@ -1108,6 +1459,23 @@ fn should_hard_reset() {
                 !metadata.execution_results.is_empty()
             );
         }
+
+        // Check deploys executed in deleted blocks have been removed from the account index.
+        for (index, deploy) in deploys.iter().enumerate() {
+            let account_hash = deploy.header().account().to_account_hash();
+            let account_deploys =
+                get_account_deploys(&mut harness, &mut storage, account_hash, 0, 10)
+                    .expect("account deploy index should be enabled");
+            let should_be_indexed = index < blocks_per_era * reset_era;
+            assert_eq!(should_be_indexed, !account_deploys.is_empty());
+        }
+
+        // Check faults in reset eras have been removed.
+        for era_id in 0..era_count {
+            let era_faults = get_era_faults(&mut harness, &mut storage, EraId::from(era_id as u64));
+            let should_have_faults = era_id < reset_era;
+            assert_eq!(should_have_faults, !era_faults.is_empty());
+        }
     };
 
     // Test with a hard reset to era 2, deleting blocks (and associated data) 6 and 7.
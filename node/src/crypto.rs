@@ -4,6 +4,7 @@ mod asymmetric_key;
 mod asymmetric_key_ext;
 mod error;
 pub mod hash;
+pub mod signer;
 
 pub use asymmetric_key::{generate_ed25519_keypair, sign, verify};
 pub use asymmetric_key_ext::AsymmetricKeyExt;
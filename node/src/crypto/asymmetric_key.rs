@@ -3,11 +3,6 @@
 // TODO - remove once schemars stops causing warning.
 #![allow(clippy::field_reassign_with_default)]
 
-use ed25519_dalek::ExpandedSecretKey;
-use k256::ecdsa::{
-    signature::{Signer, Verifier},
-    Signature as Secp256k1Signature, VerifyingKey as Secp256k1PublicKey,
-};
 #[cfg(test)]
 use k256::elliptic_curve::sec1::ToEncodedPoint;
 
@@ -30,24 +25,7 @@ pub fn sign<T: AsRef<[u8]>>(
     secret_key: &SecretKey,
     public_key: &PublicKey,
 ) -> Signature {
-    match (secret_key, public_key) {
-        (SecretKey::System, PublicKey::System) => {
-            panic!("cannot create signature with system keys",)
-        }
-        (SecretKey::Ed25519(secret_key), PublicKey::Ed25519(public_key)) => {
-            let expanded_secret_key = ExpandedSecretKey::from(secret_key);
-            let signature = expanded_secret_key.sign(message.as_ref(), public_key);
-            Signature::Ed25519(signature)
-        }
-        (SecretKey::Secp256k1(secret_key), PublicKey::Secp256k1(_public_key)) => {
-            let signer = secret_key;
-            let signature: Secp256k1Signature = signer
-                .try_sign(message.as_ref())
-                .expect("should create signature");
-            Signature::Secp256k1(signature)
-        }
-        _ => panic!("secret and public key types must match"),
-    }
+    casper_types::sign(message, secret_key, public_key).expect("should create signature")
 }
 
 /// Verifies the signature of the given message against the given public key.
@@ -56,26 +34,7 @@ pub fn verify<T: AsRef<[u8]>>(
     signature: &Signature,
     public_key: &PublicKey,
 ) -> Result<()> {
-    match (signature, public_key) {
-        (Signature::System, _) => Err(Error::AsymmetricKey(String::from(
-            "signatures based on the system key cannot be verified",
-        ))),
-        (Signature::Ed25519(signature), PublicKey::Ed25519(public_key)) => public_key
-            .verify_strict(message.as_ref(), signature)
-            .map_err(|_| Error::AsymmetricKey(String::from("failed to verify Ed25519 signature"))),
-        (Signature::Secp256k1(signature), PublicKey::Secp256k1(public_key)) => {
-            let verifier: &Secp256k1PublicKey = public_key;
-            verifier
-                .verify(message.as_ref(), signature)
-                .map_err(|error| {
-                    Error::AsymmetricKey(format!("failed to verify secp256k1 signature: {}", error))
-                })
-        }
-        _ => Err(Error::AsymmetricKey(format!(
-            "type mismatch between {} and {}",
-            signature, public_key
-        ))),
-    }
+    casper_types::verify(message, signature, public_key).map_err(Into::into)
 }
 
 #[cfg(test)]
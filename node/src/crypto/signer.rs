@@ -0,0 +1,301 @@
+//! Signing with the validator's consensus key.
+//!
+//! A `Signer` produces the signatures a validator needs: on its Highway units, endorsements and
+//! pings, and on finality signatures.  The key can either be held in process memory by a
+//! `LocalSigner`, or live in a separate signing daemon on a hardened host, accessed via a
+//! `RemoteSigner`.  The daemon refuses to sign two different units with the same sequence number
+//! in the same consensus instance, so that even a misconfigured or compromised node cannot make
+//! the validator equivocate.
+//!
+//! The bytes signed for units and for other consensus messages are prefixed with distinct domain
+//! tags, so that a signature obtained for one kind of payload is never valid for another, nor for a
+//! deploy approval.  Unit signatures also cover the unit's instance ID and sequence number, so they
+//! are only valid for the sequence number the daemon recorded.  Finality signatures are unprefixed,
+//! to keep existing ones valid, but are distinguished by their length.
+//!
+//! This `SignatureFormat::DomainSeparated` format only applies from the era set in the chainspec.
+//! Units and other consensus messages of earlier eras are signed in the `SignatureFormat::Legacy`
+//! format, i.e. their raw hashes are signed, as by older versions of the node.
+
+pub mod daemon;
+mod protocol;
+mod remote;
+
+use std::{fmt::Debug, io, sync::Arc, time::Duration};
+
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use casper_types::{EraId, PublicKey, SecretKey, Signature};
+
+use crate::{
+    crypto::{self, hash::Digest},
+    types::{BlockHash, FinalitySignature},
+};
+
+pub use remote::{Endpoint, RemoteSigner, RemoteSignerConfig};
+
+/// The maximum time to wait for `sign_on_blocking_pool`.
+const BLOCKING_SIGNING_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The prefix of the bytes signed for a Highway unit.
+const UNIT_DOMAIN_TAG: &[u8] = b"casper-consensus-unit";
+/// The prefix of the bytes signed for any other consensus message.
+const CONSENSUS_MESSAGE_DOMAIN_TAG: &[u8] = b"casper-consensus-message";
+
+/// An error signing a payload.
+#[derive(Debug, Error)]
+pub enum SignerError {
+    /// An I/O error communicating with the signing daemon.
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+
+    /// Failed to set up or establish the TLS connection to the signing daemon.
+    #[error("TLS error: {0}")]
+    Tls(String),
+
+    /// The signing daemon's certificate was not the expected one.
+    #[error("signing daemon presented an unexpected certificate")]
+    WrongCertificate,
+
+    /// Failed to serialize or deserialize a message.
+    #[error("serialization error: {0}")]
+    Serialization(#[from] bincode::Error),
+
+    /// A message exceeded the maximum frame size.
+    #[error("message of {0} bytes exceeds the maximum frame size")]
+    FrameTooLarge(usize),
+
+    /// The signing daemon refused to sign the payload.
+    #[error("signing refused: {0}")]
+    Refused(String),
+
+    /// The signing daemon sent a response that doesn't match the request.
+    #[error("unexpected response from signing daemon")]
+    UnexpectedResponse,
+
+    /// The signing daemon returned an invalid signature.
+    #[error("signing daemon returned an invalid signature")]
+    InvalidSignature,
+
+    /// The signing task panicked or was cancelled.
+    #[error("signing task failed: {0}")]
+    Task(String),
+
+    /// Signing on the blocking thread pool didn't complete in time.
+    #[error("signing timed out")]
+    Timeout,
+}
+
+/// The format of the bytes signed for units and other consensus messages.
+#[derive(Clone, Copy, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SignatureFormat {
+    /// The raw hash of the unit or message.
+    Legacy,
+    /// The hash prefixed with a domain tag and, for units, the instance ID and sequence number.
+    DomainSeparated,
+}
+
+/// Something a validator needs to sign.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SigningPayload {
+    /// A new Highway unit created by the validator.
+    Unit {
+        /// The ID of the consensus protocol instance the unit belongs to.
+        instance_id: Digest,
+        /// The unit's sequence number.
+        seq_number: u64,
+        /// The unit's hash.
+        hash: Digest,
+        /// The format of the bytes to sign.
+        format: SignatureFormat,
+    },
+    /// Any other consensus message, i.e. an endorsement or a ping.
+    ConsensusMessage {
+        /// The ID of the consensus protocol instance the message belongs to.
+        instance_id: Digest,
+        /// The message's hash.
+        hash: Digest,
+        /// The format of the bytes to sign.
+        format: SignatureFormat,
+    },
+    /// A finality signature, confirming that a block is finalized.
+    FinalitySignature {
+        /// The hash of the finalized block.
+        block_hash: BlockHash,
+        /// The era in which the block was created.
+        era_id: EraId,
+    },
+}
+
+impl SigningPayload {
+    /// Returns the bytes to be signed.
+    pub(crate) fn bytes_to_sign(&self) -> Vec<u8> {
+        match self {
+            SigningPayload::Unit {
+                instance_id,
+                seq_number,
+                hash,
+                format,
+            } => unit_bytes_to_sign(*format, instance_id, *seq_number, hash),
+            SigningPayload::ConsensusMessage { hash, format, .. } => {
+                consensus_message_bytes_to_sign(*format, hash)
+            }
+            SigningPayload::FinalitySignature { block_hash, era_id } => {
+                FinalitySignature::bytes_to_sign(block_hash, *era_id)
+            }
+        }
+    }
+}
+
+/// Returns the bytes a validator signs for a new Highway unit.
+pub(crate) fn unit_bytes_to_sign(
+    format: SignatureFormat,
+    instance_id: &Digest,
+    seq_number: u64,
+    hash: &Digest,
+) -> Vec<u8> {
+    if format == SignatureFormat::Legacy {
+        return hash.as_ref().to_vec();
+    }
+    let mut bytes = UNIT_DOMAIN_TAG.to_vec();
+    bytes.extend_from_slice(instance_id.as_ref());
+    bytes.extend_from_slice(&seq_number.to_be_bytes());
+    bytes.extend_from_slice(hash.as_ref());
+    bytes
+}
+
+/// Returns the bytes a validator signs for a consensus message other than a unit.
+pub(crate) fn consensus_message_bytes_to_sign(format: SignatureFormat, hash: &Digest) -> Vec<u8> {
+    if format == SignatureFormat::Legacy {
+        return hash.as_ref().to_vec();
+    }
+    let mut bytes = CONSENSUS_MESSAGE_DOMAIN_TAG.to_vec();
+    bytes.extend_from_slice(hash.as_ref());
+    bytes
+}
+
+/// Produces signatures with the validator's consensus key.
+pub trait Signer: Debug + Send + Sync {
+    /// Returns the public key of the validator.
+    fn public_key(&self) -> &PublicKey;
+
+    /// Signs the payload.
+    fn sign(&self, payload: &SigningPayload) -> Result<Signature, SignerError>;
+
+    /// Returns whether `sign` may block, e.g. on network I/O.  If so, it must not be called on the
+    /// reactor thread; see `sign_on_blocking_pool`.
+    fn may_block(&self) -> bool {
+        false
+    }
+}
+
+/// Signs the payload on tokio's blocking thread pool, so that a blocking signer doesn't stall the
+/// caller.
+pub(crate) async fn sign_on_blocking_pool(
+    signer: Arc<dyn Signer>,
+    payload: SigningPayload,
+) -> Result<Signature, SignerError> {
+    let task = tokio::task::spawn_blocking(move || signer.sign(&payload));
+    match tokio::time::timeout(BLOCKING_SIGNING_TIMEOUT, task).await {
+        Ok(Ok(result)) => result,
+        Ok(Err(error)) => Err(SignerError::Task(error.to_string())),
+        Err(_) => Err(SignerError::Timeout),
+    }
+}
+
+/// A signer holding the secret key in process memory.
+#[derive(Debug)]
+pub struct LocalSigner {
+    secret_key: Arc<SecretKey>,
+    public_key: PublicKey,
+}
+
+impl LocalSigner {
+    /// Creates a new local signer with the given secret key.
+    pub fn new(secret_key: Arc<SecretKey>) -> Self {
+        let public_key = PublicKey::from(secret_key.as_ref());
+        LocalSigner {
+            secret_key,
+            public_key,
+        }
+    }
+}
+
+impl Signer for LocalSigner {
+    fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    fn sign(&self, payload: &SigningPayload) -> Result<Signature, SignerError> {
+        Ok(crypto::sign(
+            payload.bytes_to_sign(),
+            &self.secret_key,
+            &self.public_key,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{crypto::AsymmetricKeyExt, testing::TestRng};
+
+    #[test]
+    fn should_not_accept_signature_for_other_payload_kind() {
+        let mut rng = TestRng::new();
+        let signer = LocalSigner::new(Arc::new(SecretKey::random(&mut rng)));
+        let instance_id = Digest::from([1; Digest::LENGTH]);
+        let hash = Digest::from([2; Digest::LENGTH]);
+        let unit = |seq_number| SigningPayload::Unit {
+            instance_id,
+            seq_number,
+            hash,
+            format: SignatureFormat::DomainSeparated,
+        };
+        let message = SigningPayload::ConsensusMessage {
+            instance_id,
+            hash,
+            format: SignatureFormat::DomainSeparated,
+        };
+
+        let verifies = |payload: &SigningPayload, signature: &Signature| {
+            crypto::verify(payload.bytes_to_sign(), signature, signer.public_key()).is_ok()
+        };
+        let unit_signature = signer.sign(&unit(5)).unwrap();
+        let message_signature = signer.sign(&message).unwrap();
+        assert!(verifies(&unit(5), &unit_signature));
+        assert!(!verifies(&unit(6), &unit_signature));
+        assert!(!verifies(&message, &unit_signature));
+        assert!(verifies(&message, &message_signature));
+        assert!(!verifies(&unit(5), &message_signature));
+
+        // Neither is the raw hash, as signed e.g. for deploy approvals.
+        assert!(crypto::verify(hash, &message_signature, signer.public_key()).is_err());
+    }
+
+    #[test]
+    fn should_sign_raw_hash_in_legacy_format() {
+        let mut rng = TestRng::new();
+        let signer = LocalSigner::new(Arc::new(SecretKey::random(&mut rng)));
+        let instance_id = Digest::from([1; Digest::LENGTH]);
+        let hash = Digest::from([2; Digest::LENGTH]);
+        let unit = SigningPayload::Unit {
+            instance_id,
+            seq_number: 5,
+            hash,
+            format: SignatureFormat::Legacy,
+        };
+        let message = SigningPayload::ConsensusMessage {
+            instance_id,
+            hash,
+            format: SignatureFormat::Legacy,
+        };
+
+        for payload in &[unit, message] {
+            let signature = signer.sign(payload).unwrap();
+            assert!(crypto::verify(hash, &signature, signer.public_key()).is_ok());
+        }
+    }
+}
@@ -0,0 +1,364 @@
+//! A reference implementation of the signing daemon used by `RemoteSigner`.
+//!
+//! The daemon holds the validator's secret key and signs on behalf of the nodes whose TLS
+//! certificates it is configured to accept.  Before signing a unit, it durably records the unit's
+//! sequence number and hash, and it refuses to sign any other unit with the same or a lower
+//! sequence number in the same consensus instance.  In the domain-separated signature format, the
+//! signed bytes include the instance ID and sequence number, so a signature is only valid for the
+//! sequence number the daemon recorded, even if the node reported a wrong one.
+//!
+//! If listening on a Unix socket, access to the socket should additionally be restricted via file
+//! system permissions.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    fs, io,
+    net::TcpListener,
+    os::unix::net::UnixListener,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use anyhow::{bail, Context};
+use openssl::ssl::SslAcceptor;
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use casper_types::SecretKey;
+
+use super::{
+    protocol::{self, Request, Response, Socket},
+    Endpoint, LocalSigner, Signer, SignerError, SigningPayload,
+};
+use crate::{
+    crypto::hash::Digest,
+    tls::{self, KeyFingerprint},
+    utils::{External, WithDir},
+};
+
+/// Configuration of the signing daemon.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+// Disallow unknown fields to ensure config files contain valid keys.
+#[serde(deny_unknown_fields)]
+pub struct DaemonConfig {
+    /// The address to listen on.
+    pub endpoint: Endpoint,
+    /// Path to the validator's secret key.
+    pub secret_key_path: External<SecretKey>,
+    /// Path to the daemon's TLS certificate.
+    pub cert_path: PathBuf,
+    /// Path to the secret key of the daemon's TLS certificate.
+    pub tls_secret_key_path: PathBuf,
+    /// Paths to the TLS certificates of the nodes allowed to request signatures.
+    pub client_cert_paths: Vec<PathBuf>,
+    /// Path to the file recording the latest unit signed in each consensus instance.
+    pub signed_units_path: PathBuf,
+}
+
+/// The latest unit signed in each consensus instance: its sequence number and hash.
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct SignedUnits(BTreeMap<Digest, (u64, Digest)>);
+
+impl SignedUnits {
+    /// Loads the record from the given file, or returns an empty one if the file doesn't exist.
+    fn load(path: &Path) -> anyhow::Result<Self> {
+        match fs::read(path) {
+            Ok(bytes) => bincode::deserialize(&bytes)
+                .with_context(|| format!("failed to parse {}", path.display())),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(SignedUnits::default()),
+            Err(error) => Err(error).with_context(|| format!("failed to read {}", path.display())),
+        }
+    }
+
+    /// Atomically writes the record to the given file.
+    fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let temp_path = path.with_extension("tmp");
+        let bytes = bincode::serialize(self)?;
+        fs::write(&temp_path, bytes)?;
+        fs::rename(&temp_path, path)?;
+        Ok(())
+    }
+
+    /// Records the unit, if it doesn't conflict with any unit signed before.
+    ///
+    /// Returns `Ok(true)` if the record changed, `Ok(false)` if exactly this unit was recorded
+    /// already, or an error message if signing the unit would be an equivocation.
+    fn record(
+        &mut self,
+        instance_id: Digest,
+        seq_number: u64,
+        hash: Digest,
+    ) -> Result<bool, String> {
+        match self.0.get(&instance_id) {
+            Some((latest_seq_number, latest_hash)) if *latest_seq_number == seq_number => {
+                if *latest_hash == hash {
+                    Ok(false)
+                } else {
+                    Err(format!(
+                        "already signed a different unit with sequence number {}",
+                        seq_number
+                    ))
+                }
+            }
+            Some((latest_seq_number, _)) if *latest_seq_number > seq_number => Err(format!(
+                "already signed a unit with sequence number {}, greater than {}",
+                latest_seq_number, seq_number
+            )),
+            _ => {
+                self.0.insert(instance_id, (seq_number, hash));
+                Ok(true)
+            }
+        }
+    }
+}
+
+/// A socket listener.
+enum Listener {
+    Unix(UnixListener),
+    Tcp(TcpListener),
+}
+
+impl Listener {
+    fn bind(endpoint: &Endpoint) -> io::Result<Self> {
+        match endpoint {
+            Endpoint::Unix(path) => {
+                // Remove a socket file left behind by a previous run.
+                if path.exists() {
+                    fs::remove_file(path)?;
+                }
+                Ok(Listener::Unix(UnixListener::bind(path)?))
+            }
+            Endpoint::Tcp(address) => Ok(Listener::Tcp(TcpListener::bind(address)?)),
+        }
+    }
+
+    fn accept(&self) -> io::Result<Socket> {
+        match self {
+            Listener::Unix(listener) => Ok(Socket::Unix(listener.accept()?.0)),
+            Listener::Tcp(listener) => Ok(Socket::Tcp(listener.accept()?.0)),
+        }
+    }
+}
+
+/// The state shared by all connections.
+struct Shared {
+    signer: LocalSigner,
+    signed_units: Mutex<SignedUnits>,
+    signed_units_path: PathBuf,
+    acceptor: SslAcceptor,
+    client_fingerprints: HashSet<KeyFingerprint>,
+}
+
+/// A signing daemon, bound to its endpoint.
+pub struct Daemon {
+    listener: Listener,
+    shared: Arc<Shared>,
+}
+
+impl Daemon {
+    /// Loads the keys and certificates and binds to the configured endpoint.
+    ///
+    /// Relative paths in the config are resolved relative to the config's directory.
+    pub fn bind(config: WithDir<DaemonConfig>) -> anyhow::Result<Self> {
+        let secret_key = config
+            .value()
+            .secret_key_path
+            .clone()
+            .load(config.dir())
+            .context("failed to load validator secret key")?;
+        let cert = tls::load_cert(config.with_dir(config.value().cert_path.clone()))?;
+        let tls_secret_key =
+            tls::load_private_key(config.with_dir(config.value().tls_secret_key_path.clone()))?;
+        let acceptor = tls::create_tls_acceptor(&cert, &tls_secret_key)?;
+        let mut client_fingerprints = HashSet::new();
+        for path in &config.value().client_cert_paths {
+            let client_cert = tls::load_cert(config.with_dir(path.clone()))?;
+            client_fingerprints.insert(tls::validate_cert(client_cert)?.public_key_fingerprint());
+        }
+        if client_fingerprints.is_empty() {
+            bail!("no client certificates configured");
+        }
+        let signed_units_path = config.with_dir(config.value().signed_units_path.clone());
+        let signed_units = SignedUnits::load(&signed_units_path)?;
+        let listener = Listener::bind(&config.value().endpoint)
+            .with_context(|| format!("failed to bind to {:?}", config.value().endpoint))?;
+
+        let signer = LocalSigner::new(Arc::new(secret_key));
+        info!(endpoint = ?config.value().endpoint, public_key = %signer.public_key(), "signing daemon bound");
+        Ok(Daemon {
+            listener,
+            shared: Arc::new(Shared {
+                signer,
+                signed_units: Mutex::new(signed_units),
+                signed_units_path,
+                acceptor,
+                client_fingerprints,
+            }),
+        })
+    }
+
+    /// Accepts connections and serves requests, forever.
+    pub fn run(self) -> anyhow::Result<()> {
+        loop {
+            let socket = self.listener.accept()?;
+            let shared = Arc::clone(&self.shared);
+            thread::spawn(move || {
+                if let Err(error) = shared.serve_connection(socket) {
+                    debug!(%error, "connection closed");
+                }
+            });
+        }
+    }
+}
+
+impl Shared {
+    /// Authenticates the client and serves its requests until the connection is closed.
+    fn serve_connection(&self, socket: Socket) -> Result<(), SignerError> {
+        let mut session = self
+            .acceptor
+            .accept(socket)
+            .map_err(|error| SignerError::Tls(error.to_string()))?;
+        let peer_cert = session
+            .ssl()
+            .peer_certificate()
+            .ok_or(SignerError::WrongCertificate)?;
+        let peer_fingerprint = tls::validate_cert(peer_cert)
+            .map_err(|_| SignerError::WrongCertificate)?
+            .public_key_fingerprint();
+        if !self.client_fingerprints.contains(&peer_fingerprint) {
+            warn!(
+                ?peer_fingerprint,
+                "rejecting client with unknown certificate"
+            );
+            return Err(SignerError::WrongCertificate);
+        }
+        loop {
+            let request = protocol::read_message(&mut session)?;
+            let response = self.handle_request(request);
+            protocol::write_message(&mut session, &response)?;
+        }
+    }
+
+    fn handle_request(&self, request: Request) -> Response {
+        let payload = match request {
+            Request::PublicKey => return Response::PublicKey(self.signer.public_key().clone()),
+            Request::Sign(payload) => payload,
+        };
+        if let SigningPayload::Unit {
+            instance_id,
+            seq_number,
+            hash,
+            ..
+        } = payload
+        {
+            let mut signed_units = self.signed_units.lock().expect("lock poisoned");
+            match signed_units.record(instance_id, seq_number, hash) {
+                Ok(true) => {
+                    // Only sign once the unit is durably recorded.
+                    if let Err(error) = signed_units.save(&self.signed_units_path) {
+                        warn!(%error, "failed to persist signed units");
+                        return Response::Refused("failed to persist signed units".to_string());
+                    }
+                }
+                Ok(false) => (),
+                Err(reason) => {
+                    warn!(%instance_id, seq_number, %hash, %reason, "refusing to sign unit");
+                    return Response::Refused(reason);
+                }
+            }
+        }
+        match self.signer.sign(&payload) {
+            Ok(signature) => Response::Signature(signature),
+            Err(error) => Response::Refused(error.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+    use crate::{
+        crypto::{
+            signer::{RemoteSigner, RemoteSignerConfig, SignatureFormat},
+            AsymmetricKeyExt,
+        },
+        testing::TestRng,
+        types::TimeDiff,
+    };
+
+    #[test]
+    fn should_refuse_conflicting_units() {
+        let instance_id = Digest::from([1; Digest::LENGTH]);
+        let other_instance_id = Digest::from([2; Digest::LENGTH]);
+        let hash0 = Digest::from([3; Digest::LENGTH]);
+        let hash1 = Digest::from([4; Digest::LENGTH]);
+
+        let mut signed_units = SignedUnits::default();
+        assert_eq!(signed_units.record(instance_id, 5, hash0), Ok(true));
+        assert_eq!(signed_units.record(instance_id, 5, hash0), Ok(false));
+        assert!(signed_units.record(instance_id, 5, hash1).is_err());
+        assert!(signed_units.record(instance_id, 4, hash1).is_err());
+        assert_eq!(signed_units.record(instance_id, 6, hash1), Ok(true));
+        assert_eq!(signed_units.record(other_instance_id, 5, hash1), Ok(true));
+    }
+
+    #[test]
+    fn should_sign_via_unix_socket() {
+        let dir = TempDir::new().unwrap();
+        for name in &["node", "daemon"] {
+            let (cert, key) = tls::generate_node_cert().unwrap();
+            tls::save_cert(&cert, dir.path().join(format!("{}_cert.pem", name))).unwrap();
+            tls::save_private_key(&key, dir.path().join(format!("{}_key.pem", name))).unwrap();
+        }
+        let mut rng = TestRng::new();
+        let secret_key = SecretKey::random(&mut rng);
+        let endpoint = Endpoint::Unix(dir.path().join("signer.sock"));
+
+        let daemon_config = DaemonConfig {
+            endpoint: endpoint.clone(),
+            secret_key_path: External::from_value(secret_key),
+            cert_path: "daemon_cert.pem".into(),
+            tls_secret_key_path: "daemon_key.pem".into(),
+            client_cert_paths: vec!["node_cert.pem".into()],
+            signed_units_path: "signed_units.bin".into(),
+        };
+        let daemon = Daemon::bind(WithDir::new(dir.path(), daemon_config)).unwrap();
+        let public_key = daemon.shared.signer.public_key().clone();
+        thread::spawn(move || daemon.run());
+
+        let signer_config = RemoteSignerConfig {
+            endpoint,
+            cert_path: "node_cert.pem".into(),
+            secret_key_path: "node_key.pem".into(),
+            signer_cert_path: "daemon_cert.pem".into(),
+            timeout: TimeDiff::from(5_000),
+        };
+        let signer = RemoteSigner::connect(WithDir::new(dir.path(), signer_config)).unwrap();
+        assert_eq!(*signer.public_key(), public_key);
+
+        let instance_id = Digest::from([1; Digest::LENGTH]);
+        let unit = |seq_number, byte| SigningPayload::Unit {
+            instance_id,
+            seq_number,
+            hash: Digest::from([byte; Digest::LENGTH]),
+            format: SignatureFormat::DomainSeparated,
+        };
+        signer.sign(&unit(0, 2)).unwrap();
+        signer.sign(&unit(0, 2)).unwrap();
+        assert!(matches!(
+            signer.sign(&unit(0, 3)),
+            Err(SignerError::Refused(_))
+        ));
+        signer.sign(&unit(1, 3)).unwrap();
+
+        // The record of signed units survives a restart.
+        let signed_units = SignedUnits::load(&dir.path().join("signed_units.bin")).unwrap();
+        assert_eq!(
+            signed_units.0.get(&instance_id),
+            Some(&(1, Digest::from([3; Digest::LENGTH])))
+        );
+    }
+}
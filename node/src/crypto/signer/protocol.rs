@@ -0,0 +1,159 @@
+//! The wire protocol between a `RemoteSigner` and the signing daemon.
+//!
+//! Both sides authenticate each other via mutual TLS, with each side pinning the other's
+//! certificate.  Within the TLS session, each message is a bincode-serialized `Request` or
+//! `Response`, prefixed with its length as a big-endian `u32`.  The client sends one request at a
+//! time and waits for the response.
+
+use std::{
+    convert::TryFrom,
+    io::{self, Read, Write},
+    net::TcpStream,
+    os::unix::net::UnixStream,
+    time::Duration,
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use casper_types::{PublicKey, Signature};
+
+use super::{SignerError, SigningPayload};
+
+/// The maximum size of a single message, in bytes.
+const MAX_FRAME_SIZE: usize = 4096;
+
+/// A request to the signing daemon.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) enum Request {
+    /// Returns the public key of the key held by the daemon.
+    PublicKey,
+    /// Signs the payload.
+    Sign(SigningPayload),
+}
+
+/// A response from the signing daemon.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) enum Response {
+    /// The public key of the key held by the daemon.
+    PublicKey(PublicKey),
+    /// The signature of the requested payload.
+    Signature(Signature),
+    /// The daemon refused to sign the payload, for the given reason.
+    Refused(String),
+}
+
+/// A stream socket underlying a TLS session with the signing daemon.
+#[derive(Debug)]
+pub(super) enum Socket {
+    Unix(UnixStream),
+    Tcp(TcpStream),
+}
+
+impl Socket {
+    /// Sets the read and write timeouts of the socket.
+    pub(super) fn set_timeout(&self, timeout: Duration) -> io::Result<()> {
+        match self {
+            Socket::Unix(stream) => {
+                stream.set_read_timeout(Some(timeout))?;
+                stream.set_write_timeout(Some(timeout))
+            }
+            Socket::Tcp(stream) => {
+                stream.set_read_timeout(Some(timeout))?;
+                stream.set_write_timeout(Some(timeout))
+            }
+        }
+    }
+}
+
+impl Read for Socket {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Socket::Unix(stream) => stream.read(buf),
+            Socket::Tcp(stream) => stream.read(buf),
+        }
+    }
+}
+
+impl Write for Socket {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Socket::Unix(stream) => stream.write(buf),
+            Socket::Tcp(stream) => stream.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Socket::Unix(stream) => stream.flush(),
+            Socket::Tcp(stream) => stream.flush(),
+        }
+    }
+}
+
+/// Writes a single length-prefixed message to the stream.
+pub(super) fn write_message<W: Write, T: Serialize>(
+    stream: &mut W,
+    message: &T,
+) -> Result<(), SignerError> {
+    let bytes = bincode::serialize(message)?;
+    let length = u32::try_from(bytes.len())
+        .ok()
+        .filter(|length| *length as usize <= MAX_FRAME_SIZE)
+        .ok_or_else(|| SignerError::FrameTooLarge(bytes.len()))?;
+    stream.write_all(&length.to_be_bytes())?;
+    stream.write_all(&bytes)?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// Reads a single length-prefixed message from the stream.
+pub(super) fn read_message<R: Read, T: DeserializeOwned>(stream: &mut R) -> Result<T, SignerError> {
+    let mut length_bytes = [0u8; 4];
+    stream.read_exact(&mut length_bytes)?;
+    let length = u32::from_be_bytes(length_bytes) as usize;
+    if length > MAX_FRAME_SIZE {
+        return Err(SignerError::FrameTooLarge(length));
+    }
+    let mut bytes = vec![0u8; length];
+    stream.read_exact(&mut bytes)?;
+    Ok(bincode::deserialize(&bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::crypto::{hash::Digest, signer::SignatureFormat};
+
+    #[test]
+    fn should_roundtrip_messages() {
+        let payload = SigningPayload::Unit {
+            instance_id: Digest::from([1; Digest::LENGTH]),
+            seq_number: 7,
+            hash: Digest::from([2; Digest::LENGTH]),
+            format: SignatureFormat::DomainSeparated,
+        };
+        let mut buffer = vec![];
+        write_message(&mut buffer, &Request::Sign(payload.clone())).unwrap();
+        write_message(&mut buffer, &Request::PublicKey).unwrap();
+
+        let mut cursor = Cursor::new(buffer);
+        match read_message(&mut cursor).unwrap() {
+            Request::Sign(read_payload) => assert_eq!(read_payload, payload),
+            other => panic!("unexpected request {:?}", other),
+        }
+        assert!(matches!(
+            read_message(&mut cursor).unwrap(),
+            Request::PublicKey
+        ));
+    }
+
+    #[test]
+    fn should_reject_oversized_frame() {
+        let mut bytes = ((MAX_FRAME_SIZE + 1) as u32).to_be_bytes().to_vec();
+        bytes.extend(vec![0; MAX_FRAME_SIZE + 1]);
+        let result: Result<Request, _> = read_message(&mut Cursor::new(bytes));
+        assert!(matches!(result, Err(SignerError::FrameTooLarge(_))));
+    }
+}
@@ -0,0 +1,205 @@
+//! A signer that forwards all signing requests to an external signing daemon.
+
+use std::{
+    io,
+    net::{TcpStream, ToSocketAddrs},
+    os::unix::net::UnixStream,
+    path::PathBuf,
+    sync::Mutex,
+    time::Duration,
+};
+
+use datasize::DataSize;
+use openssl::ssl::{SslConnector, SslStream};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info};
+
+use casper_types::{PublicKey, Signature};
+
+use super::{
+    protocol::{self, Request, Response, Socket},
+    Signer, SignerError, SigningPayload,
+};
+use crate::{
+    crypto,
+    tls::{self, KeyFingerprint},
+    types::TimeDiff,
+    utils::WithDir,
+};
+
+/// The address of the signing daemon.
+#[derive(Clone, DataSize, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Endpoint {
+    /// A Unix domain socket at the given path.
+    Unix(PathBuf),
+    /// A TCP socket at the given address, e.g. `"10.0.0.2:7777"`.
+    Tcp(String),
+}
+
+/// Configuration of the connection to a remote signing daemon.
+#[derive(Clone, DataSize, Debug, Deserialize, Serialize)]
+// Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
+#[serde(deny_unknown_fields)]
+pub struct RemoteSignerConfig {
+    /// The address of the signing daemon.
+    pub endpoint: Endpoint,
+    /// Path to the TLS certificate the node presents to the signing daemon.
+    pub cert_path: PathBuf,
+    /// Path to the secret key of the node's TLS certificate.
+    pub secret_key_path: PathBuf,
+    /// Path to the TLS certificate the signing daemon must present.
+    pub signer_cert_path: PathBuf,
+    /// Timeout for connecting to the signing daemon and for each request.
+    pub timeout: TimeDiff,
+}
+
+/// A signer that forwards all signing requests to an external signing daemon.
+///
+/// Requests are synchronous: A single connection is kept open and reestablished if it fails.
+/// Since signing blocks until the daemon responds, `may_block` returns `true`.
+#[derive(Debug)]
+pub struct RemoteSigner {
+    endpoint: Endpoint,
+    connector: SslConnector,
+    signer_fingerprint: KeyFingerprint,
+    timeout: Duration,
+    public_key: PublicKey,
+    session: Mutex<Option<SslStream<Socket>>>,
+}
+
+impl RemoteSigner {
+    /// Connects to the signing daemon and retrieves the validator's public key.
+    ///
+    /// Relative paths in the config are resolved relative to the config's directory.
+    pub fn connect(config: WithDir<RemoteSignerConfig>) -> Result<Self, SignerError> {
+        let load_error = |error: anyhow::Error| SignerError::Tls(format!("{:#}", error));
+        let cert = tls::load_cert(config.with_dir(config.value().cert_path.clone()))
+            .map_err(load_error)?;
+        let secret_key =
+            tls::load_private_key(config.with_dir(config.value().secret_key_path.clone()))
+                .map_err(load_error)?;
+        let signer_cert = tls::load_cert(config.with_dir(config.value().signer_cert_path.clone()))
+            .map_err(load_error)?;
+        let signer_fingerprint = tls::validate_cert(signer_cert)
+            .map_err(|error| SignerError::Tls(error.to_string()))?
+            .public_key_fingerprint();
+        let connector = tls::create_tls_connector(&cert, &secret_key)
+            .map_err(|error| SignerError::Tls(error.to_string()))?;
+        let (_, config) = config.into_parts();
+
+        let mut session = connect(
+            &config.endpoint,
+            &connector,
+            signer_fingerprint,
+            config.timeout.into(),
+        )?;
+        let public_key = match exchange(&mut session, &Request::PublicKey)? {
+            Response::PublicKey(public_key) => public_key,
+            _ => return Err(SignerError::UnexpectedResponse),
+        };
+        info!(endpoint = ?config.endpoint, %public_key, "connected to remote signer");
+
+        Ok(RemoteSigner {
+            endpoint: config.endpoint,
+            connector,
+            signer_fingerprint,
+            timeout: config.timeout.into(),
+            public_key,
+            session: Mutex::new(Some(session)),
+        })
+    }
+
+    /// Sends the request and returns the response.  If the existing connection fails, retries once
+    /// with a new one.
+    fn request(&self, request: &Request) -> Result<Response, SignerError> {
+        let mut maybe_session = self.session.lock().expect("lock poisoned");
+        if let Some(session) = maybe_session.as_mut() {
+            match exchange(session, request) {
+                Ok(response) => return Ok(response),
+                Err(error) => debug!(%error, "remote signer connection failed, reconnecting"),
+            }
+        }
+        *maybe_session = None;
+        let mut session = connect(
+            &self.endpoint,
+            &self.connector,
+            self.signer_fingerprint,
+            self.timeout,
+        )?;
+        let response = exchange(&mut session, request)?;
+        *maybe_session = Some(session);
+        Ok(response)
+    }
+}
+
+impl Signer for RemoteSigner {
+    fn public_key(&self) -> &PublicKey {
+        &self.public_key
+    }
+
+    fn sign(&self, payload: &SigningPayload) -> Result<Signature, SignerError> {
+        match self.request(&Request::Sign(payload.clone()))? {
+            Response::Signature(signature) => {
+                crypto::verify(payload.bytes_to_sign(), &signature, &self.public_key)
+                    .map_err(|_| SignerError::InvalidSignature)?;
+                Ok(signature)
+            }
+            Response::Refused(reason) => Err(SignerError::Refused(reason)),
+            Response::PublicKey(_) => Err(SignerError::UnexpectedResponse),
+        }
+    }
+
+    fn may_block(&self) -> bool {
+        true
+    }
+}
+
+/// Opens a TLS session with the signing daemon and checks its certificate.
+fn connect(
+    endpoint: &Endpoint,
+    connector: &SslConnector,
+    signer_fingerprint: KeyFingerprint,
+    timeout: Duration,
+) -> Result<SslStream<Socket>, SignerError> {
+    let socket = match endpoint {
+        Endpoint::Unix(path) => Socket::Unix(UnixStream::connect(path)?),
+        Endpoint::Tcp(address) => {
+            let socket_address = address.to_socket_addrs()?.next().ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, "could not resolve address")
+            })?;
+            Socket::Tcp(TcpStream::connect_timeout(&socket_address, timeout)?)
+        }
+    };
+    socket.set_timeout(timeout)?;
+
+    let ssl = connector
+        .configure()
+        .and_then(|mut config| {
+            // We pin the daemon's certificate instead of checking a host name.
+            config.set_verify_hostname(false);
+            config.into_ssl("this-will-not-be-checked.example.com")
+        })
+        .map_err(|error| SignerError::Tls(error.to_string()))?;
+    let session = ssl
+        .connect(socket)
+        .map_err(|error| SignerError::Tls(error.to_string()))?;
+
+    let peer_cert = session
+        .ssl()
+        .peer_certificate()
+        .ok_or(SignerError::WrongCertificate)?;
+    let peer_fingerprint = tls::validate_cert(peer_cert)
+        .map_err(|_| SignerError::WrongCertificate)?
+        .public_key_fingerprint();
+    if peer_fingerprint != signer_fingerprint {
+        return Err(SignerError::WrongCertificate);
+    }
+    Ok(session)
+}
+
+/// Sends a request over the session and reads the response.
+fn exchange(session: &mut SslStream<Socket>, request: &Request) -> Result<Response, SignerError> {
+    protocol::write_message(session, request)?;
+    protocol::read_message(session)
+}
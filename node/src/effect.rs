@@ -88,15 +88,16 @@ use casper_execution_engine::{
         genesis::GenesisResult,
         step::{StepRequest, StepResult},
         upgrade::{UpgradeConfig, UpgradeResult},
-        BalanceRequest, BalanceResult, GetBidsRequest, GetBidsResult, QueryRequest, QueryResult,
-        MAX_PAYMENT,
+        BalanceRequest, BalanceResult, CallContractRequest, CallContractResult, ExecuteRequest,
+        GetAuctionProofsRequest, GetAuctionProofsResult, GetBidsRequest, GetBidsResult,
+        QueryRequest, QueryResult, MAX_PAYMENT,
     },
     shared::{newtypes::Blake2bHash, stored_value::StoredValue},
     storage::{protocol_data::ProtocolData, trie::Trie},
 };
 use casper_types::{
-    system::auction::EraValidators, EraId, ExecutionResult, Key, ProtocolVersion, PublicKey,
-    Transfer, U512,
+    account::AccountHash, system::auction::EraValidators, EraId, ExecutionResult, Key,
+    ProtocolVersion, PublicKey, Transfer, U512,
 };
 
 use crate::{
@@ -113,8 +114,8 @@ use crate::{
     reactor::{EventQueueHandle, QueueKind},
     types::{
         Block, BlockByHeight, BlockHash, BlockHeader, BlockSignatures, Chainspec, ChainspecInfo,
-        Deploy, DeployHash, DeployHeader, DeployMetadata, FinalitySignature, FinalizedBlock, Item,
-        ProtoBlock, TimeDiff, Timestamp,
+        Deploy, DeployHash, DeployHeader, DeployMetadata, EraFault, EraValidatorPerformance,
+        FinalitySignature, FinalizedBlock, HighwayStateDump, Item, ProtoBlock, TimeDiff, Timestamp,
     },
     utils::Source,
 };
@@ -129,7 +130,7 @@ use requests::{
     ProtoBlockRequest, StateStoreRequest, StorageRequest,
 };
 
-use self::announcements::BlocklistAnnouncement;
+use self::announcements::{BlocklistAnnouncement, PeerFailure};
 
 /// A resource that will never be available, thus trying to acquire it will wait forever.
 static UNOBTAINIUM: Lazy<Semaphore> = Lazy::new(|| Semaphore::new(0));
@@ -563,6 +564,19 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Gets the reputation scores of connected network peers.
+    pub async fn network_peer_reputations<I>(self) -> BTreeMap<I, i64>
+    where
+        REv: From<NetworkInfoRequest<I>>,
+        I: Send + 'static,
+    {
+        self.make_request(
+            |responder| NetworkInfoRequest::GetPeerReputations { responder },
+            QueueKind::Api,
+        )
+        .await
+    }
+
     /// Announces that a network message has been received.
     pub(crate) async fn announce_message_received<I, P>(self, sender: I, payload: P)
     where
@@ -978,6 +992,7 @@ impl<REv> EffectBuilder<REv> {
     pub(crate) async fn put_execution_results_to_storage(
         self,
         block_hash: BlockHash,
+        block_height: u64,
         execution_results: HashMap<DeployHash, ExecutionResult>,
     ) where
         REv: From<StorageRequest>,
@@ -985,6 +1000,7 @@ impl<REv> EffectBuilder<REv> {
         self.make_request(
             |responder| StorageRequest::PutExecutionResults {
                 block_hash: Box::new(block_hash),
+                block_height,
                 execution_results,
                 responder,
             },
@@ -1275,6 +1291,22 @@ impl<REv> EffectBuilder<REv> {
             .await
     }
 
+    /// Announce that a peer failed to serve a request, lowering its reputation.
+    pub(crate) async fn announce_peer_failure<I>(self, peer: I, failure: PeerFailure)
+    where
+        REv: From<BlocklistAnnouncement<I>>,
+    {
+        self.0
+            .schedule(
+                BlocklistAnnouncement::PeerFailed {
+                    peer: Box::new(peer),
+                    failure,
+                },
+                QueueKind::Regular,
+            )
+            .await
+    }
+
     /// The linear chain has stored a newly-created block.
     pub(crate) async fn announce_block_added(self, block: Box<Block>)
     where
@@ -1405,6 +1437,60 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Retrieves a page of the deploys sent by the given account, along with the heights of the
+    /// blocks in which they were executed.
+    ///
+    /// Returns `None` if the account deploy index is disabled.
+    pub(crate) async fn get_account_deploys_from_storage(
+        self,
+        account_hash: AccountHash,
+        offset: u32,
+        limit: u32,
+    ) -> Option<Vec<(DeployHash, u64)>>
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            move |responder| StorageRequest::GetAccountDeploys {
+                account_hash: Box::new(account_hash),
+                offset,
+                limit,
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
+    /// Puts the evidence of a validator's fault into storage.
+    ///
+    /// Returns `false` if a fault by the same validator in the same era was already stored.
+    pub(crate) async fn put_era_fault_to_storage(self, era_fault: EraFault) -> bool
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            |responder| StorageRequest::PutEraFault {
+                era_fault: Box::new(era_fault),
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
+    /// Gets the evidence of all faults in the given era from storage.
+    pub(crate) async fn get_era_faults_from_storage(self, era_id: EraId) -> Vec<EraFault>
+    where
+        REv: From<StorageRequest>,
+    {
+        self.make_request(
+            |responder| StorageRequest::GetEraFaults { era_id, responder },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
     /// Save state to storage.
     ///
     /// Key must be a unique key across the the application, as all keys share a common namespace.
@@ -1438,6 +1524,25 @@ impl<REv> EffectBuilder<REv> {
         }
     }
 
+    /// Requests a stored contract's entry point be called on the Contract Runtime component
+    /// without committing its effects.
+    pub(crate) async fn call_contract(
+        self,
+        call_contract_request: CallContractRequest,
+    ) -> Result<CallContractResult, engine_state::Error>
+    where
+        REv: From<ContractRuntimeRequest>,
+    {
+        self.make_request(
+            |responder| ContractRuntimeRequest::CallContract {
+                call_contract_request: Box::new(call_contract_request),
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
     /// Requests a query be executed on the Contract Runtime component.
     pub(crate) async fn query_global_state(
         self,
@@ -1536,6 +1641,25 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Requests the deploys be executed on the Contract Runtime component without committing
+    /// their effects.
+    pub(crate) async fn speculative_execute(
+        self,
+        execute_request: ExecuteRequest,
+    ) -> Result<ExecutionResult, engine_state::Error>
+    where
+        REv: From<ContractRuntimeRequest>,
+    {
+        self.make_request(
+            |responder| ContractRuntimeRequest::SpeculativeExecute {
+                execute_request: Box::new(execute_request),
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
     /// Requests a query be executed on the Contract Runtime component.
     pub(crate) async fn get_bids(
         self,
@@ -1554,6 +1678,24 @@ impl<REv> EffectBuilder<REv> {
         .await
     }
 
+    /// Requests proofs of the bids and era validators from the Contract Runtime component.
+    pub(crate) async fn get_auction_proofs(
+        self,
+        get_auction_proofs_request: GetAuctionProofsRequest,
+    ) -> Result<GetAuctionProofsResult, engine_state::Error>
+    where
+        REv: From<ContractRuntimeRequest>,
+    {
+        self.make_request(
+            |responder| ContractRuntimeRequest::GetAuctionProofs {
+                get_auction_proofs_request,
+                responder,
+            },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
     /// Runs the end of era step using the system smart contract.
     pub(crate) async fn run_step(
         self,
@@ -1687,6 +1829,37 @@ impl<REv> EffectBuilder<REv> {
             .await
     }
 
+    /// Gets a snapshot of the Highway protocol state of the given era, or of the current era if
+    /// `None`.
+    pub(crate) async fn dump_consensus_state(
+        self,
+        era_id: Option<EraId>,
+    ) -> Option<Box<HighwayStateDump>>
+    where
+        REv: From<ConsensusRequest>,
+    {
+        self.make_request(
+            |responder| ConsensusRequest::DumpState { era_id, responder },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
+    /// Gets the validators' participation in the given era, or in the current era if `None`.
+    pub(crate) async fn get_validator_performance(
+        self,
+        era_id: Option<EraId>,
+    ) -> Option<Box<EraValidatorPerformance>>
+    where
+        REv: From<ConsensusRequest>,
+    {
+        self.make_request(
+            |responder| ConsensusRequest::ValidatorPerformance { era_id, responder },
+            QueueKind::Regular,
+        )
+        .await
+    }
+
     /// Collects the key blocks for the eras identified by provided era IDs. Returns
     /// `Some(HashMap(era_id → block_header))` if all the blocks have been read correctly, and
     /// `None` if at least one was missing. The header for EraId `n` is from the key block for that
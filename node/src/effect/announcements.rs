@@ -200,6 +200,13 @@ impl Display for ConsensusAnnouncement {
 pub enum BlocklistAnnouncement<I> {
     /// A given peer committed a blockable offense.
     OffenseCommitted(Box<I>),
+    /// A given peer failed to serve a request, which lowers its reputation.
+    PeerFailed {
+        /// The peer that failed.
+        peer: Box<I>,
+        /// How the peer failed.
+        failure: PeerFailure,
+    },
 }
 
 impl<I> Display for BlocklistAnnouncement<I>
@@ -211,6 +218,24 @@ where
             BlocklistAnnouncement::OffenseCommitted(peer) => {
                 write!(f, "peer {} committed offense", peer)
             }
+            BlocklistAnnouncement::PeerFailed { peer, failure } => {
+                write!(f, "peer {} failed: {}", peer, failure)
+            }
+        }
+    }
+}
+
+/// A way in which a peer can fail to serve a request.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum PeerFailure {
+    /// The peer did not respond in time.
+    TimedOut,
+}
+
+impl Display for PeerFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PeerFailure::TimedOut => write!(f, "request timed out"),
         }
     }
 }
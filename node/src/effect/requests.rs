@@ -20,9 +20,14 @@ use casper_execution_engine::{
     core::engine_state::{
         self,
         balance::{BalanceRequest, BalanceResult},
+        call_contract::{CallContractRequest, CallContractResult, ContractIdentifier},
         era_validators::GetEraValidatorsError,
+        execute_request::ExecuteRequest,
         genesis::GenesisResult,
-        query::{GetBidsRequest, GetBidsResult, QueryRequest, QueryResult},
+        query::{
+            GetAuctionProofsRequest, GetAuctionProofsResult, GetBidsRequest, GetBidsResult,
+            QueryRequest, QueryResult,
+        },
         step::{StepRequest, StepResult},
         upgrade::{UpgradeConfig, UpgradeResult},
     },
@@ -30,8 +35,9 @@ use casper_execution_engine::{
     storage::{protocol_data::ProtocolData, trie::Trie},
 };
 use casper_types::{
+    account::AccountHash,
     system::auction::{EraValidators, ValidatorWeights},
-    EraId, ExecutionResult, Key, ProtocolVersion, PublicKey, Transfer, URef,
+    EraId, ExecutionResult, Key, ProtocolVersion, PublicKey, RuntimeArgs, Transfer, URef,
 };
 
 use super::Responder;
@@ -46,8 +52,9 @@ use crate::{
     rpcs::chain::BlockIdentifier,
     types::{
         Block as LinearBlock, Block, BlockHash, BlockHeader, BlockSignatures, Chainspec,
-        ChainspecInfo, Deploy, DeployHash, DeployHeader, DeployMetadata, FinalizedBlock, Item,
-        NodeId, ProtoBlock, StatusFeed, TimeDiff, Timestamp,
+        ChainspecInfo, Deploy, DeployHash, DeployHeader, DeployMetadata, EraFault,
+        EraValidatorPerformance, FinalizedBlock, HighwayStateDump, Item, NodeId, ProtoBlock,
+        StatusFeed, TimeDiff, Timestamp,
     },
     utils::DisplayIter,
 };
@@ -182,6 +189,11 @@ pub enum NetworkInfoRequest<I> {
         // TODO - change the `String` field to a `libp2p::Multiaddr` once small_network is removed.
         responder: Responder<BTreeMap<I, String>>,
     },
+    /// Get the reputation scores of the connected peers.
+    GetPeerReputations {
+        /// Responder to be called with the reputation score of each connected peer that has one.
+        responder: Responder<BTreeMap<I, i64>>,
+    },
 }
 
 impl<I> Display for NetworkInfoRequest<I>
@@ -191,6 +203,9 @@ where
     fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
         match self {
             NetworkInfoRequest::GetPeers { responder: _ } => write!(formatter, "get peers"),
+            NetworkInfoRequest::GetPeerReputations { responder: _ } => {
+                write!(formatter, "get peer reputations")
+            }
         }
     }
 }
@@ -310,6 +325,8 @@ pub enum StorageRequest {
     PutExecutionResults {
         /// Hash of block.
         block_hash: Box<BlockHash>,
+        /// Height of block.
+        block_height: u64,
         /// Mapping of deploys to execution results of the block.
         execution_results: HashMap<DeployHash, ExecutionResult>,
         /// Responder to call when done storing.
@@ -356,6 +373,34 @@ pub enum StorageRequest {
         /// stored.
         responder: Responder<bool>,
     },
+    /// Retrieve a page of the deploys sent by an account, along with the heights of the blocks in
+    /// which they were executed.
+    GetAccountDeploys {
+        /// Hash of the account.
+        account_hash: Box<AccountHash>,
+        /// Number of deploys to skip.
+        offset: u32,
+        /// Maximum number of deploys to return.
+        limit: u32,
+        /// Responder to call with the results.  Returns `None` if the account deploy index is
+        /// disabled.
+        responder: Responder<Option<Vec<(DeployHash, u64)>>>,
+    },
+    /// Store evidence of a validator's fault in an era.
+    PutEraFault {
+        /// The fault to be stored.
+        era_fault: Box<EraFault>,
+        /// Responder to call with the result.  Returns `true` if the fault was stored, or `false`
+        /// if a fault by the same validator in the same era was already stored.
+        responder: Responder<bool>,
+    },
+    /// Retrieve the evidence of all faults stored for an era.
+    GetEraFaults {
+        /// The era ID.
+        era_id: EraId,
+        /// Responder to call with the results.
+        responder: Responder<Vec<EraFault>>,
+    },
 }
 
 impl Display for StorageRequest {
@@ -430,6 +475,17 @@ impl Display for StorageRequest {
             StorageRequest::GetFinalizedDeploys { ttl, .. } => {
                 write!(formatter, "get finalized deploys, ttl: {:?}", ttl)
             }
+            StorageRequest::GetAccountDeploys { account_hash, .. } => {
+                write!(formatter, "get deploys of account {}", account_hash)
+            }
+            StorageRequest::PutEraFault { era_fault, .. } => write!(
+                formatter,
+                "put fault by {} in era {}",
+                era_fault.public_key, era_fault.era_id
+            ),
+            StorageRequest::GetEraFaults { era_id, .. } => {
+                write!(formatter, "get faults in era {}", era_id)
+            }
         }
     }
 }
@@ -572,6 +628,15 @@ pub enum RpcRequest<I> {
         /// Responder to call with the result.
         responder: Responder<Result<GetBidsResult, engine_state::Error>>,
     },
+    /// Get proofs of the bids and era validators at the given root hash.
+    GetAuctionProofs {
+        /// The global state hash.
+        state_root_hash: Digest,
+        /// The protocol version.
+        protocol_version: ProtocolVersion,
+        /// Responder to call with the result.
+        responder: Responder<Result<GetAuctionProofsResult, engine_state::Error>>,
+    },
     /// Query the contract runtime for protocol version data.
     QueryProtocolData {
         /// The protocol version.
@@ -588,6 +653,39 @@ pub enum RpcRequest<I> {
         /// Responder to call with the result.
         responder: Responder<Result<BalanceResult, engine_state::Error>>,
     },
+    /// Execute the deploy against the given root hash without committing its effects.
+    SpeculativeExecute {
+        /// The state root hash.
+        state_root_hash: Digest,
+        /// The header of the block the state root hash belongs to, if known.
+        block_header: Option<Box<BlockHeader>>,
+        /// The deploy to execute.
+        deploy: Box<Deploy>,
+        /// The protocol version.
+        protocol_version: ProtocolVersion,
+        /// Responder to call with the result.
+        responder: Responder<Result<ExecutionResult, engine_state::Error>>,
+    },
+    /// Call the entry point of a stored contract against the given root hash without committing
+    /// its effects.
+    CallContract {
+        /// The state root hash.
+        state_root_hash: Digest,
+        /// The header of the block the state root hash belongs to, if known.
+        block_header: Option<Box<BlockHeader>>,
+        /// The account making the call, or `None` for the system account.
+        caller: Option<Box<AccountHash>>,
+        /// The contract to call.
+        contract: Box<ContractIdentifier>,
+        /// The name of the entry point to call.
+        entry_point: String,
+        /// The arguments to pass to the entry point.
+        args: RuntimeArgs,
+        /// The protocol version.
+        protocol_version: ProtocolVersion,
+        /// Responder to call with the result.
+        responder: Responder<Result<CallContractResult, engine_state::Error>>,
+    },
     /// Return the specified deploy and metadata if it exists, else `None`.
     GetDeploy {
         /// The hash of the deploy to be retrieved.
@@ -595,11 +693,42 @@ pub enum RpcRequest<I> {
         /// Responder to call with the result.
         responder: Responder<Option<(Deploy, DeployMetadata)>>,
     },
+    /// Return a page of the deploys sent by the specified account, along with the heights of the
+    /// blocks in which they were executed, or `None` if the account deploy index is disabled.
+    GetAccountDeploys {
+        /// The hash of the account.
+        account_hash: Box<AccountHash>,
+        /// The number of deploys to skip.
+        offset: u32,
+        /// The maximum number of deploys to return.
+        limit: u32,
+        /// Responder to call with the result.
+        responder: Responder<Option<Vec<(DeployHash, u64)>>>,
+    },
+    /// Return the evidence of all faults in the given era.
+    GetEraFaults {
+        /// The era ID.
+        era_id: EraId,
+        /// Responder to call with the result.
+        responder: Responder<Vec<EraFault>>,
+    },
     /// Return the connected peers.
     GetPeers {
         /// Responder to call with the result.
         responder: Responder<BTreeMap<I, String>>,
     },
+    /// Return the reputation scores of the connected peers.
+    GetPeerReputations {
+        /// Responder to call with the result.
+        responder: Responder<BTreeMap<I, i64>>,
+    },
+    /// Return the validators' participation in the given era, or in the current era if `None`.
+    GetValidatorPerformance {
+        /// The era ID, or `None` for the current era.
+        era_id: Option<EraId>,
+        /// Responder to call with the result.
+        responder: Responder<Option<Box<EraValidatorPerformance>>>,
+    },
     /// Return string formatted status or `None` if an error occurred.
     GetStatus {
         /// Responder to call with the result.
@@ -649,6 +778,9 @@ impl<I> Display for RpcRequest<I> {
             } => {
                 write!(formatter, "bids {}", state_root_hash)
             }
+            RpcRequest::GetAuctionProofs {
+                state_root_hash, ..
+            } => write!(formatter, "auction proofs {}", state_root_hash),
             RpcRequest::GetBalance {
                 state_root_hash,
                 purse_uref,
@@ -658,8 +790,42 @@ impl<I> Display for RpcRequest<I> {
                 "balance {}, purse_uref: {}",
                 state_root_hash, purse_uref
             ),
+            RpcRequest::SpeculativeExecute {
+                state_root_hash,
+                deploy,
+                ..
+            } => write!(
+                formatter,
+                "speculative execute {} at {}",
+                deploy.id(),
+                state_root_hash
+            ),
+            RpcRequest::CallContract {
+                state_root_hash,
+                contract,
+                entry_point,
+                ..
+            } => write!(
+                formatter,
+                "call contract {:?}, entry_point: {} at {}",
+                contract, entry_point, state_root_hash
+            ),
             RpcRequest::GetDeploy { hash, .. } => write!(formatter, "get {}", hash),
+            RpcRequest::GetAccountDeploys { account_hash, .. } => {
+                write!(formatter, "get deploys of account {}", account_hash)
+            }
+            RpcRequest::GetEraFaults { era_id, .. } => {
+                write!(formatter, "get faults in era {}", era_id)
+            }
             RpcRequest::GetPeers { .. } => write!(formatter, "get peers"),
+            RpcRequest::GetPeerReputations { .. } => write!(formatter, "get peer reputations"),
+            RpcRequest::GetValidatorPerformance {
+                era_id: Some(era_id),
+                ..
+            } => write!(formatter, "get validator performance in era {}", era_id),
+            RpcRequest::GetValidatorPerformance { era_id: None, .. } => {
+                write!(formatter, "get validator performance in current era")
+            }
             RpcRequest::GetStatus { .. } => write!(formatter, "get status"),
             RpcRequest::GetMetrics { .. } => write!(formatter, "get metrics"),
         }
@@ -683,6 +849,14 @@ pub enum RestRequest<I> {
         /// Responder to call with the result.
         responder: Responder<Option<String>>,
     },
+    /// Return a snapshot of the Highway protocol state of the given era, or of the current era if
+    /// `None`.
+    DumpConsensusState {
+        /// The era to dump, or `None` for the current era.
+        era_id: Option<EraId>,
+        /// Responder to call with the result.
+        responder: Responder<Option<Box<HighwayStateDump>>>,
+    },
 }
 
 impl<I> Display for RestRequest<I> {
@@ -690,6 +864,13 @@ impl<I> Display for RestRequest<I> {
         match self {
             RestRequest::GetStatus { .. } => write!(formatter, "get status"),
             RestRequest::GetMetrics { .. } => write!(formatter, "get metrics"),
+            RestRequest::DumpConsensusState {
+                era_id: Some(era_id),
+                ..
+            } => write!(formatter, "dump consensus state of era {}", era_id),
+            RestRequest::DumpConsensusState { era_id: None, .. } => {
+                write!(formatter, "dump consensus state of current era")
+            }
         }
     }
 }
@@ -731,6 +912,14 @@ pub enum ContractRuntimeRequest {
         /// Responder to call with the query result.
         responder: Responder<Result<QueryResult, engine_state::Error>>,
     },
+    /// A request to call a stored contract's entry point without committing its effects.
+    CallContract {
+        /// Call contract request.
+        #[serde(skip_serializing)]
+        call_contract_request: Box<CallContractRequest>,
+        /// Responder to call with the result.
+        responder: Responder<Result<CallContractResult, engine_state::Error>>,
+    },
     /// A balance request.
     GetBalance {
         /// Balance request.
@@ -763,6 +952,22 @@ pub enum ContractRuntimeRequest {
         /// Responder to call with the result.
         responder: Responder<Result<GetBidsResult, engine_state::Error>>,
     },
+    /// Return proofs of the bids and era validators at a given state root hash
+    GetAuctionProofs {
+        /// Get auction proofs request.
+        #[serde(skip_serializing)]
+        get_auction_proofs_request: GetAuctionProofsRequest,
+        /// Responder to call with the result.
+        responder: Responder<Result<GetAuctionProofsResult, engine_state::Error>>,
+    },
+    /// Executes the deploys in the request without committing their effects.
+    SpeculativeExecute {
+        /// The execute request.
+        #[serde(skip_serializing)]
+        execute_request: Box<ExecuteRequest>,
+        /// Responder to call with the result.
+        responder: Responder<Result<ExecutionResult, engine_state::Error>>,
+    },
     /// Performs a step consisting of calculating rewards, slashing and running the auction at the
     /// end of an era.
     Step {
@@ -830,6 +1035,15 @@ impl Display for ContractRuntimeRequest {
                 write!(formatter, "query request: {:?}", query_request)
             }
 
+            ContractRuntimeRequest::CallContract {
+                call_contract_request,
+                ..
+            } => write!(
+                formatter,
+                "call contract request: {:?}",
+                call_contract_request
+            ),
+
             ContractRuntimeRequest::GetBalance {
                 balance_request, ..
             } => write!(formatter, "balance request: {:?}", balance_request),
@@ -848,6 +1062,23 @@ impl Display for ContractRuntimeRequest {
                 write!(formatter, "get bids request: {:?}", get_bids_request)
             }
 
+            ContractRuntimeRequest::GetAuctionProofs {
+                get_auction_proofs_request,
+                ..
+            } => write!(
+                formatter,
+                "get auction proofs request: {:?}",
+                get_auction_proofs_request
+            ),
+
+            ContractRuntimeRequest::SpeculativeExecute {
+                execute_request, ..
+            } => write!(
+                formatter,
+                "speculative execute request: {:?}",
+                execute_request
+            ),
+
             ContractRuntimeRequest::Step { step_request, .. } => {
                 write!(formatter, "step: {:?}", step_request)
             }
@@ -961,6 +1192,22 @@ impl<I: Display> Display for LinearChainRequest<I> {
 pub enum ConsensusRequest {
     /// Request for our public key, and if we're a validator, the next round length.
     Status(Responder<Option<(PublicKey, Option<TimeDiff>)>>),
+    /// Request for a snapshot of the Highway protocol state of the given era, or of the current
+    /// era if `None`.
+    DumpState {
+        /// The era to dump, or `None` for the current era.
+        era_id: Option<EraId>,
+        /// Responder to call with the dump, if the era is active and runs Highway.
+        responder: Responder<Option<Box<HighwayStateDump>>>,
+    },
+    /// Request for the validators' participation in the given era, or in the current era if
+    /// `None`.
+    ValidatorPerformance {
+        /// The era ID, or `None` for the current era.
+        era_id: Option<EraId>,
+        /// Responder to call with the report, if the era is active and runs Highway.
+        responder: Responder<Option<Box<EraValidatorPerformance>>>,
+    },
 }
 
 /// ChainspecLoader component requests.
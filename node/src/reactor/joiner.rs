@@ -48,9 +48,9 @@ use crate::{
     },
     effect::{
         announcements::{
-            ChainspecLoaderAnnouncement, ContractRuntimeAnnouncement, ControlAnnouncement,
-            DeployAcceptorAnnouncement, GossiperAnnouncement, LinearChainAnnouncement,
-            LinearChainBlock, NetworkAnnouncement,
+            BlocklistAnnouncement, ChainspecLoaderAnnouncement, ContractRuntimeAnnouncement,
+            ControlAnnouncement, DeployAcceptorAnnouncement, GossiperAnnouncement,
+            LinearChainAnnouncement, LinearChainBlock, NetworkAnnouncement,
         },
         requests::{
             BlockProposerRequest, BlockValidationRequest, ChainspecLoaderRequest, ConsensusRequest,
@@ -216,6 +216,10 @@ pub enum Event {
     /// Consensus request.
     #[from]
     ConsensusRequest(#[serde(skip_serializing)] ConsensusRequest),
+
+    /// Blocklist announcement.
+    #[from]
+    BlocklistAnnouncement(BlocklistAnnouncement<NodeId>),
 }
 
 impl ReactorEvent for Event {
@@ -322,6 +326,7 @@ impl Display for Event {
             }
             Event::StateStoreRequest(req) => write!(f, "state store request: {}", req),
             Event::ConsensusRequest(req) => write!(f, "consensus request: {:?}", req),
+            Event::BlocklistAnnouncement(ann) => write!(f, "blocklist announcement: {}", ann),
         }
     }
 }
@@ -405,7 +410,7 @@ impl reactor::Reactor for Reactor {
         )?;
         let (small_network, small_network_effects) = SmallNetwork::new(
             event_queue,
-            config.network.clone(),
+            WithDir::new(root.clone(), config.network.clone()),
             registry,
             small_network_identity,
             chainspec_loader.chainspec().as_ref(),
@@ -462,8 +467,11 @@ impl reactor::Reactor for Reactor {
             *protocol_version,
         )?;
 
-        let event_stream_server =
-            EventStreamServer::new(config.event_stream_server.clone(), *protocol_version)?;
+        let event_stream_server = EventStreamServer::new(
+            config.event_stream_server.clone(),
+            storage.root_path(),
+            *protocol_version,
+        )?;
 
         let block_validator = BlockValidator::new(Arc::clone(&chainspec_loader.chainspec()));
 
@@ -882,6 +890,15 @@ impl reactor::Reactor for Reactor {
                 // no consensus, respond with None
                 responder.respond(None).ignore()
             }
+            Event::ConsensusRequest(ConsensusRequest::DumpState { responder, .. }) => {
+                responder.respond(None).ignore()
+            }
+            Event::ConsensusRequest(ConsensusRequest::ValidatorPerformance {
+                responder, ..
+            }) => responder.respond(None).ignore(),
+            Event::BlocklistAnnouncement(ann) => {
+                self.dispatch_event(effect_builder, rng, Event::SmallNetwork(ann.into()))
+            }
         }
     }
 
@@ -30,7 +30,7 @@ use crate::{
         block_proposer::{self, BlockProposer},
         block_validator::{self, BlockValidator},
         chainspec_loader::{self, ChainspecLoader},
-        consensus::{self, EraSupervisor, HighwayProtocol},
+        consensus::{self, EraSupervisor},
         contract_runtime::{self, ContractRuntime},
         deploy_acceptor::{self, DeployAcceptor},
         event_stream_server::{self, EventStreamServer},
@@ -417,7 +417,7 @@ impl reactor::Reactor for Reactor {
         )?;
         let (small_network, small_network_effects) = SmallNetwork::new(
             event_queue,
-            config.network,
+            WithDir::new(root.clone(), config.network),
             registry,
             small_network_identity,
             chainspec_loader.chainspec().as_ref(),
@@ -428,8 +428,12 @@ impl reactor::Reactor for Reactor {
             Gossiper::new_for_complete_items("address_gossiper", config.gossip, registry)?;
 
         let protocol_version = &chainspec_loader.chainspec().protocol_config.version;
-        let rpc_server =
-            RpcServer::new(config.rpc_server.clone(), effect_builder, *protocol_version)?;
+        let rpc_server = RpcServer::new(
+            config.rpc_server.clone(),
+            effect_builder,
+            *protocol_version,
+            registry,
+        )?;
         let rest_server = RestServer::new(
             config.rest_server.clone(),
             effect_builder,
@@ -473,7 +477,7 @@ impl reactor::Reactor for Reactor {
             latest_block.as_ref().map(Block::header),
             maybe_next_activation_point,
             registry,
-            Box::new(HighwayProtocol::new_boxed),
+            Box::new(consensus::new_consensus_protocol),
         )?;
         effects.extend(reactor::wrap_effects(
             Event::Consensus,
@@ -994,8 +998,14 @@ impl reactor::Reactor for Reactor {
                 });
                 effects.extend(self.dispatch_event(effect_builder, rng, reactor_event));
 
-                // send to event stream
+                // send to event stream and WebSocket subscribers
                 for (deploy_hash, (deploy_header, execution_result)) in execution_results {
+                    let reactor_event = Event::RpcServer(rpc_server::Event::DeployProcessed {
+                        deploy_hash,
+                        block_hash,
+                        execution_result: Box::new(execution_result.clone()),
+                    });
+                    effects.extend(self.dispatch_event(effect_builder, rng, reactor_event));
                     let reactor_event =
                         Event::EventStreamServer(event_stream_server::Event::DeployProcessed {
                             deploy_hash,
@@ -1028,6 +1038,8 @@ impl reactor::Reactor for Reactor {
                 let reactor_event =
                     Event::EventStreamServer(event_stream_server::Event::BlockAdded(block.clone()));
                 let mut effects = self.dispatch_event(effect_builder, rng, reactor_event);
+                let reactor_event = Event::RpcServer(rpc_server::Event::BlockAdded(block.clone()));
+                effects.extend(self.dispatch_event(effect_builder, rng, reactor_event));
                 let reactor_event = Event::Consensus(consensus::Event::BlockAdded(block));
                 effects.extend(self.dispatch_event(effect_builder, rng, reactor_event));
                 effects
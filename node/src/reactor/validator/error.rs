@@ -1,7 +1,9 @@
 use thiserror::Error;
 
 use crate::{
-    components::{contract_runtime, network, small_network, storage},
+    components::{
+        contract_runtime, event_stream_server, network, rpc_server, small_network, storage,
+    },
     utils::ListeningError,
 };
 
@@ -24,6 +26,14 @@ pub enum Error {
     #[error("http server listening error: {0}")]
     ListeningError(#[from] ListeningError),
 
+    /// `RpcServer` component error.
+    #[error("rpc server error: {0}")]
+    RpcServer(#[from] rpc_server::Error),
+
+    /// `EventStreamServer` component error.
+    #[error("event stream server error: {0}")]
+    EventStreamServer(#[from] event_stream_server::Error),
+
     /// `Storage` component error.
     #[error("storage error: {0}")]
     Storage(#[from] storage::Error),
@@ -4,7 +4,9 @@ pub(crate) mod appendable_block;
 mod block;
 pub mod chainspec;
 mod deploy;
+mod era_fault;
 mod exit_code;
+mod highway_state_dump;
 mod item;
 pub mod json_compatibility;
 mod node_config;
@@ -12,6 +14,7 @@ mod node_id;
 mod peers_map;
 mod status_feed;
 mod timestamp;
+mod validator_performance;
 
 use rand::{CryptoRng, RngCore};
 #[cfg(not(test))]
@@ -28,13 +31,19 @@ pub use deploy::{
     Approval, Deploy, DeployHash, DeployHeader, DeployMetadata, DeployValidationFailure,
     Error as DeployError, ExcessiveSizeError as ExcessiveSizeDeployError,
 };
+pub(crate) use deploy::{LegacyDeployMetadata, LegacyExecutionResult};
+pub use era_fault::{EraFault, EraFaultError};
 pub use exit_code::ExitCode;
+pub use highway_state_dump::{
+    HighwayStateDump, ObservationDump, SummitDump, UnitDump, ValidatorDump,
+};
 pub use item::{Item, Tag};
 pub use node_config::NodeConfig;
 pub(crate) use node_id::NodeId;
 pub use peers_map::PeersMap;
 pub use status_feed::{ChainspecInfo, GetStatusResult, StatusFeed};
 pub use timestamp::{TimeDiff, Timestamp};
+pub use validator_performance::{EraValidatorPerformance, ValidatorPerformance};
 
 /// An object-safe RNG trait that requires a cryptographically strong random number generator.
 pub trait CryptoRngCore: CryptoRng + RngCore {}
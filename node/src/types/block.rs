@@ -1113,7 +1113,8 @@ impl Block {
         &self.hash
     }
 
-    pub(crate) fn state_root_hash(&self) -> &Digest {
+    /// The root hash of the global state after the deploys in this block were executed.
+    pub fn state_root_hash(&self) -> &Digest {
         self.header.state_root_hash()
     }
 
@@ -1617,8 +1618,7 @@ impl FinalitySignature {
         secret_key: &SecretKey,
         public_key: PublicKey,
     ) -> Self {
-        let mut bytes = block_hash.inner().to_vec();
-        bytes.extend_from_slice(&era_id.to_le_bytes());
+        let bytes = Self::bytes_to_sign(&block_hash, era_id);
         let signature = crypto::sign(bytes, &secret_key, &public_key);
         FinalitySignature {
             block_hash,
@@ -1630,12 +1630,17 @@ impl FinalitySignature {
 
     /// Verifies whether the signature is correct.
     pub fn verify(&self) -> crypto::Result<()> {
-        // NOTE: This needs to be in sync with the `new` constructor.
-        let mut bytes = self.block_hash.inner().to_vec();
-        bytes.extend_from_slice(&self.era_id.to_le_bytes());
+        let bytes = Self::bytes_to_sign(&self.block_hash, self.era_id);
         crypto::verify(bytes, &self.signature, &self.public_key)
     }
 
+    /// Returns the bytes a validator signs to confirm that the given block is finalized.
+    pub(crate) fn bytes_to_sign(block_hash: &BlockHash, era_id: EraId) -> Vec<u8> {
+        let mut bytes = block_hash.inner().to_vec();
+        bytes.extend_from_slice(&era_id.to_le_bytes());
+        bytes
+    }
+
     #[cfg(test)]
     pub fn random_for_block(block_hash: BlockHash, era_id: u64) -> Self {
         let (sec_key, pub_key) = generate_ed25519_keypair();
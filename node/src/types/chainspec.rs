@@ -33,9 +33,14 @@ use casper_types::{
 pub(crate) use self::accounts_config::{AccountConfig, ValidatorConfig};
 pub use self::error::Error;
 pub(crate) use self::{
-    accounts_config::AccountsConfig, activation_point::ActivationPoint, core_config::CoreConfig,
-    deploy_config::DeployConfig, global_state_update::GlobalStateUpdate,
-    highway_config::HighwayConfig, network_config::NetworkConfig, protocol_config::ProtocolConfig,
+    accounts_config::AccountsConfig,
+    activation_point::ActivationPoint,
+    core_config::{ConsensusProtocolName, CoreConfig},
+    deploy_config::DeployConfig,
+    global_state_update::GlobalStateUpdate,
+    highway_config::HighwayConfig,
+    network_config::NetworkConfig,
+    protocol_config::ProtocolConfig,
 };
 #[cfg(test)]
 use crate::testing::TestRng;
@@ -269,6 +274,19 @@ mod tests {
             remove_contract_user_group_urefs: HostFunction::new(131, [0, 1, 2, 3, 4, 5]),
             print: HostFunction::new(123, [0, 1]),
             blake2b: HostFunction::new(133, [0, 1, 2, 3]),
+            emit_event: HostFunction::new(142, [0, 1, 2, 3]),
+            verify_signature: HostFunction::new(143, [0, 1, 2, 3, 4, 5]),
+            sha256: HostFunction::new(144, [0, 1, 2, 3]),
+            keccak256: HostFunction::new(145, [0, 1, 2, 3]),
+            random_seed: HostFunction::new(146, [0, 1]),
+            get_block_height: HostFunction::new(147, [0]),
+            get_era_id: HostFunction::new(148, [0]),
+            get_parent_block_hash: HostFunction::new(149, [0]),
+            get_deploy_hash: HostFunction::new(150, [0]),
+            load_call_stack: HostFunction::new(151, [0, 1]),
+            new_dictionary: HostFunction::new(152, [0]),
+            dictionary_get: HostFunction::new(153, [0, 1, 2, 3, 4]),
+            dictionary_put: HostFunction::new(154, [0, 1, 2, 3, 4, 5]),
         });
     static EXPECTED_GENESIS_WASM_COSTS: Lazy<WasmConfig> = Lazy::new(|| {
         WasmConfig::new(
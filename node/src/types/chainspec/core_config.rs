@@ -4,12 +4,68 @@ use num::rational::Ratio;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+use casper_types::bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH};
 
 #[cfg(test)]
 use crate::testing::TestRng;
 use crate::types::TimeDiff;
 
+const HIGHWAY_TAG: u8 = 0;
+const SIMPLE_BFT_TAG: u8 = 1;
+
+/// The consensus protocol used to agree on the blocks in each era.
+#[derive(Copy, Clone, DataSize, PartialEq, Eq, Serialize, Deserialize, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsensusProtocolName {
+    /// The Highway protocol.
+    Highway,
+    /// A simple round-based BFT protocol with a round-robin leader.
+    SimpleBft,
+}
+
+impl Default for ConsensusProtocolName {
+    fn default() -> Self {
+        ConsensusProtocolName::Highway
+    }
+}
+
+#[cfg(test)]
+impl ConsensusProtocolName {
+    /// Generates a random instance using a `TestRng`.
+    pub fn random(rng: &mut TestRng) -> Self {
+        if rng.gen() {
+            ConsensusProtocolName::Highway
+        } else {
+            ConsensusProtocolName::SimpleBft
+        }
+    }
+}
+
+impl ToBytes for ConsensusProtocolName {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let tag = match self {
+            ConsensusProtocolName::Highway => HIGHWAY_TAG,
+            ConsensusProtocolName::SimpleBft => SIMPLE_BFT_TAG,
+        };
+        Ok(vec![tag])
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+    }
+}
+
+impl FromBytes for ConsensusProtocolName {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            HIGHWAY_TAG => Ok((ConsensusProtocolName::Highway, remainder)),
+            SIMPLE_BFT_TAG => Ok((ConsensusProtocolName::SimpleBft, remainder)),
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
 #[derive(Copy, Clone, DataSize, PartialEq, Eq, Serialize, Deserialize, Debug)]
 // Disallow unknown fields to ensure config files and command-line overrides contain valid keys.
 #[serde(deny_unknown_fields)]
@@ -28,6 +84,9 @@ pub struct CoreConfig {
     /// Round seigniorage rate represented as a fractional number.
     #[data_size(skip)]
     pub(crate) round_seigniorage_rate: Ratio<u64>,
+    /// The consensus protocol to use.
+    #[serde(default)]
+    pub(crate) consensus_protocol: ConsensusProtocolName,
 }
 
 #[cfg(test)]
@@ -44,6 +103,7 @@ impl CoreConfig {
             rng.gen_range(1..1_000_000_000),
             rng.gen_range(1..1_000_000_000),
         );
+        let consensus_protocol = ConsensusProtocolName::random(rng);
 
         CoreConfig {
             era_duration,
@@ -53,6 +113,7 @@ impl CoreConfig {
             locked_funds_period,
             unbonding_delay,
             round_seigniorage_rate,
+            consensus_protocol,
         }
     }
 }
@@ -67,6 +128,7 @@ impl ToBytes for CoreConfig {
         buffer.extend(self.locked_funds_period.to_bytes()?);
         buffer.extend(self.unbonding_delay.to_bytes()?);
         buffer.extend(self.round_seigniorage_rate.to_bytes()?);
+        buffer.extend(self.consensus_protocol.to_bytes()?);
         Ok(buffer)
     }
 
@@ -78,6 +140,7 @@ impl ToBytes for CoreConfig {
             + self.locked_funds_period.serialized_length()
             + self.unbonding_delay.serialized_length()
             + self.round_seigniorage_rate.serialized_length()
+            + self.consensus_protocol.serialized_length()
     }
 }
 
@@ -90,6 +153,7 @@ impl FromBytes for CoreConfig {
         let (locked_funds_period, remainder) = TimeDiff::from_bytes(remainder)?;
         let (unbonding_delay, remainder) = u64::from_bytes(remainder)?;
         let (round_seigniorage_rate, remainder) = Ratio::<u64>::from_bytes(remainder)?;
+        let (consensus_protocol, remainder) = ConsensusProtocolName::from_bytes(remainder)?;
         let config = CoreConfig {
             era_duration,
             minimum_era_height,
@@ -98,6 +162,7 @@ impl FromBytes for CoreConfig {
             locked_funds_period,
             unbonding_delay,
             round_seigniorage_rate,
+            consensus_protocol,
         };
         Ok((config, remainder))
     }
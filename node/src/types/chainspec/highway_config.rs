@@ -4,8 +4,12 @@ use num::rational::Ratio;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    EraId,
+};
 
+use crate::crypto::signer::SignatureFormat;
 #[cfg(test)]
 use crate::testing::TestRng;
 #[cfg(not(feature = "fast-sync"))]
@@ -23,6 +27,11 @@ pub(crate) struct HighwayConfig {
     /// quorum, i.e. no finality.
     #[data_size(skip)]
     pub(crate) reduced_reward_multiplier: Ratio<u64>,
+    /// The era from which units and other consensus messages are signed over domain-separated
+    /// payloads, usually the activation point of an upgrade.  Earlier eras, or all eras if this is
+    /// not set, sign the raw hashes.
+    #[serde(default)]
+    pub(crate) domain_separated_signatures_activation_point: Option<EraId>,
 }
 
 impl HighwayConfig {
@@ -55,6 +64,17 @@ impl HighwayConfig {
         }
     }
 
+    /// Returns the format of the bytes signed for units and other consensus messages in the given
+    /// era.
+    pub(crate) fn signature_format(&self, era_id: EraId) -> SignatureFormat {
+        match self.domain_separated_signatures_activation_point {
+            Some(activation_point) if era_id >= activation_point => {
+                SignatureFormat::DomainSeparated
+            }
+            _ => SignatureFormat::Legacy,
+        }
+    }
+
     /// Returns the length of the longest allowed round.
     #[cfg(not(feature = "fast-sync"))]
     pub fn max_round_length(&self) -> TimeDiff {
@@ -76,12 +96,18 @@ impl HighwayConfig {
         let minimum_round_exponent = rng.gen_range(0..16);
         let maximum_round_exponent = rng.gen_range(16..22);
         let reduced_reward_multiplier = Ratio::new(rng.gen_range(0..10), 10);
+        let domain_separated_signatures_activation_point = if rng.gen() {
+            Some(EraId::from(rng.gen_range(0..10)))
+        } else {
+            None
+        };
 
         HighwayConfig {
             finality_threshold_fraction,
             minimum_round_exponent,
             maximum_round_exponent,
             reduced_reward_multiplier,
+            domain_separated_signatures_activation_point,
         }
     }
 }
@@ -93,6 +119,10 @@ impl ToBytes for HighwayConfig {
         buffer.extend(self.minimum_round_exponent.to_bytes()?);
         buffer.extend(self.maximum_round_exponent.to_bytes()?);
         buffer.extend(self.reduced_reward_multiplier.to_bytes()?);
+        buffer.extend(
+            self.domain_separated_signatures_activation_point
+                .to_bytes()?,
+        );
         Ok(buffer)
     }
 
@@ -101,6 +131,9 @@ impl ToBytes for HighwayConfig {
             + self.minimum_round_exponent.serialized_length()
             + self.maximum_round_exponent.serialized_length()
             + self.reduced_reward_multiplier.serialized_length()
+            + self
+                .domain_separated_signatures_activation_point
+                .serialized_length()
     }
 }
 
@@ -110,11 +143,14 @@ impl FromBytes for HighwayConfig {
         let (minimum_round_exponent, remainder) = u8::from_bytes(remainder)?;
         let (maximum_round_exponent, remainder) = u8::from_bytes(remainder)?;
         let (reduced_reward_multiplier, remainder) = Ratio::<u64>::from_bytes(remainder)?;
+        let (domain_separated_signatures_activation_point, remainder) =
+            Option::<EraId>::from_bytes(remainder)?;
         let config = HighwayConfig {
             finality_threshold_fraction,
             minimum_round_exponent,
             maximum_round_exponent,
             reduced_reward_multiplier,
+            domain_separated_signatures_activation_point,
         };
         Ok((config, remainder))
     }
@@ -131,6 +167,28 @@ mod tests {
         bytesrepr::test_serialization_roundtrip(&config);
     }
 
+    #[test]
+    fn should_use_domain_separated_signatures_from_activation_point() {
+        let mut rng = crate::new_rng();
+        let mut config = HighwayConfig::random(&mut rng);
+
+        config.domain_separated_signatures_activation_point = None;
+        assert_eq!(
+            config.signature_format(EraId::from(100)),
+            SignatureFormat::Legacy
+        );
+
+        config.domain_separated_signatures_activation_point = Some(EraId::from(5));
+        assert_eq!(
+            config.signature_format(EraId::from(4)),
+            SignatureFormat::Legacy
+        );
+        assert_eq!(
+            config.signature_format(EraId::from(5)),
+            SignatureFormat::DomainSeparated
+        );
+    }
+
     #[test]
     fn toml_roundtrip() {
         let mut rng = crate::new_rng();
@@ -28,7 +28,8 @@ use casper_types::{
     bytesrepr::{self, FromBytes, ToBytes},
     runtime_args,
     system::standard_payment::ARG_AMOUNT,
-    AsymmetricType, ExecutionResult, PublicKey, RuntimeArgs, SecretKey, Signature, U512,
+    AsymmetricType, ExecutionEffect, ExecutionResult, PublicKey, RuntimeArgs, SecretKey, Signature,
+    TransferAddr, U512,
 };
 
 use super::{BlockHash, Item, Tag, TimeDiff, Timestamp};
@@ -886,6 +887,73 @@ pub struct DeployMetadata {
     pub execution_results: HashMap<BlockHash, ExecutionResult>,
 }
 
+/// The deploy metadata as serialized by versions which didn't record contract events.
+///
+/// Only used to migrate data persisted by those versions.
+#[derive(Deserialize)]
+pub(crate) struct LegacyDeployMetadata {
+    execution_results: HashMap<BlockHash, LegacyExecutionResult>,
+}
+
+impl From<LegacyDeployMetadata> for DeployMetadata {
+    fn from(legacy_metadata: LegacyDeployMetadata) -> Self {
+        let execution_results = legacy_metadata
+            .execution_results
+            .into_iter()
+            .map(|(block_hash, execution_result)| (block_hash, execution_result.into()))
+            .collect();
+        DeployMetadata { execution_results }
+    }
+}
+
+/// An execution result as serialized by versions which didn't record contract events.
+///
+/// The variants and fields need to stay in the order of the old `ExecutionResult`, as its
+/// serialized form doesn't include their names.
+#[derive(Deserialize)]
+pub(crate) enum LegacyExecutionResult {
+    Failure {
+        effect: ExecutionEffect,
+        transfers: Vec<TransferAddr>,
+        cost: U512,
+        error_message: String,
+    },
+    Success {
+        effect: ExecutionEffect,
+        transfers: Vec<TransferAddr>,
+        cost: U512,
+    },
+}
+
+impl From<LegacyExecutionResult> for ExecutionResult {
+    fn from(legacy_result: LegacyExecutionResult) -> Self {
+        match legacy_result {
+            LegacyExecutionResult::Failure {
+                effect,
+                transfers,
+                cost,
+                error_message,
+            } => ExecutionResult::Failure {
+                effect,
+                transfers,
+                events: vec![],
+                cost,
+                error_message,
+            },
+            LegacyExecutionResult::Success {
+                effect,
+                transfers,
+                cost,
+            } => ExecutionResult::Success {
+                effect,
+                transfers,
+                events: vec![],
+                cost,
+            },
+        }
+    }
+}
+
 impl ToBytes for Deploy {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut buffer = bytesrepr::allocate_buffer(self)?;
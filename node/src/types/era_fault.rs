@@ -0,0 +1,163 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use once_cell::sync::Lazy;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use casper_types::{bytesrepr::Bytes, EraId, PublicKey, SecretKey};
+
+use crate::{
+    components::consensus::{self, EvidenceError},
+    crypto::{hash::Digest, signer::SignatureFormat, AsymmetricKeyExt},
+    rpcs::docs::DocExample,
+    types::{TimeDiff, Timestamp},
+};
+
+static ERA_FAULT: Lazy<EraFault> = Lazy::new(|| {
+    let instance_id = Digest::from([3u8; Digest::LENGTH]);
+    let timestamp = *Timestamp::doc_example();
+    let evidence = consensus::serialized_equivocation(
+        SecretKey::doc_example(),
+        PublicKey::doc_example(),
+        instance_id,
+        (timestamp, timestamp + TimeDiff::from(1)),
+        SignatureFormat::DomainSeparated,
+    );
+    EraFault {
+        era_id: EraId::from(1),
+        public_key: PublicKey::doc_example().clone(),
+        endorsed_creator: None,
+        instance_id,
+        timestamp,
+        evidence: evidence.into(),
+    }
+});
+
+/// An error verifying an `EraFault`.
+#[derive(Debug, Error)]
+pub enum EraFaultError {
+    /// The evidence could not be deserialized.
+    #[error("failed to deserialize evidence: {0}")]
+    Deserialization(#[from] bincode::Error),
+
+    /// The evidence does not prove the validator faulty.
+    #[error("invalid evidence: {0}")]
+    InvalidEvidence(#[from] EvidenceError),
+}
+
+/// Cryptographic proof that a validator was faulty in an era.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EraFault {
+    /// The era in which the fault was committed.
+    pub era_id: EraId,
+    /// The public key of the faulty validator.
+    pub public_key: PublicKey,
+    /// If the fault was endorsing conflicting units, the public key of the validator who created
+    /// them.
+    pub endorsed_creator: Option<PublicKey>,
+    /// The ID of the era's consensus protocol instance, which all units in the evidence must
+    /// belong to.
+    pub instance_id: Digest,
+    /// The time at which this node received the evidence.
+    pub timestamp: Timestamp,
+    /// The evidence, being the serialized conflicting units and endorsements signed by the faulty
+    /// validator.
+    #[schemars(with = "String", description = "Hex-encoded serialized evidence.")]
+    pub evidence: Bytes,
+}
+
+impl EraFault {
+    /// Verifies that the evidence proves the validator faulty in the consensus instance
+    /// `instance_id`.
+    ///
+    /// Apart from the fault itself, this only requires the format of the signatures in `era_id`,
+    /// which is `SignatureFormat::Legacy` before the era configured in the chainspec and
+    /// `SignatureFormat::DomainSeparated` from then on.  A third party should also check that
+    /// `instance_id` is the expected instance ID for `era_id`, and that `public_key` and
+    /// `endorsed_creator` (if any) were validators in that era.
+    pub fn verify(&self, signature_format: SignatureFormat) -> Result<(), EraFaultError> {
+        consensus::validate_serialized_evidence(
+            self.evidence.as_ref(),
+            &self.public_key,
+            self.endorsed_creator.as_ref(),
+            &self.instance_id,
+            signature_format,
+        )
+    }
+}
+
+impl DocExample for EraFault {
+    fn doc_example() -> &'static Self {
+        &*ERA_FAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn doc_example_should_verify() {
+        EraFault::doc_example()
+            .verify(SignatureFormat::DomainSeparated)
+            .unwrap();
+    }
+
+    #[test]
+    fn should_fail_to_verify_with_wrong_key_or_instance() {
+        let mut era_fault = EraFault::doc_example().clone();
+        era_fault.instance_id = Digest::from([4u8; Digest::LENGTH]);
+        assert!(matches!(
+            era_fault.verify(SignatureFormat::DomainSeparated),
+            Err(EraFaultError::InvalidEvidence(
+                EvidenceError::EquivocationInstanceId
+            ))
+        ));
+
+        let mut era_fault = EraFault::doc_example().clone();
+        let secret_key = SecretKey::ed25519_from_bytes([1u8; SecretKey::ED25519_LENGTH]).unwrap();
+        era_fault.public_key = PublicKey::from(&secret_key);
+        assert!(matches!(
+            era_fault.verify(SignatureFormat::DomainSeparated),
+            Err(EraFaultError::InvalidEvidence(EvidenceError::Signature))
+        ));
+    }
+
+    #[test]
+    fn should_verify_only_in_the_format_of_the_era() {
+        assert!(matches!(
+            EraFault::doc_example().verify(SignatureFormat::Legacy),
+            Err(EraFaultError::InvalidEvidence(EvidenceError::Signature))
+        ));
+
+        // Evidence from eras before the domain-separated format activated is signed over the raw
+        // unit hashes.
+        let mut era_fault = EraFault::doc_example().clone();
+        era_fault.evidence = consensus::serialized_equivocation(
+            SecretKey::doc_example(),
+            PublicKey::doc_example(),
+            era_fault.instance_id,
+            (era_fault.timestamp, era_fault.timestamp + TimeDiff::from(1)),
+            SignatureFormat::Legacy,
+        )
+        .into();
+        era_fault.verify(SignatureFormat::Legacy).unwrap();
+        assert!(matches!(
+            era_fault.verify(SignatureFormat::DomainSeparated),
+            Err(EraFaultError::InvalidEvidence(EvidenceError::Signature))
+        ));
+    }
+
+    #[test]
+    fn should_fail_to_verify_garbage() {
+        let mut era_fault = EraFault::doc_example().clone();
+        era_fault.evidence = vec![1, 2, 3].into();
+        assert!(matches!(
+            era_fault.verify(SignatureFormat::DomainSeparated),
+            Err(EraFaultError::Deserialization(_))
+        ));
+    }
+}
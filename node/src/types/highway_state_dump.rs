@@ -0,0 +1,186 @@
+//! A snapshot of the Highway protocol state of an era, for debugging.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Write,
+};
+
+use datasize::DataSize;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{EraId, PublicKey};
+
+use crate::{crypto::hash::Digest, types::Timestamp};
+
+/// A snapshot of the Highway protocol state of an era: the units in the protocol DAG, and the
+/// progress of the finality detector.
+#[derive(Clone, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HighwayStateDump {
+    /// The era ID.
+    pub era_id: EraId,
+    /// The ID of the era's consensus protocol instance.
+    pub instance_id: Digest,
+    /// The fault tolerance threshold, as a weight.
+    pub fault_tolerance_threshold: u64,
+    /// The validators, ordered by index.
+    pub validators: Vec<ValidatorDump>,
+    /// All units, ordered by timestamp, creator and sequence number.
+    pub units: Vec<UnitDump>,
+    /// The finalized blocks, by the hash of the unit which proposed them, in ascending order of
+    /// height.
+    pub finalized_blocks: Vec<Digest>,
+    /// The blocks in the current fork choice which are not yet finalized, with the level of the
+    /// highest summit the finality detector found for them, in ascending order of height.
+    pub summits: Vec<SummitDump>,
+}
+
+/// A validator in a `HighwayStateDump`.
+#[derive(Clone, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ValidatorDump {
+    /// The validator's index, used to refer to it in units.
+    pub index: u32,
+    /// The validator's public key.
+    pub public_key: PublicKey,
+    /// The validator's weight.
+    pub weight: u64,
+    /// Whether the validator is known to be faulty.
+    pub faulty: bool,
+}
+
+/// A unit in a `HighwayStateDump`.
+#[derive(Clone, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct UnitDump {
+    /// The unit's hash.
+    pub hash: Digest,
+    /// The index of the validator who created the unit.
+    pub creator: u32,
+    /// The number of earlier units by the same creator.
+    pub seq_number: u64,
+    /// The unit's timestamp.
+    pub timestamp: Timestamp,
+    /// The unit's round exponent.
+    pub round_exp: u8,
+    /// The block the unit votes for, by the hash of the unit which proposed it.
+    pub block: Digest,
+    /// The height of the block the unit proposes, if it is a proposal.
+    pub proposed_height: Option<u64>,
+    /// The latest units by each validator that the unit cites, ordered by validator index.
+    pub panorama: Vec<ObservationDump>,
+    /// The units this unit cites as endorsed.
+    pub endorsed: Vec<Digest>,
+    /// The indices of the validators known to have endorsed this unit.
+    pub endorsements: Vec<u32>,
+}
+
+/// A unit's observation of a validator in a `HighwayStateDump`.
+#[derive(Clone, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum ObservationDump {
+    /// No unit by the validator was seen.
+    None,
+    /// The validator's latest unit.
+    Correct(Digest),
+    /// The validator was seen to be faulty.
+    Faulty,
+}
+
+/// A block which is not yet finalized, and the finality detector's progress towards finalizing
+/// it.
+#[derive(Clone, DataSize, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SummitDump {
+    /// The block, by the hash of the unit which proposed it.
+    pub block: Digest,
+    /// The block's height.
+    pub height: u64,
+    /// The level of the highest summit for the block.  A level greater than zero means the block
+    /// is finalized.
+    pub level: usize,
+}
+
+impl HighwayStateDump {
+    /// Renders the protocol DAG in the graphviz DOT format.
+    ///
+    /// Each validator's units are drawn in a separate row, with edges to the previous unit by the
+    /// same creator and to all units by other validators which are cited for the first time.
+    /// Proposals are drawn as boxes, finalized ones filled, and faulty validators' units are red.
+    pub fn to_dot(&self) -> String {
+        let finalized: HashSet<&Digest> = self.finalized_blocks.iter().collect();
+        let summits: BTreeMap<&Digest, usize> = self
+            .summits
+            .iter()
+            .map(|summit| (&summit.block, summit.level))
+            .collect();
+        let units: BTreeMap<&Digest, &UnitDump> =
+            self.units.iter().map(|unit| (&unit.hash, unit)).collect();
+
+        let mut dot = String::new();
+        // Writing to a `String` cannot fail.
+        let _ = writeln!(dot, "digraph \"era {}\" {{", self.era_id);
+        let _ = writeln!(dot, "  rankdir=LR;");
+        let _ = writeln!(dot, "  node [fontname=\"monospace\", fontsize=10];");
+
+        for validator in &self.validators {
+            let _ = writeln!(dot, "  subgraph cluster_{} {{", validator.index);
+            let _ = writeln!(
+                dot,
+                "    label=\"validator {} ({}), weight {}{}\";",
+                validator.index,
+                validator.public_key,
+                validator.weight,
+                if validator.faulty { ", faulty" } else { "" }
+            );
+            for unit in self
+                .units
+                .iter()
+                .filter(|unit| unit.creator == validator.index)
+            {
+                let mut label = format!("{}:{} {}", unit.creator, unit.seq_number, unit.hash);
+                let mut attributes = vec![];
+                if let Some(height) = unit.proposed_height {
+                    let _ = write!(label, "\\nheight {}", height);
+                    attributes.push("shape=box".to_string());
+                    if finalized.contains(&unit.hash) {
+                        attributes.push("style=filled".to_string());
+                        attributes.push("fillcolor=lightgreen".to_string());
+                    } else if let Some(level) = summits.get(&unit.hash) {
+                        let _ = write!(label, "\\nsummit level {}", level);
+                    }
+                }
+                if validator.faulty {
+                    attributes.push("color=red".to_string());
+                }
+                attributes.push(format!("label=\"{}\"", label));
+                let _ = writeln!(dot, "    \"{:x}\" [{}];", unit.hash, attributes.join(", "));
+            }
+            let _ = writeln!(dot, "  }}");
+        }
+
+        for unit in &self.units {
+            let previous_panorama = match unit.panorama.get(unit.creator as usize) {
+                Some(ObservationDump::Correct(previous)) => {
+                    units.get(previous).map(|previous| &previous.panorama)
+                }
+                _ => None,
+            };
+            for (index, observation) in unit.panorama.iter().enumerate() {
+                let cited = match observation {
+                    ObservationDump::Correct(cited) => cited,
+                    ObservationDump::None | ObservationDump::Faulty => continue,
+                };
+                let newly_cited = index == unit.creator as usize
+                    || previous_panorama
+                        .map_or(true, |panorama| panorama.get(index) != Some(observation));
+                if newly_cited {
+                    let _ = writeln!(dot, "  \"{:x}\" -> \"{:x}\";", unit.hash, cited);
+                }
+            }
+        }
+
+        let _ = writeln!(dot, "}}");
+        dot
+    }
+}
@@ -87,6 +87,13 @@ pub struct JsonEraValidators {
     validator_weights: Vec<JsonValidatorWeights>,
 }
 
+impl JsonEraValidators {
+    /// Returns the era to which these validators belong.
+    pub fn era_id(&self) -> EraId {
+        self.era_id
+    }
+}
+
 /// A delegator associated with the given validator.
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone, JsonSchema)]
 #[serde(deny_unknown_fields)]
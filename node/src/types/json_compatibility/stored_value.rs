@@ -13,7 +13,7 @@ use casper_execution_engine::shared::stored_value::StoredValue as ExecutionEngin
 use casper_types::{
     bytesrepr::{self, ToBytes},
     system::auction::{Bid, EraInfo, SeigniorageRecipients, UnbondingPurse},
-    CLValue, DeployInfo, Transfer,
+    CLValue, DeployInfo, DictionaryValue, Transfer,
 };
 
 use super::{Account, Contract, ContractPackage};
@@ -47,6 +47,8 @@ pub enum StoredValue {
     Withdraw(Vec<UnbondingPurse>),
     /// The seignorage recipients.
     EraValidators(SeigniorageRecipients),
+    /// An item in a dictionary.
+    Dictionary(DictionaryValue),
 }
 
 impl TryFrom<&ExecutionEngineStoredValue> for StoredValue {
@@ -77,6 +79,9 @@ impl TryFrom<&ExecutionEngineStoredValue> for StoredValue {
             ExecutionEngineStoredValue::EraValidators(recipients) => {
                 StoredValue::EraValidators(recipients.clone())
             }
+            ExecutionEngineStoredValue::Dictionary(dictionary_value) => {
+                StoredValue::Dictionary(dictionary_value.clone())
+            }
         };
 
         Ok(stored_value)
@@ -13,6 +13,9 @@ use crate::types::NodeId;
 struct PeerEntry {
     node_id: String,
     address: String,
+    /// The peer's reputation score, if known. Peers with too low a score are disconnected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reputation: Option<i64>,
 }
 
 /// Map of peer IDs to network addresses.
@@ -20,15 +23,26 @@ struct PeerEntry {
 #[serde(deny_unknown_fields)]
 pub struct PeersMap(Vec<PeerEntry>);
 
-impl From<BTreeMap<NodeId, String>> for PeersMap {
-    fn from(input: BTreeMap<NodeId, String>) -> Self {
-        let ret = input
+impl PeersMap {
+    /// Creates the map of peers, including the reputation scores of those that have one.
+    pub(crate) fn with_reputations(
+        peers: BTreeMap<NodeId, String>,
+        reputations: &BTreeMap<NodeId, i64>,
+    ) -> Self {
+        let ret = peers
             .into_iter()
             .map(|(node_id, address)| PeerEntry {
                 node_id: node_id.to_string(),
                 address,
+                reputation: reputations.get(&node_id).copied(),
             })
             .collect();
         PeersMap(ret)
     }
 }
+
+impl From<BTreeMap<NodeId, String>> for PeersMap {
+    fn from(input: BTreeMap<NodeId, String>) -> Self {
+        PeersMap::with_reputations(input, &BTreeMap::new())
+    }
+}
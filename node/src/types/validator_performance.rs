@@ -0,0 +1,69 @@
+//! A report on how well the validators participate in an era's consensus protocol.
+
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use once_cell::sync::Lazy;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use casper_types::{EraId, PublicKey};
+
+use crate::{rpcs::docs::DocExample, types::Timestamp};
+
+static ERA_VALIDATOR_PERFORMANCE: Lazy<EraValidatorPerformance> =
+    Lazy::new(|| EraValidatorPerformance {
+        era_id: EraId::from(1),
+        validators: vec![ValidatorPerformance {
+            public_key: PublicKey::doc_example().clone(),
+            weight: 1_000,
+            faulty: false,
+            last_seen: *Timestamp::doc_example(),
+            round_exponent: Some(16),
+            rounds_participated: 98,
+            rounds_missed: 2,
+            assigned_rewards: 97_000_000_000_000,
+        }],
+    });
+
+/// The participation of all validators in an era's consensus protocol, as seen by this node.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EraValidatorPerformance {
+    /// The era ID.
+    pub era_id: EraId,
+    /// The validators, ordered by public key.
+    pub validators: Vec<ValidatorPerformance>,
+}
+
+impl DocExample for EraValidatorPerformance {
+    fn doc_example() -> &'static Self {
+        &*ERA_VALIDATOR_PERFORMANCE
+    }
+}
+
+/// A validator's participation in an era's consensus protocol.
+///
+/// Only rounds in which a block was proposed that is now finalized are counted, since rewards are
+/// only paid for those.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ValidatorPerformance {
+    /// The validator's public key.
+    pub public_key: PublicKey,
+    /// The validator's weight in the era.
+    pub weight: u64,
+    /// Whether the validator is known to be faulty.
+    pub faulty: bool,
+    /// The timestamp of the latest unit or ping by the validator, or the era's start if there is
+    /// none.
+    pub last_seen: Timestamp,
+    /// The round exponent of the validator's latest unit, if any.
+    pub round_exponent: Option<u8>,
+    /// The number of rounds the validator was assigned to and created units in.
+    pub rounds_participated: u64,
+    /// The number of rounds the validator was assigned to but did not create any units in.
+    pub rounds_missed: u64,
+    /// The rewards for the finalized blocks the validator has earned so far.
+    pub assigned_rewards: u64,
+}
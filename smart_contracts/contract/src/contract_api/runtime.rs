@@ -9,14 +9,19 @@ use core::mem::MaybeUninit;
 use casper_types::{
     account::AccountHash,
     api_error,
-    bytesrepr::{self, FromBytes},
+    bytesrepr::{self, FromBytes, ToBytes, U64_SERIALIZED_LENGTH},
     contracts::{ContractVersion, NamedKeys},
-    ApiError, BlockTime, CLTyped, CLValue, ContractHash, ContractPackageHash, Key, Phase,
-    RuntimeArgs, URef, BLAKE2B_DIGEST_LENGTH, BLOCKTIME_SERIALIZED_LENGTH, PHASE_SERIALIZED_LENGTH,
+    ApiError, BlockTime, CLTyped, CLValue, CallStackElement, ContractHash, ContractPackageHash,
+    DeployHash, EraId, Key, Phase, PublicKey, RuntimeArgs, Signature, URef, BLAKE2B_DIGEST_LENGTH,
+    BLOCKTIME_SERIALIZED_LENGTH, DEPLOY_HASH_LENGTH, KECCAK256_DIGEST_LENGTH,
+    PHASE_SERIALIZED_LENGTH, SHA256_DIGEST_LENGTH,
 };
 
 use crate::{contract_api, ext_ffi, unwrap_or_revert::UnwrapOrRevert};
 
+/// The number of bytes in a seed returned by [`random_seed`].
+pub const RANDOM_SEED_LENGTH: usize = 32;
+
 /// Returns the given [`CLValue`] to the host, terminating the currently running module.
 ///
 /// Note this function is only relevant to contracts stored on chain which are invoked via
@@ -220,6 +225,61 @@ pub fn get_phase() -> Phase {
     bytesrepr::deserialize(bytes).unwrap_or_revert()
 }
 
+/// Returns the height of the block in which the current deploy is executed.
+pub fn get_block_height() -> u64 {
+    read_from_host(U64_SERIALIZED_LENGTH, ext_ffi::casper_get_block_height)
+}
+
+/// Returns the [`EraId`] of the block in which the current deploy is executed.
+pub fn get_era_id() -> EraId {
+    read_from_host(U64_SERIALIZED_LENGTH, ext_ffi::casper_get_era_id)
+}
+
+/// Returns the hash of the parent of the block in which the current deploy is executed.
+pub fn get_parent_block_hash() -> [u8; BLAKE2B_DIGEST_LENGTH] {
+    read_from_host(BLAKE2B_DIGEST_LENGTH, ext_ffi::casper_get_parent_block_hash)
+}
+
+/// Returns the [`DeployHash`] of the current deploy.
+pub fn get_deploy_hash() -> DeployHash {
+    read_from_host(DEPLOY_HASH_LENGTH, ext_ffi::casper_get_deploy_hash)
+}
+
+/// Returns the call stack of the current deploy.
+///
+/// The first element is the session of the account which sent the deploy, and the last element is
+/// the currently-executing module.
+pub fn get_call_stack() -> Vec<CallStackElement> {
+    let (call_stack_len, result_size) = {
+        let mut call_stack_len = MaybeUninit::uninit();
+        let mut result_size = 0;
+        let ret = unsafe {
+            ext_ffi::casper_load_call_stack(
+                call_stack_len.as_mut_ptr(),
+                &mut result_size as *mut usize,
+            )
+        };
+        api_error::result_from(ret).unwrap_or_revert();
+        let call_stack_len = unsafe { call_stack_len.assume_init() };
+        (call_stack_len, result_size)
+    };
+    if call_stack_len == 0 {
+        return Vec::new();
+    }
+    let bytes = read_host_buffer(result_size).unwrap_or_revert();
+    bytesrepr::deserialize(bytes).unwrap_or_revert()
+}
+
+/// Reads a value of `size` serialized bytes which `host_function` writes into Wasm memory.
+fn read_from_host<T: FromBytes>(size: usize, host_function: unsafe extern "C" fn(*mut u8)) -> T {
+    let dest_non_null_ptr = contract_api::alloc_bytes(size);
+    let bytes = unsafe {
+        host_function(dest_non_null_ptr.as_ptr());
+        Vec::from_raw_parts(dest_non_null_ptr.as_ptr(), size, size)
+    };
+    bytesrepr::deserialize(bytes).unwrap_or_revert()
+}
+
 /// Returns the requested named [`Key`] from the current context.
 ///
 /// The current context is either the caller's account or a stored contract depending on whether the
@@ -320,6 +380,82 @@ pub fn blake2b<T: AsRef<[u8]>>(input: T) -> [u8; BLAKE2B_DIGEST_LENGTH] {
     ret
 }
 
+/// Returns a 32-byte SHA-256 digest
+pub fn sha256<T: AsRef<[u8]>>(input: T) -> [u8; SHA256_DIGEST_LENGTH] {
+    let mut ret = [0; SHA256_DIGEST_LENGTH];
+    let result = unsafe {
+        ext_ffi::casper_sha256(
+            input.as_ref().as_ptr(),
+            input.as_ref().len(),
+            ret.as_mut_ptr(),
+            SHA256_DIGEST_LENGTH,
+        )
+    };
+    api_error::result_from(result).unwrap_or_revert();
+    ret
+}
+
+/// Returns a 32-byte Keccak-256 digest
+pub fn keccak256<T: AsRef<[u8]>>(input: T) -> [u8; KECCAK256_DIGEST_LENGTH] {
+    let mut ret = [0; KECCAK256_DIGEST_LENGTH];
+    let result = unsafe {
+        ext_ffi::casper_keccak256(
+            input.as_ref().as_ptr(),
+            input.as_ref().len(),
+            ret.as_mut_ptr(),
+            KECCAK256_DIGEST_LENGTH,
+        )
+    };
+    api_error::result_from(result).unwrap_or_revert();
+    ret
+}
+
+/// Returns a 32-byte seed derived from the current deploy hash, phase and block.
+///
+/// Every call within a given deploy and phase returns a different seed. The seeds are predictable
+/// by the deploy sender and the block proposer, so they must not be used where unpredictability
+/// matters.
+pub fn random_seed() -> [u8; RANDOM_SEED_LENGTH] {
+    let mut ret = [0; RANDOM_SEED_LENGTH];
+    let result = unsafe { ext_ffi::casper_random_seed(ret.as_mut_ptr(), RANDOM_SEED_LENGTH) };
+    api_error::result_from(result).unwrap_or_revert();
+    ret
+}
+
+/// Returns `true` if `signature` is a valid signature of `message` by `public_key`.
+pub fn verify_signature<T: AsRef<[u8]>>(
+    message: T,
+    signature: &Signature,
+    public_key: &PublicKey,
+) -> bool {
+    let (signature_ptr, signature_size, _bytes1) = contract_api::to_ptr(*signature);
+    let (public_key_ptr, public_key_size, _bytes2) = contract_api::to_ptr(public_key.clone());
+    let result = unsafe {
+        ext_ffi::casper_verify_signature(
+            message.as_ref().as_ptr(),
+            message.as_ref().len(),
+            signature_ptr,
+            signature_size,
+            public_key_ptr,
+            public_key_size,
+        )
+    };
+    result != 0
+}
+
+/// Emits an event with the given topic and payload.
+///
+/// The event is recorded in the execution result of the current deploy, tagged with the hash of
+/// the emitting contract.
+pub fn emit_event<T: CLTyped + ToBytes>(topic: &str, payload: T) {
+    let payload = CLValue::from_t(payload).unwrap_or_revert();
+    let (topic_ptr, topic_size, _bytes1) = contract_api::to_ptr(topic);
+    let (payload_ptr, payload_size, _bytes2) = contract_api::to_ptr(payload);
+    let result =
+        unsafe { ext_ffi::casper_emit_event(topic_ptr, topic_size, payload_ptr, payload_size) };
+    api_error::result_from(result).unwrap_or_revert()
+}
+
 fn read_host_buffer_into(dest: &mut [u8]) -> Result<usize, ApiError> {
     let mut bytes_written = MaybeUninit::uninit();
     let ret = unsafe {
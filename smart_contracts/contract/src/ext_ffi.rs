@@ -700,6 +700,173 @@ extern "C" {
         out_ptr: *mut u8,
         out_size: usize,
     ) -> i32;
+    /// Emits an event which is recorded in the execution result of the current deploy.
+    ///
+    /// # Arguments
+    ///
+    /// * `topic_ptr` - pointer to serialized topic of the event
+    /// * `topic_size` - size of serialized topic of the event
+    /// * `payload_ptr` - pointer to serialized `CLValue` payload of the event
+    /// * `payload_size` - size of serialized `CLValue` payload of the event
+    pub fn casper_emit_event(
+        topic_ptr: *const u8,
+        topic_size: usize,
+        payload_ptr: *const u8,
+        payload_size: usize,
+    ) -> i32;
+    /// Verifies the signature of the given message against the given public key.
+    ///
+    /// Returns `1` if the signature is valid, and `0` otherwise, including when the signature or
+    /// public key bytes are malformed.
+    ///
+    /// # Arguments
+    ///
+    /// * `message_ptr` - pointer to message bytes
+    /// * `message_size` - length of message bytes
+    /// * `signature_ptr` - pointer to serialized `Signature`
+    /// * `signature_size` - size of serialized `Signature`
+    /// * `public_key_ptr` - pointer to serialized `PublicKey`
+    /// * `public_key_size` - size of serialized `PublicKey`
+    pub fn casper_verify_signature(
+        message_ptr: *const u8,
+        message_size: usize,
+        signature_ptr: *const u8,
+        signature_size: usize,
+        public_key_ptr: *const u8,
+        public_key_size: usize,
+    ) -> i32;
+    /// Returns a 32-byte SHA-256 hash digest from the given input bytes
+    ///
+    /// # Arguments
+    /// * `in_ptr` - pointer to bytes
+    /// * `in_size` - length of bytes
+    /// * `out_ptr` - pointer to the location where the digest will be copied from the host side
+    /// * `out_size` - size of output pointer
+    pub fn casper_sha256(
+        in_ptr: *const u8,
+        in_size: usize,
+        out_ptr: *const u8,
+        out_size: usize,
+    ) -> i32;
+    /// Returns a 32-byte Keccak-256 hash digest from the given input bytes
+    ///
+    /// # Arguments
+    /// * `in_ptr` - pointer to bytes
+    /// * `in_size` - length of bytes
+    /// * `out_ptr` - pointer to the location where the digest will be copied from the host side
+    /// * `out_size` - size of output pointer
+    pub fn casper_keccak256(
+        in_ptr: *const u8,
+        in_size: usize,
+        out_ptr: *const u8,
+        out_size: usize,
+    ) -> i32;
+    /// Returns a 32-byte seed derived deterministically from the current deploy hash, phase, block
+    /// and the number of seeds returned so far.
+    ///
+    /// # Arguments
+    /// * `out_ptr` - pointer to the location where the seed will be copied from the host side
+    /// * `out_size` - size of output pointer
+    pub fn casper_random_seed(out_ptr: *const u8, out_size: usize) -> i32;
+    /// This function gets the height of the block this deploy is included in. The
+    /// return value is always a 64-bit unsigned integer. It is up to the caller to
+    /// ensure there are 8 bytes allocated at `dest_ptr`, otherwise data corruption
+    /// in the wasm memory may occur.
+    ///
+    /// # Arguments
+    ///
+    /// * `dest_ptr` - pointer in wasm memory where to write the result
+    pub fn casper_get_block_height(dest_ptr: *mut u8);
+    /// This function gets the id of the era in which the block this deploy is
+    /// included in was proposed. The return value is always a 64-bit unsigned
+    /// integer. It is up to the caller to ensure there are 8 bytes allocated at
+    /// `dest_ptr`, otherwise data corruption in the wasm memory may occur.
+    ///
+    /// # Arguments
+    ///
+    /// * `dest_ptr` - pointer in wasm memory where to write the result
+    pub fn casper_get_era_id(dest_ptr: *mut u8);
+    /// This function gets the hash of the parent of the block this deploy is
+    /// included in. It is up to the caller to ensure there are 32 bytes allocated
+    /// at `dest_ptr`, otherwise data corruption in the wasm memory may occur.
+    ///
+    /// # Arguments
+    ///
+    /// * `dest_ptr` - pointer in wasm memory where to write the result
+    pub fn casper_get_parent_block_hash(dest_ptr: *mut u8);
+    /// This function gets the hash of the currently executing deploy. It is up to
+    /// the caller to ensure there are 32 bytes allocated at `dest_ptr`, otherwise
+    /// data corruption in the wasm memory may occur.
+    ///
+    /// # Arguments
+    ///
+    /// * `dest_ptr` - pointer in wasm memory where to write the result
+    pub fn casper_get_deploy_hash(dest_ptr: *mut u8);
+    /// This function loads the call stack of the currently executing deploy into
+    /// the host buffer, starting with the session of the deploy's sender and
+    /// ending with the currently executing contract. The number of elements and
+    /// the size of the serialized call stack are written to the given locations.
+    ///
+    /// # Arguments
+    ///
+    /// * `call_stack_len` - pointer to the location where the number of elements will be written
+    /// * `result_size` - pointer to the location where the size of the serialized call stack will
+    ///   be written
+    pub fn casper_load_call_stack(call_stack_len: *mut usize, result_size: *mut usize) -> i32;
+    /// This function causes the runtime to generate a new `URef` which seeds a
+    /// dictionary. The new `URef` is written (in serialized form) to the wasm
+    /// linear memory starting from the `uref_ptr` offset. Note that data
+    /// corruption is possible if not enough memory is allocated for the `URef`
+    /// at `uref_ptr`.
+    ///
+    /// # Arguments
+    ///
+    /// * `uref_ptr` - pointer to the offset in wasm memory where the new `URef` will be written
+    pub fn casper_new_dictionary(uref_ptr: *mut u8);
+    /// The bytes in wasm memory from offset `uref_ptr` to `uref_ptr + uref_size`
+    /// must correspond to a valid seed `URef` with read access. The value
+    /// stored under the dictionary item key given by the raw bytes from
+    /// `key_ptr` to `key_ptr + key_size` is serialized and buffered in the
+    /// runtime. This result can be obtained via the [`casper_read_host_buffer`]
+    /// function. Returns standard error code.
+    ///
+    /// # Arguments
+    ///
+    /// * `uref_ptr` - pointer to bytes representing the seed `URef` of the dictionary
+    /// * `uref_size` - size of the seed `URef` (in bytes)
+    /// * `key_ptr` - pointer to bytes representing the dictionary item key
+    /// * `key_size` - size of the dictionary item key (in bytes)
+    /// * `output_size` - pointer to a value where host will write size of bytes of the read value
+    pub fn casper_dictionary_get(
+        uref_ptr: *const u8,
+        uref_size: usize,
+        key_ptr: *const u8,
+        key_size: usize,
+        output_size: *mut usize,
+    ) -> i32;
+    /// The bytes in wasm memory from offset `uref_ptr` to `uref_ptr + uref_size`
+    /// must correspond to a valid seed `URef` with write access. This function
+    /// writes the provided value (read via de-serializing the bytes in wasm
+    /// memory from offset `value_ptr` to `value_ptr + value_size`) under the
+    /// dictionary item key given by the raw bytes from `key_ptr` to
+    /// `key_ptr + key_size`. Returns standard error code.
+    ///
+    /// # Arguments
+    ///
+    /// * `uref_ptr` - pointer to bytes representing the seed `URef` of the dictionary
+    /// * `uref_size` - size of the seed `URef` (in bytes)
+    /// * `key_ptr` - pointer to bytes representing the dictionary item key
+    /// * `key_size` - size of the dictionary item key (in bytes)
+    /// * `value_ptr` - pointer to bytes representing the value to write under the item key
+    /// * `value_size` - size of the value (in bytes)
+    pub fn casper_dictionary_put(
+        uref_ptr: *const u8,
+        uref_size: usize,
+        key_ptr: *const u8,
+        key_size: usize,
+        value_ptr: *const u8,
+        value_size: usize,
+    ) -> i32;
     /// Prints data directly to stanadard output on the host.
     ///
     /// # Arguments
@@ -0,0 +1,43 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{AccessRights, ApiError, URef};
+
+const DICTIONARY_NAME: &str = "dictionary";
+const DICTIONARY_ITEM_KEY: &str = "item";
+const DICTIONARY_VALUE: &str = "value";
+const ARG_METHOD: &str = "method";
+const METHOD_WRITE_READ_ONLY: &str = "write_read_only";
+const METHOD_WRITE_FORGED: &str = "write_forged";
+
+#[repr(u16)]
+enum Error {
+    InvalidMethod,
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let method: String = runtime::get_named_arg(ARG_METHOD);
+
+    let seed_uref = storage::new_dictionary();
+    runtime::put_key(DICTIONARY_NAME, seed_uref.into());
+
+    let target_uref = match method.as_str() {
+        // A valid seed `URef` stripped of its write access.
+        METHOD_WRITE_READ_ONLY => seed_uref.into_read(),
+        // A seed `URef` never granted to this context.
+        METHOD_WRITE_FORGED => URef::new([1u8; 32], AccessRights::READ_ADD_WRITE),
+        _ => runtime::revert(ApiError::User(Error::InvalidMethod as u16)),
+    };
+
+    storage::dictionary_put(
+        target_uref,
+        DICTIONARY_ITEM_KEY,
+        DICTIONARY_VALUE.to_string(),
+    );
+}
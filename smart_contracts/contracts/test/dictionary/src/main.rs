@@ -0,0 +1,41 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+
+const DICTIONARY_NAME: &str = "dictionary";
+const DICTIONARY_VALUE: &str = "value";
+const ARG_DICTIONARY_ITEM_KEY: &str = "dictionary_item_key";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let dictionary_item_key: String = runtime::get_named_arg(ARG_DICTIONARY_ITEM_KEY);
+
+    let seed_uref = storage::new_dictionary();
+    runtime::put_key(DICTIONARY_NAME, seed_uref.into());
+
+    let maybe_value: Option<String> =
+        storage::dictionary_get(seed_uref, &dictionary_item_key).unwrap_or_revert();
+    assert!(maybe_value.is_none(), "new dictionary should be empty");
+
+    storage::dictionary_put(
+        seed_uref,
+        &dictionary_item_key,
+        DICTIONARY_VALUE.to_string(),
+    );
+
+    let maybe_value: Option<String> =
+        storage::dictionary_get(seed_uref, &dictionary_item_key).unwrap_or_revert();
+    assert_eq!(
+        maybe_value.as_deref(),
+        Some(DICTIONARY_VALUE),
+        "should read back dictionary item"
+    );
+}
@@ -0,0 +1,83 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use casper_contract::{
+    contract_api::{account, runtime, storage, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::Parameters, ApiError, CLType, EntryPoint, EntryPointAccess, EntryPointType,
+    EntryPoints, RuntimeArgs, URef, U512,
+};
+
+const ENTRY_POINT_EMIT: &str = "emit";
+const ENTRY_POINT_EMIT_AND_REVERT: &str = "emit_and_revert";
+const ENTRY_POINT_EMIT_AND_PAY: &str = "emit_and_pay";
+const CONTRACT_NAME: &str = "emit_event_contract";
+const GET_PAYMENT_PURSE: &str = "get_payment_purse";
+
+const ARG_TOPIC: &str = "topic";
+const ARG_PAYLOAD: &str = "payload";
+const ARG_AMOUNT: &str = "amount";
+
+#[no_mangle]
+pub extern "C" fn emit() {
+    let topic: String = runtime::get_named_arg(ARG_TOPIC);
+    let payload: u64 = runtime::get_named_arg(ARG_PAYLOAD);
+    runtime::emit_event(&topic, payload);
+}
+
+#[no_mangle]
+pub extern "C" fn emit_and_revert() {
+    emit();
+    runtime::revert(ApiError::User(0));
+}
+
+#[no_mangle]
+pub extern "C" fn emit_and_pay() {
+    emit();
+
+    let amount: U512 = runtime::get_named_arg(ARG_AMOUNT);
+    let main_purse = account::get_main_purse();
+    let payment_purse: URef = runtime::call_contract(
+        system::get_handle_payment(),
+        GET_PAYMENT_PURSE,
+        RuntimeArgs::default(),
+    );
+    system::transfer_from_purse_to_purse(main_purse, payment_purse, amount, None)
+        .unwrap_or_revert();
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    emit();
+
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        for &entry_point_name in &[ENTRY_POINT_EMIT, ENTRY_POINT_EMIT_AND_REVERT] {
+            entry_points.add_entry_point(EntryPoint::new(
+                entry_point_name,
+                Parameters::new(),
+                CLType::Unit,
+                EntryPointAccess::Public,
+                EntryPointType::Contract,
+            ));
+        }
+        // Runs as payment code in the context of the deploy's account.
+        entry_points.add_entry_point(EntryPoint::new(
+            ENTRY_POINT_EMIT_AND_PAY,
+            Parameters::new(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Session,
+        ));
+        entry_points
+    };
+
+    let (contract_hash, _contract_version) = storage::new_contract(entry_points, None, None, None);
+    runtime::put_key(CONTRACT_NAME, contract_hash.into());
+}
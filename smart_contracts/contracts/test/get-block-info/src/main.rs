@@ -0,0 +1,48 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec;
+
+use casper_contract::contract_api::runtime;
+use casper_types::{CallStackElement, DeployHash, EraId};
+
+const ARG_KNOWN_BLOCK_HEIGHT: &str = "known_block_height";
+const ARG_KNOWN_ERA_ID: &str = "known_era_id";
+const ARG_KNOWN_PARENT_BLOCK_HASH: &str = "known_parent_block_hash";
+const ARG_KNOWN_DEPLOY_HASH: &str = "known_deploy_hash";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let known_block_height: u64 = runtime::get_named_arg(ARG_KNOWN_BLOCK_HEIGHT);
+    let known_era_id: EraId = runtime::get_named_arg(ARG_KNOWN_ERA_ID);
+    let known_parent_block_hash: [u8; 32] = runtime::get_named_arg(ARG_KNOWN_PARENT_BLOCK_HASH);
+    let known_deploy_hash: [u8; 32] = runtime::get_named_arg(ARG_KNOWN_DEPLOY_HASH);
+
+    assert_eq!(
+        runtime::get_block_height(),
+        known_block_height,
+        "actual block height not known block height"
+    );
+    assert_eq!(
+        runtime::get_era_id(),
+        known_era_id,
+        "actual era id not known era id"
+    );
+    assert_eq!(
+        runtime::get_parent_block_hash(),
+        known_parent_block_hash,
+        "actual parent block hash not known parent block hash"
+    );
+    assert_eq!(
+        runtime::get_deploy_hash(),
+        DeployHash::new(known_deploy_hash),
+        "actual deploy hash not known deploy hash"
+    );
+    assert_eq!(
+        runtime::get_call_stack(),
+        vec![CallStackElement::session(runtime::get_caller())],
+        "session call stack should only contain the caller"
+    );
+}
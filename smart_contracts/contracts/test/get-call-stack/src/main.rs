@@ -0,0 +1,70 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+    contracts::Parameters, ApiError, CLType, CLTyped, CLValue, CallStackElement, EntryPoint,
+    EntryPointAccess, EntryPointType, EntryPoints, RuntimeArgs,
+};
+
+const ENTRY_POINT_OUTER: &str = "outer";
+const ENTRY_POINT_INNER: &str = "inner";
+const CONTRACT_NAME: &str = "get_call_stack_contract";
+const INNER_CALL_STACK: &str = "inner_call_stack";
+
+#[repr(u16)]
+enum Error {
+    NotCalledByStoredContract,
+}
+
+/// Calls `inner` on this contract and stores the call stack it sees.
+#[no_mangle]
+pub extern "C" fn outer() {
+    let contract_hash = match runtime::get_call_stack().last() {
+        Some(CallStackElement::StoredContract { contract_hash, .. }) => *contract_hash,
+        _ => runtime::revert(ApiError::User(Error::NotCalledByStoredContract as u16)),
+    };
+    let inner_call_stack: Vec<CallStackElement> =
+        runtime::call_contract(contract_hash, ENTRY_POINT_INNER, RuntimeArgs::new());
+    let uref = storage::new_uref(inner_call_stack);
+    runtime::put_key(INNER_CALL_STACK, uref.into());
+}
+
+/// Returns the call stack.
+#[no_mangle]
+pub extern "C" fn inner() {
+    let call_stack = CLValue::from_t(runtime::get_call_stack()).unwrap_or_revert();
+    runtime::ret(call_stack)
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let entry_points = {
+        let mut entry_points = EntryPoints::new();
+        entry_points.add_entry_point(EntryPoint::new(
+            ENTRY_POINT_OUTER,
+            Parameters::new(),
+            CLType::Unit,
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+        entry_points.add_entry_point(EntryPoint::new(
+            ENTRY_POINT_INNER,
+            Parameters::new(),
+            Vec::<CallStackElement>::cl_type(),
+            EntryPointAccess::Public,
+            EntryPointType::Contract,
+        ));
+        entry_points
+    };
+
+    let (contract_hash, _contract_version) = storage::new_contract(entry_points, None, None, None);
+    runtime::put_key(CONTRACT_NAME, contract_hash.into());
+}
@@ -0,0 +1,39 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{ApiError, Key};
+
+const ARG_INPUT: &str = "input";
+
+const SHA256_DIGEST: &str = "sha256_digest";
+const KECCAK256_DIGEST: &str = "keccak256_digest";
+const RANDOM_SEED: &str = "random_seed";
+
+#[repr(u16)]
+enum Error {
+    RandomSeedRepeated,
+}
+
+fn store(name: &str, digest: [u8; 32]) {
+    let uref = storage::new_uref(digest);
+    runtime::put_key(name, Key::from(uref));
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let input: Vec<u8> = runtime::get_named_arg(ARG_INPUT);
+
+    store(SHA256_DIGEST, runtime::sha256(&input));
+    store(KECCAK256_DIGEST, runtime::keccak256(&input));
+
+    let random_seed = runtime::random_seed();
+    if random_seed == runtime::random_seed() {
+        runtime::revert(ApiError::User(Error::RandomSeedRepeated as u16));
+    }
+    store(RANDOM_SEED, random_seed);
+}
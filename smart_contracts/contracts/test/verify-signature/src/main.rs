@@ -0,0 +1,39 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    ext_ffi,
+};
+
+const ARG_MESSAGE: &str = "message";
+const ARG_SIGNATURE: &str = "signature";
+const ARG_PUBLIC_KEY: &str = "public_key";
+
+const SIGNATURE_VALID: &str = "signature_valid";
+
+#[no_mangle]
+pub extern "C" fn call() {
+    let message: Vec<u8> = runtime::get_named_arg(ARG_MESSAGE);
+    // The serialized signature and public key are passed to the host as given so that malformed
+    // bytes can be checked too.
+    let signature_bytes: Vec<u8> = runtime::get_named_arg(ARG_SIGNATURE);
+    let public_key_bytes: Vec<u8> = runtime::get_named_arg(ARG_PUBLIC_KEY);
+
+    let result = unsafe {
+        ext_ffi::casper_verify_signature(
+            message.as_ptr(),
+            message.len(),
+            signature_bytes.as_ptr(),
+            signature_bytes.len(),
+            public_key_bytes.as_ptr(),
+            public_key_bytes.len(),
+        )
+    };
+    let uref = storage::new_uref(result != 0);
+    runtime::put_key(SIGNATURE_VALID, uref.into())
+}
@@ -0,0 +1,150 @@
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    account::AccountHash,
+    bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
+    CLType, CLTyped, ContractHash,
+};
+
+// The serialized form is that of the equivalent `Result<(ContractHash, String), AccountHash>`.
+const SESSION_TAG: u8 = bytesrepr::RESULT_ERR_TAG;
+const STORED_CONTRACT_TAG: u8 = bytesrepr::RESULT_OK_TAG;
+
+/// A single frame of the call stack of a running deploy.
+///
+/// The first element of a call stack is always the [`CallStackElement::Session`] of the account
+/// which sent the deploy, followed by one [`CallStackElement::StoredContract`] per nested contract
+/// call, ending with the currently executing contract.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CallStackElement {
+    /// Session code executed on behalf of an account.
+    Session {
+        /// The account hash of the deploy's sender.
+        account_hash: AccountHash,
+    },
+    /// An entry point of a stored contract.
+    StoredContract {
+        /// The hash of the called contract.
+        contract_hash: ContractHash,
+        /// The name of the called entry point.
+        entry_point: String,
+    },
+}
+
+impl CallStackElement {
+    /// Creates a [`CallStackElement::Session`].
+    pub fn session(account_hash: AccountHash) -> Self {
+        CallStackElement::Session { account_hash }
+    }
+
+    /// Creates a [`CallStackElement::StoredContract`].
+    pub fn stored_contract(contract_hash: ContractHash, entry_point: String) -> Self {
+        CallStackElement::StoredContract {
+            contract_hash,
+            entry_point,
+        }
+    }
+}
+
+impl CLTyped for CallStackElement {
+    fn cl_type() -> CLType {
+        <Result<(ContractHash, String), AccountHash>>::cl_type()
+    }
+}
+
+impl ToBytes for CallStackElement {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        match self {
+            CallStackElement::Session { account_hash } => {
+                result.push(SESSION_TAG);
+                result.append(&mut account_hash.to_bytes()?);
+            }
+            CallStackElement::StoredContract {
+                contract_hash,
+                entry_point,
+            } => {
+                result.push(STORED_CONTRACT_TAG);
+                result.append(&mut contract_hash.to_bytes()?);
+                result.append(&mut entry_point.to_bytes()?);
+            }
+        }
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + match self {
+                CallStackElement::Session { account_hash } => account_hash.serialized_length(),
+                CallStackElement::StoredContract {
+                    contract_hash,
+                    entry_point,
+                } => contract_hash.serialized_length() + entry_point.serialized_length(),
+            }
+    }
+}
+
+impl FromBytes for CallStackElement {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            SESSION_TAG => {
+                let (account_hash, remainder) = AccountHash::from_bytes(remainder)?;
+                Ok((CallStackElement::Session { account_hash }, remainder))
+            }
+            STORED_CONTRACT_TAG => {
+                let (contract_hash, remainder) = ContractHash::from_bytes(remainder)?;
+                let (entry_point, remainder) = String::from_bytes(remainder)?;
+                Ok((
+                    CallStackElement::StoredContract {
+                        contract_hash,
+                        entry_point,
+                    },
+                    remainder,
+                ))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::{String, ToString};
+
+    use proptest::prelude::*;
+
+    use super::CallStackElement;
+    use crate::{
+        account::AccountHash,
+        bytesrepr::{self, ToBytes},
+        gens, ContractHash,
+    };
+
+    #[test]
+    fn should_serialize_as_equivalent_result() {
+        let account_hash = AccountHash::new([1; 32]);
+        let session: Result<(ContractHash, String), AccountHash> = Err(account_hash);
+        assert_eq!(
+            CallStackElement::session(account_hash).to_bytes().unwrap(),
+            session.to_bytes().unwrap()
+        );
+
+        let contract_hash = ContractHash::new([2; 32]);
+        let entry_point = "entry_point".to_string();
+        let stored_contract: Result<_, AccountHash> = Ok((contract_hash, entry_point.clone()));
+        assert_eq!(
+            CallStackElement::stored_contract(contract_hash, entry_point)
+                .to_bytes()
+                .unwrap(),
+            stored_contract.to_bytes().unwrap()
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn test_serialization_roundtrip(call_stack_element in gens::call_stack_element_arb()) {
+            bytesrepr::test_serialization_roundtrip(&call_stack_element)
+        }
+    }
+}
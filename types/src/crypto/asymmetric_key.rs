@@ -16,12 +16,14 @@ use core::{
 
 use datasize::DataSize;
 use ed25519_dalek::{
-    ed25519::signature::Signature as _Signature, PUBLIC_KEY_LENGTH as ED25519_PUBLIC_KEY_LENGTH,
-    SECRET_KEY_LENGTH as ED25519_SECRET_KEY_LENGTH, SIGNATURE_LENGTH as ED25519_SIGNATURE_LENGTH,
+    ed25519::signature::Signature as _Signature, ExpandedSecretKey,
+    PUBLIC_KEY_LENGTH as ED25519_PUBLIC_KEY_LENGTH, SECRET_KEY_LENGTH as ED25519_SECRET_KEY_LENGTH,
+    SIGNATURE_LENGTH as ED25519_SIGNATURE_LENGTH,
 };
 use hex_fmt::HexFmt;
 use k256::{
     ecdsa::{
+        signature::{Signer, Verifier},
         Signature as Secp256k1Signature, SigningKey as Secp256k1SecretKey,
         VerifyingKey as Secp256k1PublicKey,
     },
@@ -703,6 +705,63 @@ impl JsonSchema for Signature {
     }
 }
 
+/// Signs the given message using the given key pair.
+///
+/// Returns an error if the key pair is made of system keys or keys of differing types.
+pub fn sign<T: AsRef<[u8]>>(
+    message: T,
+    secret_key: &SecretKey,
+    public_key: &PublicKey,
+) -> Result<Signature, Error> {
+    match (secret_key, public_key) {
+        (SecretKey::System, PublicKey::System) => Err(Error::AsymmetricKey(String::from(
+            "cannot create signature with system keys",
+        ))),
+        (SecretKey::Ed25519(secret_key), PublicKey::Ed25519(public_key)) => {
+            let expanded_secret_key = ExpandedSecretKey::from(secret_key);
+            let signature = expanded_secret_key.sign(message.as_ref(), public_key);
+            Ok(Signature::Ed25519(signature))
+        }
+        (SecretKey::Secp256k1(secret_key), PublicKey::Secp256k1(_public_key)) => {
+            let signer = secret_key;
+            let signature: Secp256k1Signature = signer.try_sign(message.as_ref())?;
+            Ok(Signature::Secp256k1(signature))
+        }
+        _ => Err(Error::AsymmetricKey(format!(
+            "type mismatch between {} and {}",
+            secret_key, public_key
+        ))),
+    }
+}
+
+/// Verifies the signature of the given message against the given public key.
+pub fn verify<T: AsRef<[u8]>>(
+    message: T,
+    signature: &Signature,
+    public_key: &PublicKey,
+) -> Result<(), Error> {
+    match (signature, public_key) {
+        (Signature::System, _) => Err(Error::AsymmetricKey(String::from(
+            "signatures based on the system key cannot be verified",
+        ))),
+        (Signature::Ed25519(signature), PublicKey::Ed25519(public_key)) => public_key
+            .verify_strict(message.as_ref(), signature)
+            .map_err(|_| Error::AsymmetricKey(String::from("failed to verify Ed25519 signature"))),
+        (Signature::Secp256k1(signature), PublicKey::Secp256k1(public_key)) => {
+            let verifier: &Secp256k1PublicKey = public_key;
+            verifier
+                .verify(message.as_ref(), signature)
+                .map_err(|error| {
+                    Error::AsymmetricKey(format!("failed to verify secp256k1 signature: {}", error))
+                })
+        }
+        _ => Err(Error::AsymmetricKey(format!(
+            "type mismatch between {} and {}",
+            signature, public_key
+        ))),
+    }
+}
+
 mod detail {
     use alloc::{string::String, vec::Vec};
 
@@ -1,4 +1,7 @@
-use crate::{crypto::SecretKey, AsymmetricType, PublicKey};
+use crate::{
+    crypto::{sign, verify, SecretKey},
+    AsymmetricType, PublicKey,
+};
 
 #[test]
 fn can_construct_ed25519_keypair_from_zeroes() {
@@ -39,3 +42,38 @@ fn can_construct_system_public_key() {
 
     assert_ne!(public_key, secret_key.into())
 }
+
+fn check_sign_and_verify(secret_key: SecretKey) {
+    let public_key = PublicKey::from(&secret_key);
+    let other_secret_key = SecretKey::ed25519_from_bytes([2; SecretKey::ED25519_LENGTH]).unwrap();
+    let other_public_key = PublicKey::from(&other_secret_key);
+    let message = b"message";
+
+    let signature = sign(message, &secret_key, &public_key).unwrap();
+    assert!(verify(message, &signature, &public_key).is_ok());
+    assert!(verify(b"other message", &signature, &public_key).is_err());
+    assert!(verify(message, &signature, &other_public_key).is_err());
+}
+
+#[test]
+fn can_sign_and_verify_ed25519() {
+    let secret_key = SecretKey::ed25519_from_bytes([1; SecretKey::ED25519_LENGTH]).unwrap();
+    check_sign_and_verify(secret_key);
+}
+
+#[test]
+fn can_sign_and_verify_secp256k1() {
+    let secret_key = SecretKey::secp256k1_from_bytes([1; SecretKey::SECP256K1_LENGTH]).unwrap();
+    check_sign_and_verify(secret_key);
+}
+
+#[test]
+fn cannot_sign_with_mismatched_or_system_keys() {
+    let ed25519_secret_key = SecretKey::ed25519_from_bytes([1; SecretKey::ED25519_LENGTH]).unwrap();
+    let secp256k1_secret_key =
+        SecretKey::secp256k1_from_bytes([1; SecretKey::SECP256K1_LENGTH]).unwrap();
+    let secp256k1_public_key = PublicKey::from(&secp256k1_secret_key);
+
+    assert!(sign(b"message", &ed25519_secret_key, &secp256k1_public_key).is_err());
+    assert!(sign(b"message", &SecretKey::system(), &PublicKey::System).is_err());
+}
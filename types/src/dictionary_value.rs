@@ -0,0 +1,131 @@
+// TODO - remove once schemars stops causing warning.
+#![allow(clippy::field_reassign_with_default)]
+
+use alloc::vec::Vec;
+
+use datasize::DataSize;
+#[cfg(feature = "std")]
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
+    CLValue, URef,
+};
+
+/// A value stored in a dictionary, along with the seed `URef` and item key it was written under.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Serialize, Deserialize, DataSize)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct DictionaryValue {
+    /// The actual [`CLValue`] held in the dictionary.
+    cl_value: CLValue,
+    /// The `URef` seeding the dictionary, without access rights.
+    seed_uref: URef,
+    /// The raw bytes of the dictionary item key.
+    #[cfg_attr(feature = "std", schemars(with = "String"))]
+    dictionary_item_key: Bytes,
+}
+
+impl DictionaryValue {
+    /// Creates a [`DictionaryValue`].  Access rights are stripped from `seed_uref`.
+    pub fn new(cl_value: CLValue, seed_uref: URef, dictionary_item_key: Bytes) -> Self {
+        DictionaryValue {
+            cl_value,
+            seed_uref: seed_uref.remove_access_rights(),
+            dictionary_item_key,
+        }
+    }
+
+    /// Returns a reference to the [`CLValue`] held in the dictionary.
+    pub fn cl_value(&self) -> &CLValue {
+        &self.cl_value
+    }
+
+    /// Returns the `URef` seeding the dictionary, without access rights.
+    pub fn seed_uref(&self) -> URef {
+        self.seed_uref
+    }
+
+    /// Returns the raw bytes of the dictionary item key.
+    pub fn dictionary_item_key(&self) -> &Bytes {
+        &self.dictionary_item_key
+    }
+
+    /// Consumes `self`, returning the [`CLValue`] held in the dictionary.
+    pub fn into_cl_value(self) -> CLValue {
+        self.cl_value
+    }
+}
+
+impl ToBytes for DictionaryValue {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut result = bytesrepr::allocate_buffer(self)?;
+        result.append(&mut self.cl_value.to_bytes()?);
+        result.append(&mut self.seed_uref.to_bytes()?);
+        result.append(&mut self.dictionary_item_key.to_bytes()?);
+        Ok(result)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.cl_value.serialized_length()
+            + self.seed_uref.serialized_length()
+            + self.dictionary_item_key.serialized_length()
+    }
+}
+
+impl FromBytes for DictionaryValue {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (cl_value, rem) = CLValue::from_bytes(bytes)?;
+        let (seed_uref, rem) = URef::from_bytes(rem)?;
+        let (dictionary_item_key, rem) = Bytes::from_bytes(rem)?;
+        Ok((
+            DictionaryValue {
+                cl_value,
+                seed_uref,
+                dictionary_item_key,
+            },
+            rem,
+        ))
+    }
+}
+
+/// Generators for a [`DictionaryValue`]
+#[cfg(any(feature = "gens", test))]
+pub(crate) mod gens {
+    use proptest::{collection, prelude::*};
+
+    use crate::{
+        gens::{cl_value_arb, uref_arb},
+        key::DICTIONARY_ITEM_KEY_MAX_LENGTH,
+        DictionaryValue,
+    };
+
+    /// Creates an arbitrary [`DictionaryValue`]
+    pub fn dictionary_value_arb() -> impl Strategy<Value = DictionaryValue> {
+        (
+            cl_value_arb(),
+            uref_arb(),
+            collection::vec(any::<u8>(), 0..=DICTIONARY_ITEM_KEY_MAX_LENGTH),
+        )
+            .prop_map(|(cl_value, seed_uref, dictionary_item_key)| {
+                DictionaryValue::new(cl_value, seed_uref, dictionary_item_key.into())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use crate::bytesrepr;
+
+    use super::gens;
+
+    proptest! {
+        #[test]
+        fn test_serialization_roundtrip(dictionary_value in gens::dictionary_value_arb()) {
+            bytesrepr::test_serialization_roundtrip(&dictionary_value)
+        }
+    }
+}
@@ -32,7 +32,8 @@ use crate::{
     account::AccountHash,
     bytesrepr::{self, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
     system::auction::{Bid, EraInfo, SeigniorageRecipients, UnbondingPurse},
-    CLValue, DeployInfo, NamedKey, Transfer, TransferAddr, U128, U256, U512,
+    CLValue, ContractHash, DeployInfo, DictionaryValue, NamedKey, Transfer, TransferAddr, U128,
+    U256, U512,
 };
 
 /// Constants to track ExecutionResult serialization.
@@ -65,6 +66,7 @@ const TRANSFORM_ADD_UINT256_TAG: u8 = 15;
 const TRANSFORM_ADD_UINT512_TAG: u8 = 16;
 const TRANSFORM_ADD_KEYS_TAG: u8 = 17;
 const TRANSFORM_FAILURE_TAG: u8 = 18;
+const TRANSFORM_WRITE_DICTIONARY_TAG: u8 = 19;
 
 #[cfg(feature = "std")]
 static EXECUTION_RESULT: Lazy<ExecutionResult> = Lazy::new(|| {
@@ -100,9 +102,16 @@ static EXECUTION_RESULT: Lazy<ExecutionResult> = Lazy::new(|| {
         TransferAddr::new([130; KEY_HASH_LENGTH]),
     ];
 
+    let events = vec![ContractEvent {
+        contract_hash: Some(ContractHash::new([42; KEY_HASH_LENGTH])),
+        topic: "transfer".to_string(),
+        payload: CLValue::from_t(U512::from(1_000)).unwrap(),
+    }];
+
     ExecutionResult::Success {
         effect,
         transfers,
+        events,
         cost: U512::from(123_456),
     }
 });
@@ -118,6 +127,9 @@ pub enum ExecutionResult {
         effect: ExecutionEffect,
         /// A record of Transfers performed while executing the deploy.
         transfers: Vec<TransferAddr>,
+        /// The events emitted by contracts while executing the deploy's payment code, if the
+        /// session code failed. The events of failed code are discarded along with its effects.
+        events: Vec<ContractEvent>,
         /// The cost of executing the deploy.
         cost: U512,
         /// The error message associated with executing the deploy.
@@ -129,6 +141,8 @@ pub enum ExecutionResult {
         effect: ExecutionEffect,
         /// A record of Transfers performed while executing the deploy.
         transfers: Vec<TransferAddr>,
+        /// The events emitted by contracts while executing the deploy.
+        events: Vec<ContractEvent>,
         /// The cost of executing the deploy.
         cost: U512,
     },
@@ -177,10 +191,17 @@ impl Distribution<ExecutionResult> for Standard {
             transfers.push(TransferAddr::new(rng.gen()))
         }
 
+        let event_count = rng.gen_range(0..6);
+        let mut events = vec![];
+        for _ in 0..event_count {
+            events.push(rng.gen())
+        }
+
         if rng.gen() {
             ExecutionResult::Failure {
                 effect,
                 transfers,
+                events,
                 cost: rng.gen::<u64>().into(),
                 error_message: format!("Error message {}", rng.gen::<u64>()),
             }
@@ -188,6 +209,7 @@ impl Distribution<ExecutionResult> for Standard {
             ExecutionResult::Success {
                 effect,
                 transfers,
+                events,
                 cost: rng.gen::<u64>().into(),
             }
         }
@@ -201,23 +223,27 @@ impl ToBytes for ExecutionResult {
             ExecutionResult::Failure {
                 effect,
                 transfers,
+                events,
                 cost,
                 error_message,
             } => {
                 buffer.push(EXECUTION_RESULT_FAILURE_TAG);
                 buffer.extend(effect.to_bytes()?);
                 buffer.extend(transfers.to_bytes()?);
+                buffer.extend(events.to_bytes()?);
                 buffer.extend(cost.to_bytes()?);
                 buffer.extend(error_message.to_bytes()?);
             }
             ExecutionResult::Success {
                 effect,
                 transfers,
+                events,
                 cost,
             } => {
                 buffer.push(EXECUTION_RESULT_SUCCESS_TAG);
                 buffer.extend(effect.to_bytes()?);
                 buffer.extend(transfers.to_bytes()?);
+                buffer.extend(events.to_bytes()?);
                 buffer.extend(cost.to_bytes()?);
             }
         }
@@ -230,21 +256,25 @@ impl ToBytes for ExecutionResult {
                 ExecutionResult::Failure {
                     effect,
                     transfers,
+                    events,
                     cost,
                     error_message,
                 } => {
                     effect.serialized_length()
                         + transfers.serialized_length()
+                        + events.serialized_length()
                         + cost.serialized_length()
                         + error_message.serialized_length()
                 }
                 ExecutionResult::Success {
                     effect,
                     transfers,
+                    events,
                     cost,
                 } => {
                     effect.serialized_length()
                         + transfers.serialized_length()
+                        + events.serialized_length()
                         + cost.serialized_length()
                 }
             }
@@ -258,11 +288,13 @@ impl FromBytes for ExecutionResult {
             EXECUTION_RESULT_FAILURE_TAG => {
                 let (effect, remainder) = ExecutionEffect::from_bytes(remainder)?;
                 let (transfers, remainder) = Vec::<TransferAddr>::from_bytes(remainder)?;
+                let (events, remainder) = Vec::<ContractEvent>::from_bytes(remainder)?;
                 let (cost, remainder) = U512::from_bytes(remainder)?;
                 let (error_message, remainder) = String::from_bytes(remainder)?;
                 let execution_result = ExecutionResult::Failure {
                     effect,
                     transfers,
+                    events,
                     cost,
                     error_message,
                 };
@@ -271,10 +303,12 @@ impl FromBytes for ExecutionResult {
             EXECUTION_RESULT_SUCCESS_TAG => {
                 let (effect, remainder) = ExecutionEffect::from_bytes(remainder)?;
                 let (transfers, remainder) = Vec::<TransferAddr>::from_bytes(remainder)?;
+                let (events, remainder) = Vec::<ContractEvent>::from_bytes(remainder)?;
                 let (cost, remainder) = U512::from_bytes(remainder)?;
                 let execution_result = ExecutionResult::Success {
                     effect,
                     transfers,
+                    events,
                     cost,
                 };
                 Ok((execution_result, remainder))
@@ -320,6 +354,64 @@ impl FromBytes for ExecutionEffect {
     }
 }
 
+/// An event emitted by a contract while executing a deploy.
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug)]
+#[cfg_attr(feature = "std", derive(JsonSchema))]
+#[serde(deny_unknown_fields)]
+pub struct ContractEvent {
+    /// The hash of the emitting contract, or `None` if emitted by session code.
+    pub contract_hash: Option<ContractHash>,
+    /// The topic of the event, chosen by the contract.
+    pub topic: String,
+    /// The payload of the event.
+    pub payload: CLValue,
+}
+
+impl ToBytes for ContractEvent {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.contract_hash.to_bytes()?);
+        buffer.extend(self.topic.to_bytes()?);
+        buffer.extend(self.payload.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.contract_hash.serialized_length()
+            + self.topic.serialized_length()
+            + self.payload.serialized_length()
+    }
+}
+
+impl FromBytes for ContractEvent {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (contract_hash, remainder) = Option::<ContractHash>::from_bytes(bytes)?;
+        let (topic, remainder) = String::from_bytes(remainder)?;
+        let (payload, remainder) = CLValue::from_bytes(remainder)?;
+        let contract_event = ContractEvent {
+            contract_hash,
+            topic,
+            payload,
+        };
+        Ok((contract_event, remainder))
+    }
+}
+
+impl Distribution<ContractEvent> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ContractEvent {
+        let contract_hash = if rng.gen() {
+            Some(ContractHash::new(rng.gen()))
+        } else {
+            None
+        };
+        ContractEvent {
+            contract_hash,
+            topic: rng.gen::<u64>().to_string(),
+            payload: CLValue::from_t(rng.gen::<u64>()).unwrap(),
+        }
+    }
+}
+
 /// An operation performed while executing a deploy.
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Debug)]
 #[cfg_attr(feature = "std", derive(JsonSchema))]
@@ -458,6 +550,8 @@ pub enum Transform {
     WriteWithdraw(Vec<UnbondingPurse>),
     /// Writes the given EraValidators to global state.
     WriteEraValidators(SeigniorageRecipients),
+    /// Writes the given dictionary value to global state.
+    WriteDictionary(DictionaryValue),
     /// Adds the given `i32`.
     AddInt32(i32),
     /// Adds the given `u64`.
@@ -516,6 +610,10 @@ impl ToBytes for Transform {
                 buffer.insert(0, TRANSFORM_WRITE_ERA_VALIDATORS_TAG);
                 buffer.extend(recipients.to_bytes()?);
             }
+            Transform::WriteDictionary(dictionary_value) => {
+                buffer.insert(0, TRANSFORM_WRITE_DICTIONARY_TAG);
+                buffer.extend(dictionary_value.to_bytes()?);
+            }
             Transform::AddInt32(value) => {
                 buffer.insert(0, TRANSFORM_ADD_INT32_TAG);
                 buffer.extend(value.to_bytes()?);
@@ -555,6 +653,7 @@ impl ToBytes for Transform {
             Transform::WriteDeployInfo(value) => value.serialized_length() + U8_SERIALIZED_LENGTH,
             Transform::WriteEraInfo(value) => value.serialized_length() + U8_SERIALIZED_LENGTH,
             Transform::WriteTransfer(value) => value.serialized_length() + U8_SERIALIZED_LENGTH,
+            Transform::WriteDictionary(value) => value.serialized_length() + U8_SERIALIZED_LENGTH,
             Transform::AddInt32(value) => value.serialized_length() + U8_SERIALIZED_LENGTH,
             Transform::AddUInt64(value) => value.serialized_length() + U8_SERIALIZED_LENGTH,
             Transform::AddUInt128(value) => value.serialized_length() + U8_SERIALIZED_LENGTH,
@@ -597,6 +696,10 @@ impl FromBytes for Transform {
                 let (transfer, remainder) = Transfer::from_bytes(remainder)?;
                 Ok((Transform::WriteTransfer(transfer), remainder))
             }
+            TRANSFORM_WRITE_DICTIONARY_TAG => {
+                let (dictionary_value, remainder) = DictionaryValue::from_bytes(remainder)?;
+                Ok((Transform::WriteDictionary(dictionary_value), remainder))
+            }
             TRANSFORM_ADD_INT32_TAG => {
                 let (value_i32, remainder) = i32::from_bytes(remainder)?;
                 Ok((Transform::AddInt32(value_i32), remainder))
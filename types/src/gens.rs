@@ -18,13 +18,16 @@ use crate::{
         ContractPackageStatus, ContractVersions, DisabledVersions, Groups, NamedKeys, Parameters,
     },
     transfer::TransferAddr,
-    AccessRights, CLType, CLValue, Contract, ContractHash, ContractPackage, ContractVersionKey,
-    ContractWasm, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints, EraId, Group, Key,
-    NamedArg, Parameter, Phase, ProtocolVersion, SemVer, URef, U128, U256, U512,
+    AccessRights, CLType, CLValue, CallStackElement, Contract, ContractHash, ContractPackage,
+    ContractVersionKey, ContractWasm, EntryPoint, EntryPointAccess, EntryPointType, EntryPoints,
+    EraId, Group, Key, NamedArg, Parameter, Phase, ProtocolVersion, SemVer, URef, U128, U256, U512,
 };
 
 use crate::deploy_info::gens::{deploy_hash_arb, transfer_addr_arb};
-pub use crate::{deploy_info::gens::deploy_info_arb, transfer::gens::transfer_arb};
+pub use crate::{
+    deploy_info::gens::deploy_info_arb, dictionary_value::gens::dictionary_value_arb,
+    transfer::gens::transfer_arb,
+};
 
 pub fn u8_slice_32() -> impl Strategy<Value = [u8; 32]> {
     vec(any::<u8>(), 32).prop_map(|b| {
@@ -89,6 +92,7 @@ pub fn key_arb() -> impl Strategy<Value = Key> {
         account_hash_arb().prop_map(Key::Bid),
         account_hash_arb().prop_map(Key::Withdraw),
         era_id_arb().prop_map(Key::EraValidators),
+        u8_slice_32().prop_map(Key::Dictionary),
     ]
 }
 
@@ -253,6 +257,18 @@ pub fn result_arb() -> impl Strategy<Value = Result<u32, u32>> {
     result::maybe_ok(any::<u32>(), any::<u32>())
 }
 
+pub fn call_stack_element_arb() -> impl Strategy<Value = CallStackElement> {
+    prop_oneof![
+        account_hash_arb().prop_map(CallStackElement::session),
+        (u8_slice_32().prop_map(ContractHash::new), ".*").prop_map(
+            |(contract_hash, entry_point)| CallStackElement::stored_contract(
+                contract_hash,
+                entry_point
+            )
+        ),
+    ]
+}
+
 pub fn named_args_arb() -> impl Strategy<Value = NamedArg> {
     (".*", cl_value_arb()).prop_map(|(name, value)| NamedArg::new(name, value))
 }
@@ -16,7 +16,7 @@ use rand::{
 use serde::{de::Error as SerdeError, Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{
-    account::{self, AccountHash, AccountHashBytes, TryFromSliceForAccountHashError},
+    account::{self, blake2b, AccountHash, AccountHashBytes, TryFromSliceForAccountHashError},
     bytesrepr::{self, Error, FromBytes, ToBytes},
     contract_wasm::ContractWasmHash,
     contracts::{ContractHash, ContractPackageHash},
@@ -32,15 +32,24 @@ const BALANCE_PREFIX: &str = "balance-";
 const BID_PREFIX: &str = "bid-";
 const WITHDRAW_PREFIX: &str = "withdraw-";
 const VALIDATORS_PREFIX: &str = "validator-era-";
+const DICTIONARY_PREFIX: &str = "dictionary-";
 
 /// The number of bytes in a Blake2b hash
 pub const BLAKE2B_DIGEST_LENGTH: usize = 32;
+/// The number of bytes in a SHA-256 hash
+pub const SHA256_DIGEST_LENGTH: usize = 32;
+/// The number of bytes in a Keccak-256 hash
+pub const KECCAK256_DIGEST_LENGTH: usize = 32;
 /// The number of bytes in a [`Key::Hash`].
 pub const KEY_HASH_LENGTH: usize = 32;
 /// The number of bytes in a [`Key::Transfer`].
 pub const KEY_TRANSFER_LENGTH: usize = TRANSFER_ADDR_LENGTH;
 /// The number of bytes in a [`Key::DeployInfo`].
 pub const KEY_DEPLOY_INFO_LENGTH: usize = DEPLOY_HASH_LENGTH;
+/// The number of bytes in a [`Key::Dictionary`].
+pub const KEY_DICTIONARY_LENGTH: usize = 32;
+/// The maximum number of bytes in a dictionary item key.
+pub const DICTIONARY_ITEM_KEY_MAX_LENGTH: usize = 64;
 
 const KEY_ID_SERIALIZED_LENGTH: usize = 1;
 // u8 used to determine the ID
@@ -55,10 +64,14 @@ const KEY_BID_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + KEY_HASH_LEN
 const KEY_WITHDRAW_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + KEY_HASH_LENGTH;
 const KEY_ERA_VALIDATORS_SERIALIZED_LENGTH: usize =
     KEY_ID_SERIALIZED_LENGTH + PaddedEraId::SERIALIZED_LENGTH;
+const KEY_DICTIONARY_SERIALIZED_LENGTH: usize = KEY_ID_SERIALIZED_LENGTH + KEY_DICTIONARY_LENGTH;
 
 /// An alias for [`Key`]s hash variant.
 pub type HashAddr = [u8; KEY_HASH_LENGTH];
 
+/// An alias for [`Key`]s dictionary variant.
+pub type DictionaryAddr = [u8; KEY_DICTIONARY_LENGTH];
+
 impl From<HashAddr> for Key {
     fn from(addr: HashAddr) -> Self {
         Key::Hash(addr)
@@ -79,6 +92,7 @@ pub enum KeyTag {
     Bid = 7,
     Withdraw = 8,
     EraValidators = 9,
+    Dictionary = 10,
 }
 
 /// The type under which data (e.g. [`CLValue`](crate::CLValue)s, smart contracts, user accounts)
@@ -107,6 +121,8 @@ pub enum Key {
     Withdraw(AccountHash),
     /// A `Key` under which we store validator information.
     EraValidators(EraId),
+    /// A `Key` under which we store a single item of a dictionary.
+    Dictionary(DictionaryAddr),
 }
 
 #[derive(Debug)]
@@ -187,6 +203,7 @@ impl Key {
             Key::Bid(_) => String::from("Key::Bid"),
             Key::Withdraw(_) => String::from("Key::Unbond"),
             Key::EraValidators(_) => String::from("Key::EraValidators"),
+            Key::Dictionary(_) => String::from("Key::Dictionary"),
         }
     }
 
@@ -234,6 +251,13 @@ impl Key {
             Key::EraValidators(era_id) => {
                 format!("{}{}", VALIDATORS_PREFIX, era_id.value())
             }
+            Key::Dictionary(dictionary_addr) => {
+                format!(
+                    "{}{}",
+                    DICTIONARY_PREFIX,
+                    base16::encode_lower(dictionary_addr)
+                )
+            }
         }
     }
 
@@ -269,6 +293,10 @@ impl Key {
             )?)))
         } else if let Some(era_id_str) = input.strip_prefix(VALIDATORS_PREFIX) {
             Ok(Key::EraValidators(EraId::from_str(era_id_str)?))
+        } else if let Some(hex) = input.strip_prefix(DICTIONARY_PREFIX) {
+            Ok(Key::Dictionary(DictionaryAddr::try_from(
+                base16::decode(hex)?.as_ref(),
+            )?))
         } else {
             Err(FromStrError::InvalidPrefix)
         }
@@ -309,6 +337,24 @@ impl Key {
         }
     }
 
+    /// Returns the inner bytes of `self` if `self` is of type [`Key::Dictionary`], otherwise
+    /// returns `None`.
+    pub fn into_dictionary(self) -> Option<DictionaryAddr> {
+        match self {
+            Key::Dictionary(dictionary_addr) => Some(dictionary_addr),
+            _ => None,
+        }
+    }
+
+    /// Creates a [`Key::Dictionary`] addressing the item stored under `dictionary_item_key` in
+    /// the dictionary identified by `seed_uref`.
+    pub fn dictionary(seed_uref: URef, dictionary_item_key: &[u8]) -> Key {
+        let mut preimage = Vec::with_capacity(UREF_ADDR_LENGTH + dictionary_item_key.len());
+        preimage.extend_from_slice(&seed_uref.addr());
+        preimage.extend_from_slice(dictionary_item_key);
+        Key::Dictionary(blake2b(preimage))
+    }
+
     /// Casts a [`Key::URef`] to a [`Key::Hash`]
     pub fn uref_to_hash(&self) -> Option<Key> {
         let uref = self.as_uref()?;
@@ -330,6 +376,9 @@ impl Display for Key {
             Key::Bid(account_hash) => write!(f, "Key::Bid({})", account_hash),
             Key::Withdraw(account_hash) => write!(f, "Key::Withdraw({})", account_hash),
             Key::EraValidators(era_id) => write!(f, "Key::EraValidators({})", era_id),
+            Key::Dictionary(dictionary_addr) => {
+                write!(f, "Key::Dictionary({})", HexFmt(dictionary_addr))
+            }
         }
     }
 }
@@ -353,6 +402,7 @@ impl Tagged<KeyTag> for Key {
             Key::Bid(_) => KeyTag::Bid,
             Key::Withdraw(_) => KeyTag::Withdraw,
             Key::EraValidators(_) => KeyTag::EraValidators,
+            Key::Dictionary(_) => KeyTag::Dictionary,
         }
     }
 }
@@ -472,6 +522,9 @@ impl ToBytes for Key {
             Key::EraValidators(era_id) => {
                 result.append(&mut PaddedEraId(*era_id).to_bytes()?);
             }
+            Key::Dictionary(dictionary_addr) => {
+                result.append(&mut dictionary_addr.to_bytes()?);
+            }
         }
         Ok(result)
     }
@@ -490,6 +543,7 @@ impl ToBytes for Key {
             Key::Bid(_) => KEY_BID_SERIALIZED_LENGTH,
             Key::Withdraw(_) => KEY_WITHDRAW_SERIALIZED_LENGTH,
             Key::EraValidators(_) => KEY_ERA_VALIDATORS_SERIALIZED_LENGTH,
+            Key::Dictionary(_) => KEY_DICTIONARY_SERIALIZED_LENGTH,
         }
     }
 }
@@ -538,6 +592,10 @@ impl FromBytes for Key {
                 let (era_id, rem) = PaddedEraId::from_bytes(remainder)?;
                 Ok((Key::EraValidators(era_id.into_inner()), rem))
             }
+            tag if tag == KeyTag::Dictionary as u8 => {
+                let (dictionary_addr, rem) = FromBytes::from_bytes(remainder)?;
+                Ok((Key::Dictionary(dictionary_addr), rem))
+            }
             _ => Err(Error::Formatting),
         }
     }
@@ -545,7 +603,7 @@ impl FromBytes for Key {
 
 impl Distribution<Key> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Key {
-        match rng.gen_range(0..8) {
+        match rng.gen_range(0..11) {
             0 => Key::Account(rng.gen()),
             1 => Key::Hash(rng.gen()),
             2 => Key::URef(rng.gen()),
@@ -556,6 +614,7 @@ impl Distribution<Key> for Standard {
             7 => Key::Bid(rng.gen()),
             8 => Key::Withdraw(rng.gen()),
             9 => Key::EraValidators(rng.gen()),
+            10 => Key::Dictionary(rng.gen()),
             _ => unreachable!(),
         }
     }
@@ -576,6 +635,7 @@ mod serde_helpers {
         Bid(String),
         Withdraw(String),
         EraValidators(String),
+        Dictionary(String),
     }
 
     impl From<&Key> for HumanReadable {
@@ -592,6 +652,7 @@ mod serde_helpers {
                 Key::Bid(_) => HumanReadable::Bid(formatted_string),
                 Key::Withdraw(_) => HumanReadable::Withdraw(formatted_string),
                 Key::EraValidators(_) => HumanReadable::EraValidators(formatted_string),
+                Key::Dictionary(_) => HumanReadable::Dictionary(formatted_string),
             }
         }
     }
@@ -610,7 +671,8 @@ mod serde_helpers {
                 | HumanReadable::Balance(formatted_string)
                 | HumanReadable::Bid(formatted_string)
                 | HumanReadable::Withdraw(formatted_string)
-                | HumanReadable::EraValidators(formatted_string) => {
+                | HumanReadable::EraValidators(formatted_string)
+                | HumanReadable::Dictionary(formatted_string) => {
                     Key::from_formatted_str(&formatted_string)
                 }
             }
@@ -629,6 +691,7 @@ mod serde_helpers {
         Bid(&'a AccountHash),
         Withdraw(&'a AccountHash),
         EraValidators(&'a EraId),
+        Dictionary(&'a DictionaryAddr),
     }
 
     impl<'a> From<&'a Key> for BinarySerHelper<'a> {
@@ -644,6 +707,7 @@ mod serde_helpers {
                 Key::Bid(account_hash) => BinarySerHelper::Bid(account_hash),
                 Key::Withdraw(account_hash) => BinarySerHelper::Withdraw(account_hash),
                 Key::EraValidators(era_id) => BinarySerHelper::EraValidators(era_id),
+                Key::Dictionary(dictionary_addr) => BinarySerHelper::Dictionary(dictionary_addr),
             }
         }
     }
@@ -660,6 +724,7 @@ mod serde_helpers {
         Bid(AccountHash),
         Withdraw(AccountHash),
         EraValidators(EraId),
+        Dictionary(DictionaryAddr),
     }
 
     impl From<BinaryDeserHelper> for Key {
@@ -675,6 +740,7 @@ mod serde_helpers {
                 BinaryDeserHelper::Bid(account_hash) => Key::Bid(account_hash),
                 BinaryDeserHelper::Withdraw(account_hash) => Key::Withdraw(account_hash),
                 BinaryDeserHelper::EraValidators(era_id) => Key::EraValidators(era_id),
+                BinaryDeserHelper::Dictionary(dictionary_addr) => Key::Dictionary(dictionary_addr),
             }
         }
     }
@@ -855,6 +921,9 @@ mod tests {
 
         let key_era_info = Key::EraInfo(EraId::from(42));
         assert!(key_era_info.serialized_length() <= Key::max_serialized_length());
+
+        let key_dictionary = Key::Dictionary([42; KEY_DICTIONARY_LENGTH]);
+        assert!(key_dictionary.serialized_length() <= Key::max_serialized_length());
     }
 
     fn to_string_round_trip(key: Key) {
@@ -874,6 +943,7 @@ mod tests {
         to_string_round_trip(Key::Transfer(TransferAddr::new([42; KEY_HASH_LENGTH])));
         to_string_round_trip(Key::DeployInfo(DeployHash::new([42; KEY_HASH_LENGTH])));
         to_string_round_trip(Key::EraInfo(EraId::from(42)));
+        to_string_round_trip(Key::Dictionary([42; KEY_DICTIONARY_LENGTH]));
 
         let invalid_prefix = "a-0000000000000000000000000000000000000000000000000000000000000000";
         assert!(Key::from_formatted_str(invalid_prefix).is_err());
@@ -937,6 +1007,12 @@ mod tests {
             serde_json::to_string(&key_validators_info).unwrap(),
             r#"{"EraValidators":"validator-era-42"}"#.to_string()
         );
+
+        let key_dictionary = Key::Dictionary(array);
+        assert_eq!(
+            serde_json::to_string(&key_dictionary).unwrap(),
+            format!(r#"{{"Dictionary":"dictionary-{}"}}"#, hex_bytes)
+        );
     }
 
     #[test]
@@ -958,6 +1034,7 @@ mod tests {
         round_trip(&Key::Balance(URef::new(array, AccessRights::READ).addr()));
         round_trip(&Key::Bid(AccountHash::new(array)));
         round_trip(&Key::Withdraw(AccountHash::new(array)));
+        round_trip(&Key::Dictionary(array));
     }
 
     #[test]
@@ -979,6 +1056,7 @@ mod tests {
         round_trip(&Key::Balance(URef::new(array, AccessRights::READ).addr()));
         round_trip(&Key::Withdraw(AccountHash::new(array)));
         round_trip(&Key::EraValidators(EraId::from(42)));
+        round_trip(&Key::Dictionary(array));
 
         let zeros = [0; BLAKE2B_DIGEST_LENGTH];
 
@@ -992,6 +1070,22 @@ mod tests {
         round_trip(&Key::Bid(AccountHash::new(zeros)));
         round_trip(&Key::Withdraw(AccountHash::new(zeros)));
         round_trip(&Key::EraValidators(EraId::from(0)));
+        round_trip(&Key::Dictionary(zeros));
+    }
+
+    #[test]
+    fn dictionary_key_should_depend_on_seed_uref_and_item_key() {
+        let seed_uref = URef::new([1; 32], AccessRights::READ_ADD_WRITE);
+        let other_seed_uref = URef::new([2; 32], AccessRights::READ_ADD_WRITE);
+
+        let key = Key::dictionary(seed_uref, b"item");
+        assert_eq!(key, Key::dictionary(seed_uref, b"item"));
+        assert_eq!(
+            key,
+            Key::dictionary(seed_uref.with_access_rights(AccessRights::READ), b"item")
+        );
+        assert_ne!(key, Key::dictionary(seed_uref, b"other item"));
+        assert_ne!(key, Key::dictionary(other_seed_uref, b"item"));
     }
 
     proptest! {
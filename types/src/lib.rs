@@ -28,18 +28,21 @@ pub mod account;
 pub mod api_error;
 mod block_time;
 pub mod bytesrepr;
+mod call_stack_element;
 mod cl_type;
 mod cl_value;
 mod contract_wasm;
 pub mod contracts;
 pub mod crypto;
 mod deploy_info;
+mod dictionary_value;
 mod era_id;
 mod execution_result;
 #[cfg(any(feature = "gens", test))]
 pub mod gens;
 mod json_pretty_printer;
 mod key;
+pub mod merkle_proof;
 mod named_key;
 mod phase;
 mod protocol_version;
@@ -56,6 +59,7 @@ pub use access_rights::{AccessRights, ACCESS_RIGHTS_SERIALIZED_LENGTH};
 #[doc(inline)]
 pub use api_error::ApiError;
 pub use block_time::{BlockTime, BLOCKTIME_SERIALIZED_LENGTH};
+pub use call_stack_element::CallStackElement;
 pub use cl_type::{named_key_type, CLType, CLTyped};
 pub use cl_value::{CLTypeMismatch, CLValue, CLValueError};
 pub use contract_wasm::{ContractWasm, ContractWasmHash};
@@ -66,12 +70,16 @@ pub use contracts::{
 };
 pub use crypto::*;
 pub use deploy_info::DeployInfo;
+pub use dictionary_value::DictionaryValue;
 pub use execution_result::{
-    ExecutionEffect, ExecutionResult, OpKind, Operation, Transform, TransformEntry,
+    ContractEvent, ExecutionEffect, ExecutionResult, OpKind, Operation, Transform, TransformEntry,
 };
 pub use json_pretty_printer::json_pretty_print;
 #[doc(inline)]
-pub use key::{HashAddr, Key, KeyTag, BLAKE2B_DIGEST_LENGTH, KEY_HASH_LENGTH};
+pub use key::{
+    DictionaryAddr, HashAddr, Key, KeyTag, BLAKE2B_DIGEST_LENGTH, DICTIONARY_ITEM_KEY_MAX_LENGTH,
+    KECCAK256_DIGEST_LENGTH, KEY_DICTIONARY_LENGTH, KEY_HASH_LENGTH, SHA256_DIGEST_LENGTH,
+};
 pub use named_key::NamedKey;
 pub use phase::{Phase, PHASE_SERIALIZED_LENGTH};
 pub use protocol_version::{ProtocolVersion, VersionCheckResult};
@@ -0,0 +1,16 @@
+//! Merkle proofs of entries held in global state, and functions to validate them.
+//!
+//! These types only depend on `casper_types`, so light clients and Wasm wallets can check the
+//! proofs returned by a node without depending on the execution engine.
+
+mod batch;
+mod blake2b_hash;
+mod trie_merkle_proof;
+mod validation;
+
+pub use batch::TrieMerkleProofBatch;
+pub use blake2b_hash::Blake2bHash;
+pub use trie_merkle_proof::{Pointer, TrieMerkleProof, TrieMerkleProofStep, RADIX};
+pub use validation::{
+    validate_balance_proof, validate_batch_proof, validate_query_proof, ProofValue, ValidationError,
+};
@@ -0,0 +1,152 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::convert::TryFrom;
+
+use super::{TrieMerkleProof, TrieMerkleProofStep};
+use crate::bytesrepr::{self, FromBytes, ToBytes};
+
+/// The key and value of a proven entry, along with the indices of its proof steps.
+type BatchEntry<K, V> = (K, V, Vec<u32>);
+
+/// A set of [`TrieMerkleProof`]s taken against the same state root hash.
+///
+/// Proofs of entries held close together in the trie share the steps nearest the root, so each
+/// distinct [`TrieMerkleProofStep`] is stored once and the individual proofs refer to it by its
+/// index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrieMerkleProofBatch<K, V> {
+    steps: Vec<TrieMerkleProofStep>,
+    entries: Vec<BatchEntry<K, V>>,
+}
+
+impl<K, V> TrieMerkleProofBatch<K, V> {
+    /// Creates a batch from the given proofs, de-duplicating their proof steps.
+    pub fn from_proofs(proofs: Vec<TrieMerkleProof<K, V>>) -> Result<Self, bytesrepr::Error> {
+        let mut steps = Vec::new();
+        let mut step_indices: BTreeMap<Vec<u8>, u32> = BTreeMap::new();
+        let mut entries = Vec::with_capacity(proofs.len());
+        for proof in proofs {
+            let (key, value, proof_steps) = proof.into_parts();
+            let mut indices = Vec::with_capacity(proof_steps.len());
+            for step in proof_steps {
+                let step_bytes = step.to_bytes()?;
+                let index = match step_indices.get(&step_bytes) {
+                    Some(index) => *index,
+                    None => {
+                        let index = u32::try_from(steps.len())
+                            .map_err(|_| bytesrepr::Error::OutOfMemory)?;
+                        step_indices.insert(step_bytes, index);
+                        steps.push(step);
+                        index
+                    }
+                };
+                indices.push(index);
+            }
+            entries.push((key, value, indices));
+        }
+        Ok(TrieMerkleProofBatch { steps, entries })
+    }
+
+    /// Returns the number of proofs held in the batch.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the batch holds no proofs.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the distinct proof steps shared by the proofs in the batch.
+    pub fn steps(&self) -> &[TrieMerkleProofStep] {
+        &self.steps
+    }
+}
+
+impl<K: Clone, V: Clone> TrieMerkleProofBatch<K, V> {
+    /// Returns the individual proofs held in the batch.
+    pub fn proofs(&self) -> Vec<TrieMerkleProof<K, V>> {
+        self.entries
+            .iter()
+            .map(|(key, value, indices)| {
+                let proof_steps = indices
+                    .iter()
+                    .map(|index| self.steps[*index as usize].clone())
+                    .collect();
+                TrieMerkleProof::new(key.clone(), value.clone(), proof_steps)
+            })
+            .collect()
+    }
+}
+
+impl<K, V> ToBytes for TrieMerkleProofBatch<K, V>
+where
+    K: ToBytes,
+    V: ToBytes,
+{
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret: Vec<u8> = bytesrepr::allocate_buffer(self)?;
+        ret.append(&mut self.steps.to_bytes()?);
+        ret.append(&mut self.entries.to_bytes()?);
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.steps.serialized_length() + self.entries.serialized_length()
+    }
+}
+
+impl<K, V> FromBytes for TrieMerkleProofBatch<K, V>
+where
+    K: FromBytes,
+    V: FromBytes,
+{
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (steps, rem): (Vec<TrieMerkleProofStep>, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (entries, rem): (Vec<BatchEntry<K, V>>, &[u8]) = FromBytes::from_bytes(rem)?;
+        let all_indices_valid = entries
+            .iter()
+            .all(|(_, _, indices)| indices.iter().all(|index| (*index as usize) < steps.len()));
+        if !all_indices_valid {
+            return Err(bytesrepr::Error::Formatting);
+        }
+        Ok((TrieMerkleProofBatch { steps, entries }, rem))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use proptest::{collection::vec, prelude::*};
+
+    use super::TrieMerkleProofBatch;
+    use crate::{
+        bytesrepr,
+        gens::{cl_value_arb, key_arb},
+        merkle_proof::{trie_merkle_proof::gens::trie_merkle_proof_step_arb, TrieMerkleProof},
+    };
+
+    proptest! {
+        #[test]
+        fn batch_should_round_trip_proofs(
+            shared_steps in vec(trie_merkle_proof_step_arb(), 3),
+            entries in vec((key_arb(), cl_value_arb(), vec(trie_merkle_proof_step_arb(), 2)), 4)
+        ) {
+            let proofs: Vec<_> = entries
+                .into_iter()
+                .map(|(key, value, own_steps)| {
+                    let proof_steps = own_steps
+                        .into_iter()
+                        .chain(shared_steps.iter().cloned())
+                        .collect();
+                    TrieMerkleProof::new(key, value, proof_steps)
+                })
+                .collect();
+
+            let batch = TrieMerkleProofBatch::from_proofs(proofs.clone()).unwrap();
+            prop_assert!(batch.steps().len() <= 3 + 4 * 2);
+            prop_assert_eq!(batch.proofs(), proofs);
+            bytesrepr::test_serialization_roundtrip(&batch);
+        }
+    }
+}
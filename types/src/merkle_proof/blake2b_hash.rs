@@ -1,5 +1,5 @@
-/// The number of bytes in a Blake2b hash
-use std::{array::TryFromSliceError, convert::TryFrom};
+use alloc::vec::Vec;
+use core::{array::TryFromSliceError, convert::TryFrom};
 
 use blake2::{
     digest::{Update, VariableOutput},
@@ -7,13 +7,14 @@ use blake2::{
 };
 use serde::{Deserialize, Serialize};
 
-use casper_types::bytesrepr::{self, FromBytes, ToBytes};
+use crate::bytesrepr::{self, FromBytes, ToBytes};
 
 /// Represents a 32-byte BLAKE2b hash digest
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize)]
 pub struct Blake2bHash([u8; Blake2bHash::LENGTH]);
 
 impl Blake2bHash {
+    /// The number of bytes in a Blake2b hash
     pub const LENGTH: usize = 32;
 
     /// Creates a 32-byte BLAKE2b hash digest from a given a piece of data
@@ -91,9 +92,9 @@ impl AsRef<[u8]> for Blake2bHash {
     }
 }
 
-impl Into<[u8; Blake2bHash::LENGTH]> for Blake2bHash {
-    fn into(self) -> [u8; Blake2bHash::LENGTH] {
-        self.0
+impl From<Blake2bHash> for [u8; Blake2bHash::LENGTH] {
+    fn from(hash: Blake2bHash) -> Self {
+        hash.0
     }
 }
 
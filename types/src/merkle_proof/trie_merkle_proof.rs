@@ -0,0 +1,423 @@
+use alloc::{collections::VecDeque, vec::Vec};
+
+use serde::{Deserialize, Serialize};
+
+use super::Blake2bHash;
+use crate::bytesrepr::{self, Bytes, FromBytes, ToBytes, U8_SERIALIZED_LENGTH};
+
+/// The number of children of a node in the Merkle trie.
+pub const RADIX: usize = 256;
+
+const TRIE_LEAF_TAG: u8 = 0;
+const TRIE_NODE_TAG: u8 = 1;
+const TRIE_EXTENSION_TAG: u8 = 2;
+
+const POINTER_LEAF_TAG: u8 = 0;
+const POINTER_NODE_TAG: u8 = 1;
+
+const TRIE_MERKLE_PROOF_STEP_NODE_ID: u8 = 0;
+const TRIE_MERKLE_PROOF_STEP_EXTENSION_ID: u8 = 1;
+
+/// Represents a pointer to the next object in a Merkle Trie
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Pointer {
+    /// A pointer to a leaf.
+    LeafPointer(Blake2bHash),
+    /// A pointer to a node or an extension.
+    NodePointer(Blake2bHash),
+}
+
+impl Pointer {
+    /// Returns the hash this pointer refers to.
+    pub fn hash(&self) -> &Blake2bHash {
+        match self {
+            Pointer::LeafPointer(hash) => hash,
+            Pointer::NodePointer(hash) => hash,
+        }
+    }
+
+    /// Consumes the pointer, returning the hash it refers to.
+    pub fn into_hash(self) -> Blake2bHash {
+        match self {
+            Pointer::LeafPointer(hash) => hash,
+            Pointer::NodePointer(hash) => hash,
+        }
+    }
+
+    /// Returns a pointer of the same kind referring to `hash`.
+    pub fn update(&self, hash: Blake2bHash) -> Self {
+        match self {
+            Pointer::LeafPointer(_) => Pointer::LeafPointer(hash),
+            Pointer::NodePointer(_) => Pointer::NodePointer(hash),
+        }
+    }
+
+    fn tag(&self) -> u8 {
+        match self {
+            Pointer::LeafPointer(_) => POINTER_LEAF_TAG,
+            Pointer::NodePointer(_) => POINTER_NODE_TAG,
+        }
+    }
+}
+
+impl ToBytes for Pointer {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret = bytesrepr::unchecked_allocate_buffer(self);
+        ret.push(self.tag());
+        ret.extend_from_slice(self.hash().as_ref());
+        Ok(ret)
+    }
+
+    #[inline(always)]
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH + Blake2bHash::LENGTH
+    }
+}
+
+impl FromBytes for Pointer {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, rem) = u8::from_bytes(bytes)?;
+        match tag {
+            POINTER_LEAF_TAG => {
+                let (hash, rem) = Blake2bHash::from_bytes(rem)?;
+                Ok((Pointer::LeafPointer(hash), rem))
+            }
+            POINTER_NODE_TAG => {
+                let (hash, rem) = Blake2bHash::from_bytes(rem)?;
+                Ok((Pointer::NodePointer(hash), rem))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+/// A component of a proof that an entry exists in the Merkle trie.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrieMerkleProofStep {
+    /// Corresponds to `Trie::Node`
+    Node {
+        /// The index of the child on the path to the proven entry.
+        hole_index: u8,
+        /// The remaining children of the node.
+        indexed_pointers_with_hole: Vec<(u8, Pointer)>,
+    },
+    /// Corresponds to `Trie::Extension`
+    Extension {
+        /// The shared key prefix of the extension.
+        affix: Bytes,
+    },
+}
+
+impl TrieMerkleProofStep {
+    /// Constructor for  [`TrieMerkleProofStep::Node`]
+    pub fn node(hole_index: u8, indexed_pointers_with_hole: Vec<(u8, Pointer)>) -> Self {
+        Self::Node {
+            hole_index,
+            indexed_pointers_with_hole,
+        }
+    }
+
+    /// Constructor for  [`TrieMerkleProofStep::Extension`]
+    pub fn extension(affix: Vec<u8>) -> Self {
+        Self::Extension {
+            affix: affix.into(),
+        }
+    }
+
+    /// Returns the serialized `Trie` which this step represents once the hole on the proven path
+    /// is filled with `pointer`.
+    fn trie_bytes(&self, pointer: Pointer) -> Result<Vec<u8>, bytesrepr::Error> {
+        match self {
+            TrieMerkleProofStep::Node {
+                hole_index,
+                indexed_pointers_with_hole,
+            } => {
+                let mut pointer_block: [Option<Pointer>; RADIX] = [None; RADIX];
+                for (index, indexed_pointer) in indexed_pointers_with_hole.iter() {
+                    pointer_block[*index as usize] = Some(*indexed_pointer);
+                }
+                pointer_block[*hole_index as usize] = Some(pointer);
+
+                let mut ret = Vec::new();
+                ret.push(TRIE_NODE_TAG);
+                for maybe_pointer in pointer_block.iter() {
+                    ret.append(&mut maybe_pointer.to_bytes()?);
+                }
+                Ok(ret)
+            }
+            TrieMerkleProofStep::Extension { affix } => {
+                let mut ret = Vec::new();
+                ret.push(TRIE_EXTENSION_TAG);
+                ret.append(&mut affix.to_bytes()?);
+                ret.append(&mut pointer.to_bytes()?);
+                Ok(ret)
+            }
+        }
+    }
+}
+
+impl ToBytes for TrieMerkleProofStep {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret: Vec<u8> = bytesrepr::allocate_buffer(self)?;
+        match self {
+            TrieMerkleProofStep::Node {
+                hole_index,
+                indexed_pointers_with_hole,
+            } => {
+                ret.push(TRIE_MERKLE_PROOF_STEP_NODE_ID);
+                ret.push(*hole_index);
+                ret.append(&mut indexed_pointers_with_hole.to_bytes()?)
+            }
+            TrieMerkleProofStep::Extension { affix } => {
+                ret.push(TRIE_MERKLE_PROOF_STEP_EXTENSION_ID);
+                ret.append(&mut affix.to_bytes()?)
+            }
+        };
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + match self {
+                TrieMerkleProofStep::Node {
+                    hole_index,
+                    indexed_pointers_with_hole,
+                } => {
+                    (*hole_index).serialized_length()
+                        + (*indexed_pointers_with_hole).serialized_length()
+                }
+                TrieMerkleProofStep::Extension { affix } => affix.serialized_length(),
+            }
+    }
+}
+
+impl FromBytes for TrieMerkleProofStep {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, rem): (u8, &[u8]) = FromBytes::from_bytes(bytes)?;
+        match tag {
+            TRIE_MERKLE_PROOF_STEP_NODE_ID => {
+                let (hole_index, rem): (u8, &[u8]) = FromBytes::from_bytes(rem)?;
+                let (indexed_pointers_with_hole, rem): (Vec<(u8, Pointer)>, &[u8]) =
+                    FromBytes::from_bytes(rem)?;
+                Ok((
+                    TrieMerkleProofStep::Node {
+                        hole_index,
+                        indexed_pointers_with_hole,
+                    },
+                    rem,
+                ))
+            }
+            TRIE_MERKLE_PROOF_STEP_EXTENSION_ID => {
+                let (affix, rem): (_, &[u8]) = FromBytes::from_bytes(rem)?;
+                Ok((TrieMerkleProofStep::Extension { affix }, rem))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+/// A proof that a node with a specified `key` and `value` is present in the Merkle trie.
+/// Given a state hash `x`, one can validate a proof `p` by checking `x == p.compute_state_hash()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrieMerkleProof<K, V> {
+    key: K,
+    value: V,
+    proof_steps: VecDeque<TrieMerkleProofStep>,
+}
+
+impl<K, V> TrieMerkleProof<K, V> {
+    /// Constructor for [`TrieMerkleProof`]
+    pub fn new(key: K, value: V, proof_steps: VecDeque<TrieMerkleProofStep>) -> Self {
+        TrieMerkleProof {
+            key,
+            value,
+            proof_steps,
+        }
+    }
+
+    /// Getter for the key in [`TrieMerkleProof`]
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Getter for the value in [`TrieMerkleProof`]
+    pub fn value(&self) -> &V {
+        &self.value
+    }
+
+    /// Getter for the proof steps in [`TrieMerkleProof`]
+    pub fn proof_steps(&self) -> &VecDeque<TrieMerkleProofStep> {
+        &self.proof_steps
+    }
+
+    /// Transforms a [`TrieMerkleProof`] into the value it contains
+    pub fn into_value(self) -> V {
+        self.value
+    }
+
+    /// Transforms a [`TrieMerkleProof`] into its key, value and proof steps.
+    pub fn into_parts(self) -> (K, V, VecDeque<TrieMerkleProofStep>) {
+        (self.key, self.value, self.proof_steps)
+    }
+}
+
+impl<K, V> TrieMerkleProof<K, V>
+where
+    K: ToBytes,
+    V: ToBytes,
+{
+    /// Recomputes a state root hash from a [`TrieMerkleProof`].
+    /// This is done in the following steps:
+    ///
+    /// 1. Using [`TrieMerkleProof::key`] and [`TrieMerkleProof::value`], construct a leaf and
+    /// compute a hash for that leaf.
+    ///
+    /// 2. We then iterate over [`TrieMerkleProof::proof_steps`] left to right, using the hash from
+    /// the previous step combined with the next step to compute a new hash.
+    ///
+    /// 3. When there are no more steps, we return the final hash we have computed.
+    ///
+    /// The steps in this function reflect `operations::rehash` in the execution engine.
+    pub fn compute_state_hash(&self) -> Result<Blake2bHash, bytesrepr::Error> {
+        let mut hash = {
+            let mut leaf_bytes = Vec::new();
+            leaf_bytes.push(TRIE_LEAF_TAG);
+            leaf_bytes.append(&mut self.key.to_bytes()?);
+            leaf_bytes.append(&mut self.value.to_bytes()?);
+            Blake2bHash::new(&leaf_bytes)
+        };
+
+        for (proof_step_index, proof_step) in self.proof_steps.iter().enumerate() {
+            let pointer = if proof_step_index == 0 {
+                Pointer::LeafPointer(hash)
+            } else {
+                Pointer::NodePointer(hash)
+            };
+            hash = Blake2bHash::new(&proof_step.trie_bytes(pointer)?);
+        }
+        Ok(hash)
+    }
+}
+
+impl<K, V> ToBytes for TrieMerkleProof<K, V>
+where
+    K: ToBytes,
+    V: ToBytes,
+{
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut ret: Vec<u8> = bytesrepr::allocate_buffer(self)?;
+        ret.append(&mut self.key.to_bytes()?);
+        ret.append(&mut self.value.to_bytes()?);
+        ret.append(&mut self.proof_steps.to_bytes()?);
+        Ok(ret)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.key.serialized_length()
+            + self.value.serialized_length()
+            + self.proof_steps.serialized_length()
+    }
+}
+
+impl<K, V> FromBytes for TrieMerkleProof<K, V>
+where
+    K: FromBytes,
+    V: FromBytes,
+{
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (key, rem): (K, &[u8]) = FromBytes::from_bytes(bytes)?;
+        let (value, rem): (V, &[u8]) = FromBytes::from_bytes(rem)?;
+        let (proof_steps, rem): (VecDeque<TrieMerkleProofStep>, &[u8]) =
+            FromBytes::from_bytes(rem)?;
+        Ok((
+            TrieMerkleProof {
+                key,
+                value,
+                proof_steps,
+            },
+            rem,
+        ))
+    }
+}
+
+#[cfg(test)]
+pub(super) mod gens {
+    use proptest::{collection::vec, prelude::*};
+
+    use super::{Pointer, TrieMerkleProof, TrieMerkleProofStep, RADIX};
+    use crate::{
+        gens::{cl_value_arb, key_arb, u8_slice_32},
+        CLValue, Key,
+    };
+
+    const POINTERS_SIZE: usize = RADIX / 8;
+    const AFFIX_SIZE: usize = 6;
+    const STEPS_SIZE: usize = 6;
+
+    pub fn pointer_arb() -> impl Strategy<Value = Pointer> {
+        prop_oneof![
+            u8_slice_32().prop_map(|hash| Pointer::LeafPointer(hash.into())),
+            u8_slice_32().prop_map(|hash| Pointer::NodePointer(hash.into()))
+        ]
+    }
+
+    pub fn trie_merkle_proof_step_arb() -> impl Strategy<Value = TrieMerkleProofStep> {
+        prop_oneof![
+            (
+                <u8>::arbitrary(),
+                vec((<u8>::arbitrary(), pointer_arb()), POINTERS_SIZE)
+            )
+                .prop_map(|(hole_index, indexed_pointers_with_hole)| {
+                    TrieMerkleProofStep::Node {
+                        hole_index,
+                        indexed_pointers_with_hole,
+                    }
+                }),
+            vec(<u8>::arbitrary(), AFFIX_SIZE).prop_map(|affix| {
+                TrieMerkleProofStep::Extension {
+                    affix: affix.into(),
+                }
+            })
+        ]
+    }
+
+    pub fn trie_merkle_proof_arb() -> impl Strategy<Value = TrieMerkleProof<Key, CLValue>> {
+        (
+            key_arb(),
+            cl_value_arb(),
+            vec(trie_merkle_proof_step_arb(), STEPS_SIZE),
+        )
+            .prop_map(|(key, value, proof_steps)| {
+                TrieMerkleProof::new(key, value, proof_steps.into())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::gens;
+    use crate::bytesrepr;
+
+    proptest! {
+        #[test]
+        fn pointer_serialization_is_correct(pointer in gens::pointer_arb()) {
+            bytesrepr::test_serialization_roundtrip(&pointer)
+        }
+
+        #[test]
+        fn trie_merkle_proof_step_serialization_is_correct(
+            step in gens::trie_merkle_proof_step_arb()
+        ) {
+            bytesrepr::test_serialization_roundtrip(&step)
+        }
+
+        #[test]
+        fn trie_merkle_proof_serialization_is_correct(
+            proof in gens::trie_merkle_proof_arb()
+        ) {
+            bytesrepr::test_serialization_roundtrip(&proof)
+        }
+    }
+}
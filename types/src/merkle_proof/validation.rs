@@ -0,0 +1,319 @@
+use alloc::string::String;
+
+#[cfg(feature = "std")]
+use thiserror::Error;
+
+use super::{Blake2bHash, TrieMerkleProof, TrieMerkleProofBatch};
+use crate::{
+    bytesrepr::{self, ToBytes},
+    contracts::NamedKeys,
+    CLValue, CLValueError, Key, U512,
+};
+
+/// A value held in global state whose proofs can be checked by the validation functions of this
+/// module.
+pub trait ProofValue: ToBytes + PartialEq {
+    /// Returns the named keys held by the value, if it is an account or a contract.
+    fn named_keys(&self) -> Option<&NamedKeys>;
+
+    /// Returns the value as a [`CLValue`], if it is one.
+    fn as_cl_value(&self) -> Option<&CLValue>;
+}
+
+/// Error validating a [`TrieMerkleProof`].
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Error))]
+pub enum ValidationError {
+    /// The path should not have a different length than the proof less one.
+    #[cfg_attr(
+        feature = "std",
+        error("The path should not have a different length than the proof less one.")
+    )]
+    PathLengthDifferentThanProofLessOne,
+
+    /// The provided key does not match the key in the proof.
+    #[cfg_attr(
+        feature = "std",
+        error("The provided key does not match the key in the proof.")
+    )]
+    UnexpectedKey,
+
+    /// The provided value does not match the value in the proof.
+    #[cfg_attr(
+        feature = "std",
+        error("The provided value does not match the value in the proof.")
+    )]
+    UnexpectedValue,
+
+    /// The proof hash is invalid.
+    #[cfg_attr(feature = "std", error("The proof hash is invalid."))]
+    InvalidProofHash,
+
+    /// The path went cold.
+    #[cfg_attr(feature = "std", error("The path went cold."))]
+    PathCold,
+
+    /// Serialization error.
+    #[cfg_attr(feature = "std", error("Serialization error: {0}"))]
+    BytesRepr(bytesrepr::Error),
+
+    /// Key is not a URef.
+    #[cfg_attr(feature = "std", error("Key is not a URef"))]
+    KeyIsNotAURef(Key),
+
+    /// Failed to convert stored value to key.
+    #[cfg_attr(feature = "std", error("Failed to convert stored value to key"))]
+    ValueToCLValueConversion,
+
+    /// Error relating to a [`CLValue`].
+    #[cfg_attr(feature = "std", error("{0}"))]
+    CLValueError(CLValueError),
+}
+
+impl From<CLValueError> for ValidationError {
+    fn from(err: CLValueError) -> Self {
+        ValidationError::CLValueError(err)
+    }
+}
+
+impl From<bytesrepr::Error> for ValidationError {
+    fn from(error: bytesrepr::Error) -> Self {
+        Self::BytesRepr(error)
+    }
+}
+
+/// Validates the proofs returned by a query of global state.
+///
+/// `proofs` must hold one proof for `expected_first_key` followed by one proof for each of the
+/// named keys listed in `path`, the last of which must prove `expected_value`.
+pub fn validate_query_proof<V: ProofValue>(
+    hash: &Blake2bHash,
+    proofs: &[TrieMerkleProof<Key, V>],
+    expected_first_key: &Key,
+    path: &[String],
+    expected_value: &V,
+) -> Result<(), ValidationError> {
+    if proofs.len() != path.len() + 1 {
+        return Err(ValidationError::PathLengthDifferentThanProofLessOne);
+    }
+
+    let mut proofs_iter = proofs.iter();
+
+    // length check above means we are safe to unwrap here
+    let first_proof = proofs_iter.next().unwrap();
+
+    if first_proof.key() != &expected_first_key.normalize() {
+        return Err(ValidationError::UnexpectedKey);
+    }
+
+    if hash != &first_proof.compute_state_hash()? {
+        return Err(ValidationError::InvalidProofHash);
+    }
+
+    let mut proof_value = first_proof.value();
+
+    for (proof, path_component) in proofs_iter.zip(path.iter()) {
+        let named_keys = match proof_value.named_keys() {
+            Some(named_keys) => named_keys,
+            None => return Err(ValidationError::PathCold),
+        };
+
+        let key = match named_keys.get(path_component) {
+            Some(key) => key,
+            None => return Err(ValidationError::PathCold),
+        };
+
+        if proof.key() != &key.normalize() {
+            return Err(ValidationError::UnexpectedKey);
+        }
+
+        if hash != &proof.compute_state_hash()? {
+            return Err(ValidationError::InvalidProofHash);
+        }
+
+        proof_value = proof.value();
+    }
+
+    if proof_value != expected_value {
+        return Err(ValidationError::UnexpectedValue);
+    }
+
+    Ok(())
+}
+
+/// Validates the proof of a purse's balance.
+pub fn validate_balance_proof<V: ProofValue>(
+    hash: &Blake2bHash,
+    balance_proof: &TrieMerkleProof<Key, V>,
+    expected_purse_key: Key,
+    expected_motes: &U512,
+) -> Result<(), ValidationError> {
+    let expected_balance_key = expected_purse_key
+        .into_uref()
+        .map(|uref| Key::Balance(uref.addr()))
+        .ok_or(ValidationError::KeyIsNotAURef(expected_purse_key))?;
+
+    if balance_proof.key() != &expected_balance_key.normalize() {
+        return Err(ValidationError::UnexpectedKey);
+    }
+
+    if hash != &balance_proof.compute_state_hash()? {
+        return Err(ValidationError::InvalidProofHash);
+    }
+
+    let balance_proof_clvalue: CLValue = balance_proof
+        .value()
+        .as_cl_value()
+        .cloned()
+        .ok_or(ValidationError::ValueToCLValueConversion)?;
+
+    let balance_motes: U512 = balance_proof_clvalue.into_t()?;
+
+    if expected_motes != &balance_motes {
+        return Err(ValidationError::UnexpectedValue);
+    }
+
+    Ok(())
+}
+
+/// Validates that every proof held in `batch` was taken against the state root `hash`.
+pub fn validate_batch_proof<V: ProofValue + Clone>(
+    hash: &Blake2bHash,
+    batch: &TrieMerkleProofBatch<Key, V>,
+) -> Result<(), ValidationError> {
+    for proof in batch.proofs() {
+        if proof.key() != &proof.key().normalize() {
+            return Err(ValidationError::UnexpectedKey);
+        }
+        if hash != &proof.compute_state_hash()? {
+            return Err(ValidationError::InvalidProofHash);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::VecDeque, string::ToString, vec, vec::Vec};
+
+    use super::*;
+    use crate::{
+        merkle_proof::{Pointer, TrieMerkleProofStep},
+        URef,
+    };
+
+    impl ProofValue for CLValue {
+        fn named_keys(&self) -> Option<&NamedKeys> {
+            None
+        }
+
+        fn as_cl_value(&self) -> Option<&CLValue> {
+            Some(self)
+        }
+    }
+
+    fn balance_proof(
+        purse: URef,
+        motes: u64,
+        proof_steps: VecDeque<TrieMerkleProofStep>,
+    ) -> TrieMerkleProof<Key, CLValue> {
+        TrieMerkleProof::new(
+            Key::Balance(purse.addr()),
+            CLValue::from_t(U512::from(motes)).unwrap(),
+            proof_steps,
+        )
+    }
+
+    fn sibling_step(hole_index: u8) -> TrieMerkleProofStep {
+        let sibling = Pointer::LeafPointer(Blake2bHash::new(&[hole_index]));
+        TrieMerkleProofStep::node(hole_index, vec![(hole_index.wrapping_add(1), sibling)])
+    }
+
+    #[test]
+    fn should_validate_balance_proof() {
+        let purse = URef::new([1; 32], Default::default());
+        let proof = balance_proof(purse, 42, vec![sibling_step(3)].into());
+        let hash = proof.compute_state_hash().unwrap();
+
+        assert_eq!(
+            validate_balance_proof(&hash, &proof, Key::URef(purse), &U512::from(42)),
+            Ok(())
+        );
+        assert_eq!(
+            validate_balance_proof(&hash, &proof, Key::URef(purse), &U512::from(43)),
+            Err(ValidationError::UnexpectedValue)
+        );
+        assert_eq!(
+            validate_balance_proof(&Blake2bHash::new(&[]), &proof, Key::URef(purse), &42.into()),
+            Err(ValidationError::InvalidProofHash)
+        );
+        assert_eq!(
+            validate_balance_proof(&hash, &proof, Key::Hash([1; 32]), &U512::from(42)),
+            Err(ValidationError::KeyIsNotAURef(Key::Hash([1; 32])))
+        );
+    }
+
+    #[test]
+    fn should_not_follow_path_through_cl_value() {
+        let key = Key::Hash([2; 32]);
+        let value = CLValue::from_t(1u8).unwrap();
+        let proof = TrieMerkleProof::new(key, value.clone(), VecDeque::new());
+        let hash = proof.compute_state_hash().unwrap();
+
+        assert_eq!(
+            validate_query_proof(&hash, &[proof.clone()], &key, &[], &value),
+            Ok(())
+        );
+        assert_eq!(
+            validate_query_proof(
+                &hash,
+                &[proof.clone(), proof],
+                &key,
+                &["name".to_string()],
+                &value
+            ),
+            Err(ValidationError::PathCold)
+        );
+    }
+
+    #[test]
+    fn should_validate_batch_proof() {
+        // Two leaves hanging off the same root node.
+        let purse_1 = URef::new([1; 32], Default::default());
+        let purse_2 = URef::new([2; 32], Default::default());
+        let leaf_1 = balance_proof(purse_1, 1, VecDeque::new());
+        let leaf_2 = balance_proof(purse_2, 2, VecDeque::new());
+        let pointer_1 = Pointer::LeafPointer(leaf_1.compute_state_hash().unwrap());
+        let pointer_2 = Pointer::LeafPointer(leaf_2.compute_state_hash().unwrap());
+
+        let proof_1 = balance_proof(
+            purse_1,
+            1,
+            vec![TrieMerkleProofStep::node(1, vec![(2, pointer_2)])].into(),
+        );
+        let proof_2 = balance_proof(
+            purse_2,
+            2,
+            vec![TrieMerkleProofStep::node(2, vec![(1, pointer_1)])].into(),
+        );
+        let hash = proof_1.compute_state_hash().unwrap();
+        assert_eq!(hash, proof_2.compute_state_hash().unwrap());
+
+        let batch = TrieMerkleProofBatch::from_proofs(vec![proof_1, proof_2]).unwrap();
+        assert_eq!(validate_batch_proof(&hash, &batch), Ok(()));
+
+        let tampered: Vec<_> = batch
+            .proofs()
+            .into_iter()
+            .map(|proof| {
+                let (key, _, steps) = proof.into_parts();
+                TrieMerkleProof::new(key, CLValue::from_t(U512::from(3)).unwrap(), steps)
+            })
+            .collect();
+        let tampered = TrieMerkleProofBatch::from_proofs(tampered).unwrap();
+        assert_eq!(
+            validate_batch_proof(&hash, &tampered),
+            Err(ValidationError::InvalidProofHash)
+        );
+    }
+}